@@ -0,0 +1,175 @@
+//! A blocking Blips client, for using the Blips API without an async runtime.
+
+use std::future::Future;
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::runtime::{GraphQLQuery, QueryBody};
+use crate::{BlipsError, CsrfToken, SessionCookie};
+
+/// The blocking Blips client.
+///
+/// The client runs the operations of an async [`crate::BlipsClient`] to completion on a
+/// runtime of its own, so both clients build requests and map errors the same way. Its
+/// methods must not be called from within an async runtime.
+pub struct BlipsClient {
+    client: crate::BlipsClient,
+    runtime: tokio::runtime::Runtime,
+}
+
+impl BlipsClient {
+    /// Returns a new instance of the blocking Blips client using the provided session token.
+    pub fn new(session_cookie: &SessionCookie, csrf_token: &CsrfToken) -> Self {
+        Self::from_client(crate::BlipsClient::new(session_cookie, csrf_token))
+    }
+
+    /// Returns a new instance of the blocking Blips client configured from environment
+    /// variables.
+    ///
+    /// See [`crate::BlipsClient::from_env`] for the variables that are read.
+    pub fn from_env() -> Result<Self, BlipsError> {
+        Ok(Self::from_client(crate::BlipsClient::from_env()?))
+    }
+
+    /// Returns a blocking client that executes operations using the provided async client.
+    pub fn from_client(client: crate::BlipsClient) -> Self {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+
+        Self { client, runtime }
+    }
+
+    /// Returns the async client that executes the operations.
+    pub fn client(&self) -> &crate::BlipsClient {
+        &self.client
+    }
+
+    /// Executes the GraphQL operation `Q` with the provided variables.
+    ///
+    /// See [`crate::BlipsClient::execute`].
+    pub fn execute<Q: GraphQLQuery>(
+        &self,
+        variables: Q::Variables,
+    ) -> Result<Q::ResponseData, BlipsError> {
+        self.block_on(self.client.execute::<Q>(variables))
+    }
+
+    /// Executes the GraphQL operation `Q` with the provided variables and returns its data
+    /// along with the timings of the request.
+    ///
+    /// See [`crate::BlipsClient::execute_with_metrics`].
+    #[cfg(feature = "metrics")]
+    pub fn execute_with_metrics<Q: GraphQLQuery>(
+        &self,
+        variables: Q::Variables,
+    ) -> Result<(Q::ResponseData, crate::RequestMetrics), BlipsError> {
+        self.block_on(self.client.execute_with_metrics::<Q>(variables))
+    }
+
+    /// Executes the GraphQL operation `Q` with the provided variables and returns its data,
+    /// along with the data as the server sent it.
+    ///
+    /// See [`crate::BlipsClient::execute_with_raw`].
+    pub fn execute_with_raw<Q: GraphQLQuery>(
+        &self,
+        variables: Q::Variables,
+    ) -> Result<(Q::ResponseData, serde_json::Value), BlipsError> {
+        self.block_on(self.client.execute_with_raw::<Q>(variables))
+    }
+
+    /// Executes the GraphQL operation `Q` with the provided variables and returns its data,
+    /// or `None` when the response has no data and its empty data is allowed.
+    ///
+    /// See [`crate::BlipsClient::execute_optional`].
+    pub fn execute_optional<Q: GraphQLQuery>(
+        &self,
+        variables: Q::Variables,
+    ) -> Result<Option<Q::ResponseData>, BlipsError> {
+        self.block_on(self.client.execute_optional::<Q>(variables))
+    }
+
+    /// Executes the GraphQL operation `Q` with the provided variables, but only if the
+    /// entity it targets still has the given ETag or version.
+    ///
+    /// See [`crate::BlipsClient::execute_if_match`].
+    pub fn execute_if_match<Q: GraphQLQuery>(
+        &self,
+        variables: Q::Variables,
+        etag: &str,
+    ) -> Result<Q::ResponseData, BlipsError> {
+        self.block_on(self.client.execute_if_match::<Q>(variables, etag))
+    }
+
+    /// Executes the GraphQL operation `Q` with the provided variables, selecting only the
+    /// given fields of its fragment.
+    ///
+    /// See [`crate::BlipsClient::execute_partial`].
+    pub fn execute_partial<Q: crate::PartialQuery>(
+        &self,
+        variables: Q::Variables,
+        fields: &[Q::Field],
+    ) -> Result<Q::Partial, BlipsError> {
+        self.block_on(self.client.execute_partial::<Q>(variables, fields))
+    }
+
+    /// Executes the GraphQL mutation `Q` with the provided changeset, which only sends the
+    /// arguments that are set.
+    ///
+    /// See [`crate::BlipsClient::execute_changeset`].
+    pub fn execute_changeset<Q: crate::ChangesetQuery>(
+        &self,
+        changeset: Q::Changeset,
+    ) -> Result<Q::ResponseData, BlipsError> {
+        self.block_on(self.client.execute_changeset::<Q>(changeset))
+    }
+
+    /// Executes the generated operation with the given name, with variables given as JSON.
+    ///
+    /// See [`crate::BlipsClient::execute_named`].
+    pub fn execute_named(
+        &self,
+        name: &str,
+        variables: serde_json::Value,
+    ) -> Result<serde_json::Value, BlipsError> {
+        self.block_on(self.client.execute_named(name, variables))
+    }
+
+    /// Sends a request with the given body and returns its data deserialized into `T`.
+    ///
+    /// See [`crate::BlipsClient::send_query_body`].
+    pub fn send_query_body<V: Serialize, T: DeserializeOwned>(
+        &self,
+        body: &QueryBody<V>,
+    ) -> Result<T, BlipsError> {
+        self.block_on(self.client.send_query_body(body))
+    }
+
+    /// Opens a connection to the endpoint ahead of time.
+    ///
+    /// See [`crate::BlipsClient::warm_up`].
+    pub fn warm_up(&self) -> Result<(), BlipsError> {
+        self.block_on(self.client.warm_up())
+    }
+
+    /// Returns the version of the SDK the client was compiled from.
+    ///
+    /// See [`crate::BlipsClient::sdk_version`].
+    pub fn sdk_version() -> &'static str {
+        crate::BlipsClient::sdk_version()
+    }
+
+    /// Queries the version of the server, if its schema exposes one.
+    ///
+    /// See [`crate::BlipsClient::server_version`].
+    pub fn server_version(&self) -> Result<Option<String>, BlipsError> {
+        self.block_on(self.client.server_version())
+    }
+
+    /// Runs the future to completion on the runtime of the client.
+    pub(crate) fn block_on<F: Future>(&self, future: F) -> F::Output {
+        self.runtime.block_on(future)
+    }
+}
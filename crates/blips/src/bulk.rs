@@ -0,0 +1,137 @@
+use futures_util::stream::{self, StreamExt};
+
+use crate::runtime::GraphQLQuery;
+use crate::{BlipsClient, BlipsError};
+
+/// Options for executing an operation in bulk.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BulkOptions {
+    concurrency: usize,
+    abort_on_error: bool,
+}
+
+impl Default for BulkOptions {
+    fn default() -> Self {
+        Self {
+            concurrency: 4,
+            abort_on_error: false,
+        }
+    }
+}
+
+impl BulkOptions {
+    /// Returns the default [`BulkOptions`], which run up to 4 operations at once and keep
+    /// going when an operation fails.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the maximum number of operations that may be in flight at once.
+    ///
+    /// A concurrency of `0` is treated as `1`.
+    pub fn concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    /// Sets whether the first failed operation should abort the rest of the batch.
+    ///
+    /// When the batch is aborted, operations that are still in flight are cancelled and
+    /// operations that haven't started are never sent.
+    pub fn abort_on_error(mut self, abort_on_error: bool) -> Self {
+        self.abort_on_error = abort_on_error;
+        self
+    }
+}
+
+/// The outcome of executing an operation in bulk.
+#[derive(Debug)]
+pub struct BulkOutcome<T> {
+    /// The result of each operation, in the order its variables were provided.
+    ///
+    /// Operations that didn't complete because the batch was aborted are `None`.
+    pub results: Vec<Option<Result<T, BlipsError>>>,
+
+    /// The index of the operation whose error aborted the batch, if it was aborted.
+    pub aborted_at: Option<usize>,
+}
+
+impl<T> BulkOutcome<T> {
+    /// Returns the error that aborted the batch, if it was aborted.
+    pub fn aborting_error(&self) -> Option<&BlipsError> {
+        self.aborted_at
+            .and_then(|index| self.results[index].as_ref())
+            .and_then(|result| result.as_ref().err())
+    }
+
+    /// Returns the data of the operations that succeeded, along with their indices.
+    pub fn successes(&self) -> impl Iterator<Item = (usize, &T)> {
+        self.results
+            .iter()
+            .enumerate()
+            .filter_map(|(index, result)| match result {
+                Some(Ok(data)) => Some((index, data)),
+                _ => None,
+            })
+    }
+}
+
+impl BlipsClient {
+    /// Executes the GraphQL operation `Q` once for each of the provided variables, with
+    /// bounded concurrency.
+    ///
+    /// The results are returned in the order of the variables, regardless of the order in
+    /// which the operations complete.
+    pub async fn execute_bulk<Q: GraphQLQuery>(
+        &self,
+        variables: impl IntoIterator<Item = Q::Variables>,
+        options: BulkOptions,
+    ) -> BulkOutcome<Q::ResponseData> {
+        self.execute_bulk_with_progress::<Q>(variables, options, |_, _| {})
+            .await
+    }
+
+    /// Executes the GraphQL operation `Q` once for each of the provided variables, with
+    /// bounded concurrency, calling `on_progress(completed, total)` as each operation
+    /// completes.
+    ///
+    /// The callback is called on the task awaiting the batch, between polls of the
+    /// operations in flight, so it should return quickly. It isn't called for operations
+    /// that didn't complete because the batch was aborted.
+    pub async fn execute_bulk_with_progress<Q: GraphQLQuery>(
+        &self,
+        variables: impl IntoIterator<Item = Q::Variables>,
+        options: BulkOptions,
+        mut on_progress: impl FnMut(usize, usize),
+    ) -> BulkOutcome<Q::ResponseData> {
+        let variables = variables.into_iter().collect::<Vec<_>>();
+        let total = variables.len();
+
+        let mut results = Vec::with_capacity(variables.len());
+        results.resize_with(variables.len(), || None);
+
+        let mut responses = stream::iter(variables.into_iter().enumerate())
+            .map(|(index, variables)| async move { (index, self.execute::<Q>(variables).await) })
+            .buffer_unordered(options.concurrency);
+
+        let mut aborted_at = None;
+        let mut completed = 0;
+        while let Some((index, result)) = responses.next().await {
+            let is_error = result.is_err();
+            results[index] = Some(result);
+
+            completed += 1;
+            on_progress(completed, total);
+
+            if is_error && options.abort_on_error {
+                aborted_at = Some(index);
+                break;
+            }
+        }
+
+        BulkOutcome {
+            results,
+            aborted_at,
+        }
+    }
+}
@@ -0,0 +1,65 @@
+use serde::Serialize;
+
+use crate::runtime::{GraphQLQuery, QueryBody};
+use crate::{BlipsClient, BlipsError};
+
+/// A generated update mutation that can be executed with a changeset, which only sends the
+/// arguments that are set, with [`BlipsClient::execute_changeset`].
+///
+/// This is implemented for the mutations whose name starts with `update` and that have
+/// optional arguments.
+pub trait ChangesetQuery: GraphQLQuery {
+    /// The changeset of the mutation, such as `update_task::Changeset`.
+    type Changeset: Serialize;
+
+    /// Returns the body of a request for the mutation with the given changeset.
+    fn build_changeset_query(changeset: Self::Changeset) -> QueryBody<Self::Changeset>;
+}
+
+impl BlipsClient {
+    /// Executes the GraphQL mutation `Q` with the provided changeset and returns its data.
+    ///
+    /// Only the arguments that are set on the changeset are sent. Unset arguments are left
+    /// out of the variables entirely, rather than being sent as `null`, so that the server
+    /// leaves the fields they update untouched.
+    pub async fn execute_changeset<Q: ChangesetQuery>(
+        &self,
+        changeset: Q::Changeset,
+    ) -> Result<Q::ResponseData, BlipsError> {
+        self.send_query_body(&Q::build_changeset_query(changeset))
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::graphql::{update_task, UpdateTask};
+
+    #[test]
+    fn unset_arguments_are_left_out_of_the_body() {
+        let changeset =
+            update_task::Changeset::new("task-1".to_string()).with_name("Renamed".to_string());
+
+        let body = serde_json::to_value(UpdateTask::build_changeset_query(changeset)).unwrap();
+
+        assert_eq!(
+            body["variables"],
+            json!({ "name": "Renamed", "task_id": "task-1" })
+        );
+    }
+
+    #[test]
+    fn arguments_set_to_none_are_sent_as_null() {
+        let changeset = update_task::Changeset::new("task-1".to_string()).with_due_date(None);
+
+        let body = serde_json::to_value(UpdateTask::build_changeset_query(changeset)).unwrap();
+
+        assert_eq!(
+            body["variables"],
+            json!({ "due_date": null, "task_id": "task-1" })
+        );
+    }
+}
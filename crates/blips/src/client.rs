@@ -1,13 +1,60 @@
-use graphql_client::GraphQLQuery;
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::env;
+use std::pin::pin;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use futures_util::future::{self, Either};
+use reqwest::header::{
+    HeaderMap, HeaderName, HeaderValue, ACCEPT, CONTENT_TYPE, ETAG, IF_MATCH, IF_NONE_MATCH,
+};
+use reqwest::StatusCode;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
 use url::{ParseError, Url};
 
-use crate::{CsrfToken, SessionCookie};
+use crate::clock::{Clock, SystemClock};
+use crate::retry::Backoff;
+use crate::runtime::{GraphQLQuery, QueryBody, Response};
+use crate::{
+    BlipsError, ClientConfig, CsrfToken, EmptyData, OperationKind, RequestMetrics, RetryConfig,
+    SessionCookie, PERSISTED_DOCUMENT_IDS,
+};
 
 /// The Blips client.
+///
+/// Cloning a client is cheap. The clones share its connection pool, along with its ETag
+/// cache, in-flight queries, concurrency limit, and cassette, so a clone can be handed to
+/// each task that makes calls.
+#[derive(Clone)]
 pub struct BlipsClient {
     base_url: Url,
     session_cookie: SessionCookie,
     csrf_token: CsrfToken,
+    credentials: HashMap<String, Credential>,
+    operation_credentials: HashMap<String, String>,
+    empty_data: HashMap<OperationKind, EmptyData>,
+    operation_empty_data: HashMap<String, EmptyData>,
+    connect_timeout: Option<Duration>,
+    request_timeout: Option<Duration>,
+    #[cfg(feature = "gzip")]
+    gzip_requests: bool,
+    persisted_documents: bool,
+    graphql_response_json: bool,
+    etag_cache: Option<Arc<EtagCache>>,
+    in_flight_queries: Option<Arc<InFlightQueries>>,
+    default_variables: serde_json::Map<String, serde_json::Value>,
+    retry: Option<RetryConfig>,
+    max_response_bytes: Option<usize>,
+    validate_requests: bool,
+    response_extractor: Option<ResponseExtractor>,
+    concurrency_limit: Option<Arc<async_lock::Semaphore>>,
+    hedge_delay: Option<Duration>,
+    clock: Arc<dyn Clock>,
+    #[cfg(feature = "vcr")]
+    cassette: Option<Arc<crate::vcr::Cassette>>,
+    last_request_id: Option<Arc<Mutex<Option<String>>>>,
     client: reqwest::Client,
 }
 
@@ -17,6 +64,40 @@ impl BlipsClient {
         BlipsClientBuilder::new(session_cookie, csrf_token).build()
     }
 
+    /// Returns a new instance of the Blips client configured from environment variables.
+    ///
+    /// See [`BlipsClientBuilder::from_env`] for the environment variables that are read.
+    pub fn from_env() -> Result<Self, BlipsError> {
+        Ok(BlipsClientBuilder::from_env()?.build())
+    }
+
+    /// Returns a new instance of the Blips client configured from the given [`ClientConfig`].
+    pub fn from_config(config: ClientConfig) -> Result<Self, BlipsError> {
+        Ok(BlipsClientBuilder::from_config(config)?.build())
+    }
+
+    /// Returns a new instance of the Blips client for tests, pointed at a mock server with
+    /// the given base URL.
+    ///
+    /// The client has no credentials, doesn't retry requests, and uses short timeouts, so
+    /// that a misconfigured mock fails fast instead of hanging the test. It is only
+    /// intended for tests.
+    #[cfg(feature = "test-util")]
+    pub fn for_testing(base_url: &str) -> Result<Self, BlipsError> {
+        let base_url = Url::parse(base_url)
+            .map_err(|err| BlipsError::Config(format!("base URL `{}`: {}", base_url, err)))?;
+
+        let mut builder = BlipsClientBuilder::with_credentials(
+            Cow::Owned(SessionCookie::from(String::new())),
+            Cow::Owned(CsrfToken::from(String::new())),
+        )
+        .connect_timeout(Duration::from_secs(1))
+        .request_timeout(Duration::from_secs(5));
+        builder.base_url = base_url;
+
+        Ok(builder.build())
+    }
+
     /// Returns a [`BlipsClientBuilder`] that may be used to construct a Blips client.
     pub fn builder<'a>(
         session_cookie: &'a SessionCookie,
@@ -25,84 +106,1992 @@ impl BlipsClient {
         BlipsClientBuilder::new(session_cookie, csrf_token)
     }
 
-    pub(crate) fn base_url(&self) -> &Url {
+    /// Returns the URL of the GraphQL endpoint the client sends requests to.
+    pub fn endpoint(&self) -> &Url {
         &self.base_url
     }
 
-    pub(crate) fn session_cookie(&self) -> &SessionCookie {
-        &self.session_cookie
+    /// Returns the timeout for connecting to the Blips API, if one is set.
+    pub fn connect_timeout(&self) -> Option<Duration> {
+        self.connect_timeout
+    }
+
+    /// Returns the timeout for entire requests, if one is set.
+    pub fn request_timeout(&self) -> Option<Duration> {
+        self.request_timeout
+    }
+
+    /// Returns whether both a session cookie and a CSRF token are configured, without
+    /// exposing either of them.
+    pub fn has_credentials(&self) -> bool {
+        !self.session_cookie.to_string().is_empty() && !self.csrf_token.to_string().is_empty()
+    }
+
+    /// Returns the session cookie and CSRF token to send for the operation with the given
+    /// name.
+    fn credentials_for(
+        &self,
+        operation_name: &str,
+    ) -> Result<(&SessionCookie, &CsrfToken), BlipsError> {
+        let Some(credential_name) = self.operation_credentials.get(operation_name) else {
+            return Ok((&self.session_cookie, &self.csrf_token));
+        };
+
+        let credential = self.credentials.get(credential_name).ok_or_else(|| {
+            BlipsError::Config(format!(
+                "no credential named `{}` is configured, but `{}` uses it",
+                credential_name, operation_name
+            ))
+        })?;
+
+        Ok((&credential.session_cookie, &credential.csrf_token))
+    }
+
+    /// Returns how empty data is treated for the operation with the given name.
+    ///
+    /// A setting for the operation takes precedence over a setting for its kind, which
+    /// takes precedence over the default for its kind.
+    fn empty_data_for(&self, operation_name: &str) -> EmptyData {
+        if let Some(empty_data) = self.operation_empty_data.get(operation_name) {
+            return *empty_data;
+        }
+
+        let Some(kind) = operation_kind(operation_name) else {
+            return EmptyData::Error;
+        };
+
+        self.empty_data.get(&kind).copied().unwrap_or(match kind {
+            OperationKind::Query => EmptyData::None,
+            OperationKind::Mutation | OperationKind::Subscription => EmptyData::Error,
+        })
+    }
+
+    /// Returns the client with default values for the variables with the given names.
+    ///
+    /// This is useful for context arguments, like a `board_id`, that are the same across
+    /// many calls. A default is only used when the operation has a variable with that name
+    /// and the caller left it unset (`None`); a value set by the caller always takes
+    /// precedence. The names are the variable names as they are serialized, such as
+    /// `board_id`.
+    pub fn with_default_variables(
+        mut self,
+        default_variables: serde_json::Map<String, serde_json::Value>,
+    ) -> Self {
+        self.default_variables.extend(default_variables);
+        self
+    }
+
+    /// Returns the request ID the server assigned to the most recently completed call.
+    ///
+    /// This is the value of the `X-Request-Id` response header, and is only recorded when
+    /// enabled with [`BlipsClientBuilder::track_request_ids`]. Returns `None` when tracking
+    /// is disabled, no call has completed yet, or the last response had no request ID.
+    pub fn last_request_id(&self) -> Option<String> {
+        self.last_request_id
+            .as_ref()
+            .and_then(|last_request_id| last_request_id.lock().unwrap().clone())
+    }
+
+    /// Executes the GraphQL operation `Q` with the provided variables and returns its data.
+    ///
+    /// Any GraphQL errors in the response result in a [`BlipsError::GraphQl`], even if the
+    /// response also contains data. Use [`BlipsClient::execute_allow_errors`] to tolerate them.
+    pub async fn execute<Q: GraphQLQuery>(
+        &self,
+        variables: Q::Variables,
+    ) -> Result<Q::ResponseData, BlipsError> {
+        self.send_query_body(&Q::build_query(variables)).await
+    }
+
+    /// Sends a request with the given body, such as one returned by `build_query`, and
+    /// returns its data deserialized into `T`.
+    ///
+    /// This is what [`BlipsClient::execute`] and the generated methods use, so the request
+    /// is sent with the same settings, such as persisted documents, default variables, and
+    /// retries, and its errors are mapped the same way.
+    pub async fn send_query_body<V: Serialize, T: DeserializeOwned>(
+        &self,
+        body: &QueryBody<V>,
+    ) -> Result<T, BlipsError> {
+        let response = self.post_operation(body, None, None).await?;
+
+        response_data(body.operation_name, response)
+    }
+
+    /// Executes the GraphQL operation `Q` with the provided variables and returns its data
+    /// along with the timings of the request.
+    ///
+    /// See [`RequestMetrics`] for the timings that are available.
+    #[cfg(feature = "metrics")]
+    pub async fn execute_with_metrics<Q: GraphQLQuery>(
+        &self,
+        variables: Q::Variables,
+    ) -> Result<(Q::ResponseData, RequestMetrics), BlipsError> {
+        let started_at = Instant::now();
+        let body = Q::build_query(variables);
+
+        let mut metrics = RequestMetrics::default();
+        let response = self.post_operation(&body, None, Some(&mut metrics)).await?;
+        let data = response_data(body.operation_name, response)?;
+        metrics.total = started_at.elapsed();
+
+        Ok((data, metrics))
+    }
+
+    /// Executes the GraphQL operation `Q` with the provided variables and returns its data,
+    /// along with the data as the server sent it.
+    ///
+    /// This is for debugging deserialization issues, such as a field that looks wrong in
+    /// the typed data. The data is deserialized into a [`serde_json::Value`] first and then
+    /// into the typed data, which costs an extra pass over it, so prefer
+    /// [`BlipsClient::execute`] elsewhere.
+    pub async fn execute_with_raw<Q: GraphQLQuery>(
+        &self,
+        variables: Q::Variables,
+    ) -> Result<(Q::ResponseData, serde_json::Value), BlipsError> {
+        let body = Q::build_query(variables);
+
+        let response = self.post_operation(&body, None, None).await?;
+        let raw_data: serde_json::Value = response_data(body.operation_name, response)?;
+        let data = data_from_value(body.operation_name, raw_data.clone())?;
+
+        Ok((data, raw_data))
+    }
+
+    /// Executes the GraphQL operation `Q` with the provided variables and returns its data,
+    /// or `None` when the response has no data and its empty data is allowed.
+    ///
+    /// Whether empty data is allowed is set with [`BlipsClientBuilder::empty_data`] and
+    /// [`BlipsClientBuilder::operation_empty_data`]. By default, it is allowed for queries
+    /// and is a [`BlipsError::EmptyResponse`] for mutations and subscriptions. Any GraphQL
+    /// errors in the response still result in a [`BlipsError::GraphQl`].
+    pub async fn execute_optional<Q: GraphQLQuery>(
+        &self,
+        variables: Q::Variables,
+    ) -> Result<Option<Q::ResponseData>, BlipsError> {
+        let body = Q::build_query(variables);
+
+        let response = self.post_operation(&body, None, None).await?;
+
+        match response_data(body.operation_name, response) {
+            Err(BlipsError::EmptyResponse { .. })
+                if self.empty_data_for(body.operation_name) == EmptyData::None =>
+            {
+                Ok(None)
+            }
+            result => result.map(Some),
+        }
     }
 
-    pub(crate) fn csrf_token(&self) -> &CsrfToken {
-        &self.csrf_token
+    /// Executes the GraphQL operation `Q` with the provided variables, but only if the
+    /// entity it targets still has the given ETag or version, and returns its data.
+    ///
+    /// The value is sent in an `If-Match` header, so that the server can reject the write
+    /// with `412 Precondition Failed` if the entity changed since it was fetched. Pass the
+    /// ETag exactly as the server provided it, including the `W/` prefix of a weak ETag.
+    /// A rejected write fails with a [`BlipsError::Http`], for which
+    /// [`BlipsError::is_precondition_failed`] returns `true`.
+    pub async fn execute_if_match<Q: GraphQLQuery>(
+        &self,
+        variables: Q::Variables,
+        etag: &str,
+    ) -> Result<Q::ResponseData, BlipsError> {
+        let body = Q::build_query(variables);
+
+        let response = self.post_operation(&body, Some(etag), None).await?;
+
+        response_data(body.operation_name, response)
     }
 
-    pub(crate) async fn post_graphql<Q: GraphQLQuery>(
+    /// Executes the GraphQL operation `Q` with the provided variables and returns its data
+    /// along with any GraphQL errors, instead of failing when the response contains errors.
+    ///
+    /// GraphQL servers resolve as much of an operation as they can, so a response may
+    /// contain both data and errors. When a field fails, it is set to `null` and the failure
+    /// is reported in the errors, with a `path` to the field. If the field is non-nullable,
+    /// the `null` propagates up to its nearest nullable parent, or to the whole data.
+    ///
+    /// This means partial data is safe to use as long as you account for the errors: a
+    /// `null` value under the `path` of an error means the field failed, not that it is
+    /// absent. Everything outside of the errors' paths resolved normally.
+    ///
+    /// The three shapes of a response map to the following results:
+    ///
+    /// - Data only: `Ok((Some(data), vec![]))`
+    /// - Errors only: `Ok((None, errors))`
+    /// - Data and errors: `Ok((Some(data), errors))`
+    pub async fn execute_allow_errors<Q: GraphQLQuery>(
         &self,
         variables: Q::Variables,
-    ) -> Result<graphql_client::Response<Q::ResponseData>, reqwest::Error> {
+    ) -> Result<(Option<Q::ResponseData>, Vec<crate::runtime::Error>), BlipsError> {
         let body = Q::build_query(variables);
 
+        let response = self.post_operation(&body, None, None).await?;
+
+        Ok((response.data, response.errors.unwrap_or_default()))
+    }
+
+    /// Executes an ad-hoc GraphQL query and deserializes its data into `T`.
+    ///
+    /// This is useful for queries that aren't part of the generated client, but that
+    /// have a hand-written response type.
+    pub async fn graphql_raw_typed<T: DeserializeOwned>(
+        &self,
+        query: &str,
+        variables: serde_json::Value,
+    ) -> Result<T, BlipsError> {
+        let body = RawQueryBody { query, variables };
+
         let response = self
-            .client
-            .post(self.base_url().clone())
-            .header("Cookie", self.session_cookie().to_string())
-            .header("X-Csrf-Token", self.csrf_token().to_string())
-            .json(&body)
-            .send()
+            .post_graphql::<_, T>(RAW_OPERATION_NAME, &body, None, None)
             .await?;
 
-        response.json().await
+        response_data(RAW_OPERATION_NAME, response)
+    }
+
+    /// Executes the generated operation with the given name, such as `UpdateTask`, with
+    /// variables given as JSON, and returns its data as JSON.
+    ///
+    /// This is useful for tooling that selects operations at runtime. The data can be
+    /// converted into the operation's `ResponseData` with its `TryFrom<serde_json::Value>`
+    /// implementation when the operation is known.
+    ///
+    /// Returns [`BlipsError::UnknownOperation`] when no generated operation has the name.
+    pub async fn execute_named(
+        &self,
+        name: &str,
+        variables: serde_json::Value,
+    ) -> Result<serde_json::Value, BlipsError> {
+        let operation = name
+            .parse::<crate::graphql::Operation>()
+            .map_err(|_| BlipsError::UnknownOperation {
+                operation_name: name.to_owned(),
+            })?
+            .info();
+
+        let body = QueryBody {
+            variables,
+            query: operation.document,
+            operation_name: operation.name,
+        };
+
+        let response = self.post_operation(&body, None, None).await?;
+
+        response_data(operation.name, response)
+    }
+
+    /// Opens a connection to the endpoint ahead of time, so that the first operation doesn't
+    /// pay for establishing it, including the TLS handshake.
+    ///
+    /// This sends the minimal `{ __typename }` query with the same transport configuration,
+    /// authentication, and headers as any other operation, leaving the connection in the
+    /// client's pool. Warming up is best-effort: the pool may still close the connection
+    /// before it's reused, such as when it's idle for too long or the server closes it.
+    pub async fn warm_up(&self) -> Result<(), BlipsError> {
+        self.graphql_raw_typed::<serde::de::IgnoredAny>(WARM_UP_QUERY, serde_json::json!({}))
+            .await?;
+
+        Ok(())
+    }
+
+    /// Returns the version of the SDK the client was compiled from, such as `0.1.0`.
+    pub fn sdk_version() -> &'static str {
+        env!("CARGO_PKG_VERSION")
+    }
+
+    /// Queries the version of the server, for detecting an incompatible server at
+    /// startup.
+    ///
+    /// This sends the `{ version }` query, which only servers whose schema has a `version`
+    /// field on `Query` can resolve. Returns `None` when the schema has no such field,
+    /// which the server reports with GraphQL errors or a `400 Bad Request`, and when the
+    /// version is `null`. Other failures, such as transport errors, are returned as usual.
+    pub async fn server_version(&self) -> Result<Option<String>, BlipsError> {
+        let result = self
+            .graphql_raw_typed::<ServerVersionData>(SERVER_VERSION_QUERY, serde_json::json!({}))
+            .await;
+
+        match result {
+            Ok(data) => Ok(data.version),
+            Err(BlipsError::GraphQl { .. } | BlipsError::Http { status: 400, .. }) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Returns the size in bytes of the request body the client would send for the
+    /// operation, without sending it.
+    ///
+    /// The size accounts for persisted documents and default variables, but not for gzip
+    /// compression, so it's an upper bound on the size sent over the wire when request
+    /// compression is enabled. This is useful for deciding whether to split a large batch
+    /// of variables into several requests.
+    pub fn request_size<V: Serialize>(&self, body: &QueryBody<V>) -> Result<usize, BlipsError> {
+        match self.persisted_document_id(body.operation_name) {
+            Some(id) => {
+                let persisted_body = PersistedQueryBody {
+                    id,
+                    variables: &body.variables,
+                };
+
+                self.serialized_size(&persisted_body)
+            }
+            None => self.serialized_size(body),
+        }
+    }
+
+    /// Returns the size in bytes of the serialized body, with the default variables filled
+    /// in.
+    fn serialized_size<B: Serialize>(&self, body: &B) -> Result<usize, BlipsError> {
+        let body = if self.default_variables.is_empty() {
+            serde_json::to_vec(body)
+        } else {
+            serde_json::to_vec(&self.apply_default_variables(body)?)
+        };
+
+        body.map(|body| body.len())
+            .map_err(|err| BlipsError::Config(format!("failed to serialize request: {}", err)))
+    }
+
+    /// Returns the persisted document ID to send for the operation with the given name,
+    /// when persisted documents are enabled and the operation has one.
+    fn persisted_document_id(&self, operation_name: &str) -> Option<&'static str> {
+        self.persisted_documents
+            .then(|| persisted_document_id(operation_name))
+            .flatten()
+    }
+
+    /// Posts the generated operation `Q`, sending its persisted document ID instead of its
+    /// document when persisted documents are enabled and the operation has one.
+    pub(crate) async fn post_operation<V: Serialize, T: DeserializeOwned>(
+        &self,
+        body: &QueryBody<V>,
+        if_match: Option<&str>,
+        metrics: Option<&mut RequestMetrics>,
+    ) -> Result<Response<T>, BlipsError> {
+        match self.persisted_document_id(body.operation_name) {
+            Some(id) => {
+                let persisted_body = PersistedQueryBody {
+                    id,
+                    variables: &body.variables,
+                };
+
+                self.post_graphql(body.operation_name, &persisted_body, if_match, metrics)
+                    .await
+            }
+            None => {
+                self.post_graphql(body.operation_name, body, if_match, metrics)
+                    .await
+            }
+        }
+    }
+
+    pub(crate) async fn post_graphql<B: Serialize, T: DeserializeOwned>(
+        &self,
+        operation_name: &str,
+        body: &B,
+        if_match: Option<&str>,
+        metrics: Option<&mut RequestMetrics>,
+    ) -> Result<Response<T>, BlipsError> {
+        let _permit = match &self.concurrency_limit {
+            Some(concurrency_limit) => Some(concurrency_limit.acquire().await),
+            None => None,
+        };
+
+        if self.default_variables.is_empty() && !self.validate_requests {
+            return self
+                .post_graphql_body(operation_name, body, if_match, metrics)
+                .await;
+        }
+
+        let body = self.apply_default_variables(body)?;
+
+        if self.validate_requests {
+            crate::request_validation::validate_request(operation_name, &body).map_err(
+                |reason| BlipsError::InvalidRequest {
+                    operation_name: operation_name.to_string(),
+                    reason,
+                },
+            )?;
+        }
+
+        self.post_graphql_body(operation_name, &body, if_match, metrics)
+            .await
+    }
+
+    /// Returns the body with the default variables filled into the variables it leaves
+    /// unset.
+    fn apply_default_variables<B: Serialize>(
+        &self,
+        body: &B,
+    ) -> Result<serde_json::Value, BlipsError> {
+        let mut body = serde_json::to_value(body)
+            .map_err(|err| BlipsError::Config(format!("failed to serialize request: {}", err)))?;
+
+        if let Some(variables) = body
+            .get_mut("variables")
+            .and_then(|variables| variables.as_object_mut())
+        {
+            for (name, default_value) in &self.default_variables {
+                if let Some(value) = variables.get_mut(name).filter(|value| value.is_null()) {
+                    *value = default_value.clone();
+                }
+            }
+        }
+
+        Ok(body)
+    }
+
+    async fn post_graphql_body<B: Serialize, T: DeserializeOwned>(
+        &self,
+        operation_name: &str,
+        body: &B,
+        if_match: Option<&str>,
+        metrics: Option<&mut RequestMetrics>,
+    ) -> Result<Response<T>, BlipsError> {
+        // Queries are cached by their request body, which includes their variables.
+        let etag_cache_key = match &self.etag_cache {
+            Some(_) if is_query(operation_name) => serde_json::to_string(body).ok(),
+            _ => None,
+        };
+
+        let response = self
+            .send(operation_name, body, etag_cache_key.as_deref(), if_match)
+            .await?;
+
+        if let Some(metrics) = metrics {
+            metrics.time_to_first_byte = response.time_to_first_byte;
+        }
+
+        if let Some(last_request_id) = &self.last_request_id {
+            *last_request_id.lock().unwrap() = response
+                .headers
+                .get(REQUEST_ID_HEADER)
+                .and_then(|value| value.to_str().ok())
+                .map(str::to_string);
+        }
+
+        let is_graphql_response = self.graphql_response_json
+            && response
+                .headers
+                .get(CONTENT_TYPE)
+                .and_then(|value| value.to_str().ok())
+                .is_some_and(|content_type| {
+                    content_type.starts_with(GRAPHQL_RESPONSE_JSON_CONTENT_TYPE)
+                });
+
+        let (status, response_body) = match (&self.etag_cache, etag_cache_key) {
+            (Some(etag_cache), Some(key)) if response.status == StatusCode::NOT_MODIFIED => {
+                match etag_cache.body(&key) {
+                    Some(cached_body) => (StatusCode::OK, Cow::Owned(cached_body)),
+                    None => (response.status, Cow::Borrowed(response.body.as_slice())),
+                }
+            }
+            (Some(etag_cache), Some(key)) if response.status.is_success() => {
+                let etag = response.headers.get(ETAG).cloned();
+                etag_cache.store(key, etag, &response.body);
+                (response.status, Cow::Borrowed(response.body.as_slice()))
+            }
+            _ => (response.status, Cow::Borrowed(response.body.as_slice())),
+        };
+
+        let deserialize = || match &self.response_extractor {
+            Some(response_extractor) => {
+                let deserializer = &mut serde_json::Deserializer::from_slice(&response_body);
+                let body = serde_path_to_error::deserialize(deserializer)
+                    .map_err(|err| deserialize_error(operation_name, err))?;
+
+                serde_path_to_error::deserialize(response_extractor(body))
+                    .map_err(|err| deserialize_error(operation_name, err))
+            }
+            None => {
+                let deserializer = &mut serde_json::Deserializer::from_slice(&response_body);
+
+                serde_path_to_error::deserialize(deserializer)
+                    .map_err(|err| deserialize_error(operation_name, err))
+            }
+        };
+
+        if status.is_success() {
+            return deserialize();
+        }
+
+        // With `application/graphql-response+json`, a non-success status may still carry a
+        // GraphQL response describing why the request failed.
+        if is_graphql_response {
+            if let Ok(response) = deserialize() {
+                return Ok(response);
+            }
+        }
+
+        Err(BlipsError::Http {
+            status: status.as_u16(),
+            body: String::from_utf8_lossy(&response_body).into_owned(),
+        })
+    }
+
+    /// Sends the request with the given body, or shares the response of an identical query
+    /// that is already in flight when queries are deduplicated.
+    async fn send<B: Serialize>(
+        &self,
+        operation_name: &str,
+        body: &B,
+        etag_cache_key: Option<&str>,
+        if_match: Option<&str>,
+    ) -> Result<HttpResponse, BlipsError> {
+        // Queries are deduplicated by their request body, which includes their variables,
+        // and by their `If-Match` header, as the server may respond differently to it.
+        let in_flight = match &self.in_flight_queries {
+            Some(in_flight_queries) if is_query(operation_name) => serde_json::to_string(body)
+                .ok()
+                .map(|body| (in_flight_queries, (body, if_match.map(str::to_owned)))),
+            _ => None,
+        };
+        let Some((in_flight_queries, key)) = in_flight else {
+            return self
+                .send_uncoalesced(operation_name, body, etag_cache_key, if_match)
+                .await;
+        };
+
+        let in_flight_query = in_flight_queries.get_or_insert(&key);
+        let mut error = None;
+        let response = in_flight_query
+            .get_or_init(|| async {
+                match self
+                    .send_uncoalesced(operation_name, body, etag_cache_key, if_match)
+                    .await
+                {
+                    Ok(response) => Some(response),
+                    Err(err) => {
+                        error = Some(err);
+                        None
+                    }
+                }
+            })
+            .await;
+        in_flight_queries.remove(&key, &in_flight_query);
+
+        match (response, error) {
+            (_, Some(err)) => Err(err),
+            (Some(response), None) => Ok(response.clone()),
+            (None, None) => {
+                self.send_uncoalesced(operation_name, body, etag_cache_key, if_match)
+                    .await
+            }
+        }
+    }
+
+    /// Sends the request with the given body, or replays it from the cassette when one is
+    /// configured.
+    #[cfg_attr(not(feature = "vcr"), allow(unused_variables))]
+    async fn send_uncoalesced<B: Serialize>(
+        &self,
+        operation_name: &str,
+        body: &B,
+        etag_cache_key: Option<&str>,
+        if_match: Option<&str>,
+    ) -> Result<HttpResponse, BlipsError> {
+        let response = self.send_http_with_retries(operation_name, body, etag_cache_key, if_match);
+
+        #[cfg(feature = "vcr")]
+        if let Some(cassette) = &self.cassette {
+            return cassette.send(operation_name, body, response).await;
+        }
+
+        response.await
+    }
+
+    /// Sends the request with the given body over HTTP, retrying it according to the retry
+    /// configuration.
+    async fn send_http_with_retries<B: Serialize>(
+        &self,
+        operation_name: &str,
+        body: &B,
+        etag_cache_key: Option<&str>,
+        if_match: Option<&str>,
+    ) -> Result<HttpResponse, BlipsError> {
+        let Some(retry) = self.retry else {
+            return self
+                .send_http_hedged(operation_name, body, etag_cache_key, if_match)
+                .await;
+        };
+
+        // Like hedging, retrying failures that the server may have processed the request
+        // for is only safe for queries, unless the config opts in for mutations.
+        let is_query = is_query(operation_name);
+        let mut backoff = Backoff::new(retry);
+        loop {
+            let response = self
+                .send_http_hedged(operation_name, body, etag_cache_key, if_match)
+                .await;
+
+            let is_retryable = match &response {
+                Ok(response) => retry.retries_status(response.status, is_query),
+                Err(BlipsError::Transport(err)) => retry.retries(err.kind(), is_query),
+                Err(_) => false,
+            };
+
+            match backoff.next_delay() {
+                Some(delay) if is_retryable => self.clock.sleep(delay).await,
+                _ => return response,
+            }
+        }
+    }
+
+    /// Sends the request with the given body over HTTP, sending it a second time when it is
+    /// a query that hasn't responded within the hedge delay, and returning whichever
+    /// response arrives first.
+    async fn send_http_hedged<B: Serialize>(
+        &self,
+        operation_name: &str,
+        body: &B,
+        etag_cache_key: Option<&str>,
+        if_match: Option<&str>,
+    ) -> Result<HttpResponse, BlipsError> {
+        // Only queries are safe to send twice, as they don't change anything.
+        let Some(hedge_delay) = self.hedge_delay.filter(|_| is_query(operation_name)) else {
+            return self
+                .send_http(operation_name, body, etag_cache_key, if_match)
+                .await;
+        };
+
+        let request = pin!(self.send_http(operation_name, body, etag_cache_key, if_match));
+        let hedged_request = pin!(async {
+            self.clock.sleep(hedge_delay).await;
+            self.send_http(operation_name, body, etag_cache_key, if_match)
+                .await
+        });
+
+        // Dropping the request that loses cancels it.
+        match future::select(request, hedged_request).await {
+            Either::Left((response, _)) | Either::Right((response, _)) => response,
+        }
+    }
+
+    /// Sends the request with the given body over HTTP.
+    async fn send_http<B: Serialize>(
+        &self,
+        operation_name: &str,
+        body: &B,
+        etag_cache_key: Option<&str>,
+        if_match: Option<&str>,
+    ) -> Result<HttpResponse, BlipsError> {
+        let (session_cookie, csrf_token) = self.credentials_for(operation_name)?;
+
+        let mut request = self
+            .client
+            .post(self.endpoint().clone())
+            .header("Cookie", session_cookie.to_string())
+            .header("X-Csrf-Token", csrf_token.to_string())
+            .json(body);
+
+        if self.graphql_response_json {
+            request = request.header(ACCEPT, GRAPHQL_RESPONSE_JSON_ACCEPT);
+        }
+
+        if let (Some(etag_cache), Some(key)) = (&self.etag_cache, etag_cache_key) {
+            if let Some(etag) = etag_cache.etag(key) {
+                request = request.header(IF_NONE_MATCH, etag);
+            }
+        }
+
+        if let Some(if_match) = if_match {
+            request = request.header(IF_MATCH, if_match);
+        }
+
+        let request = request.build().map_err(BlipsError::transport)?;
+
+        #[cfg(feature = "gzip")]
+        let request = if self.gzip_requests {
+            gzip_request_body(request)
+        } else {
+            request
+        };
+
+        let sent_at = Instant::now();
+        let mut response = self
+            .client
+            .execute(request)
+            .await
+            .map_err(BlipsError::transport)?;
+        let time_to_first_byte = sent_at.elapsed();
+
+        let status = response.status();
+        let headers = response.headers().clone();
+
+        let body = match self.max_response_bytes {
+            Some(limit) => {
+                if response
+                    .content_length()
+                    .is_some_and(|content_length| content_length > limit as u64)
+                {
+                    return Err(BlipsError::ResponseTooLarge { limit });
+                }
+
+                let mut body = Vec::new();
+                while let Some(chunk) = response.chunk().await.map_err(BlipsError::transport)? {
+                    if body.len() + chunk.len() > limit {
+                        return Err(BlipsError::ResponseTooLarge { limit });
+                    }
+
+                    body.extend_from_slice(&chunk);
+                }
+                body
+            }
+            None => Vec::from(response.bytes().await.map_err(BlipsError::transport)?),
+        };
+
+        Ok(HttpResponse {
+            status,
+            headers,
+            body,
+            time_to_first_byte: Some(time_to_first_byte),
+        })
+    }
+}
+
+/// An HTTP response to a GraphQL request.
+#[derive(Clone)]
+pub(crate) struct HttpResponse {
+    pub status: StatusCode,
+    pub headers: HeaderMap,
+    pub body: Vec<u8>,
+
+    /// The time until the response headers were received, when the request was sent.
+    pub time_to_first_byte: Option<Duration>,
+}
+
+impl BlipsClientBuilder<'static> {
+    /// Returns a new [`BlipsClientBuilder`] configured from environment variables.
+    ///
+    /// The following environment variables are read:
+    ///
+    /// - `BLIPS_SESSION_COOKIE` (required): The session cookie.
+    /// - `BLIPS_CSRF_TOKEN` (required): The CSRF token.
+    /// - `BLIPS_CONNECT_TIMEOUT`: The connect timeout, in seconds.
+    /// - `BLIPS_REQUEST_TIMEOUT`: The request timeout, in seconds.
+    /// - `BLIPS_VCR_CASSETTE`: The cassette file for recording and replaying requests,
+    ///   with the `vcr` feature.
+    /// - `BLIPS_VCR_MODE`: The cassette mode, one of `record`, `replay`, or `auto` (the
+    ///   default), with the `vcr` feature.
+    ///
+    /// Settings made on the returned builder take precedence over the environment variables.
+    pub fn from_env() -> Result<Self, BlipsError> {
+        let session_cookie = SessionCookie::from(required_env_var("BLIPS_SESSION_COOKIE")?);
+        let csrf_token = CsrfToken::from(required_env_var("BLIPS_CSRF_TOKEN")?);
+
+        let mut builder =
+            Self::with_credentials(Cow::Owned(session_cookie), Cow::Owned(csrf_token));
+        builder.connect_timeout = duration_env_var("BLIPS_CONNECT_TIMEOUT")?;
+        builder.request_timeout = duration_env_var("BLIPS_REQUEST_TIMEOUT")?;
+
+        #[cfg(feature = "vcr")]
+        if let Some(cassette_path) = optional_env_var("BLIPS_VCR_CASSETTE")? {
+            let mode = match optional_env_var("BLIPS_VCR_MODE")? {
+                Some(mode) => crate::VcrMode::parse(&mode).ok_or_else(|| {
+                    BlipsError::Config(format!(
+                        "`BLIPS_VCR_MODE`: expected `record`, `replay`, or `auto`, got `{}`",
+                        mode
+                    ))
+                })?,
+                None => crate::VcrMode::Auto,
+            };
+
+            builder.vcr = Some((cassette_path.into(), mode));
+        }
+
+        Ok(builder)
+    }
+
+    /// Returns a new [`BlipsClientBuilder`] configured from the given [`ClientConfig`].
+    pub fn from_config(config: ClientConfig) -> Result<Self, BlipsError> {
+        let mut builder = Self::with_credentials(
+            Cow::Owned(config.session_cookie),
+            Cow::Owned(config.csrf_token),
+        );
+
+        if let Some(endpoint) = &config.endpoint {
+            builder.base_url = Url::parse(endpoint)
+                .map_err(|err| BlipsError::Config(format!("`endpoint`: {}", err)))?;
+        }
+
+        builder.connect_timeout = config.connect_timeout;
+        builder.request_timeout = config.request_timeout;
+        builder.retry = config.retry;
+        builder.max_response_bytes = config.max_response_bytes;
+
+        for (name, value) in &config.headers {
+            builder = builder.header(name, value)?;
+        }
+
+        for (operation_name, credential_name) in config.operation_credentials {
+            if operation_name.parse::<crate::graphql::Operation>().is_err() {
+                return Err(BlipsError::Config(format!(
+                    "`operation_credentials`: unknown operation `{}`",
+                    operation_name
+                )));
+            }
+
+            if !config.credentials.contains_key(&credential_name) {
+                return Err(BlipsError::Config(format!(
+                    "`operation_credentials.{}`: unknown credential `{}`",
+                    operation_name, credential_name
+                )));
+            }
+
+            builder = builder.operation_credential(operation_name, credential_name);
+        }
+
+        for (name, credential) in config.credentials {
+            builder = builder.credential(name, &credential.session_cookie, &credential.csrf_token);
+        }
+
+        Ok(builder)
+    }
+}
+
+fn required_env_var(name: &str) -> Result<String, BlipsError> {
+    env::var(name).map_err(|err| BlipsError::Config(format!("`{}`: {}", name, err)))
+}
+
+/// Reads the environment variable with the given name, if it is set.
+#[cfg(feature = "vcr")]
+fn optional_env_var(name: &str) -> Result<Option<String>, BlipsError> {
+    match env::var(name) {
+        Ok(value) => Ok(Some(value)),
+        Err(env::VarError::NotPresent) => Ok(None),
+        Err(err) => Err(BlipsError::Config(format!("`{}`: {}", name, err))),
+    }
+}
+
+/// Reads a duration, in seconds, from the environment variable with the given name.
+fn duration_env_var(name: &str) -> Result<Option<Duration>, BlipsError> {
+    let value = match env::var(name) {
+        Ok(value) => value,
+        Err(env::VarError::NotPresent) => return Ok(None),
+        Err(err) => return Err(BlipsError::Config(format!("`{}`: {}", name, err))),
+    };
+
+    value
+        .trim()
+        .parse::<f64>()
+        .ok()
+        .and_then(|seconds| Duration::try_from_secs_f64(seconds).ok())
+        .map(Some)
+        .ok_or_else(|| {
+            BlipsError::Config(format!(
+                "`{}`: expected a non-negative number of seconds, got `{}`",
+                name, value
+            ))
+        })
+}
+
+/// The media type of responses defined by the GraphQL over HTTP specification.
+const GRAPHQL_RESPONSE_JSON_CONTENT_TYPE: &str = "application/graphql-response+json";
+
+/// The `Accept` header that prefers `application/graphql-response+json`, while still
+/// accepting `application/json` from servers that don't support it.
+const GRAPHQL_RESPONSE_JSON_ACCEPT: &str =
+    "application/graphql-response+json, application/json;q=0.9";
+
+/// The response header containing the ID the server assigned to the request.
+const REQUEST_ID_HEADER: &str = "X-Request-Id";
+
+/// The operation name used to refer to ad-hoc queries in errors.
+const RAW_OPERATION_NAME: &str = "ad-hoc query";
+
+/// The query sent by [`BlipsClient::warm_up`], which every GraphQL server can resolve.
+const WARM_UP_QUERY: &str = "{ __typename }";
+
+/// The query sent by [`BlipsClient::server_version`].
+const SERVER_VERSION_QUERY: &str = "{ version }";
+
+/// A function that extracts the standard GraphQL response from the body of a response.
+type ResponseExtractor = Arc<dyn Fn(serde_json::Value) -> serde_json::Value + Send + Sync>;
+
+/// The body of an ad-hoc GraphQL request.
+#[derive(Serialize)]
+struct RawQueryBody<'a> {
+    query: &'a str,
+    variables: serde_json::Value,
+}
+
+/// The data of a response to [`SERVER_VERSION_QUERY`].
+#[derive(Deserialize)]
+struct ServerVersionData {
+    version: Option<String>,
+}
+
+/// The default number of responses kept by the ETag cache.
+const DEFAULT_ETAG_CACHE_CAPACITY: usize = 256;
+
+/// The responses to queries that had an ETag, keyed by the body of their request.
+///
+/// Once the cache holds `capacity` responses, storing another evicts the least recently
+/// used one.
+struct EtagCache {
+    capacity: usize,
+    responses: Mutex<CachedResponses>,
+}
+
+#[derive(Default)]
+struct CachedResponses {
+    entries: HashMap<String, CachedResponse>,
+    /// Incremented on every use of an entry, for ordering the entries by recency.
+    uses: u64,
+}
+
+struct CachedResponse {
+    etag: HeaderValue,
+    body: Vec<u8>,
+    last_used: u64,
+}
+
+impl CachedResponses {
+    /// Returns the entry for the given key, marking it as the most recently used.
+    fn get(&mut self, key: &str) -> Option<&CachedResponse> {
+        let entry = self.entries.get_mut(key)?;
+        self.uses += 1;
+        entry.last_used = self.uses;
+
+        Some(entry)
+    }
+}
+
+impl EtagCache {
+    fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            responses: Mutex::default(),
+        }
+    }
+
+    /// Returns the ETag of the cached response for the given key.
+    fn etag(&self, key: &str) -> Option<HeaderValue> {
+        let mut responses = self.responses.lock().unwrap();
+
+        responses.get(key).map(|response| response.etag.clone())
+    }
+
+    /// Returns the body of the cached response for the given key.
+    fn body(&self, key: &str) -> Option<Vec<u8>> {
+        let mut responses = self.responses.lock().unwrap();
+
+        responses.get(key).map(|response| response.body.clone())
+    }
+
+    /// Stores the response for the given key, or forgets any cached response if the new
+    /// one has no ETag.
+    fn store(&self, key: String, etag: Option<HeaderValue>, body: &[u8]) {
+        let mut responses = self.responses.lock().unwrap();
+
+        let Some(etag) = etag else {
+            responses.entries.remove(&key);
+            return;
+        };
+        if self.capacity == 0 {
+            return;
+        }
+
+        if !responses.entries.contains_key(&key) && responses.entries.len() >= self.capacity {
+            let least_recently_used = responses
+                .entries
+                .iter()
+                .min_by_key(|(_, response)| response.last_used)
+                .map(|(key, _)| key.clone());
+            if let Some(least_recently_used) = least_recently_used {
+                responses.entries.remove(&least_recently_used);
+            }
+        }
+
+        responses.uses += 1;
+        let last_used = responses.uses;
+        let body = body.to_vec();
+        responses.entries.insert(
+            key,
+            CachedResponse {
+                etag,
+                body,
+                last_used,
+            },
+        );
+    }
+}
+
+/// The queries that are in flight, keyed by their request, for sharing their responses
+/// with identical queries.
+#[derive(Default)]
+struct InFlightQueries {
+    queries: Mutex<HashMap<InFlightKey, Arc<InFlightQuery>>>,
+}
+
+/// The key of a query in flight, which is the body of its request along with the value of
+/// its `If-Match` header, if any.
+type InFlightKey = (String, Option<String>);
+
+/// The response of a query in flight, which is `None` when the request failed.
+type InFlightQuery = async_lock::OnceCell<Option<HttpResponse>>;
+
+impl InFlightQueries {
+    /// Returns the query in flight for the given key, starting one if there is none.
+    fn get_or_insert(&self, key: &InFlightKey) -> Arc<InFlightQuery> {
+        let mut queries = self.queries.lock().unwrap();
+
+        queries.entry(key.clone()).or_default().clone()
+    }
+
+    /// Forgets the given query once it has completed, so that later queries send requests
+    /// of their own.
+    fn remove(&self, key: &InFlightKey, query: &Arc<InFlightQuery>) {
+        let mut queries = self.queries.lock().unwrap();
+
+        if queries
+            .get(key)
+            .is_some_and(|in_flight_query| Arc::ptr_eq(in_flight_query, query))
+        {
+            queries.remove(key);
+        }
+    }
+}
+
+/// Returns whether the generated operation with the given name is a query.
+fn is_query(operation_name: &str) -> bool {
+    operation_kind(operation_name) == Some(OperationKind::Query)
+}
+
+/// Returns the kind of the generated operation with the given name, if there is one.
+fn operation_kind(operation_name: &str) -> Option<OperationKind> {
+    crate::graphql::OPERATIONS
+        .iter()
+        .find(|operation| operation.name == operation_name)
+        .map(|operation| operation.kind)
+}
+
+/// A session cookie and CSRF token used for some operations instead of the primary ones.
+#[derive(Clone)]
+struct Credential {
+    session_cookie: SessionCookie,
+    csrf_token: CsrfToken,
+}
+
+/// The body of a request for a persisted document.
+#[derive(Serialize)]
+struct PersistedQueryBody<'a, V> {
+    id: &'a str,
+    variables: &'a V,
+}
+
+/// Returns the persisted document ID of the operation with the given name, if any.
+fn persisted_document_id(operation_name: &str) -> Option<&'static str> {
+    PERSISTED_DOCUMENT_IDS
+        .iter()
+        .find(|(name, _)| *name == operation_name)
+        .map(|(_, id)| *id)
+}
+
+/// Returns the data from the response, or an error if the response contains any GraphQL
+/// errors or no data.
+///
+/// Some servers leave the `data` key out of error responses instead of sending `null`.
+/// Both deserialize to `None`, so they are handled the same way.
+pub(crate) fn response_data<T>(
+    operation_name: &str,
+    response: Response<T>,
+) -> Result<T, BlipsError> {
+    let errors = response.errors.unwrap_or_default();
+    if !errors.is_empty() {
+        return Err(BlipsError::GraphQl {
+            operation_name: operation_name.to_string(),
+            errors,
+        });
+    }
+
+    response.data.ok_or_else(|| BlipsError::EmptyResponse {
+        operation_name: operation_name.to_string(),
+    })
+}
+
+/// Deserializes the data of the operation with the given name from a JSON value.
+///
+/// This is public for the generated code, when it's vendored into another crate.
+#[doc(hidden)]
+pub fn data_from_value<T: DeserializeOwned>(
+    operation_name: &str,
+    value: serde_json::Value,
+) -> Result<T, BlipsError> {
+    serde_path_to_error::deserialize(value).map_err(|err| deserialize_error(operation_name, err))
+}
+
+fn deserialize_error(
+    operation_name: &str,
+    err: serde_path_to_error::Error<serde_json::Error>,
+) -> BlipsError {
+    BlipsError::Deserialize {
+        operation_name: operation_name.to_string(),
+        path: err.path().to_string(),
+        source: err.into_inner(),
     }
 }
 
+/// Compresses the body of the given request using gzip.
+#[cfg(feature = "gzip")]
+fn gzip_request_body(mut request: reqwest::Request) -> reqwest::Request {
+    use std::io::Write;
+
+    use flate2::write::GzEncoder;
+    use flate2::Compression;
+    use reqwest::header::{HeaderValue, CONTENT_ENCODING, CONTENT_LENGTH};
+
+    let Some(body) = request.body().and_then(|body| body.as_bytes()) else {
+        return request;
+    };
+
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    encoder
+        .write_all(body)
+        .expect("Failed to write to in-memory gzip encoder");
+    let compressed_body = encoder
+        .finish()
+        .expect("Failed to finish in-memory gzip encoder");
+
+    let headers = request.headers_mut();
+    headers.insert(CONTENT_ENCODING, HeaderValue::from_static("gzip"));
+    headers.remove(CONTENT_LENGTH);
+
+    *request.body_mut() = Some(compressed_body.into());
+
+    request
+}
+
 /// A builder for a Blips client.
 pub struct BlipsClientBuilder<'a> {
     base_url: Url,
-    session_cookie: &'a SessionCookie,
-    csrf_token: &'a CsrfToken,
+    endpoint_path: Option<String>,
+    session_cookie: Cow<'a, SessionCookie>,
+    csrf_token: Cow<'a, CsrfToken>,
+    credentials: HashMap<String, Credential>,
+    operation_credentials: HashMap<String, String>,
+    empty_data: HashMap<OperationKind, EmptyData>,
+    operation_empty_data: HashMap<String, EmptyData>,
+    connect_timeout: Option<Duration>,
+    request_timeout: Option<Duration>,
+    track_request_ids: bool,
+    persisted_documents: bool,
+    graphql_response_json: bool,
+    etag_caching: bool,
+    etag_cache_capacity: usize,
+    deduplicate_queries: bool,
+    retry: Option<RetryConfig>,
+    max_response_bytes: Option<usize>,
+    max_concurrency: Option<usize>,
+    hedge_delay: Option<Duration>,
+    validate_requests: bool,
+    response_extractor: Option<ResponseExtractor>,
+    clock: Arc<dyn Clock>,
+    headers: HeaderMap,
+    #[cfg(feature = "vcr")]
+    vcr: Option<(std::path::PathBuf, crate::VcrMode)>,
+    #[cfg(feature = "gzip")]
+    gzip_requests: bool,
 }
 
 impl<'a> BlipsClientBuilder<'a> {
     /// Returns a new [`BlipsClientBuilder`] using the provided session token.
     pub fn new(session_cookie: &'a SessionCookie, csrf_token: &'a CsrfToken) -> Self {
+        Self::with_credentials(Cow::Borrowed(session_cookie), Cow::Borrowed(csrf_token))
+    }
+
+    fn with_credentials(
+        session_cookie: Cow<'a, SessionCookie>,
+        csrf_token: Cow<'a, CsrfToken>,
+    ) -> Self {
         Self {
             base_url: Url::parse("https://blips.app/query").unwrap(),
+            endpoint_path: None,
             session_cookie,
             csrf_token,
+            credentials: HashMap::new(),
+            operation_credentials: HashMap::new(),
+            empty_data: HashMap::new(),
+            operation_empty_data: HashMap::new(),
+            connect_timeout: None,
+            request_timeout: None,
+            track_request_ids: false,
+            persisted_documents: false,
+            graphql_response_json: false,
+            etag_caching: false,
+            etag_cache_capacity: DEFAULT_ETAG_CACHE_CAPACITY,
+            deduplicate_queries: false,
+            retry: None,
+            max_response_bytes: None,
+            max_concurrency: None,
+            hedge_delay: None,
+            validate_requests: cfg!(debug_assertions),
+            response_extractor: None,
+            clock: Arc::new(SystemClock),
+            headers: HeaderMap::new(),
+            #[cfg(feature = "vcr")]
+            vcr: None,
+            #[cfg(feature = "gzip")]
+            gzip_requests: false,
         }
     }
 
     /// Sets the base URL of the Blips API that the client should point to.
-    pub fn base_url(mut self, base_url: &'a str) -> Result<BlipsClientBuilder, ParseError> {
-        self.base_url = Url::parse(base_url)?;
+    ///
+    /// When a path is set with [`BlipsClientBuilder::endpoint_path`], it is resolved
+    /// against this URL.
+    pub fn base_url(mut self, base_url: &'a str) -> Result<BlipsClientBuilder<'a>, ParseError> {
+        let base_url = Url::parse(base_url)?;
+        if let Some(endpoint_path) = &self.endpoint_path {
+            base_url.join(endpoint_path)?;
+        }
+        self.base_url = base_url;
+        Ok(self)
+    }
+
+    /// Sets the path of the GraphQL endpoint, such as `/api/graphql`, for gateways that
+    /// don't expose it at the path of the base URL.
+    ///
+    /// The path is resolved against the base URL the way a link is, so a path starting
+    /// with `/` replaces the whole path of the base URL, and it applies regardless of
+    /// whether the base URL is set before or after it. An invalid combination fails here,
+    /// rather than on the first request. By default, the path of the base URL is used,
+    /// which is `/query` for the Blips API. Requests are always sent with `POST`.
+    pub fn endpoint_path(
+        mut self,
+        endpoint_path: impl Into<String>,
+    ) -> Result<BlipsClientBuilder<'a>, ParseError> {
+        let endpoint_path = endpoint_path.into();
+        self.base_url.join(&endpoint_path)?;
+        self.endpoint_path = Some(endpoint_path);
         Ok(self)
     }
 
     /// Sets the session token that the client will use.
     pub fn session_cookie(mut self, session_cookie: &'a SessionCookie) -> Self {
-        self.session_cookie = session_cookie;
+        self.session_cookie = Cow::Borrowed(session_cookie);
         self
     }
 
     /// Sets the CSRF token that the client will use.
     pub fn csrf_token(mut self, csrf_token: &'a CsrfToken) -> Self {
-        self.csrf_token = csrf_token;
+        self.csrf_token = Cow::Borrowed(csrf_token);
+        self
+    }
+
+    /// Adds an additional credential with the given name, for operations that
+    /// authenticate differently from the rest, such as with a service account.
+    ///
+    /// The credential is only used for the operations assigned to it with
+    /// [`BlipsClientBuilder::operation_credential`].
+    pub fn credential(
+        mut self,
+        name: impl Into<String>,
+        session_cookie: &SessionCookie,
+        csrf_token: &CsrfToken,
+    ) -> Self {
+        self.credentials.insert(
+            name.into(),
+            Credential {
+                session_cookie: session_cookie.clone(),
+                csrf_token: csrf_token.clone(),
+            },
+        );
+        self
+    }
+
+    /// Sets the operation with the given name, such as `UpdateTask`, to use the credential
+    /// with the given name instead of the primary session cookie and CSRF token.
+    ///
+    /// Operations that aren't assigned a credential use the primary one. When the
+    /// credential isn't added with [`BlipsClientBuilder::credential`], executing the
+    /// operation fails with [`BlipsError::Config`].
+    pub fn operation_credential(
+        mut self,
+        operation_name: impl Into<String>,
+        credential_name: impl Into<String>,
+    ) -> Self {
+        self.operation_credentials
+            .insert(operation_name.into(), credential_name.into());
+        self
+    }
+
+    /// Sets how [`BlipsClient::execute_optional`] treats a response without data for
+    /// operations of the given kind.
+    ///
+    /// By default, empty data is returned as `None` for queries and is a
+    /// [`BlipsError::EmptyResponse`] for mutations and subscriptions.
+    pub fn empty_data(mut self, kind: OperationKind, empty_data: EmptyData) -> Self {
+        self.empty_data.insert(kind, empty_data);
+        self
+    }
+
+    /// Sets how [`BlipsClient::execute_optional`] treats a response without data for the
+    /// operation with the given name, such as `Task`.
+    ///
+    /// This takes precedence over the setting for the kind of the operation, set with
+    /// [`BlipsClientBuilder::empty_data`].
+    pub fn operation_empty_data(
+        mut self,
+        operation_name: impl Into<String>,
+        empty_data: EmptyData,
+    ) -> Self {
+        self.operation_empty_data
+            .insert(operation_name.into(), empty_data);
+        self
+    }
+
+    /// Sets the timeout for connecting to the Blips API.
+    ///
+    /// This takes precedence over the `BLIPS_CONNECT_TIMEOUT` environment variable read by
+    /// [`BlipsClientBuilder::from_env`]. By default there is no connect timeout.
+    pub fn connect_timeout(mut self, connect_timeout: Duration) -> Self {
+        self.connect_timeout = Some(connect_timeout);
+        self
+    }
+
+    /// Sets the timeout for an entire request, from connecting until the response body
+    /// has been read.
+    ///
+    /// This takes precedence over the `BLIPS_REQUEST_TIMEOUT` environment variable read by
+    /// [`BlipsClientBuilder::from_env`]. By default there is no request timeout.
+    pub fn request_timeout(mut self, request_timeout: Duration) -> Self {
+        self.request_timeout = Some(request_timeout);
+        self
+    }
+
+    /// Sets whether the client should remember the request ID of the most recent call,
+    /// which is then available from [`BlipsClient::last_request_id`].
+    ///
+    /// This is disabled by default, as it synchronizes every call on a shared lock.
+    pub fn track_request_ids(mut self, track_request_ids: bool) -> Self {
+        self.track_request_ids = track_request_ids;
+        self
+    }
+
+    /// Sets whether the client should send persisted document IDs instead of documents.
+    ///
+    /// The IDs are read from the map passed to the codegen with `--persisted-ids`.
+    /// Operations without an ID are still sent as full documents. This is disabled by
+    /// default, as the server must have the documents registered.
+    pub fn persisted_documents(mut self, persisted_documents: bool) -> Self {
+        self.persisted_documents = persisted_documents;
+        self
+    }
+
+    /// Sets whether the client should accept `application/graphql-response+json` responses,
+    /// as defined by the GraphQL over HTTP specification.
+    ///
+    /// When the server responds with this content type, a response with a non-success
+    /// status that contains GraphQL errors is reported as [`BlipsError::GraphQl`] instead
+    /// of [`BlipsError::Http`]. Servers that don't support it may still respond with
+    /// `application/json`. This is disabled by default.
+    pub fn graphql_response_json(mut self, graphql_response_json: bool) -> Self {
+        self.graphql_response_json = graphql_response_json;
+        self
+    }
+
+    /// Sets whether the client should cache the responses to queries that have an `ETag`,
+    /// and revalidate them with `If-None-Match`.
+    ///
+    /// Responses are cached per operation and variables. When the server responds with
+    /// `304 Not Modified`, the cached response is used instead. This only applies to the
+    /// generated query operations; mutations and ad-hoc queries are never cached. The
+    /// cache lives as long as the client, and holds up to
+    /// [`BlipsClientBuilder::etag_cache_capacity`] responses. This is disabled by default.
+    pub fn etag_caching(mut self, etag_caching: bool) -> Self {
+        self.etag_caching = etag_caching;
+        self
+    }
+
+    /// Sets the maximum number of responses the ETag cache holds, which is 256 by default.
+    ///
+    /// Once the cache is full, caching another response evicts the least recently used
+    /// one, so a client that makes queries with many distinct variables doesn't grow
+    /// without bound. This only applies with [`BlipsClientBuilder::etag_caching`].
+    pub fn etag_cache_capacity(mut self, etag_cache_capacity: usize) -> Self {
+        self.etag_cache_capacity = etag_cache_capacity;
+        self
+    }
+
+    /// Sets whether identical queries made while one is in flight should share its
+    /// response instead of sending requests of their own.
+    ///
+    /// Queries are identical when they have the same operation and variables, and are sent
+    /// with the same `If-Match` value, if any. Unlike caching, nothing is kept once the
+    /// request completes, including its retries, so only concurrent queries are
+    /// deduplicated. This only applies to the generated query operations; mutations and
+    /// ad-hoc queries are always sent. When the shared request fails, the queries waiting
+    /// on it send their own requests, as errors can't be shared. This is disabled by
+    /// default.
+    pub fn deduplicate_queries(mut self, deduplicate_queries: bool) -> Self {
+        self.deduplicate_queries = deduplicate_queries;
+        self
+    }
+
+    /// Sets how failed requests are retried.
+    ///
+    /// See [`RetryConfig`] for the failures that are retried. By default, requests are
+    /// not retried.
+    pub fn retry(mut self, retry: RetryConfig) -> Self {
+        self.retry = Some(retry);
+        self
+    }
+
+    /// Sets the maximum size of a response body, in bytes.
+    ///
+    /// Responses with a larger body fail with [`BlipsError::ResponseTooLarge`] as soon as
+    /// the limit is exceeded, without reading the rest of the body. By default, there is no
+    /// limit.
+    pub fn max_response_bytes(mut self, max_response_bytes: usize) -> Self {
+        self.max_response_bytes = Some(max_response_bytes);
+        self
+    }
+
+    /// Sets the maximum number of requests the client sends at the same time.
+    ///
+    /// Calls beyond the limit wait until an earlier call completes, including its
+    /// retries. This limits concurrency rather than rate, and is meant to keep bursts of
+    /// calls from overwhelming the server or exhausting connections. The limit is shared by
+    /// the clones of the client. A limit of zero is treated as one. By default, there is
+    /// no limit.
+    pub fn max_concurrency(mut self, max_concurrency: usize) -> Self {
+        self.max_concurrency = Some(max_concurrency.max(1));
+        self
+    }
+
+    /// Sets how long to wait for a response to a query before sending the same request a
+    /// second time, and using whichever response arrives first.
+    ///
+    /// Hedging trades extra load on the server for lower tail latency. It only applies to
+    /// the generated query operations, which are safe to send twice; mutations and ad-hoc
+    /// queries are never hedged. The request that loses is cancelled. The second request
+    /// shares the first one's slot of [`BlipsClientBuilder::max_concurrency`]. By default,
+    /// requests are not hedged.
+    pub fn hedge_delay(mut self, hedge_delay: Duration) -> Self {
+        self.hedge_delay = Some(hedge_delay);
+        self
+    }
+
+    /// Sets whether requests are validated against the document of their operation before
+    /// they are sent.
+    ///
+    /// The validation checks that the operation name matches the document, that every
+    /// required variable is present, and that the variables roughly match their declared
+    /// types. Invalid requests fail with [`BlipsError::InvalidRequest`] without being sent.
+    /// This catches inconsistencies between hand-edited documents and variables early.
+    ///
+    /// By default, requests are validated in debug builds and not in release builds.
+    pub fn validate_requests(mut self, validate_requests: bool) -> Self {
+        self.validate_requests = validate_requests;
+        self
+    }
+
+    /// Sets a function that extracts the standard GraphQL response from the body of each
+    /// response, for servers that wrap it in an envelope of their own.
+    ///
+    /// The function receives the JSON body of every response that is deserialized, and
+    /// must return an object with the `data` and `errors` of a standard GraphQL response,
+    /// such as `{ "data": ..., "errors": [...] }`. Servers may leave the envelope out of
+    /// some responses, such as errors, so it should return the body as-is when it doesn't
+    /// have the envelope. If the returned value doesn't have the shape of a GraphQL
+    /// response, the call fails with a [`BlipsError::Deserialize`] whose path is relative
+    /// to the returned value.
+    ///
+    /// By default, the body is expected to be a standard GraphQL response.
+    pub fn response_extractor(
+        mut self,
+        response_extractor: impl Fn(serde_json::Value) -> serde_json::Value + Send + Sync + 'static,
+    ) -> Self {
+        self.response_extractor = Some(Arc::new(response_extractor));
+        self
+    }
+
+    /// Adds a header to send with every request.
+    ///
+    /// Returns [`BlipsError::Config`] if the name or value isn't a valid header.
+    pub fn header(mut self, name: &str, value: &str) -> Result<Self, BlipsError> {
+        let name = HeaderName::from_bytes(name.as_bytes())
+            .map_err(|err| BlipsError::Config(format!("header `{}`: {}", name, err)))?;
+        let value = HeaderValue::from_str(value)
+            .map_err(|err| BlipsError::Config(format!("header `{}`: {}", name, err)))?;
+
+        self.headers.append(name, value);
+        Ok(self)
+    }
+
+    /// Sets the cassette file that HTTP interactions are recorded to and replayed from.
+    ///
+    /// Interactions are keyed by operation name and variables. This takes precedence over
+    /// the `BLIPS_VCR_CASSETTE` and `BLIPS_VCR_MODE` environment variables read by
+    /// [`BlipsClientBuilder::from_env`].
+    #[cfg(feature = "vcr")]
+    pub fn vcr(
+        mut self,
+        cassette_path: impl Into<std::path::PathBuf>,
+        mode: crate::VcrMode,
+    ) -> Self {
+        self.vcr = Some((cassette_path.into(), mode));
+        self
+    }
+
+    /// Sets the clock that the delays between retries are measured with, so that tests can
+    /// advance it manually instead of waiting for the delays.
+    ///
+    /// This is only intended for tests. By default, the client follows the system time.
+    #[cfg(feature = "test-util")]
+    pub fn mock_clock(mut self, clock: crate::MockClock) -> Self {
+        self.clock = Arc::new(clock);
+        self
+    }
+
+    /// Sets whether request bodies should be compressed using gzip.
+    ///
+    /// This is disabled by default, as not all servers accept compressed requests.
+    #[cfg(feature = "gzip")]
+    pub fn gzip_requests(mut self, gzip_requests: bool) -> Self {
+        self.gzip_requests = gzip_requests;
         self
     }
 
     /// Consumes the builder and returns the constructed client.
     pub fn build(self) -> BlipsClient {
-        let client = reqwest::Client::builder()
+        let mut client = reqwest::Client::builder()
             .user_agent(concat!("blips/", env!("CARGO_PKG_VERSION")))
-            .build()
-            .unwrap();
+            .default_headers(self.headers);
+
+        if let Some(connect_timeout) = self.connect_timeout {
+            client = client.connect_timeout(connect_timeout);
+        }
+
+        if let Some(request_timeout) = self.request_timeout {
+            client = client.timeout(request_timeout);
+        }
+
+        let client = client.build().unwrap();
+
+        // The path was checked against the base URL when either of them was set.
+        let base_url = match &self.endpoint_path {
+            Some(endpoint_path) => self.base_url.join(endpoint_path).unwrap(),
+            None => self.base_url,
+        };
 
         BlipsClient {
-            base_url: self.base_url,
-            session_cookie: self.session_cookie.to_owned(),
-            csrf_token: self.csrf_token.to_owned(),
+            base_url,
+            session_cookie: self.session_cookie.into_owned(),
+            csrf_token: self.csrf_token.into_owned(),
+            credentials: self.credentials,
+            operation_credentials: self.operation_credentials,
+            empty_data: self.empty_data,
+            operation_empty_data: self.operation_empty_data,
+            connect_timeout: self.connect_timeout,
+            request_timeout: self.request_timeout,
+            #[cfg(feature = "gzip")]
+            gzip_requests: self.gzip_requests,
+            persisted_documents: self.persisted_documents,
+            graphql_response_json: self.graphql_response_json,
+            etag_cache: self
+                .etag_caching
+                .then(|| Arc::new(EtagCache::new(self.etag_cache_capacity))),
+            in_flight_queries: self.deduplicate_queries.then(Arc::default),
+            default_variables: serde_json::Map::new(),
+            retry: self.retry,
+            max_response_bytes: self.max_response_bytes,
+            validate_requests: self.validate_requests,
+            response_extractor: self.response_extractor,
+            concurrency_limit: self
+                .max_concurrency
+                .map(|max_concurrency| Arc::new(async_lock::Semaphore::new(max_concurrency))),
+            hedge_delay: self.hedge_delay,
+            clock: self.clock,
+            #[cfg(feature = "vcr")]
+            cassette: self
+                .vcr
+                .map(|(path, mode)| Arc::new(crate::vcr::Cassette::new(path, mode))),
+            last_request_id: self.track_request_ids.then(Default::default),
             client,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use wiremock::matchers::method;
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    use super::*;
+    use crate::graphql::tasks;
+
+    #[test]
+    fn etag_cache_evicts_the_least_recently_used_response() {
+        let cache = EtagCache::new(2);
+        let etag = |etag: &'static str| Some(HeaderValue::from_static(etag));
+
+        cache.store("a".to_string(), etag("\"1\""), b"a");
+        cache.store("b".to_string(), etag("\"2\""), b"b");
+        assert_eq!(cache.body("a"), Some(b"a".to_vec()));
+        cache.store("c".to_string(), etag("\"3\""), b"c");
+
+        assert_eq!(cache.etag("a"), etag("\"1\""));
+        assert_eq!(cache.etag("b"), None);
+        assert_eq!(cache.etag("c"), etag("\"3\""));
+    }
+
+    #[test]
+    fn etag_cache_updates_responses_in_place_when_full() {
+        let cache = EtagCache::new(2);
+        let etag = |etag: &'static str| Some(HeaderValue::from_static(etag));
+
+        cache.store("a".to_string(), etag("\"1\""), b"a");
+        cache.store("b".to_string(), etag("\"2\""), b"b");
+        cache.store("a".to_string(), etag("\"3\""), b"a2");
+
+        assert_eq!(cache.body("a"), Some(b"a2".to_vec()));
+        assert_eq!(cache.body("b"), Some(b"b".to_vec()));
+    }
+
+    #[test]
+    fn etag_cache_without_capacity_stores_nothing() {
+        let cache = EtagCache::new(0);
+
+        cache.store(
+            "a".to_string(),
+            Some(HeaderValue::from_static("\"1\"")),
+            b"a",
+        );
+
+        assert_eq!(cache.etag("a"), None);
+    }
+
+    /// Returns a mock server that responds to every request after the given delay.
+    async fn delayed_mock_server(delay: Duration) -> MockServer {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(json!({ "data": { "tasks": [] } }))
+                    .set_delay(delay),
+            )
+            .mount(&server)
+            .await;
+
+        server
+    }
+
+    #[tokio::test]
+    async fn max_concurrency_is_shared_by_clones() {
+        let delay = Duration::from_millis(200);
+        let server = delayed_mock_server(delay).await;
+        let (session_cookie, csrf_token) = (
+            SessionCookie::from(String::new()),
+            CsrfToken::from(String::new()),
+        );
+        let client = BlipsClientBuilder::new(&session_cookie, &csrf_token)
+            .base_url(&server.uri())
+            .unwrap()
+            .max_concurrency(2)
+            .build();
+        let clone = client.clone();
+
+        let started = Instant::now();
+        let results = tokio::join!(
+            client.warm_up(),
+            client.warm_up(),
+            clone.warm_up(),
+            clone.warm_up(),
+        );
+
+        assert!(matches!(results, (Ok(()), Ok(()), Ok(()), Ok(()))));
+        // With two slots between them, the four calls take at least two rounds.
+        assert!(started.elapsed() >= delay * 2);
+    }
+
+    /// The bodies of responses without data: one with a `null` `data` and one without a
+    /// `data` key.
+    const EMPTY_DATA_RESPONSES: [&str; 2] = [r#"{ "data": null }"#, "{}"];
+
+    /// Returns a mock server that responds to every request with the given body.
+    async fn mock_server(body: &str) -> MockServer {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(200).set_body_raw(body, "application/json"))
+            .mount(&server)
+            .await;
+
+        server
+    }
+
+    /// Returns a client that sends its requests to the given mock server, configured with
+    /// `configure`.
+    fn mock_client(
+        server: &MockServer,
+        configure: impl FnOnce(BlipsClientBuilder<'_>) -> BlipsClientBuilder<'_>,
+    ) -> BlipsClient {
+        let (session_cookie, csrf_token) = (
+            SessionCookie::from(String::new()),
+            CsrfToken::from(String::new()),
+        );
+        let uri = server.uri();
+        let builder = BlipsClientBuilder::new(&session_cookie, &csrf_token)
+            .base_url(&uri)
+            .unwrap();
+
+        configure(builder).build()
+    }
+
+    #[tokio::test]
+    async fn queries_without_data_are_none() {
+        for body in EMPTY_DATA_RESPONSES {
+            let server = mock_server(body).await;
+            let client = mock_client(&server, |builder| builder);
+
+            let data = client
+                .execute_optional::<crate::graphql::Tasks>(tasks::Variables::default())
+                .await;
+
+            assert!(matches!(data, Ok(None)), "for {body}");
+        }
+    }
+
+    #[tokio::test]
+    async fn mutations_without_data_are_empty_responses() {
+        use crate::graphql::delete_task;
+
+        for body in EMPTY_DATA_RESPONSES {
+            let server = mock_server(body).await;
+            let client = mock_client(&server, |builder| builder);
+
+            let err = client
+                .execute_optional::<crate::graphql::DeleteTask>(delete_task::Variables::new(
+                    "task-1".to_string(),
+                ))
+                .await
+                .unwrap_err();
+
+            assert!(
+                matches!(
+                    err,
+                    BlipsError::EmptyResponse { ref operation_name }
+                        if operation_name == "DeleteTask"
+                ),
+                "for {body}"
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn empty_data_overrides_apply_to_responses_without_data() {
+        use crate::graphql::delete_task;
+
+        for body in EMPTY_DATA_RESPONSES {
+            let server = mock_server(body).await;
+            let client = mock_client(&server, |builder| {
+                builder
+                    .empty_data(OperationKind::Query, EmptyData::Error)
+                    .operation_empty_data("DeleteTask", EmptyData::None)
+            });
+
+            let query = client
+                .execute_optional::<crate::graphql::Tasks>(tasks::Variables::default())
+                .await;
+            let mutation = client
+                .execute_optional::<crate::graphql::DeleteTask>(delete_task::Variables::new(
+                    "task-1".to_string(),
+                ))
+                .await;
+
+            assert!(
+                matches!(query, Err(BlipsError::EmptyResponse { .. })),
+                "for {body}"
+            );
+            assert!(matches!(mutation, Ok(None)), "for {body}");
+        }
+    }
+
+    #[tokio::test]
+    async fn errors_without_data_are_graphql_errors() {
+        let bodies = [
+            r#"{ "data": null, "errors": [{ "message": "boom" }] }"#,
+            r#"{ "errors": [{ "message": "boom" }] }"#,
+        ];
+
+        for body in bodies {
+            let server = mock_server(body).await;
+            let client = mock_client(&server, |builder| builder);
+
+            let data = client
+                .execute_optional::<crate::graphql::Tasks>(tasks::Variables::default())
+                .await;
+
+            assert!(
+                matches!(data, Err(BlipsError::GraphQl { .. })),
+                "for {body}"
+            );
+        }
+    }
+
+    /// Returns a retry config that retries twice without waiting.
+    fn immediate_retries() -> RetryConfig {
+        RetryConfig::new()
+            .max_retries(2)
+            .base_delay(Duration::ZERO)
+            .jitter(crate::Jitter::None)
+    }
+
+    /// Returns the number of requests the mock server received.
+    async fn request_count(server: &MockServer) -> usize {
+        server.received_requests().await.unwrap().len()
+    }
+
+    fn delete_task_variables() -> crate::graphql::delete_task::Variables {
+        crate::graphql::delete_task::Variables::new("task-1".to_string())
+    }
+
+    #[tokio::test]
+    async fn mutations_are_not_resent_after_a_timeout() {
+        let server = delayed_mock_server(Duration::from_millis(200)).await;
+        let client = mock_client(&server, |builder| {
+            builder
+                .request_timeout(Duration::from_millis(50))
+                .retry(immediate_retries())
+        });
+
+        let result = client
+            .execute::<crate::graphql::DeleteTask>(delete_task_variables())
+            .await;
+
+        assert!(
+            matches!(result, Err(ref err) if err.transport_error_kind() == Some(crate::TransportErrorKind::Timeout))
+        );
+        assert_eq!(request_count(&server).await, 1);
+    }
+
+    #[tokio::test]
+    async fn queries_and_opted_in_mutations_are_resent_after_a_timeout() {
+        let server = delayed_mock_server(Duration::from_millis(200)).await;
+        let client = mock_client(&server, |builder| {
+            builder
+                .request_timeout(Duration::from_millis(50))
+                .retry(immediate_retries().retry_mutations(true))
+        });
+
+        let query = client
+            .execute::<crate::graphql::Tasks>(tasks::Variables::default())
+            .await;
+        assert!(query.is_err());
+        assert_eq!(request_count(&server).await, 3);
+
+        let mutation = client
+            .execute::<crate::graphql::DeleteTask>(delete_task_variables())
+            .await;
+        assert!(mutation.is_err());
+        assert_eq!(request_count(&server).await, 6);
+    }
+
+    #[tokio::test]
+    async fn mutations_are_only_resent_for_statuses_the_server_did_not_process() {
+        let sent_requests = [(502, 1), (504, 1), (503, 3), (429, 3)];
+
+        for (status, requests) in sent_requests {
+            let server = MockServer::start().await;
+            Mock::given(method("POST"))
+                .respond_with(ResponseTemplate::new(status))
+                .mount(&server)
+                .await;
+            let client = mock_client(&server, |builder| builder.retry(immediate_retries()));
+
+            let result = client
+                .execute::<crate::graphql::DeleteTask>(delete_task_variables())
+                .await;
+
+            assert!(matches!(result, Err(BlipsError::Http { status: got, .. }) if got == status));
+            assert_eq!(request_count(&server).await, requests, "for {status}");
+        }
+    }
+
+    #[cfg(feature = "test-util")]
+    #[tokio::test]
+    async fn retries_sleep_for_the_exact_backoff_delays_of_a_mock_clock() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(503))
+            .mount(&server)
+            .await;
+        let clock = crate::MockClock::new();
+        let client = mock_client(&server, |builder| {
+            builder
+                .retry(
+                    RetryConfig::new()
+                        .max_retries(4)
+                        .base_delay(Duration::from_millis(100))
+                        .max_delay(Duration::from_millis(500))
+                        .jitter(crate::Jitter::None),
+                )
+                .mock_clock(clock.clone())
+        });
+        let delays = [100, 200, 400, 500].map(Duration::from_millis);
+
+        // Advances the clock by each delay once the client is waiting for it, so the
+        // client only continues when it slept for exactly that long.
+        let advance_clock = async {
+            for (retry, delay) in delays.into_iter().enumerate() {
+                for _ in 0..5000 {
+                    if clock.sleeps().len() > retry {
+                        break;
+                    }
+                    futures_timer::Delay::new(Duration::from_millis(1)).await;
+                }
+                assert_eq!(
+                    clock.sleeps().len(),
+                    retry + 1,
+                    "retry {retry} didn't sleep"
+                );
+                clock.advance(delay);
+            }
+        };
+        let (result, ()) = tokio::join!(
+            client.execute::<crate::graphql::Tasks>(tasks::Variables::default()),
+            advance_clock,
+        );
+
+        assert!(matches!(result, Err(BlipsError::Http { status: 503, .. })));
+        assert_eq!(clock.sleeps(), delays);
+        assert_eq!(clock.elapsed(), Duration::from_millis(1200));
+        assert_eq!(request_count(&server).await, 5);
+    }
+
+    #[tokio::test]
+    async fn identical_queries_in_flight_are_deduplicated_by_if_match() {
+        let server = delayed_mock_server(Duration::from_millis(100)).await;
+        let client = mock_client(&server, |builder| builder.deduplicate_queries(true));
+        let execute_if_match =
+            |etag| client.execute_if_match::<crate::graphql::Tasks>(Default::default(), etag);
+
+        let results = tokio::join!(
+            client.execute::<crate::graphql::Tasks>(Default::default()),
+            execute_if_match("\"1\""),
+            execute_if_match("\"1\""),
+            execute_if_match("\"2\""),
+        );
+
+        assert!(matches!(results, (Ok(_), Ok(_), Ok(_), Ok(_))));
+        // The queries with the same `If-Match` share a request, and the others don't.
+        let requests = server.received_requests().await.unwrap();
+        let mut if_matches = requests
+            .iter()
+            .map(|request| {
+                request
+                    .headers
+                    .iter()
+                    .find(|(name, _)| name.as_str() == IF_MATCH.as_str())
+                    .map(|(_, values)| values.last().as_str().to_owned())
+            })
+            .collect::<Vec<_>>();
+        if_matches.sort();
+        assert_eq!(
+            if_matches,
+            vec![None, Some("\"1\"".to_string()), Some("\"2\"".to_string())]
+        );
+    }
+}
@@ -2,621 +2,832 @@ impl crate::BlipsClient {
     pub async fn board(
         &self,
         variables: crate::graphql::board::Variables,
-    ) -> Result<crate::graphql::board::ResponseData, reqwest::Error> {
-        let response_body = self
-            .post_graphql::<crate::graphql::Board>(variables)
-            .await?;
-
-        Ok(response_body.data.expect("No data"))
+    ) -> crate::Result<crate::graphql::board::ResponseData> {
+        self.execute::<crate::graphql::Board>(variables).await
     }
 
     pub async fn boards(
         &self,
         variables: crate::graphql::boards::Variables,
-    ) -> Result<crate::graphql::boards::ResponseData, reqwest::Error> {
-        let response_body = self
-            .post_graphql::<crate::graphql::Boards>(variables)
-            .await?;
-
-        Ok(response_body.data.expect("No data"))
+    ) -> crate::Result<crate::graphql::boards::ResponseData> {
+        self.execute::<crate::graphql::Boards>(variables).await
     }
 
     pub async fn container(
         &self,
         variables: crate::graphql::container::Variables,
-    ) -> Result<crate::graphql::container::ResponseData, reqwest::Error> {
-        let response_body = self
-            .post_graphql::<crate::graphql::Container>(variables)
-            .await?;
-
-        Ok(response_body.data.expect("No data"))
+    ) -> crate::Result<crate::graphql::container::ResponseData> {
+        self.execute::<crate::graphql::Container>(variables).await
     }
 
     pub async fn current_user(
         &self,
         variables: crate::graphql::current_user::Variables,
-    ) -> Result<crate::graphql::current_user::ResponseData, reqwest::Error> {
-        let response_body = self
-            .post_graphql::<crate::graphql::CurrentUser>(variables)
-            .await?;
-
-        Ok(response_body.data.expect("No data"))
+    ) -> crate::Result<crate::graphql::current_user::ResponseData> {
+        self.execute::<crate::graphql::CurrentUser>(variables).await
     }
 
     pub async fn diary(
         &self,
         variables: crate::graphql::diary::Variables,
-    ) -> Result<crate::graphql::diary::ResponseData, reqwest::Error> {
-        let response_body = self
-            .post_graphql::<crate::graphql::Diary>(variables)
-            .await?;
-
-        Ok(response_body.data.expect("No data"))
+    ) -> crate::Result<crate::graphql::diary::ResponseData> {
+        self.execute::<crate::graphql::Diary>(variables).await
     }
 
     pub async fn me(
         &self,
         variables: crate::graphql::me::Variables,
-    ) -> Result<crate::graphql::me::ResponseData, reqwest::Error> {
-        let response_body = self.post_graphql::<crate::graphql::Me>(variables).await?;
-
-        Ok(response_body.data.expect("No data"))
+    ) -> crate::Result<crate::graphql::me::ResponseData> {
+        self.execute::<crate::graphql::Me>(variables).await
     }
 
     pub async fn note(
         &self,
         variables: crate::graphql::note::Variables,
-    ) -> Result<crate::graphql::note::ResponseData, reqwest::Error> {
-        let response_body = self.post_graphql::<crate::graphql::Note>(variables).await?;
-
-        Ok(response_body.data.expect("No data"))
+    ) -> crate::Result<crate::graphql::note::ResponseData> {
+        self.execute::<crate::graphql::Note>(variables).await
     }
 
     pub async fn notes(
         &self,
         variables: crate::graphql::notes::Variables,
-    ) -> Result<crate::graphql::notes::ResponseData, reqwest::Error> {
-        let response_body = self
-            .post_graphql::<crate::graphql::Notes>(variables)
-            .await?;
-
-        Ok(response_body.data.expect("No data"))
+    ) -> crate::Result<crate::graphql::notes::ResponseData> {
+        self.execute::<crate::graphql::Notes>(variables).await
     }
 
     pub async fn project(
         &self,
         variables: crate::graphql::project::Variables,
-    ) -> Result<crate::graphql::project::ResponseData, reqwest::Error> {
-        let response_body = self
-            .post_graphql::<crate::graphql::Project>(variables)
-            .await?;
-
-        Ok(response_body.data.expect("No data"))
+    ) -> crate::Result<crate::graphql::project::ResponseData> {
+        self.execute::<crate::graphql::Project>(variables).await
     }
 
     pub async fn project_columns(
         &self,
         variables: crate::graphql::project_columns::Variables,
-    ) -> Result<crate::graphql::project_columns::ResponseData, reqwest::Error> {
-        let response_body = self
-            .post_graphql::<crate::graphql::ProjectColumns>(variables)
-            .await?;
-
-        Ok(response_body.data.expect("No data"))
+    ) -> crate::Result<crate::graphql::project_columns::ResponseData> {
+        self.execute::<crate::graphql::ProjectColumns>(variables)
+            .await
     }
 
     pub async fn projects(
         &self,
         variables: crate::graphql::projects::Variables,
-    ) -> Result<crate::graphql::projects::ResponseData, reqwest::Error> {
-        let response_body = self
-            .post_graphql::<crate::graphql::Projects>(variables)
-            .await?;
-
-        Ok(response_body.data.expect("No data"))
+    ) -> crate::Result<crate::graphql::projects::ResponseData> {
+        self.execute::<crate::graphql::Projects>(variables).await
     }
 
     pub async fn search(
         &self,
         variables: crate::graphql::search::Variables,
-    ) -> Result<crate::graphql::search::ResponseData, reqwest::Error> {
-        let response_body = self
-            .post_graphql::<crate::graphql::Search>(variables)
-            .await?;
-
-        Ok(response_body.data.expect("No data"))
+    ) -> crate::Result<crate::graphql::search::ResponseData> {
+        self.execute::<crate::graphql::Search>(variables).await
     }
 
     pub async fn tags(
         &self,
         variables: crate::graphql::tags::Variables,
-    ) -> Result<crate::graphql::tags::ResponseData, reqwest::Error> {
-        let response_body = self.post_graphql::<crate::graphql::Tags>(variables).await?;
-
-        Ok(response_body.data.expect("No data"))
+    ) -> crate::Result<crate::graphql::tags::ResponseData> {
+        self.execute::<crate::graphql::Tags>(variables).await
     }
 
     pub async fn tasks(
         &self,
         variables: crate::graphql::tasks::Variables,
-    ) -> Result<crate::graphql::tasks::ResponseData, reqwest::Error> {
-        let response_body = self
-            .post_graphql::<crate::graphql::Tasks>(variables)
-            .await?;
-
-        Ok(response_body.data.expect("No data"))
+    ) -> crate::Result<crate::graphql::tasks::ResponseData> {
+        self.execute::<crate::graphql::Tasks>(variables).await
     }
 
     pub async fn archive_board(
         &self,
         variables: crate::graphql::archive_board::Variables,
-    ) -> Result<crate::graphql::archive_board::ResponseData, reqwest::Error> {
-        let response_body = self
-            .post_graphql::<crate::graphql::ArchiveBoard>(variables)
-            .await?;
-
-        Ok(response_body.data.expect("No data"))
+    ) -> crate::Result<crate::graphql::archive_board::ResponseData> {
+        self.execute::<crate::graphql::ArchiveBoard>(variables)
+            .await
     }
 
     pub async fn complete_project(
         &self,
         variables: crate::graphql::complete_project::Variables,
-    ) -> Result<crate::graphql::complete_project::ResponseData, reqwest::Error> {
-        let response_body = self
-            .post_graphql::<crate::graphql::CompleteProject>(variables)
-            .await?;
-
-        Ok(response_body.data.expect("No data"))
+    ) -> crate::Result<crate::graphql::complete_project::ResponseData> {
+        self.execute::<crate::graphql::CompleteProject>(variables)
+            .await
     }
 
     pub async fn complete_task(
         &self,
         variables: crate::graphql::complete_task::Variables,
-    ) -> Result<crate::graphql::complete_task::ResponseData, reqwest::Error> {
-        let response_body = self
-            .post_graphql::<crate::graphql::CompleteTask>(variables)
-            .await?;
-
-        Ok(response_body.data.expect("No data"))
+    ) -> crate::Result<crate::graphql::complete_task::ResponseData> {
+        self.execute::<crate::graphql::CompleteTask>(variables)
+            .await
     }
 
     pub async fn create_board(
         &self,
         variables: crate::graphql::create_board::Variables,
-    ) -> Result<crate::graphql::create_board::ResponseData, reqwest::Error> {
-        let response_body = self
-            .post_graphql::<crate::graphql::CreateBoard>(variables)
-            .await?;
-
-        Ok(response_body.data.expect("No data"))
+    ) -> crate::Result<crate::graphql::create_board::ResponseData> {
+        self.execute::<crate::graphql::CreateBoard>(variables).await
     }
 
     pub async fn create_boards(
         &self,
         variables: crate::graphql::create_boards::Variables,
-    ) -> Result<crate::graphql::create_boards::ResponseData, reqwest::Error> {
-        let response_body = self
-            .post_graphql::<crate::graphql::CreateBoards>(variables)
-            .await?;
-
-        Ok(response_body.data.expect("No data"))
+    ) -> crate::Result<crate::graphql::create_boards::ResponseData> {
+        self.execute::<crate::graphql::CreateBoards>(variables)
+            .await
     }
 
     pub async fn create_groups(
         &self,
         variables: crate::graphql::create_groups::Variables,
-    ) -> Result<crate::graphql::create_groups::ResponseData, reqwest::Error> {
-        let response_body = self
-            .post_graphql::<crate::graphql::CreateGroups>(variables)
-            .await?;
-
-        Ok(response_body.data.expect("No data"))
+    ) -> crate::Result<crate::graphql::create_groups::ResponseData> {
+        self.execute::<crate::graphql::CreateGroups>(variables)
+            .await
     }
 
     pub async fn create_note(
         &self,
         variables: crate::graphql::create_note::Variables,
-    ) -> Result<crate::graphql::create_note::ResponseData, reqwest::Error> {
-        let response_body = self
-            .post_graphql::<crate::graphql::CreateNote>(variables)
-            .await?;
-
-        Ok(response_body.data.expect("No data"))
+    ) -> crate::Result<crate::graphql::create_note::ResponseData> {
+        self.execute::<crate::graphql::CreateNote>(variables).await
     }
 
     pub async fn create_project(
         &self,
         variables: crate::graphql::create_project::Variables,
-    ) -> Result<crate::graphql::create_project::ResponseData, reqwest::Error> {
-        let response_body = self
-            .post_graphql::<crate::graphql::CreateProject>(variables)
-            .await?;
-
-        Ok(response_body.data.expect("No data"))
+    ) -> crate::Result<crate::graphql::create_project::ResponseData> {
+        self.execute::<crate::graphql::CreateProject>(variables)
+            .await
     }
 
     pub async fn create_project_column(
         &self,
         variables: crate::graphql::create_project_column::Variables,
-    ) -> Result<crate::graphql::create_project_column::ResponseData, reqwest::Error> {
-        let response_body = self
-            .post_graphql::<crate::graphql::CreateProjectColumn>(variables)
-            .await?;
-
-        Ok(response_body.data.expect("No data"))
+    ) -> crate::Result<crate::graphql::create_project_column::ResponseData> {
+        self.execute::<crate::graphql::CreateProjectColumn>(variables)
+            .await
     }
 
     pub async fn create_projects(
         &self,
         variables: crate::graphql::create_projects::Variables,
-    ) -> Result<crate::graphql::create_projects::ResponseData, reqwest::Error> {
-        let response_body = self
-            .post_graphql::<crate::graphql::CreateProjects>(variables)
-            .await?;
-
-        Ok(response_body.data.expect("No data"))
+    ) -> crate::Result<crate::graphql::create_projects::ResponseData> {
+        self.execute::<crate::graphql::CreateProjects>(variables)
+            .await
     }
 
     pub async fn create_tasks(
         &self,
         variables: crate::graphql::create_tasks::Variables,
-    ) -> Result<crate::graphql::create_tasks::ResponseData, reqwest::Error> {
-        let response_body = self
-            .post_graphql::<crate::graphql::CreateTasks>(variables)
-            .await?;
-
-        Ok(response_body.data.expect("No data"))
+    ) -> crate::Result<crate::graphql::create_tasks::ResponseData> {
+        self.execute::<crate::graphql::CreateTasks>(variables).await
     }
 
     pub async fn delete_board(
         &self,
         variables: crate::graphql::delete_board::Variables,
-    ) -> Result<crate::graphql::delete_board::ResponseData, reqwest::Error> {
-        let response_body = self
-            .post_graphql::<crate::graphql::DeleteBoard>(variables)
-            .await?;
-
-        Ok(response_body.data.expect("No data"))
+    ) -> crate::Result<crate::graphql::delete_board::ResponseData> {
+        self.execute::<crate::graphql::DeleteBoard>(variables).await
     }
 
     pub async fn delete_group(
         &self,
         variables: crate::graphql::delete_group::Variables,
-    ) -> Result<crate::graphql::delete_group::ResponseData, reqwest::Error> {
-        let response_body = self
-            .post_graphql::<crate::graphql::DeleteGroup>(variables)
-            .await?;
-
-        Ok(response_body.data.expect("No data"))
+    ) -> crate::Result<crate::graphql::delete_group::ResponseData> {
+        self.execute::<crate::graphql::DeleteGroup>(variables).await
     }
 
     pub async fn delete_note(
         &self,
         variables: crate::graphql::delete_note::Variables,
-    ) -> Result<crate::graphql::delete_note::ResponseData, reqwest::Error> {
-        let response_body = self
-            .post_graphql::<crate::graphql::DeleteNote>(variables)
-            .await?;
-
-        Ok(response_body.data.expect("No data"))
+    ) -> crate::Result<crate::graphql::delete_note::ResponseData> {
+        self.execute::<crate::graphql::DeleteNote>(variables).await
     }
 
     pub async fn delete_project(
         &self,
         variables: crate::graphql::delete_project::Variables,
-    ) -> Result<crate::graphql::delete_project::ResponseData, reqwest::Error> {
-        let response_body = self
-            .post_graphql::<crate::graphql::DeleteProject>(variables)
-            .await?;
-
-        Ok(response_body.data.expect("No data"))
+    ) -> crate::Result<crate::graphql::delete_project::ResponseData> {
+        self.execute::<crate::graphql::DeleteProject>(variables)
+            .await
     }
 
     pub async fn delete_task(
         &self,
         variables: crate::graphql::delete_task::Variables,
-    ) -> Result<crate::graphql::delete_task::ResponseData, reqwest::Error> {
-        let response_body = self
-            .post_graphql::<crate::graphql::DeleteTask>(variables)
-            .await?;
-
-        Ok(response_body.data.expect("No data"))
+    ) -> crate::Result<crate::graphql::delete_task::ResponseData> {
+        self.execute::<crate::graphql::DeleteTask>(variables).await
     }
 
     pub async fn delete_tasks(
         &self,
         variables: crate::graphql::delete_tasks::Variables,
-    ) -> Result<crate::graphql::delete_tasks::ResponseData, reqwest::Error> {
-        let response_body = self
-            .post_graphql::<crate::graphql::DeleteTasks>(variables)
-            .await?;
-
-        Ok(response_body.data.expect("No data"))
+    ) -> crate::Result<crate::graphql::delete_tasks::ResponseData> {
+        self.execute::<crate::graphql::DeleteTasks>(variables).await
     }
 
     pub async fn enable_otp(
         &self,
         variables: crate::graphql::enable_otp::Variables,
-    ) -> Result<crate::graphql::enable_otp::ResponseData, reqwest::Error> {
-        let response_body = self
-            .post_graphql::<crate::graphql::EnableOtp>(variables)
-            .await?;
-
-        Ok(response_body.data.expect("No data"))
+    ) -> crate::Result<crate::graphql::enable_otp::ResponseData> {
+        self.execute::<crate::graphql::EnableOtp>(variables).await
     }
 
     pub async fn generate_new_otp(
         &self,
         variables: crate::graphql::generate_new_otp::Variables,
-    ) -> Result<crate::graphql::generate_new_otp::ResponseData, reqwest::Error> {
-        let response_body = self
-            .post_graphql::<crate::graphql::GenerateNewOtp>(variables)
-            .await?;
-
-        Ok(response_body.data.expect("No data"))
+    ) -> crate::Result<crate::graphql::generate_new_otp::ResponseData> {
+        self.execute::<crate::graphql::GenerateNewOtp>(variables)
+            .await
     }
 
     pub async fn move_tasks(
         &self,
         variables: crate::graphql::move_tasks::Variables,
-    ) -> Result<crate::graphql::move_tasks::ResponseData, reqwest::Error> {
-        let response_body = self
-            .post_graphql::<crate::graphql::MoveTasks>(variables)
-            .await?;
-
-        Ok(response_body.data.expect("No data"))
+    ) -> crate::Result<crate::graphql::move_tasks::ResponseData> {
+        self.execute::<crate::graphql::MoveTasks>(variables).await
     }
 
     pub async fn persist_group_order(
         &self,
         variables: crate::graphql::persist_group_order::Variables,
-    ) -> Result<crate::graphql::persist_group_order::ResponseData, reqwest::Error> {
-        let response_body = self
-            .post_graphql::<crate::graphql::PersistGroupOrder>(variables)
-            .await?;
-
-        Ok(response_body.data.expect("No data"))
+    ) -> crate::Result<crate::graphql::persist_group_order::ResponseData> {
+        self.execute::<crate::graphql::PersistGroupOrder>(variables)
+            .await
     }
 
     pub async fn persist_priority_order(
         &self,
         variables: crate::graphql::persist_priority_order::Variables,
-    ) -> Result<crate::graphql::persist_priority_order::ResponseData, reqwest::Error> {
-        let response_body = self
-            .post_graphql::<crate::graphql::PersistPriorityOrder>(variables)
-            .await?;
-
-        Ok(response_body.data.expect("No data"))
+    ) -> crate::Result<crate::graphql::persist_priority_order::ResponseData> {
+        self.execute::<crate::graphql::PersistPriorityOrder>(variables)
+            .await
     }
 
     pub async fn persist_project_column_order(
         &self,
         variables: crate::graphql::persist_project_column_order::Variables,
-    ) -> Result<crate::graphql::persist_project_column_order::ResponseData, reqwest::Error> {
-        let response_body = self
-            .post_graphql::<crate::graphql::PersistProjectColumnOrder>(variables)
-            .await?;
-
-        Ok(response_body.data.expect("No data"))
+    ) -> crate::Result<crate::graphql::persist_project_column_order::ResponseData> {
+        self.execute::<crate::graphql::PersistProjectColumnOrder>(variables)
+            .await
     }
 
     pub async fn persist_project_order(
         &self,
         variables: crate::graphql::persist_project_order::Variables,
-    ) -> Result<crate::graphql::persist_project_order::ResponseData, reqwest::Error> {
-        let response_body = self
-            .post_graphql::<crate::graphql::PersistProjectOrder>(variables)
-            .await?;
-
-        Ok(response_body.data.expect("No data"))
+    ) -> crate::Result<crate::graphql::persist_project_order::ResponseData> {
+        self.execute::<crate::graphql::PersistProjectOrder>(variables)
+            .await
     }
 
     pub async fn persist_task_order(
         &self,
         variables: crate::graphql::persist_task_order::Variables,
-    ) -> Result<crate::graphql::persist_task_order::ResponseData, reqwest::Error> {
-        let response_body = self
-            .post_graphql::<crate::graphql::PersistTaskOrder>(variables)
-            .await?;
-
-        Ok(response_body.data.expect("No data"))
+    ) -> crate::Result<crate::graphql::persist_task_order::ResponseData> {
+        self.execute::<crate::graphql::PersistTaskOrder>(variables)
+            .await
     }
 
     pub async fn prioritize_tasks(
         &self,
         variables: crate::graphql::prioritize_tasks::Variables,
-    ) -> Result<crate::graphql::prioritize_tasks::ResponseData, reqwest::Error> {
-        let response_body = self
-            .post_graphql::<crate::graphql::PrioritizeTasks>(variables)
-            .await?;
-
-        Ok(response_body.data.expect("No data"))
+    ) -> crate::Result<crate::graphql::prioritize_tasks::ResponseData> {
+        self.execute::<crate::graphql::PrioritizeTasks>(variables)
+            .await
     }
 
     pub async fn register_user(
         &self,
         variables: crate::graphql::register_user::Variables,
-    ) -> Result<crate::graphql::register_user::ResponseData, reqwest::Error> {
-        let response_body = self
-            .post_graphql::<crate::graphql::RegisterUser>(variables)
-            .await?;
-
-        Ok(response_body.data.expect("No data"))
+    ) -> crate::Result<crate::graphql::register_user::ResponseData> {
+        self.execute::<crate::graphql::RegisterUser>(variables)
+            .await
     }
 
     pub async fn spring_project(
         &self,
         variables: crate::graphql::spring_project::Variables,
-    ) -> Result<crate::graphql::spring_project::ResponseData, reqwest::Error> {
-        let response_body = self
-            .post_graphql::<crate::graphql::SpringProject>(variables)
-            .await?;
-
-        Ok(response_body.data.expect("No data"))
+    ) -> crate::Result<crate::graphql::spring_project::ResponseData> {
+        self.execute::<crate::graphql::SpringProject>(variables)
+            .await
     }
 
     pub async fn tag_task(
         &self,
         variables: crate::graphql::tag_task::Variables,
-    ) -> Result<crate::graphql::tag_task::ResponseData, reqwest::Error> {
-        let response_body = self
-            .post_graphql::<crate::graphql::TagTask>(variables)
-            .await?;
-
-        Ok(response_body.data.expect("No data"))
+    ) -> crate::Result<crate::graphql::tag_task::ResponseData> {
+        self.execute::<crate::graphql::TagTask>(variables).await
     }
 
     pub async fn unarchive_board(
         &self,
         variables: crate::graphql::unarchive_board::Variables,
-    ) -> Result<crate::graphql::unarchive_board::ResponseData, reqwest::Error> {
-        let response_body = self
-            .post_graphql::<crate::graphql::UnarchiveBoard>(variables)
-            .await?;
-
-        Ok(response_body.data.expect("No data"))
+    ) -> crate::Result<crate::graphql::unarchive_board::ResponseData> {
+        self.execute::<crate::graphql::UnarchiveBoard>(variables)
+            .await
     }
 
     pub async fn uncomplete_project(
         &self,
         variables: crate::graphql::uncomplete_project::Variables,
-    ) -> Result<crate::graphql::uncomplete_project::ResponseData, reqwest::Error> {
-        let response_body = self
-            .post_graphql::<crate::graphql::UncompleteProject>(variables)
-            .await?;
-
-        Ok(response_body.data.expect("No data"))
+    ) -> crate::Result<crate::graphql::uncomplete_project::ResponseData> {
+        self.execute::<crate::graphql::UncompleteProject>(variables)
+            .await
     }
 
     pub async fn uncomplete_task(
         &self,
         variables: crate::graphql::uncomplete_task::Variables,
-    ) -> Result<crate::graphql::uncomplete_task::ResponseData, reqwest::Error> {
-        let response_body = self
-            .post_graphql::<crate::graphql::UncompleteTask>(variables)
-            .await?;
-
-        Ok(response_body.data.expect("No data"))
+    ) -> crate::Result<crate::graphql::uncomplete_task::ResponseData> {
+        self.execute::<crate::graphql::UncompleteTask>(variables)
+            .await
     }
 
     pub async fn unprioritize_tasks(
         &self,
         variables: crate::graphql::unprioritize_tasks::Variables,
-    ) -> Result<crate::graphql::unprioritize_tasks::ResponseData, reqwest::Error> {
-        let response_body = self
-            .post_graphql::<crate::graphql::UnprioritizeTasks>(variables)
-            .await?;
-
-        Ok(response_body.data.expect("No data"))
+    ) -> crate::Result<crate::graphql::unprioritize_tasks::ResponseData> {
+        self.execute::<crate::graphql::UnprioritizeTasks>(variables)
+            .await
     }
 
     pub async fn unspring_project(
         &self,
         variables: crate::graphql::unspring_project::Variables,
-    ) -> Result<crate::graphql::unspring_project::ResponseData, reqwest::Error> {
-        let response_body = self
-            .post_graphql::<crate::graphql::UnspringProject>(variables)
-            .await?;
-
-        Ok(response_body.data.expect("No data"))
+    ) -> crate::Result<crate::graphql::unspring_project::ResponseData> {
+        self.execute::<crate::graphql::UnspringProject>(variables)
+            .await
     }
 
     pub async fn update_board(
         &self,
         variables: crate::graphql::update_board::Variables,
-    ) -> Result<crate::graphql::update_board::ResponseData, reqwest::Error> {
-        let response_body = self
-            .post_graphql::<crate::graphql::UpdateBoard>(variables)
-            .await?;
-
-        Ok(response_body.data.expect("No data"))
+    ) -> crate::Result<crate::graphql::update_board::ResponseData> {
+        self.execute::<crate::graphql::UpdateBoard>(variables).await
     }
 
     pub async fn update_container(
         &self,
         variables: crate::graphql::update_container::Variables,
-    ) -> Result<crate::graphql::update_container::ResponseData, reqwest::Error> {
-        let response_body = self
-            .post_graphql::<crate::graphql::UpdateContainer>(variables)
-            .await?;
-
-        Ok(response_body.data.expect("No data"))
+    ) -> crate::Result<crate::graphql::update_container::ResponseData> {
+        self.execute::<crate::graphql::UpdateContainer>(variables)
+            .await
     }
 
     pub async fn update_diary(
         &self,
         variables: crate::graphql::update_diary::Variables,
-    ) -> Result<crate::graphql::update_diary::ResponseData, reqwest::Error> {
-        let response_body = self
-            .post_graphql::<crate::graphql::UpdateDiary>(variables)
-            .await?;
-
-        Ok(response_body.data.expect("No data"))
+    ) -> crate::Result<crate::graphql::update_diary::ResponseData> {
+        self.execute::<crate::graphql::UpdateDiary>(variables).await
     }
 
     pub async fn update_group(
         &self,
         variables: crate::graphql::update_group::Variables,
-    ) -> Result<crate::graphql::update_group::ResponseData, reqwest::Error> {
-        let response_body = self
-            .post_graphql::<crate::graphql::UpdateGroup>(variables)
-            .await?;
-
-        Ok(response_body.data.expect("No data"))
+    ) -> crate::Result<crate::graphql::update_group::ResponseData> {
+        self.execute::<crate::graphql::UpdateGroup>(variables).await
     }
 
     pub async fn update_note(
         &self,
         variables: crate::graphql::update_note::Variables,
-    ) -> Result<crate::graphql::update_note::ResponseData, reqwest::Error> {
-        let response_body = self
-            .post_graphql::<crate::graphql::UpdateNote>(variables)
-            .await?;
-
-        Ok(response_body.data.expect("No data"))
+    ) -> crate::Result<crate::graphql::update_note::ResponseData> {
+        self.execute::<crate::graphql::UpdateNote>(variables).await
     }
 
     pub async fn update_project(
         &self,
         variables: crate::graphql::update_project::Variables,
-    ) -> Result<crate::graphql::update_project::ResponseData, reqwest::Error> {
-        let response_body = self
-            .post_graphql::<crate::graphql::UpdateProject>(variables)
-            .await?;
-
-        Ok(response_body.data.expect("No data"))
+    ) -> crate::Result<crate::graphql::update_project::ResponseData> {
+        self.execute::<crate::graphql::UpdateProject>(variables)
+            .await
     }
 
     pub async fn update_project_column(
         &self,
         variables: crate::graphql::update_project_column::Variables,
-    ) -> Result<crate::graphql::update_project_column::ResponseData, reqwest::Error> {
-        let response_body = self
-            .post_graphql::<crate::graphql::UpdateProjectColumn>(variables)
-            .await?;
-
-        Ok(response_body.data.expect("No data"))
+    ) -> crate::Result<crate::graphql::update_project_column::ResponseData> {
+        self.execute::<crate::graphql::UpdateProjectColumn>(variables)
+            .await
     }
 
     pub async fn update_task(
         &self,
         variables: crate::graphql::update_task::Variables,
-    ) -> Result<crate::graphql::update_task::ResponseData, reqwest::Error> {
-        let response_body = self
-            .post_graphql::<crate::graphql::UpdateTask>(variables)
-            .await?;
-
-        Ok(response_body.data.expect("No data"))
+    ) -> crate::Result<crate::graphql::update_task::ResponseData> {
+        self.execute::<crate::graphql::UpdateTask>(variables).await
     }
 
     pub async fn update_user_settings(
         &self,
         variables: crate::graphql::update_user_settings::Variables,
-    ) -> Result<crate::graphql::update_user_settings::ResponseData, reqwest::Error> {
-        let response_body = self
-            .post_graphql::<crate::graphql::UpdateUserSettings>(variables)
-            .await?;
+    ) -> crate::Result<crate::graphql::update_user_settings::ResponseData> {
+        self.execute::<crate::graphql::UpdateUserSettings>(variables)
+            .await
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl crate::blocking::BlipsClient {
+    pub fn board(
+        &self,
+        variables: crate::graphql::board::Variables,
+    ) -> crate::Result<crate::graphql::board::ResponseData> {
+        self.block_on(self.client().board(variables))
+    }
+
+    pub fn boards(
+        &self,
+        variables: crate::graphql::boards::Variables,
+    ) -> crate::Result<crate::graphql::boards::ResponseData> {
+        self.block_on(self.client().boards(variables))
+    }
 
-        Ok(response_body.data.expect("No data"))
+    pub fn container(
+        &self,
+        variables: crate::graphql::container::Variables,
+    ) -> crate::Result<crate::graphql::container::ResponseData> {
+        self.block_on(self.client().container(variables))
+    }
+
+    pub fn current_user(
+        &self,
+        variables: crate::graphql::current_user::Variables,
+    ) -> crate::Result<crate::graphql::current_user::ResponseData> {
+        self.block_on(self.client().current_user(variables))
+    }
+
+    pub fn diary(
+        &self,
+        variables: crate::graphql::diary::Variables,
+    ) -> crate::Result<crate::graphql::diary::ResponseData> {
+        self.block_on(self.client().diary(variables))
+    }
+
+    pub fn me(
+        &self,
+        variables: crate::graphql::me::Variables,
+    ) -> crate::Result<crate::graphql::me::ResponseData> {
+        self.block_on(self.client().me(variables))
+    }
+
+    pub fn note(
+        &self,
+        variables: crate::graphql::note::Variables,
+    ) -> crate::Result<crate::graphql::note::ResponseData> {
+        self.block_on(self.client().note(variables))
+    }
+
+    pub fn notes(
+        &self,
+        variables: crate::graphql::notes::Variables,
+    ) -> crate::Result<crate::graphql::notes::ResponseData> {
+        self.block_on(self.client().notes(variables))
+    }
+
+    pub fn project(
+        &self,
+        variables: crate::graphql::project::Variables,
+    ) -> crate::Result<crate::graphql::project::ResponseData> {
+        self.block_on(self.client().project(variables))
+    }
+
+    pub fn project_columns(
+        &self,
+        variables: crate::graphql::project_columns::Variables,
+    ) -> crate::Result<crate::graphql::project_columns::ResponseData> {
+        self.block_on(self.client().project_columns(variables))
+    }
+
+    pub fn projects(
+        &self,
+        variables: crate::graphql::projects::Variables,
+    ) -> crate::Result<crate::graphql::projects::ResponseData> {
+        self.block_on(self.client().projects(variables))
+    }
+
+    pub fn search(
+        &self,
+        variables: crate::graphql::search::Variables,
+    ) -> crate::Result<crate::graphql::search::ResponseData> {
+        self.block_on(self.client().search(variables))
+    }
+
+    pub fn tags(
+        &self,
+        variables: crate::graphql::tags::Variables,
+    ) -> crate::Result<crate::graphql::tags::ResponseData> {
+        self.block_on(self.client().tags(variables))
+    }
+
+    pub fn tasks(
+        &self,
+        variables: crate::graphql::tasks::Variables,
+    ) -> crate::Result<crate::graphql::tasks::ResponseData> {
+        self.block_on(self.client().tasks(variables))
+    }
+
+    pub fn archive_board(
+        &self,
+        variables: crate::graphql::archive_board::Variables,
+    ) -> crate::Result<crate::graphql::archive_board::ResponseData> {
+        self.block_on(self.client().archive_board(variables))
+    }
+
+    pub fn complete_project(
+        &self,
+        variables: crate::graphql::complete_project::Variables,
+    ) -> crate::Result<crate::graphql::complete_project::ResponseData> {
+        self.block_on(self.client().complete_project(variables))
+    }
+
+    pub fn complete_task(
+        &self,
+        variables: crate::graphql::complete_task::Variables,
+    ) -> crate::Result<crate::graphql::complete_task::ResponseData> {
+        self.block_on(self.client().complete_task(variables))
+    }
+
+    pub fn create_board(
+        &self,
+        variables: crate::graphql::create_board::Variables,
+    ) -> crate::Result<crate::graphql::create_board::ResponseData> {
+        self.block_on(self.client().create_board(variables))
+    }
+
+    pub fn create_boards(
+        &self,
+        variables: crate::graphql::create_boards::Variables,
+    ) -> crate::Result<crate::graphql::create_boards::ResponseData> {
+        self.block_on(self.client().create_boards(variables))
+    }
+
+    pub fn create_groups(
+        &self,
+        variables: crate::graphql::create_groups::Variables,
+    ) -> crate::Result<crate::graphql::create_groups::ResponseData> {
+        self.block_on(self.client().create_groups(variables))
+    }
+
+    pub fn create_note(
+        &self,
+        variables: crate::graphql::create_note::Variables,
+    ) -> crate::Result<crate::graphql::create_note::ResponseData> {
+        self.block_on(self.client().create_note(variables))
+    }
+
+    pub fn create_project(
+        &self,
+        variables: crate::graphql::create_project::Variables,
+    ) -> crate::Result<crate::graphql::create_project::ResponseData> {
+        self.block_on(self.client().create_project(variables))
+    }
+
+    pub fn create_project_column(
+        &self,
+        variables: crate::graphql::create_project_column::Variables,
+    ) -> crate::Result<crate::graphql::create_project_column::ResponseData> {
+        self.block_on(self.client().create_project_column(variables))
+    }
+
+    pub fn create_projects(
+        &self,
+        variables: crate::graphql::create_projects::Variables,
+    ) -> crate::Result<crate::graphql::create_projects::ResponseData> {
+        self.block_on(self.client().create_projects(variables))
+    }
+
+    pub fn create_tasks(
+        &self,
+        variables: crate::graphql::create_tasks::Variables,
+    ) -> crate::Result<crate::graphql::create_tasks::ResponseData> {
+        self.block_on(self.client().create_tasks(variables))
+    }
+
+    pub fn delete_board(
+        &self,
+        variables: crate::graphql::delete_board::Variables,
+    ) -> crate::Result<crate::graphql::delete_board::ResponseData> {
+        self.block_on(self.client().delete_board(variables))
+    }
+
+    pub fn delete_group(
+        &self,
+        variables: crate::graphql::delete_group::Variables,
+    ) -> crate::Result<crate::graphql::delete_group::ResponseData> {
+        self.block_on(self.client().delete_group(variables))
+    }
+
+    pub fn delete_note(
+        &self,
+        variables: crate::graphql::delete_note::Variables,
+    ) -> crate::Result<crate::graphql::delete_note::ResponseData> {
+        self.block_on(self.client().delete_note(variables))
+    }
+
+    pub fn delete_project(
+        &self,
+        variables: crate::graphql::delete_project::Variables,
+    ) -> crate::Result<crate::graphql::delete_project::ResponseData> {
+        self.block_on(self.client().delete_project(variables))
+    }
+
+    pub fn delete_task(
+        &self,
+        variables: crate::graphql::delete_task::Variables,
+    ) -> crate::Result<crate::graphql::delete_task::ResponseData> {
+        self.block_on(self.client().delete_task(variables))
+    }
+
+    pub fn delete_tasks(
+        &self,
+        variables: crate::graphql::delete_tasks::Variables,
+    ) -> crate::Result<crate::graphql::delete_tasks::ResponseData> {
+        self.block_on(self.client().delete_tasks(variables))
+    }
+
+    pub fn enable_otp(
+        &self,
+        variables: crate::graphql::enable_otp::Variables,
+    ) -> crate::Result<crate::graphql::enable_otp::ResponseData> {
+        self.block_on(self.client().enable_otp(variables))
+    }
+
+    pub fn generate_new_otp(
+        &self,
+        variables: crate::graphql::generate_new_otp::Variables,
+    ) -> crate::Result<crate::graphql::generate_new_otp::ResponseData> {
+        self.block_on(self.client().generate_new_otp(variables))
+    }
+
+    pub fn move_tasks(
+        &self,
+        variables: crate::graphql::move_tasks::Variables,
+    ) -> crate::Result<crate::graphql::move_tasks::ResponseData> {
+        self.block_on(self.client().move_tasks(variables))
+    }
+
+    pub fn persist_group_order(
+        &self,
+        variables: crate::graphql::persist_group_order::Variables,
+    ) -> crate::Result<crate::graphql::persist_group_order::ResponseData> {
+        self.block_on(self.client().persist_group_order(variables))
+    }
+
+    pub fn persist_priority_order(
+        &self,
+        variables: crate::graphql::persist_priority_order::Variables,
+    ) -> crate::Result<crate::graphql::persist_priority_order::ResponseData> {
+        self.block_on(self.client().persist_priority_order(variables))
+    }
+
+    pub fn persist_project_column_order(
+        &self,
+        variables: crate::graphql::persist_project_column_order::Variables,
+    ) -> crate::Result<crate::graphql::persist_project_column_order::ResponseData> {
+        self.block_on(self.client().persist_project_column_order(variables))
+    }
+
+    pub fn persist_project_order(
+        &self,
+        variables: crate::graphql::persist_project_order::Variables,
+    ) -> crate::Result<crate::graphql::persist_project_order::ResponseData> {
+        self.block_on(self.client().persist_project_order(variables))
+    }
+
+    pub fn persist_task_order(
+        &self,
+        variables: crate::graphql::persist_task_order::Variables,
+    ) -> crate::Result<crate::graphql::persist_task_order::ResponseData> {
+        self.block_on(self.client().persist_task_order(variables))
+    }
+
+    pub fn prioritize_tasks(
+        &self,
+        variables: crate::graphql::prioritize_tasks::Variables,
+    ) -> crate::Result<crate::graphql::prioritize_tasks::ResponseData> {
+        self.block_on(self.client().prioritize_tasks(variables))
+    }
+
+    pub fn register_user(
+        &self,
+        variables: crate::graphql::register_user::Variables,
+    ) -> crate::Result<crate::graphql::register_user::ResponseData> {
+        self.block_on(self.client().register_user(variables))
+    }
+
+    pub fn spring_project(
+        &self,
+        variables: crate::graphql::spring_project::Variables,
+    ) -> crate::Result<crate::graphql::spring_project::ResponseData> {
+        self.block_on(self.client().spring_project(variables))
+    }
+
+    pub fn tag_task(
+        &self,
+        variables: crate::graphql::tag_task::Variables,
+    ) -> crate::Result<crate::graphql::tag_task::ResponseData> {
+        self.block_on(self.client().tag_task(variables))
+    }
+
+    pub fn unarchive_board(
+        &self,
+        variables: crate::graphql::unarchive_board::Variables,
+    ) -> crate::Result<crate::graphql::unarchive_board::ResponseData> {
+        self.block_on(self.client().unarchive_board(variables))
+    }
+
+    pub fn uncomplete_project(
+        &self,
+        variables: crate::graphql::uncomplete_project::Variables,
+    ) -> crate::Result<crate::graphql::uncomplete_project::ResponseData> {
+        self.block_on(self.client().uncomplete_project(variables))
+    }
+
+    pub fn uncomplete_task(
+        &self,
+        variables: crate::graphql::uncomplete_task::Variables,
+    ) -> crate::Result<crate::graphql::uncomplete_task::ResponseData> {
+        self.block_on(self.client().uncomplete_task(variables))
+    }
+
+    pub fn unprioritize_tasks(
+        &self,
+        variables: crate::graphql::unprioritize_tasks::Variables,
+    ) -> crate::Result<crate::graphql::unprioritize_tasks::ResponseData> {
+        self.block_on(self.client().unprioritize_tasks(variables))
+    }
+
+    pub fn unspring_project(
+        &self,
+        variables: crate::graphql::unspring_project::Variables,
+    ) -> crate::Result<crate::graphql::unspring_project::ResponseData> {
+        self.block_on(self.client().unspring_project(variables))
+    }
+
+    pub fn update_board(
+        &self,
+        variables: crate::graphql::update_board::Variables,
+    ) -> crate::Result<crate::graphql::update_board::ResponseData> {
+        self.block_on(self.client().update_board(variables))
+    }
+
+    pub fn update_container(
+        &self,
+        variables: crate::graphql::update_container::Variables,
+    ) -> crate::Result<crate::graphql::update_container::ResponseData> {
+        self.block_on(self.client().update_container(variables))
+    }
+
+    pub fn update_diary(
+        &self,
+        variables: crate::graphql::update_diary::Variables,
+    ) -> crate::Result<crate::graphql::update_diary::ResponseData> {
+        self.block_on(self.client().update_diary(variables))
+    }
+
+    pub fn update_group(
+        &self,
+        variables: crate::graphql::update_group::Variables,
+    ) -> crate::Result<crate::graphql::update_group::ResponseData> {
+        self.block_on(self.client().update_group(variables))
+    }
+
+    pub fn update_note(
+        &self,
+        variables: crate::graphql::update_note::Variables,
+    ) -> crate::Result<crate::graphql::update_note::ResponseData> {
+        self.block_on(self.client().update_note(variables))
+    }
+
+    pub fn update_project(
+        &self,
+        variables: crate::graphql::update_project::Variables,
+    ) -> crate::Result<crate::graphql::update_project::ResponseData> {
+        self.block_on(self.client().update_project(variables))
+    }
+
+    pub fn update_project_column(
+        &self,
+        variables: crate::graphql::update_project_column::Variables,
+    ) -> crate::Result<crate::graphql::update_project_column::ResponseData> {
+        self.block_on(self.client().update_project_column(variables))
+    }
+
+    pub fn update_task(
+        &self,
+        variables: crate::graphql::update_task::Variables,
+    ) -> crate::Result<crate::graphql::update_task::ResponseData> {
+        self.block_on(self.client().update_task(variables))
+    }
+
+    pub fn update_user_settings(
+        &self,
+        variables: crate::graphql::update_user_settings::Variables,
+    ) -> crate::Result<crate::graphql::update_user_settings::ResponseData> {
+        self.block_on(self.client().update_user_settings(variables))
     }
 }
+
+/// The persisted document IDs of the operations, keyed by operation name.
+pub(crate) const PERSISTED_DOCUMENT_IDS: &[(&str, &str)] = &[];
@@ -0,0 +1,145 @@
+use std::time::Duration;
+
+use futures_util::future::BoxFuture;
+
+/// A source of time for the delays between retries.
+pub(crate) trait Clock: Send + Sync {
+    /// Returns a future that completes once the given duration has passed.
+    fn sleep(&self, duration: Duration) -> BoxFuture<'static, ()>;
+}
+
+/// The clock that follows the system time, which is used by default.
+pub(crate) struct SystemClock;
+
+impl Clock for SystemClock {
+    fn sleep(&self, duration: Duration) -> BoxFuture<'static, ()> {
+        Box::pin(futures_timer::Delay::new(duration))
+    }
+}
+
+#[cfg(feature = "test-util")]
+pub use mock::MockClock;
+
+#[cfg(feature = "test-util")]
+mod mock {
+    use std::collections::HashMap;
+    use std::future::Future;
+    use std::pin::Pin;
+    use std::sync::{Arc, Mutex};
+    use std::task::{Context, Poll, Waker};
+    use std::time::Duration;
+
+    use futures_util::future::BoxFuture;
+
+    use super::Clock;
+
+    /// A clock that only advances when told to, for testing the delays between retries
+    /// without waiting for them.
+    ///
+    /// Set it on a client with [`crate::BlipsClientBuilder::mock_clock`]. Clones share
+    /// the same time, so a clone can be kept to advance the clock while the client waits.
+    /// It is only intended for tests.
+    #[derive(Debug, Clone, Default)]
+    pub struct MockClock {
+        state: Arc<Mutex<MockClockState>>,
+    }
+
+    #[derive(Debug, Default)]
+    struct MockClockState {
+        elapsed: Duration,
+        sleeps: Vec<Duration>,
+        next_sleeper_id: u64,
+        sleepers: HashMap<u64, (Duration, Option<Waker>)>,
+    }
+
+    impl MockClock {
+        /// Returns a new [`MockClock`], starting at zero.
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        /// Advances the clock by the given duration, completing the sleeps that are due.
+        pub fn advance(&self, duration: Duration) {
+            let mut state = self.state.lock().unwrap();
+            state.elapsed += duration;
+
+            let elapsed = state.elapsed;
+            for (deadline, waker) in state.sleepers.values_mut() {
+                if *deadline <= elapsed {
+                    if let Some(waker) = waker.take() {
+                        waker.wake();
+                    }
+                }
+            }
+        }
+
+        /// Returns how far the clock has been advanced.
+        pub fn elapsed(&self) -> Duration {
+            self.state.lock().unwrap().elapsed
+        }
+
+        /// Returns the duration of every sleep started on the clock, in the order they
+        /// were started.
+        ///
+        /// For a client, these are the delays between retries.
+        pub fn sleeps(&self) -> Vec<Duration> {
+            self.state.lock().unwrap().sleeps.clone()
+        }
+
+        /// Returns the number of sleeps that haven't completed yet.
+        pub fn pending_sleeps(&self) -> usize {
+            self.state.lock().unwrap().sleepers.len()
+        }
+    }
+
+    impl Clock for MockClock {
+        fn sleep(&self, duration: Duration) -> BoxFuture<'static, ()> {
+            let mut state = self.state.lock().unwrap();
+            state.sleeps.push(duration);
+
+            let id = state.next_sleeper_id;
+            state.next_sleeper_id += 1;
+
+            let deadline = state.elapsed + duration;
+            state.sleepers.insert(id, (deadline, None));
+
+            Box::pin(MockSleep {
+                state: self.state.clone(),
+                id,
+            })
+        }
+    }
+
+    /// A sleep on a [`MockClock`], which completes once the clock reaches its deadline.
+    struct MockSleep {
+        state: Arc<Mutex<MockClockState>>,
+        id: u64,
+    }
+
+    impl Future for MockSleep {
+        type Output = ();
+
+        fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+            let mut state = self.state.lock().unwrap();
+            let elapsed = state.elapsed;
+
+            match state.sleepers.get_mut(&self.id) {
+                Some((deadline, _)) if *deadline <= elapsed => {
+                    state.sleepers.remove(&self.id);
+                    Poll::Ready(())
+                }
+                Some((_, waker)) => {
+                    *waker = Some(cx.waker().clone());
+                    Poll::Pending
+                }
+                None => Poll::Ready(()),
+            }
+        }
+    }
+
+    impl Drop for MockSleep {
+        fn drop(&mut self) {
+            self.state.lock().unwrap().sleepers.remove(&self.id);
+        }
+    }
+}
@@ -0,0 +1,113 @@
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+use serde::{Deserialize, Deserializer};
+
+use crate::{CsrfToken, RetryConfig, SessionCookie};
+
+/// The configuration of a [`BlipsClient`](crate::BlipsClient), for loading it from a
+/// configuration file.
+///
+/// Only `session_cookie` and `csrf_token` are required. Durations are given in seconds:
+///
+/// ```toml
+/// session_cookie = "user_session=...; _chex_session=..."
+/// csrf_token = "..."
+/// endpoint = "https://blips.app/query"
+/// connect_timeout = 5
+/// request_timeout = 30
+///
+/// [retry]
+/// max_retries = 5
+/// base_delay = 0.2
+/// jitter = "equal"
+///
+/// [headers]
+/// X-Client-Name = "my-app"
+///
+/// [credentials.service]
+/// session_cookie = "user_session=...; _chex_session=..."
+/// csrf_token = "..."
+///
+/// [operation_credentials]
+/// ExportData = "service"
+/// ```
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ClientConfig {
+    /// The Blips session cookie.
+    pub session_cookie: SessionCookie,
+
+    /// The Blips CSRF token.
+    pub csrf_token: CsrfToken,
+
+    /// The URL of the GraphQL endpoint. Defaults to the Blips API.
+    #[serde(default)]
+    pub endpoint: Option<String>,
+
+    /// The timeout for connecting to the server. Defaults to no timeout.
+    #[serde(default, deserialize_with = "deserialize_optional_seconds")]
+    pub connect_timeout: Option<Duration>,
+
+    /// The timeout for the entire request. Defaults to no timeout.
+    #[serde(default, deserialize_with = "deserialize_optional_seconds")]
+    pub request_timeout: Option<Duration>,
+
+    /// How failed requests are retried. Defaults to not retrying them.
+    ///
+    /// Any fields left out of the retry configuration use the defaults of
+    /// [`RetryConfig::new`].
+    #[serde(default)]
+    pub retry: Option<RetryConfig>,
+
+    /// The maximum size of a response body, in bytes. Defaults to no limit.
+    #[serde(default)]
+    pub max_response_bytes: Option<usize>,
+
+    /// Additional headers to send with every request.
+    #[serde(default)]
+    pub headers: BTreeMap<String, String>,
+
+    /// Additional credentials, by name, for operations that authenticate differently from
+    /// the rest.
+    #[serde(default)]
+    pub credentials: BTreeMap<String, CredentialConfig>,
+
+    /// The names of the credentials to use for specific operations, by operation name.
+    ///
+    /// Operations that aren't listed use `session_cookie` and `csrf_token`.
+    #[serde(default)]
+    pub operation_credentials: BTreeMap<String, String>,
+}
+
+/// A named credential in a [`ClientConfig`].
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CredentialConfig {
+    /// The session cookie.
+    pub session_cookie: SessionCookie,
+
+    /// The CSRF token.
+    pub csrf_token: CsrfToken,
+}
+
+/// Deserializes a [`Duration`] from a non-negative number of seconds.
+pub(crate) fn deserialize_seconds<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Duration, D::Error> {
+    let seconds = f64::deserialize(deserializer)?;
+
+    Duration::try_from_secs_f64(seconds).map_err(|_| {
+        serde::de::Error::custom(format!(
+            "expected a non-negative number of seconds, got `{}`",
+            seconds
+        ))
+    })
+}
+
+/// Deserializes an optional [`Duration`] from a non-negative number of seconds.
+fn deserialize_optional_seconds<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<Duration>, D::Error> {
+    deserialize_seconds(deserializer).map(Some)
+}
@@ -1,5 +1,25 @@
+mod consistency_warning;
 mod csrf_token;
+mod empty_data;
+mod entity_state;
+mod extra_fields;
+mod field_change;
+mod one_or_many;
+mod operation_info;
+mod request_metrics;
 mod session_cookie;
+#[cfg(feature = "schema-reflection")]
+mod type_info;
 
+pub use consistency_warning::*;
 pub use csrf_token::*;
+pub use empty_data::*;
+pub use entity_state::*;
+pub use extra_fields::*;
+pub use field_change::*;
+pub use one_or_many::*;
+pub use operation_info::*;
+pub use request_metrics::*;
 pub use session_cookie::*;
+#[cfg(feature = "schema-reflection")]
+pub use type_info::*;
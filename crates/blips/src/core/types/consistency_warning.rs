@@ -0,0 +1,37 @@
+use std::fmt::Display;
+
+/// A contradiction between the fields of an entity, which can happen due to races or
+/// server bugs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ConsistencyWarning {
+    /// `completed` is `true`, but `completedAt` is missing.
+    CompletedWithoutCompletedAt,
+
+    /// `completed` is `false`, but `completedAt` is set.
+    CompletedAtWithoutCompleted,
+}
+
+impl ConsistencyWarning {
+    /// Returns the warning for an entity whose `completed` flag disagrees with whether it
+    /// has a `completedAt` time, if they disagree.
+    pub fn for_completion(completed: bool, has_completed_at: bool) -> Option<Self> {
+        match (completed, has_completed_at) {
+            (true, false) => Some(Self::CompletedWithoutCompletedAt),
+            (false, true) => Some(Self::CompletedAtWithoutCompleted),
+            (true, true) | (false, false) => None,
+        }
+    }
+}
+
+impl Display for ConsistencyWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::CompletedWithoutCompletedAt => {
+                write!(f, "`completed` is `true`, but `completedAt` is missing")
+            }
+            Self::CompletedAtWithoutCompleted => {
+                write!(f, "`completed` is `false`, but `completedAt` is set")
+            }
+        }
+    }
+}
@@ -1,7 +1,10 @@
 use std::fmt::Display;
 
+use serde::Deserialize;
+
 /// A Blips CSRF token.
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Deserialize)]
+#[serde(transparent)]
 pub struct CsrfToken(String);
 
 impl Display for CsrfToken {
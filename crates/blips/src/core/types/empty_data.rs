@@ -0,0 +1,15 @@
+/// How a response without data, and without errors, is treated by
+/// [`crate::BlipsClient::execute_optional`].
+///
+/// Some servers respond to a query for something that doesn't exist with `null` data and
+/// no errors. For such queries, empty data means "not found" and can be returned as
+/// `None`, while a mutation that returns no data almost always failed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EmptyData {
+    /// Empty data is a [`crate::BlipsError::EmptyResponse`], which is the default for
+    /// mutations and subscriptions.
+    Error,
+
+    /// Empty data is returned as `None`, which is the default for queries.
+    None,
+}
@@ -0,0 +1,64 @@
+/// The state of a task, derived from its flags.
+///
+/// When several flags are set, the first matching state in the following order wins:
+/// [`Completed`](TaskState::Completed), [`Recurring`](TaskState::Recurring),
+/// [`Spring`](TaskState::Spring), and finally [`Active`](TaskState::Active). For
+/// example, a completed recurring task is `Completed`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TaskState {
+    /// The task is active.
+    Active,
+
+    /// The task is completed.
+    Completed,
+
+    /// The task recurs.
+    Recurring,
+
+    /// The task is a spring task.
+    Spring,
+}
+
+impl TaskState {
+    /// Returns the state of a task with the given flags.
+    pub fn from_flags(completed: bool, is_recurring: bool, spring: bool) -> Self {
+        if completed {
+            Self::Completed
+        } else if is_recurring {
+            Self::Recurring
+        } else if spring {
+            Self::Spring
+        } else {
+            Self::Active
+        }
+    }
+}
+
+/// The state of a project, derived from its flags.
+///
+/// When both flags are set, [`Completed`](ProjectState::Completed) wins over
+/// [`Spring`](ProjectState::Spring).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ProjectState {
+    /// The project is active.
+    Active,
+
+    /// The project is completed.
+    Completed,
+
+    /// The project has spring enabled.
+    Spring,
+}
+
+impl ProjectState {
+    /// Returns the state of a project with the given flags.
+    pub fn from_flags(completed: bool, spring_enabled: bool) -> Self {
+        if completed {
+            Self::Completed
+        } else if spring_enabled {
+            Self::Spring
+        } else {
+            Self::Active
+        }
+    }
+}
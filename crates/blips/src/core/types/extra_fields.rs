@@ -0,0 +1,97 @@
+use heck::ToSnakeCase;
+use serde::{Deserialize, Deserializer};
+
+/// The fields of a response object that its generated type doesn't declare, such as a
+/// field the server added after the SDK was generated.
+///
+/// The generated response types only capture these in an `extras` field when the code is
+/// generated with `blips_codegen --extra-fields`. Otherwise, fields the types don't declare
+/// are ignored.
+pub type ExtraFields = serde_json::Map<String, serde_json::Value>;
+
+/// Deserializes the [`ExtraFields`] of a response object with their keys as received.
+///
+/// This is the `deserialize_with` function of the `extras` fields generated with
+/// `--extra-fields as-received`, which is the default. `__typename` is left out, as the
+/// generated documents select it on every object.
+pub fn deserialize_extra_fields<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<ExtraFields, D::Error> {
+    let mut fields = ExtraFields::deserialize(deserializer)?;
+    fields.remove("__typename");
+
+    Ok(fields)
+}
+
+/// Deserializes the [`ExtraFields`] of a response object with their keys converted to
+/// `snake_case`, such as `dueDate` to `due_date`.
+///
+/// This is the `deserialize_with` function of the `extras` fields generated with
+/// `--extra-fields snake-case`, for forwarding them to a system that expects `snake_case`
+/// keys. Only the keys of the extra fields are converted, not those of objects nested in
+/// their values. When two keys convert to the same name, the value of the last one is
+/// kept.
+pub fn deserialize_snake_case_extra_fields<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<ExtraFields, D::Error> {
+    let fields = deserialize_extra_fields(deserializer)?;
+
+    Ok(fields
+        .into_iter()
+        .map(|(key, value)| (key.to_snake_case(), value))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    /// A response type with `extras`, as generated with `--extra-fields as-received`.
+    #[derive(Debug, Deserialize)]
+    struct Task {
+        id: String,
+        #[serde(flatten, deserialize_with = "deserialize_extra_fields")]
+        extras: ExtraFields,
+    }
+
+    /// A response type with `extras`, as generated with `--extra-fields snake-case`.
+    #[derive(Debug, Deserialize)]
+    struct SnakeCaseTask {
+        id: String,
+        #[serde(flatten, deserialize_with = "deserialize_snake_case_extra_fields")]
+        extras: ExtraFields,
+    }
+
+    fn task_json() -> serde_json::Value {
+        json!({
+            "__typename": "Task",
+            "id": "task-1",
+            "dueDate": "2023-09-01",
+            "recurrence": { "repeatEvery": 2 },
+        })
+    }
+
+    #[test]
+    fn extra_fields_are_stored_as_received() {
+        let task: Task = serde_json::from_value(task_json()).unwrap();
+
+        assert_eq!(task.id, "task-1");
+        assert_eq!(
+            serde_json::Value::Object(task.extras),
+            json!({ "dueDate": "2023-09-01", "recurrence": { "repeatEvery": 2 } })
+        );
+    }
+
+    #[test]
+    fn extra_fields_are_converted_to_snake_case() {
+        let task: SnakeCaseTask = serde_json::from_value(task_json()).unwrap();
+
+        assert_eq!(task.id, "task-1");
+        assert_eq!(
+            serde_json::Value::Object(task.extras),
+            json!({ "due_date": "2023-09-01", "recurrence": { "repeatEvery": 2 } })
+        );
+    }
+}
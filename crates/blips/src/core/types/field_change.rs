@@ -0,0 +1,25 @@
+use serde::Serialize;
+
+/// A field whose value differs between two instances of the same type.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldChange {
+    /// The name of the field in the schema.
+    pub field: &'static str,
+
+    /// The value of the field in the first instance.
+    pub old: serde_json::Value,
+
+    /// The value of the field in the second instance.
+    pub new: serde_json::Value,
+}
+
+impl FieldChange {
+    /// Returns a change of the given field from `old` to `new`.
+    pub fn new<T: Serialize>(field: &'static str, old: &T, new: &T) -> Self {
+        Self {
+            field,
+            old: serde_json::to_value(old).unwrap_or_default(),
+            new: serde_json::to_value(new).unwrap_or_default(),
+        }
+    }
+}
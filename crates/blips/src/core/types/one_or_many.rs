@@ -0,0 +1,34 @@
+/// A value for a list argument, which is either a list or a single value.
+///
+/// GraphQL coerces a single value passed to a list argument into a list with one element.
+/// This mirrors that coercion for the `with_*` setters of the generated `Variables`, so
+/// that `with_task_ids(task_id)` can be used instead of `with_task_ids(vec![task_id])`.
+/// A single value is always sent as a list with one element, so it doesn't change what
+/// is sent to the server.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OneOrMany<T>(Vec<T>);
+
+impl<T> OneOrMany<T> {
+    /// Returns the values as a list.
+    pub fn into_vec(self) -> Vec<T> {
+        self.0
+    }
+}
+
+impl<T> From<T> for OneOrMany<T> {
+    fn from(value: T) -> Self {
+        Self(vec![value])
+    }
+}
+
+impl<T> From<Vec<T>> for OneOrMany<T> {
+    fn from(values: Vec<T>) -> Self {
+        Self(values)
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for OneOrMany<T> {
+    fn from(values: [T; N]) -> Self {
+        Self(Vec::from(values))
+    }
+}
@@ -0,0 +1,103 @@
+use std::error::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// The kind of a GraphQL operation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OperationKind {
+    /// A query, which reads data.
+    Query,
+
+    /// A mutation, which writes data.
+    Mutation,
+
+    /// A subscription, which streams data.
+    Subscription,
+}
+
+impl FromStr for OperationKind {
+    type Err = UnknownOperationKindError;
+
+    /// Parses an operation kind from its keyword in GraphQL documents, such as `query`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "query" => Ok(Self::Query),
+            "mutation" => Ok(Self::Mutation),
+            "subscription" => Ok(Self::Subscription),
+            _ => Err(UnknownOperationKindError(s.to_owned())),
+        }
+    }
+}
+
+/// An error returned when parsing an unknown [`OperationKind`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownOperationKindError(pub String);
+
+impl Display for UnknownOperationKindError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "unknown operation kind `{}`, expected `query`, `mutation`, or `subscription`",
+            self.0
+        )
+    }
+}
+
+impl Error for UnknownOperationKindError {}
+
+/// An error returned when parsing the name of an operation that wasn't generated.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownOperationError(pub String);
+
+impl Display for UnknownOperationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "unknown operation `{}`", self.0)
+    }
+}
+
+impl Error for UnknownOperationError {}
+
+/// Metadata about an argument of a generated operation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ArgumentInfo {
+    /// The name of the argument in the schema.
+    pub name: &'static str,
+
+    /// The name of the variable the document passes to the argument.
+    pub variable_name: &'static str,
+
+    /// The GraphQL type of the argument, such as `[ID!]!`.
+    pub graphql_type: &'static str,
+}
+
+impl ArgumentInfo {
+    /// Returns whether the argument must be provided, which is the case for non-null types.
+    pub fn is_required(&self) -> bool {
+        self.graphql_type.ends_with('!')
+    }
+}
+
+/// Metadata about a generated operation, for using the operations dynamically.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OperationInfo {
+    /// The name of the operation.
+    pub name: &'static str,
+
+    /// The kind of the operation.
+    pub kind: OperationKind,
+
+    /// The arguments of the operation, in the order they are declared in the schema.
+    pub arguments: &'static [ArgumentInfo],
+
+    /// The GraphQL document of the operation.
+    pub document: &'static str,
+}
+
+impl OperationInfo {
+    /// Returns the arguments that must be provided to execute the operation.
+    pub fn required_arguments(&self) -> impl Iterator<Item = &ArgumentInfo> {
+        self.arguments
+            .iter()
+            .filter(|argument| argument.is_required())
+    }
+}
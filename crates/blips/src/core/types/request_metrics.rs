@@ -0,0 +1,19 @@
+use std::time::Duration;
+
+/// Timings of a request, for finding out where its latency comes from.
+///
+/// The HTTP client doesn't expose the timings of the DNS lookup, the TCP connection, or
+/// the TLS handshake, so only the timings that can be measured around it are included.
+/// It is non-exhaustive so that those phases can be added once they are available.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct RequestMetrics {
+    /// The time from sending the request until the response headers were received.
+    ///
+    /// When the request was retried, this is the time of the last attempt. It is `None`
+    /// when the response was replayed from a cassette.
+    pub time_to_first_byte: Option<Duration>,
+
+    /// The time the whole call took, including retries and deserializing the response.
+    pub total: Duration,
+}
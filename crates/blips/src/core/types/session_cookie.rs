@@ -1,7 +1,10 @@
 use std::fmt::Display;
 
+use serde::Deserialize;
+
 /// A Blips session cookie.
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Deserialize)]
+#[serde(transparent)]
 pub struct SessionCookie(String);
 
 impl Display for SessionCookie {
@@ -0,0 +1,95 @@
+/// The kind of a type in the GraphQL schema.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TypeKind {
+    /// A scalar, such as `String` or a custom scalar.
+    Scalar,
+
+    /// An object, which has fields.
+    Object,
+
+    /// An interface, which objects implement.
+    Interface,
+
+    /// A union of objects.
+    Union,
+
+    /// An enum, which has a fixed set of values.
+    Enum,
+
+    /// An input object, which has input fields.
+    InputObject,
+}
+
+/// Metadata about an argument or an input field in the GraphQL schema.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InputValueInfo {
+    /// The name of the input value.
+    pub name: &'static str,
+
+    /// The GraphQL type of the input value, such as `[ID!]!`.
+    pub graphql_type: &'static str,
+
+    /// The default value of the input value, as a GraphQL literal.
+    pub default_value: Option<&'static str>,
+}
+
+impl InputValueInfo {
+    /// Returns whether the input value must be provided, which is the case for non-null
+    /// types without a default value.
+    pub fn is_required(&self) -> bool {
+        self.graphql_type.ends_with('!') && self.default_value.is_none()
+    }
+}
+
+/// Metadata about a field of an object or an interface in the GraphQL schema.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FieldInfo {
+    /// The name of the field.
+    pub name: &'static str,
+
+    /// The GraphQL type of the field, such as `[Task!]!`.
+    pub graphql_type: &'static str,
+
+    /// The arguments of the field.
+    pub arguments: &'static [InputValueInfo],
+
+    /// Whether the field is deprecated.
+    pub is_deprecated: bool,
+}
+
+/// Metadata about a type in the GraphQL schema, for introspecting the schema without
+/// parsing it at runtime.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TypeInfo {
+    /// The name of the type.
+    pub name: &'static str,
+
+    /// The kind of the type.
+    pub kind: TypeKind,
+
+    /// The fields of the type, if it's an object or an interface.
+    pub fields: &'static [FieldInfo],
+
+    /// The input fields of the type, if it's an input object.
+    pub input_fields: &'static [InputValueInfo],
+
+    /// The names of the values of the type, if it's an enum.
+    pub enum_values: &'static [&'static str],
+
+    /// The names of the objects that belong to the type, if it's a union or an interface.
+    pub possible_types: &'static [&'static str],
+}
+
+impl TypeInfo {
+    /// Returns the field with the given name, if the type has one.
+    pub fn field(&self, name: &str) -> Option<&'static FieldInfo> {
+        self.fields.iter().find(|field| field.name == name)
+    }
+
+    /// Returns the input field with the given name, if the type has one.
+    pub fn input_field(&self, name: &str) -> Option<&'static InputValueInfo> {
+        self.input_fields
+            .iter()
+            .find(|input_field| input_field.name == name)
+    }
+}
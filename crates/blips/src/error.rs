@@ -0,0 +1,664 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::error::Error;
+use std::fmt::Display;
+
+/// A result with a [`BlipsError`] by default.
+pub type Result<T, E = BlipsError> = std::result::Result<T, E>;
+
+/// An error that occurred while interacting with the Blips API.
+#[derive(Debug)]
+pub enum BlipsError {
+    /// The client configuration is invalid.
+    Config(String),
+
+    /// An error occurred while sending the request or receiving the response.
+    Transport(TransportError),
+
+    /// The server responded with a non-success HTTP status.
+    Http {
+        /// The status code of the response.
+        status: u16,
+
+        /// The body of the response.
+        body: String,
+    },
+
+    /// The response could not be deserialized.
+    Deserialize {
+        /// The name of the operation whose response could not be deserialized.
+        operation_name: String,
+
+        /// The JSON path to the value that could not be deserialized.
+        path: String,
+
+        /// The underlying deserialization error.
+        source: serde_json::Error,
+    },
+
+    /// The response contained GraphQL errors.
+    GraphQl {
+        /// The name of the operation that returned the errors.
+        operation_name: String,
+
+        /// The errors returned by the server.
+        errors: Vec<crate::runtime::Error>,
+    },
+
+    /// The response contained neither data nor errors.
+    EmptyResponse {
+        /// The name of the operation that returned the empty response.
+        operation_name: String,
+    },
+
+    /// The request didn't match the document of its operation, so it wasn't sent.
+    InvalidRequest {
+        /// The name of the operation whose request was invalid.
+        operation_name: String,
+
+        /// Why the request was invalid.
+        reason: String,
+    },
+
+    /// The response body was larger than the configured maximum.
+    ResponseTooLarge {
+        /// The maximum size of a response body, in bytes.
+        limit: usize,
+    },
+
+    /// No generated operation has the requested name.
+    UnknownOperation {
+        /// The name that was requested.
+        operation_name: String,
+    },
+}
+
+impl BlipsError {
+    /// Returns the HTTP status code of the response that caused the error, if there was
+    /// one.
+    pub fn status_code(&self) -> Option<u16> {
+        match self {
+            Self::Transport(err) => err.status_code(),
+            Self::Http { status, .. } => Some(*status),
+            Self::Config(_)
+            | Self::Deserialize { .. }
+            | Self::GraphQl { .. }
+            | Self::EmptyResponse { .. }
+            | Self::InvalidRequest { .. }
+            | Self::ResponseTooLarge { .. }
+            | Self::UnknownOperation { .. } => None,
+        }
+    }
+
+    /// Returns whether the server rejected a conditional request with
+    /// `412 Precondition Failed`, such as a write made with
+    /// [`BlipsClient::execute_if_match`](crate::BlipsClient::execute_if_match) to an entity
+    /// that changed since it was fetched.
+    pub fn is_precondition_failed(&self) -> bool {
+        self.status_code() == Some(reqwest::StatusCode::PRECONDITION_FAILED.as_u16())
+    }
+
+    /// Returns the class of the underlying transport error, for a [`BlipsError::Transport`].
+    pub fn transport_error_kind(&self) -> Option<TransportErrorKind> {
+        match self {
+            Self::Transport(err) => Some(err.kind()),
+            Self::Config(_)
+            | Self::Http { .. }
+            | Self::Deserialize { .. }
+            | Self::GraphQl { .. }
+            | Self::EmptyResponse { .. }
+            | Self::InvalidRequest { .. }
+            | Self::ResponseTooLarge { .. }
+            | Self::UnknownOperation { .. } => None,
+        }
+    }
+
+    /// Returns whether the error is transient, so that sending the request again may
+    /// succeed.
+    ///
+    /// Timeouts, connect errors, and `429`, `502`, `503`, and `504` responses are
+    /// retryable. Other transport errors, such as failing to send the request body, are
+    /// not, as the server may have already received the request.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Self::Transport(err) => err.kind().is_retryable(),
+            Self::Http { status, .. } => {
+                reqwest::StatusCode::from_u16(*status).is_ok_and(crate::retry::is_retryable_status)
+            }
+            Self::Config(_)
+            | Self::Deserialize { .. }
+            | Self::GraphQl { .. }
+            | Self::EmptyResponse { .. }
+            | Self::InvalidRequest { .. }
+            | Self::ResponseTooLarge { .. }
+            | Self::UnknownOperation { .. } => false,
+        }
+    }
+
+    /// Returns whether the error was caused by the server returning `null` for a field that
+    /// the schema declares as non-null.
+    ///
+    /// This means the server violated its own schema. The path of the
+    /// [`BlipsError::Deserialize`] error identifies the offending field.
+    pub fn is_unexpected_null(&self) -> bool {
+        match self {
+            Self::Deserialize { source, .. } => is_unexpected_null(source),
+            Self::Config(_)
+            | Self::Transport(_)
+            | Self::Http { .. }
+            | Self::GraphQl { .. }
+            | Self::EmptyResponse { .. }
+            | Self::InvalidRequest { .. }
+            | Self::ResponseTooLarge { .. }
+            | Self::UnknownOperation { .. } => false,
+        }
+    }
+
+    /// Returns the GraphQL errors grouped by the first segment of their path, which is the
+    /// requested field (or list index) they belong to.
+    ///
+    /// Errors without a path are grouped under `None`. Returns an empty map for errors
+    /// other than [`BlipsError::GraphQl`].
+    pub fn errors_by_field(&self) -> BTreeMap<Option<PathSegment>, Vec<&crate::runtime::Error>> {
+        match self {
+            Self::GraphQl { errors, .. } => group_errors_by_field(errors),
+            Self::Config(_)
+            | Self::Transport(_)
+            | Self::Http { .. }
+            | Self::Deserialize { .. }
+            | Self::EmptyResponse { .. }
+            | Self::InvalidRequest { .. }
+            | Self::ResponseTooLarge { .. }
+            | Self::UnknownOperation { .. } => BTreeMap::new(),
+        }
+    }
+
+    /// Returns the GraphQL errors deduplicated by message and code, with the number of
+    /// times each occurred and the paths it occurred at.
+    ///
+    /// See [`summarize_errors`] for the order of the summaries. Returns an empty list for
+    /// errors other than [`BlipsError::GraphQl`].
+    pub fn summarized_errors(&self) -> Vec<ErrorSummary> {
+        match self {
+            Self::GraphQl { errors, .. } => summarize_errors(errors),
+            Self::Config(_)
+            | Self::Transport(_)
+            | Self::Http { .. }
+            | Self::Deserialize { .. }
+            | Self::EmptyResponse { .. }
+            | Self::InvalidRequest { .. }
+            | Self::ResponseTooLarge { .. }
+            | Self::UnknownOperation { .. } => Vec::new(),
+        }
+    }
+}
+
+/// The class of a [`BlipsError::Transport`] error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TransportErrorKind {
+    /// The request timed out, either while connecting or while waiting for the response.
+    Timeout,
+
+    /// The connection to the server couldn't be established.
+    Connect,
+
+    /// The request couldn't be sent, such as when the connection was closed while sending
+    /// it.
+    Request,
+
+    /// The request or response body couldn't be written or read.
+    Body,
+
+    /// The response body couldn't be decoded.
+    Decode,
+
+    /// Any other transport error, such as following too many redirects.
+    Other,
+}
+
+impl TransportErrorKind {
+    /// Returns the class of the given `reqwest` error.
+    ///
+    /// A timeout while connecting is classified as a [`TransportErrorKind::Timeout`].
+    pub(crate) fn of(err: &reqwest::Error) -> Self {
+        if err.is_timeout() {
+            Self::Timeout
+        } else if err.is_connect() {
+            Self::Connect
+        } else if err.is_request() {
+            Self::Request
+        } else if err.is_body() {
+            Self::Body
+        } else if err.is_decode() {
+            Self::Decode
+        } else {
+            Self::Other
+        }
+    }
+
+    /// Returns whether errors of this class are retried by default, which is the case for
+    /// timeouts and connect errors.
+    pub fn is_retryable(self) -> bool {
+        matches!(self, Self::Timeout | Self::Connect)
+    }
+}
+
+/// An error that occurred while sending a request or receiving its response.
+///
+/// The error of the underlying HTTP client is the [`Error::source`] of the
+/// [`BlipsError::Transport`] holding this error, for inspecting it beyond its
+/// [`TransportErrorKind`] by downcasting it.
+#[derive(Debug)]
+pub struct TransportError(reqwest::Error);
+
+impl TransportError {
+    /// Returns the class of the error.
+    pub fn kind(&self) -> TransportErrorKind {
+        TransportErrorKind::of(&self.0)
+    }
+
+    /// Returns the HTTP status code of the response the error occurred for, if any.
+    pub fn status_code(&self) -> Option<u16> {
+        self.0.status().map(|status| status.as_u16())
+    }
+
+    /// Returns the error of the underlying HTTP client.
+    pub(crate) fn inner(&self) -> &reqwest::Error {
+        &self.0
+    }
+}
+
+impl Display for TransportError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Error for TransportError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.0.source()
+    }
+}
+
+/// Returns whether the deserialization error was caused by a `null` where a value was
+/// expected.
+fn is_unexpected_null(err: &serde_json::Error) -> bool {
+    err.is_data() && err.to_string().starts_with("invalid type: null,")
+}
+
+/// A segment of the path to the response field a GraphQL error belongs to.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PathSegment {
+    /// The name (or alias) of a field.
+    Field(String),
+
+    /// The index of an item in a list.
+    Index(usize),
+}
+
+impl PathSegment {
+    /// Returns the typed path of the given GraphQL error, or an empty path if it has none.
+    pub fn path_of(error: &crate::runtime::Error) -> Vec<PathSegment> {
+        error.path.iter().flatten().map(PathSegment::from).collect()
+    }
+}
+
+impl From<&crate::runtime::PathFragment> for PathSegment {
+    fn from(value: &crate::runtime::PathFragment) -> Self {
+        match value {
+            crate::runtime::PathFragment::Key(key) => Self::Field(key.clone()),
+            // A negative index isn't a valid list index, so we keep it as it was sent.
+            crate::runtime::PathFragment::Index(index) => {
+                usize::try_from(*index).map_or_else(|_| Self::Field(index.to_string()), Self::Index)
+            }
+        }
+    }
+}
+
+impl Display for PathSegment {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Field(name) => write!(f, "{}", name),
+            Self::Index(index) => write!(f, "{}", index),
+        }
+    }
+}
+
+/// Groups the given GraphQL errors by the first segment of their path.
+///
+/// Errors without a path are grouped under `None`.
+pub fn group_errors_by_field(
+    errors: &[crate::runtime::Error],
+) -> BTreeMap<Option<PathSegment>, Vec<&crate::runtime::Error>> {
+    let mut grouped: BTreeMap<_, Vec<_>> = BTreeMap::new();
+    for error in errors {
+        let first_segment = error
+            .path
+            .as_ref()
+            .and_then(|path| path.first())
+            .map(PathSegment::from);
+
+        grouped.entry(first_segment).or_default().push(error);
+    }
+
+    grouped
+}
+
+/// GraphQL errors that share a message and code, for presenting many errors at once.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ErrorSummary {
+    /// The message of the errors.
+    pub message: String,
+
+    /// The `code` in the extensions of the errors, if they have one.
+    pub code: Option<String>,
+
+    /// The number of errors with the message and code.
+    pub count: usize,
+
+    /// The distinct paths of the errors, sorted. Errors without a path don't add one.
+    pub paths: Vec<Vec<PathSegment>>,
+}
+
+/// Deduplicates the given GraphQL errors by message and code.
+///
+/// The summaries are sorted by how many errors they cover, most first, and then by message
+/// and code.
+pub fn summarize_errors(errors: &[crate::runtime::Error]) -> Vec<ErrorSummary> {
+    let mut summarized: BTreeMap<_, (usize, BTreeSet<_>)> = BTreeMap::new();
+    for error in errors {
+        let code = error_code(error).map(str::to_string);
+
+        let (count, paths) = summarized.entry((error.message.clone(), code)).or_default();
+        *count += 1;
+        if error.path.is_some() {
+            paths.insert(PathSegment::path_of(error));
+        }
+    }
+
+    let mut summaries = summarized
+        .into_iter()
+        .map(|((message, code), (count, paths))| ErrorSummary {
+            message,
+            code,
+            count,
+            paths: paths.into_iter().collect(),
+        })
+        .collect::<Vec<_>>();
+    // The sort is stable, so summaries with the same count stay ordered by message and code.
+    summaries.sort_by_key(|summary| std::cmp::Reverse(summary.count));
+
+    summaries
+}
+
+/// Returns the `code` in the extensions of the GraphQL error, if it has one.
+fn error_code(error: &crate::runtime::Error) -> Option<&str> {
+    error
+        .extensions
+        .as_ref()
+        .and_then(|extensions| extensions.get("code"))
+        .and_then(|code| code.as_str())
+}
+
+impl Display for BlipsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Config(message) => write!(f, "invalid client configuration: {}", message),
+            // The transport error is the source of this one, so it isn't repeated here.
+            Self::Transport(_) => write!(f, "request failed"),
+            Self::Http { status, body } => {
+                write!(f, "server responded with status {}", status)?;
+                if let Some(reason) = reqwest::StatusCode::from_u16(*status)
+                    .ok()
+                    .and_then(|status| status.canonical_reason())
+                {
+                    write!(f, " {}", reason)?;
+                }
+                if !body.is_empty() {
+                    write!(f, ": {}", body)?;
+                }
+                Ok(())
+            }
+            Self::Deserialize {
+                operation_name,
+                path,
+                source,
+            } if is_unexpected_null(source) => write!(
+                f,
+                "`{}` response has `null` at `{}`, which the schema declares as non-null \
+                 (the server violated its schema): {}",
+                operation_name, path, source
+            ),
+            Self::Deserialize {
+                operation_name,
+                path,
+                source,
+            } => write!(
+                f,
+                "failed to deserialize `{}` response at `{}`: {}",
+                operation_name, path, source
+            ),
+            Self::GraphQl {
+                operation_name,
+                errors,
+            } => write!(
+                f,
+                "`{}` returned GraphQL error(s): {}",
+                operation_name,
+                errors
+                    .iter()
+                    .map(|error| match error_code(error) {
+                        Some(code) => format!("{}: {}", code, error.message),
+                        None => error.message.clone(),
+                    })
+                    .collect::<Vec<_>>()
+                    .join("; ")
+            ),
+            Self::EmptyResponse { operation_name } => {
+                write!(f, "`{}` returned an empty response", operation_name)
+            }
+            Self::InvalidRequest {
+                operation_name,
+                reason,
+            } => write!(f, "invalid `{}` request: {}", operation_name, reason),
+            Self::ResponseTooLarge { limit } => {
+                write!(f, "response body exceeded the limit of {} bytes", limit)
+            }
+            Self::UnknownOperation { operation_name } => {
+                write!(f, "unknown operation `{}`", operation_name)
+            }
+        }
+    }
+}
+
+impl Error for BlipsError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Transport(err) => Some(err.inner()),
+            Self::Deserialize { source, .. } => Some(source),
+            Self::Config(_)
+            | Self::Http { .. }
+            | Self::GraphQl { .. }
+            | Self::EmptyResponse { .. }
+            | Self::InvalidRequest { .. }
+            | Self::ResponseTooLarge { .. }
+            | Self::UnknownOperation { .. } => None,
+        }
+    }
+}
+
+/// Serializes the error as an object with a stable shape, for sending it across a boundary
+/// such as an API:
+///
+/// ```json
+/// { "kind": "http", "message": "server responded with status 503 Service Unavailable", "code": 503 }
+/// ```
+///
+/// - `kind` is one of `config`, `transport`, `http`, `deserialize`, `graphql`,
+///   `empty_response`, `invalid_request`, `response_too_large`, or `unknown_operation`,
+///   matching the variant of the error.
+/// - `message` is the [`Display`] representation of the error.
+/// - `code` is the HTTP status code of the response that caused the error, or `null` if
+///   there was none.
+#[cfg(feature = "serde")]
+impl serde::Serialize for BlipsError {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+
+        let kind = match self {
+            Self::Config(_) => "config",
+            Self::Transport(_) => "transport",
+            Self::Http { .. } => "http",
+            Self::Deserialize { .. } => "deserialize",
+            Self::GraphQl { .. } => "graphql",
+            Self::EmptyResponse { .. } => "empty_response",
+            Self::InvalidRequest { .. } => "invalid_request",
+            Self::ResponseTooLarge { .. } => "response_too_large",
+            Self::UnknownOperation { .. } => "unknown_operation",
+        };
+
+        let mut state = serializer.serialize_struct("BlipsError", 3)?;
+        state.serialize_field("kind", kind)?;
+        state.serialize_field("message", &self.to_string())?;
+        state.serialize_field("code", &self.status_code())?;
+        state.end()
+    }
+}
+
+impl BlipsError {
+    /// Returns a [`BlipsError::Transport`] for the given error of the HTTP client.
+    pub(crate) fn transport(err: reqwest::Error) -> Self {
+        Self::Transport(TransportError(err))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn transport_error() -> BlipsError {
+        let err = reqwest::Client::new().get("not a url").build().unwrap_err();
+
+        BlipsError::transport(err)
+    }
+
+    fn deserialize_error(value: serde_json::Value) -> BlipsError {
+        BlipsError::Deserialize {
+            operation_name: "Tasks".to_string(),
+            path: "tasks[0].id".to_string(),
+            source: serde_json::from_value::<String>(value).unwrap_err(),
+        }
+    }
+
+    fn graphql_error(error: serde_json::Value) -> crate::runtime::Error {
+        serde_json::from_value(error).unwrap()
+    }
+
+    #[test]
+    fn display_of_each_variant() {
+        let cases = [
+            (
+                BlipsError::Config("missing CSRF token".to_string()),
+                "invalid client configuration: missing CSRF token",
+            ),
+            (transport_error(), "request failed"),
+            (
+                BlipsError::Http {
+                    status: 503,
+                    body: String::new(),
+                },
+                "server responded with status 503 Service Unavailable",
+            ),
+            (
+                BlipsError::Http {
+                    status: 404,
+                    body: "no such route".to_string(),
+                },
+                "server responded with status 404 Not Found: no such route",
+            ),
+            (
+                deserialize_error(json!(1)),
+                "failed to deserialize `Tasks` response at `tasks[0].id`: invalid type: \
+                 integer `1`, expected a string",
+            ),
+            (
+                deserialize_error(json!(null)),
+                "`Tasks` response has `null` at `tasks[0].id`, which the schema declares as \
+                 non-null (the server violated its schema): invalid type: null, expected a string",
+            ),
+            (
+                BlipsError::GraphQl {
+                    operation_name: "Task".to_string(),
+                    errors: vec![
+                        graphql_error(json!({
+                            "message": "task not found",
+                            "extensions": { "code": "NOT_FOUND" },
+                        })),
+                        graphql_error(json!({ "message": "rate limited" })),
+                    ],
+                },
+                "`Task` returned GraphQL error(s): NOT_FOUND: task not found; rate limited",
+            ),
+            (
+                BlipsError::EmptyResponse {
+                    operation_name: "Task".to_string(),
+                },
+                "`Task` returned an empty response",
+            ),
+            (
+                BlipsError::InvalidRequest {
+                    operation_name: "Task".to_string(),
+                    reason: "missing variable `id`".to_string(),
+                },
+                "invalid `Task` request: missing variable `id`",
+            ),
+            (
+                BlipsError::ResponseTooLarge { limit: 1024 },
+                "response body exceeded the limit of 1024 bytes",
+            ),
+            (
+                BlipsError::UnknownOperation {
+                    operation_name: "Tsk".to_string(),
+                },
+                "unknown operation `Tsk`",
+            ),
+        ];
+
+        for (err, message) in cases {
+            assert_eq!(err.to_string(), message);
+        }
+    }
+
+    #[test]
+    fn anyhow_walks_the_chain_of_a_transport_error_to_reqwest() {
+        let err = anyhow::Error::from(transport_error());
+
+        let mut chain = err.chain();
+        assert_eq!(chain.next().unwrap().to_string(), "request failed");
+        assert!(chain
+            .next()
+            .unwrap()
+            .downcast_ref::<reqwest::Error>()
+            .is_some_and(reqwest::Error::is_builder));
+    }
+
+    #[test]
+    fn anyhow_walks_the_chain_of_a_deserialize_error_to_serde_json() {
+        let err = anyhow::Error::from(deserialize_error(json!(1)));
+
+        assert!(err
+            .chain()
+            .any(|err| err.downcast_ref::<serde_json::Error>().is_some()));
+        assert!(err.root_cause().is::<serde_json::Error>());
+    }
+
+    #[test]
+    fn variants_without_an_underlying_error_have_no_source() {
+        let err = BlipsError::EmptyResponse {
+            operation_name: "Task".to_string(),
+        };
+
+        assert!(err.source().is_none());
+    }
+}
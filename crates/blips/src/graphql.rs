@@ -1,5 +1,7 @@
-mod custom_scalars;
+pub mod custom_scalars;
 mod generated;
+#[cfg(test)]
+mod tests;
 
 // Auto-generated:
 pub use generated::archive_board::*;
@@ -26,6 +28,7 @@ pub use generated::delete_tasks::*;
 pub use generated::diary::*;
 pub use generated::enable_otp::*;
 pub use generated::generate_new_otp::*;
+pub use generated::groups_updated::*;
 pub use generated::me::*;
 pub use generated::move_tasks::*;
 pub use generated::note::*;
@@ -45,6 +48,8 @@ pub use generated::spring_project::*;
 pub use generated::tag_task::*;
 pub use generated::tags::*;
 pub use generated::tasks::*;
+pub use generated::tasks_created::*;
+pub use generated::tasks_updated::*;
 pub use generated::unarchive_board::*;
 pub use generated::uncomplete_project::*;
 pub use generated::uncomplete_task::*;
@@ -59,3 +64,1118 @@ pub use generated::update_project::*;
 pub use generated::update_project_column::*;
 pub use generated::update_task::*;
 pub use generated::update_user_settings::*;
+
+/// The generated operations, for using them dynamically.
+pub static OPERATIONS: &[crate::OperationInfo] = &[
+    crate::OperationInfo {
+        name: archive_board::OPERATION_NAME,
+        kind: archive_board::OPERATION_KIND,
+        arguments: &[crate::ArgumentInfo {
+            name: "boardId",
+            variable_name: "board_id",
+            graphql_type: "ID!",
+        }],
+        document: archive_board::QUERY,
+    },
+    crate::OperationInfo {
+        name: board::OPERATION_NAME,
+        kind: board::OPERATION_KIND,
+        arguments: &[crate::ArgumentInfo {
+            name: "boardId",
+            variable_name: "board_id",
+            graphql_type: "ID",
+        }],
+        document: board::QUERY,
+    },
+    crate::OperationInfo {
+        name: boards::OPERATION_NAME,
+        kind: boards::OPERATION_KIND,
+        arguments: &[],
+        document: boards::QUERY,
+    },
+    crate::OperationInfo {
+        name: complete_project::OPERATION_NAME,
+        kind: complete_project::OPERATION_KIND,
+        arguments: &[crate::ArgumentInfo {
+            name: "projectId",
+            variable_name: "project_id",
+            graphql_type: "ID!",
+        }],
+        document: complete_project::QUERY,
+    },
+    crate::OperationInfo {
+        name: complete_task::OPERATION_NAME,
+        kind: complete_task::OPERATION_KIND,
+        arguments: &[crate::ArgumentInfo {
+            name: "taskId",
+            variable_name: "task_id",
+            graphql_type: "ID!",
+        }],
+        document: complete_task::QUERY,
+    },
+    crate::OperationInfo {
+        name: container::OPERATION_NAME,
+        kind: container::OPERATION_KIND,
+        arguments: &[
+            crate::ArgumentInfo {
+                name: "date",
+                variable_name: "date",
+                graphql_type: "Date",
+            },
+            crate::ArgumentInfo {
+                name: "inbox",
+                variable_name: "inbox",
+                graphql_type: "Boolean",
+            },
+            crate::ArgumentInfo {
+                name: "projectId",
+                variable_name: "project_id",
+                graphql_type: "ID",
+            },
+        ],
+        document: container::QUERY,
+    },
+    crate::OperationInfo {
+        name: create_board::OPERATION_NAME,
+        kind: create_board::OPERATION_KIND,
+        arguments: &[],
+        document: create_board::QUERY,
+    },
+    crate::OperationInfo {
+        name: create_boards::OPERATION_NAME,
+        kind: create_boards::OPERATION_KIND,
+        arguments: &[crate::ArgumentInfo {
+            name: "names",
+            variable_name: "names",
+            graphql_type: "[String!]!",
+        }],
+        document: create_boards::QUERY,
+    },
+    crate::OperationInfo {
+        name: create_groups::OPERATION_NAME,
+        kind: create_groups::OPERATION_KIND,
+        arguments: &[
+            crate::ArgumentInfo {
+                name: "containerId",
+                variable_name: "container_id",
+                graphql_type: "ID!",
+            },
+            crate::ArgumentInfo {
+                name: "containerType",
+                variable_name: "container_type",
+                graphql_type: "ContainerTypeEnum!",
+            },
+            crate::ArgumentInfo {
+                name: "names",
+                variable_name: "names",
+                graphql_type: "[String!]!",
+            },
+        ],
+        document: create_groups::QUERY,
+    },
+    crate::OperationInfo {
+        name: create_note::OPERATION_NAME,
+        kind: create_note::OPERATION_KIND,
+        arguments: &[
+            crate::ArgumentInfo {
+                name: "date",
+                variable_name: "date",
+                graphql_type: "Date",
+            },
+            crate::ArgumentInfo {
+                name: "name",
+                variable_name: "name",
+                graphql_type: "String",
+            },
+            crate::ArgumentInfo {
+                name: "projectId",
+                variable_name: "project_id",
+                graphql_type: "ID",
+            },
+        ],
+        document: create_note::QUERY,
+    },
+    crate::OperationInfo {
+        name: create_project::OPERATION_NAME,
+        kind: create_project::OPERATION_KIND,
+        arguments: &[
+            crate::ArgumentInfo {
+                name: "boardId",
+                variable_name: "board_id",
+                graphql_type: "ID",
+            },
+            crate::ArgumentInfo {
+                name: "name",
+                variable_name: "name",
+                graphql_type: "String",
+            },
+            crate::ArgumentInfo {
+                name: "projectColumnId",
+                variable_name: "project_column_id",
+                graphql_type: "ID",
+            },
+            crate::ArgumentInfo {
+                name: "sourceTaskId",
+                variable_name: "source_task_id",
+                graphql_type: "ID",
+            },
+        ],
+        document: create_project::QUERY,
+    },
+    crate::OperationInfo {
+        name: create_project_column::OPERATION_NAME,
+        kind: create_project_column::OPERATION_KIND,
+        arguments: &[
+            crate::ArgumentInfo {
+                name: "boardId",
+                variable_name: "board_id",
+                graphql_type: "ID!",
+            },
+            crate::ArgumentInfo {
+                name: "name",
+                variable_name: "name",
+                graphql_type: "String!",
+            },
+        ],
+        document: create_project_column::QUERY,
+    },
+    crate::OperationInfo {
+        name: create_projects::OPERATION_NAME,
+        kind: create_projects::OPERATION_KIND,
+        arguments: &[
+            crate::ArgumentInfo {
+                name: "boardId",
+                variable_name: "board_id",
+                graphql_type: "ID",
+            },
+            crate::ArgumentInfo {
+                name: "date",
+                variable_name: "date",
+                graphql_type: "Date",
+            },
+            crate::ArgumentInfo {
+                name: "names",
+                variable_name: "names",
+                graphql_type: "[String!]!",
+            },
+            crate::ArgumentInfo {
+                name: "projectColumnId",
+                variable_name: "project_column_id",
+                graphql_type: "ID",
+            },
+        ],
+        document: create_projects::QUERY,
+    },
+    crate::OperationInfo {
+        name: create_tasks::OPERATION_NAME,
+        kind: create_tasks::OPERATION_KIND,
+        arguments: &[
+            crate::ArgumentInfo {
+                name: "after",
+                variable_name: "after",
+                graphql_type: "ID",
+            },
+            crate::ArgumentInfo {
+                name: "date",
+                variable_name: "date",
+                graphql_type: "Date",
+            },
+            crate::ArgumentInfo {
+                name: "groupId",
+                variable_name: "group_id",
+                graphql_type: "ID",
+            },
+            crate::ArgumentInfo {
+                name: "link",
+                variable_name: "link",
+                graphql_type: "String",
+            },
+            crate::ArgumentInfo {
+                name: "dueDate",
+                variable_name: "due_date",
+                graphql_type: "Date",
+            },
+            crate::ArgumentInfo {
+                name: "names",
+                variable_name: "names",
+                graphql_type: "[String!]!",
+            },
+            crate::ArgumentInfo {
+                name: "prioritized",
+                variable_name: "prioritized",
+                graphql_type: "Boolean",
+            },
+            crate::ArgumentInfo {
+                name: "projectId",
+                variable_name: "project_id",
+                graphql_type: "ID",
+            },
+            crate::ArgumentInfo {
+                name: "tagSlug",
+                variable_name: "tag_slug",
+                graphql_type: "String",
+            },
+        ],
+        document: create_tasks::QUERY,
+    },
+    crate::OperationInfo {
+        name: current_user::OPERATION_NAME,
+        kind: current_user::OPERATION_KIND,
+        arguments: &[],
+        document: current_user::QUERY,
+    },
+    crate::OperationInfo {
+        name: delete_board::OPERATION_NAME,
+        kind: delete_board::OPERATION_KIND,
+        arguments: &[crate::ArgumentInfo {
+            name: "boardId",
+            variable_name: "board_id",
+            graphql_type: "ID!",
+        }],
+        document: delete_board::QUERY,
+    },
+    crate::OperationInfo {
+        name: delete_group::OPERATION_NAME,
+        kind: delete_group::OPERATION_KIND,
+        arguments: &[
+            crate::ArgumentInfo {
+                name: "deleteTasks",
+                variable_name: "delete_tasks",
+                graphql_type: "Boolean",
+            },
+            crate::ArgumentInfo {
+                name: "groupId",
+                variable_name: "group_id",
+                graphql_type: "ID!",
+            },
+        ],
+        document: delete_group::QUERY,
+    },
+    crate::OperationInfo {
+        name: delete_note::OPERATION_NAME,
+        kind: delete_note::OPERATION_KIND,
+        arguments: &[crate::ArgumentInfo {
+            name: "noteId",
+            variable_name: "note_id",
+            graphql_type: "ID!",
+        }],
+        document: delete_note::QUERY,
+    },
+    crate::OperationInfo {
+        name: delete_project::OPERATION_NAME,
+        kind: delete_project::OPERATION_KIND,
+        arguments: &[
+            crate::ArgumentInfo {
+                name: "deleteTasks",
+                variable_name: "delete_tasks",
+                graphql_type: "Boolean",
+            },
+            crate::ArgumentInfo {
+                name: "projectId",
+                variable_name: "project_id",
+                graphql_type: "ID!",
+            },
+        ],
+        document: delete_project::QUERY,
+    },
+    crate::OperationInfo {
+        name: delete_task::OPERATION_NAME,
+        kind: delete_task::OPERATION_KIND,
+        arguments: &[crate::ArgumentInfo {
+            name: "taskId",
+            variable_name: "task_id",
+            graphql_type: "ID!",
+        }],
+        document: delete_task::QUERY,
+    },
+    crate::OperationInfo {
+        name: delete_tasks::OPERATION_NAME,
+        kind: delete_tasks::OPERATION_KIND,
+        arguments: &[crate::ArgumentInfo {
+            name: "taskIds",
+            variable_name: "task_ids",
+            graphql_type: "[ID!]!",
+        }],
+        document: delete_tasks::QUERY,
+    },
+    crate::OperationInfo {
+        name: diary::OPERATION_NAME,
+        kind: diary::OPERATION_KIND,
+        arguments: &[crate::ArgumentInfo {
+            name: "date",
+            variable_name: "date",
+            graphql_type: "Date!",
+        }],
+        document: diary::QUERY,
+    },
+    crate::OperationInfo {
+        name: enable_otp::OPERATION_NAME,
+        kind: enable_otp::OPERATION_KIND,
+        arguments: &[
+            crate::ArgumentInfo {
+                name: "otpAttempt",
+                variable_name: "otp_attempt",
+                graphql_type: "String!",
+            },
+            crate::ArgumentInfo {
+                name: "otpSecret",
+                variable_name: "otp_secret",
+                graphql_type: "String!",
+            },
+        ],
+        document: enable_otp::QUERY,
+    },
+    crate::OperationInfo {
+        name: generate_new_otp::OPERATION_NAME,
+        kind: generate_new_otp::OPERATION_KIND,
+        arguments: &[],
+        document: generate_new_otp::QUERY,
+    },
+    crate::OperationInfo {
+        name: groups_updated::OPERATION_NAME,
+        kind: groups_updated::OPERATION_KIND,
+        arguments: &[],
+        document: groups_updated::QUERY,
+    },
+    crate::OperationInfo {
+        name: me::OPERATION_NAME,
+        kind: me::OPERATION_KIND,
+        arguments: &[],
+        document: me::QUERY,
+    },
+    crate::OperationInfo {
+        name: move_tasks::OPERATION_NAME,
+        kind: move_tasks::OPERATION_KIND,
+        arguments: &[
+            crate::ArgumentInfo {
+                name: "date",
+                variable_name: "date",
+                graphql_type: "Date!",
+            },
+            crate::ArgumentInfo {
+                name: "taskIds",
+                variable_name: "task_ids",
+                graphql_type: "[ID!]!",
+            },
+        ],
+        document: move_tasks::QUERY,
+    },
+    crate::OperationInfo {
+        name: note::OPERATION_NAME,
+        kind: note::OPERATION_KIND,
+        arguments: &[crate::ArgumentInfo {
+            name: "noteId",
+            variable_name: "note_id",
+            graphql_type: "ID!",
+        }],
+        document: note::QUERY,
+    },
+    crate::OperationInfo {
+        name: notes::OPERATION_NAME,
+        kind: notes::OPERATION_KIND,
+        arguments: &[
+            crate::ArgumentInfo {
+                name: "date",
+                variable_name: "date",
+                graphql_type: "Date",
+            },
+            crate::ArgumentInfo {
+                name: "projectId",
+                variable_name: "project_id",
+                graphql_type: "ID",
+            },
+            crate::ArgumentInfo {
+                name: "query",
+                variable_name: "query",
+                graphql_type: "String",
+            },
+        ],
+        document: notes::QUERY,
+    },
+    crate::OperationInfo {
+        name: persist_group_order::OPERATION_NAME,
+        kind: persist_group_order::OPERATION_KIND,
+        arguments: &[crate::ArgumentInfo {
+            name: "order",
+            variable_name: "order",
+            graphql_type: "[OrderInput!]",
+        }],
+        document: persist_group_order::QUERY,
+    },
+    crate::OperationInfo {
+        name: persist_priority_order::OPERATION_NAME,
+        kind: persist_priority_order::OPERATION_KIND,
+        arguments: &[crate::ArgumentInfo {
+            name: "order",
+            variable_name: "order",
+            graphql_type: "[OrderInput!]",
+        }],
+        document: persist_priority_order::QUERY,
+    },
+    crate::OperationInfo {
+        name: persist_project_column_order::OPERATION_NAME,
+        kind: persist_project_column_order::OPERATION_KIND,
+        arguments: &[crate::ArgumentInfo {
+            name: "order",
+            variable_name: "order",
+            graphql_type: "[OrderInput!]",
+        }],
+        document: persist_project_column_order::QUERY,
+    },
+    crate::OperationInfo {
+        name: persist_project_order::OPERATION_NAME,
+        kind: persist_project_order::OPERATION_KIND,
+        arguments: &[crate::ArgumentInfo {
+            name: "order",
+            variable_name: "order",
+            graphql_type: "[OrderInput!]",
+        }],
+        document: persist_project_order::QUERY,
+    },
+    crate::OperationInfo {
+        name: persist_task_order::OPERATION_NAME,
+        kind: persist_task_order::OPERATION_KIND,
+        arguments: &[crate::ArgumentInfo {
+            name: "taskOrder",
+            variable_name: "task_order",
+            graphql_type: "[OrderInput!]",
+        }],
+        document: persist_task_order::QUERY,
+    },
+    crate::OperationInfo {
+        name: prioritize_tasks::OPERATION_NAME,
+        kind: prioritize_tasks::OPERATION_KIND,
+        arguments: &[crate::ArgumentInfo {
+            name: "ids",
+            variable_name: "ids",
+            graphql_type: "[ID!]!",
+        }],
+        document: prioritize_tasks::QUERY,
+    },
+    crate::OperationInfo {
+        name: project::OPERATION_NAME,
+        kind: project::OPERATION_KIND,
+        arguments: &[crate::ArgumentInfo {
+            name: "projectId",
+            variable_name: "project_id",
+            graphql_type: "ID!",
+        }],
+        document: project::QUERY,
+    },
+    crate::OperationInfo {
+        name: project_columns::OPERATION_NAME,
+        kind: project_columns::OPERATION_KIND,
+        arguments: &[],
+        document: project_columns::QUERY,
+    },
+    crate::OperationInfo {
+        name: projects::OPERATION_NAME,
+        kind: projects::OPERATION_KIND,
+        arguments: &[
+            crate::ArgumentInfo {
+                name: "date",
+                variable_name: "date",
+                graphql_type: "Date",
+            },
+            crate::ArgumentInfo {
+                name: "limit",
+                variable_name: "limit",
+                graphql_type: "Int",
+            },
+            crate::ArgumentInfo {
+                name: "query",
+                variable_name: "query",
+                graphql_type: "String",
+            },
+            crate::ArgumentInfo {
+                name: "boardId",
+                variable_name: "board_id",
+                graphql_type: "ID",
+            },
+        ],
+        document: projects::QUERY,
+    },
+    crate::OperationInfo {
+        name: register_user::OPERATION_NAME,
+        kind: register_user::OPERATION_KIND,
+        arguments: &[
+            crate::ArgumentInfo {
+                name: "email",
+                variable_name: "email",
+                graphql_type: "String!",
+            },
+            crate::ArgumentInfo {
+                name: "password",
+                variable_name: "password",
+                graphql_type: "String!",
+            },
+            crate::ArgumentInfo {
+                name: "secretCode",
+                variable_name: "secret_code",
+                graphql_type: "String!",
+            },
+        ],
+        document: register_user::QUERY,
+    },
+    crate::OperationInfo {
+        name: search::OPERATION_NAME,
+        kind: search::OPERATION_KIND,
+        arguments: &[crate::ArgumentInfo {
+            name: "query",
+            variable_name: "query",
+            graphql_type: "String!",
+        }],
+        document: search::QUERY,
+    },
+    crate::OperationInfo {
+        name: spring_project::OPERATION_NAME,
+        kind: spring_project::OPERATION_KIND,
+        arguments: &[crate::ArgumentInfo {
+            name: "projectId",
+            variable_name: "project_id",
+            graphql_type: "ID!",
+        }],
+        document: spring_project::QUERY,
+    },
+    crate::OperationInfo {
+        name: tag_task::OPERATION_NAME,
+        kind: tag_task::OPERATION_KIND,
+        arguments: &[
+            crate::ArgumentInfo {
+                name: "tagId",
+                variable_name: "tag_id",
+                graphql_type: "ID!",
+            },
+            crate::ArgumentInfo {
+                name: "taskId",
+                variable_name: "task_id",
+                graphql_type: "ID!",
+            },
+        ],
+        document: tag_task::QUERY,
+    },
+    crate::OperationInfo {
+        name: tags::OPERATION_NAME,
+        kind: tags::OPERATION_KIND,
+        arguments: &[],
+        document: tags::QUERY,
+    },
+    crate::OperationInfo {
+        name: tasks::OPERATION_NAME,
+        kind: tasks::OPERATION_KIND,
+        arguments: &[
+            crate::ArgumentInfo {
+                name: "completed",
+                variable_name: "completed",
+                graphql_type: "Boolean",
+            },
+            crate::ArgumentInfo {
+                name: "date",
+                variable_name: "date",
+                graphql_type: "Date",
+            },
+            crate::ArgumentInfo {
+                name: "dueDate",
+                variable_name: "due_date",
+                graphql_type: "Date",
+            },
+            crate::ArgumentInfo {
+                name: "focus",
+                variable_name: "focus",
+                graphql_type: "Boolean",
+            },
+            crate::ArgumentInfo {
+                name: "inbox",
+                variable_name: "inbox",
+                graphql_type: "Boolean",
+            },
+            crate::ArgumentInfo {
+                name: "projectId",
+                variable_name: "project_id",
+                graphql_type: "ID",
+            },
+        ],
+        document: tasks::QUERY,
+    },
+    crate::OperationInfo {
+        name: tasks_created::OPERATION_NAME,
+        kind: tasks_created::OPERATION_KIND,
+        arguments: &[],
+        document: tasks_created::QUERY,
+    },
+    crate::OperationInfo {
+        name: tasks_updated::OPERATION_NAME,
+        kind: tasks_updated::OPERATION_KIND,
+        arguments: &[],
+        document: tasks_updated::QUERY,
+    },
+    crate::OperationInfo {
+        name: unarchive_board::OPERATION_NAME,
+        kind: unarchive_board::OPERATION_KIND,
+        arguments: &[crate::ArgumentInfo {
+            name: "boardId",
+            variable_name: "board_id",
+            graphql_type: "ID!",
+        }],
+        document: unarchive_board::QUERY,
+    },
+    crate::OperationInfo {
+        name: uncomplete_project::OPERATION_NAME,
+        kind: uncomplete_project::OPERATION_KIND,
+        arguments: &[crate::ArgumentInfo {
+            name: "projectId",
+            variable_name: "project_id",
+            graphql_type: "ID!",
+        }],
+        document: uncomplete_project::QUERY,
+    },
+    crate::OperationInfo {
+        name: uncomplete_task::OPERATION_NAME,
+        kind: uncomplete_task::OPERATION_KIND,
+        arguments: &[crate::ArgumentInfo {
+            name: "taskId",
+            variable_name: "task_id",
+            graphql_type: "ID!",
+        }],
+        document: uncomplete_task::QUERY,
+    },
+    crate::OperationInfo {
+        name: unprioritize_tasks::OPERATION_NAME,
+        kind: unprioritize_tasks::OPERATION_KIND,
+        arguments: &[crate::ArgumentInfo {
+            name: "ids",
+            variable_name: "ids",
+            graphql_type: "[ID!]!",
+        }],
+        document: unprioritize_tasks::QUERY,
+    },
+    crate::OperationInfo {
+        name: unspring_project::OPERATION_NAME,
+        kind: unspring_project::OPERATION_KIND,
+        arguments: &[crate::ArgumentInfo {
+            name: "projectId",
+            variable_name: "project_id",
+            graphql_type: "ID!",
+        }],
+        document: unspring_project::QUERY,
+    },
+    crate::OperationInfo {
+        name: update_board::OPERATION_NAME,
+        kind: update_board::OPERATION_KIND,
+        arguments: &[
+            crate::ArgumentInfo {
+                name: "emoji",
+                variable_name: "emoji",
+                graphql_type: "String",
+            },
+            crate::ArgumentInfo {
+                name: "name",
+                variable_name: "name",
+                graphql_type: "String",
+            },
+            crate::ArgumentInfo {
+                name: "boardId",
+                variable_name: "board_id",
+                graphql_type: "ID!",
+            },
+            crate::ArgumentInfo {
+                name: "projectCompletedProjectColumnId",
+                variable_name: "project_completed_project_column_id",
+                graphql_type: "ID",
+            },
+            crate::ArgumentInfo {
+                name: "taskCompletedProjectColumnId",
+                variable_name: "task_completed_project_column_id",
+                graphql_type: "ID",
+            },
+        ],
+        document: update_board::QUERY,
+    },
+    crate::OperationInfo {
+        name: update_container::OPERATION_NAME,
+        kind: update_container::OPERATION_KIND,
+        arguments: &[
+            crate::ArgumentInfo {
+                name: "collapseCompleted",
+                variable_name: "collapse_completed",
+                graphql_type: "Boolean",
+            },
+            crate::ArgumentInfo {
+                name: "date",
+                variable_name: "date",
+                graphql_type: "Date",
+            },
+            crate::ArgumentInfo {
+                name: "noteBody",
+                variable_name: "note_body",
+                graphql_type: "String",
+            },
+            crate::ArgumentInfo {
+                name: "projectId",
+                variable_name: "project_id",
+                graphql_type: "ID",
+            },
+            crate::ArgumentInfo {
+                name: "state",
+                variable_name: "state",
+                graphql_type: "DiaryStateEnum",
+            },
+        ],
+        document: update_container::QUERY,
+    },
+    crate::OperationInfo {
+        name: update_diary::OPERATION_NAME,
+        kind: update_diary::OPERATION_KIND,
+        arguments: &[
+            crate::ArgumentInfo {
+                name: "date",
+                variable_name: "date",
+                graphql_type: "Date!",
+            },
+            crate::ArgumentInfo {
+                name: "noteBody",
+                variable_name: "note_body",
+                graphql_type: "String!",
+            },
+        ],
+        document: update_diary::QUERY,
+    },
+    crate::OperationInfo {
+        name: update_group::OPERATION_NAME,
+        kind: update_group::OPERATION_KIND,
+        arguments: &[
+            crate::ArgumentInfo {
+                name: "collapsed",
+                variable_name: "collapsed",
+                graphql_type: "Boolean",
+            },
+            crate::ArgumentInfo {
+                name: "date",
+                variable_name: "date",
+                graphql_type: "Date",
+            },
+            crate::ArgumentInfo {
+                name: "groupId",
+                variable_name: "group_id",
+                graphql_type: "ID!",
+            },
+            crate::ArgumentInfo {
+                name: "keepTasks",
+                variable_name: "keep_tasks",
+                graphql_type: "Boolean",
+            },
+            crate::ArgumentInfo {
+                name: "name",
+                variable_name: "name",
+                graphql_type: "String",
+            },
+        ],
+        document: update_group::QUERY,
+    },
+    crate::OperationInfo {
+        name: update_note::OPERATION_NAME,
+        kind: update_note::OPERATION_KIND,
+        arguments: &[
+            crate::ArgumentInfo {
+                name: "body",
+                variable_name: "body",
+                graphql_type: "String",
+            },
+            crate::ArgumentInfo {
+                name: "date",
+                variable_name: "date",
+                graphql_type: "Date",
+            },
+            crate::ArgumentInfo {
+                name: "endDate",
+                variable_name: "end_date",
+                graphql_type: "Date",
+            },
+            crate::ArgumentInfo {
+                name: "hidePreview",
+                variable_name: "hide_preview",
+                graphql_type: "Boolean",
+            },
+            crate::ArgumentInfo {
+                name: "lastUpdatedAt",
+                variable_name: "last_updated_at",
+                graphql_type: "DateTime",
+            },
+            crate::ArgumentInfo {
+                name: "name",
+                variable_name: "name",
+                graphql_type: "String",
+            },
+            crate::ArgumentInfo {
+                name: "noteId",
+                variable_name: "note_id",
+                graphql_type: "ID!",
+            },
+            crate::ArgumentInfo {
+                name: "projectId",
+                variable_name: "project_id",
+                graphql_type: "ID",
+            },
+        ],
+        document: update_note::QUERY,
+    },
+    crate::OperationInfo {
+        name: update_project::OPERATION_NAME,
+        kind: update_project::OPERATION_KIND,
+        arguments: &[
+            crate::ArgumentInfo {
+                name: "boardId",
+                variable_name: "board_id",
+                graphql_type: "ID",
+            },
+            crate::ArgumentInfo {
+                name: "date",
+                variable_name: "date",
+                graphql_type: "Date",
+            },
+            crate::ArgumentInfo {
+                name: "endDate",
+                variable_name: "end_date",
+                graphql_type: "Date",
+            },
+            crate::ArgumentInfo {
+                name: "name",
+                variable_name: "name",
+                graphql_type: "String",
+            },
+            crate::ArgumentInfo {
+                name: "projectId",
+                variable_name: "project_id",
+                graphql_type: "ID!",
+            },
+        ],
+        document: update_project::QUERY,
+    },
+    crate::OperationInfo {
+        name: update_project_column::OPERATION_NAME,
+        kind: update_project_column::OPERATION_KIND,
+        arguments: &[
+            crate::ArgumentInfo {
+                name: "collapsed",
+                variable_name: "collapsed",
+                graphql_type: "Boolean",
+            },
+            crate::ArgumentInfo {
+                name: "name",
+                variable_name: "name",
+                graphql_type: "String",
+            },
+            crate::ArgumentInfo {
+                name: "projectColumnId",
+                variable_name: "project_column_id",
+                graphql_type: "ID!",
+            },
+        ],
+        document: update_project_column::QUERY,
+    },
+    crate::OperationInfo {
+        name: update_task::OPERATION_NAME,
+        kind: update_task::OPERATION_KIND,
+        arguments: &[
+            crate::ArgumentInfo {
+                name: "date",
+                variable_name: "date",
+                graphql_type: "Date",
+            },
+            crate::ArgumentInfo {
+                name: "description",
+                variable_name: "description",
+                graphql_type: "String",
+            },
+            crate::ArgumentInfo {
+                name: "dueDate",
+                variable_name: "due_date",
+                graphql_type: "Date",
+            },
+            crate::ArgumentInfo {
+                name: "link",
+                variable_name: "link",
+                graphql_type: "String",
+            },
+            crate::ArgumentInfo {
+                name: "name",
+                variable_name: "name",
+                graphql_type: "String",
+            },
+            crate::ArgumentInfo {
+                name: "projectId",
+                variable_name: "project_id",
+                graphql_type: "ID",
+            },
+            crate::ArgumentInfo {
+                name: "recurrence",
+                variable_name: "recurrence",
+                graphql_type: "RecurrenceInput",
+            },
+            crate::ArgumentInfo {
+                name: "taskId",
+                variable_name: "task_id",
+                graphql_type: "ID!",
+            },
+        ],
+        document: update_task::QUERY,
+    },
+    crate::OperationInfo {
+        name: update_user_settings::OPERATION_NAME,
+        kind: update_user_settings::OPERATION_KIND,
+        arguments: &[crate::ArgumentInfo {
+            name: "badgeCountMode",
+            variable_name: "badge_count_mode",
+            graphql_type: "BadgeCountModeEnum",
+        }],
+        document: update_user_settings::QUERY,
+    },
+];
+
+/// A generated operation, for selecting one by name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Operation {
+    ArchiveBoard,
+    Board,
+    Boards,
+    CompleteProject,
+    CompleteTask,
+    Container,
+    CreateBoard,
+    CreateBoards,
+    CreateGroups,
+    CreateNote,
+    CreateProject,
+    CreateProjectColumn,
+    CreateProjects,
+    CreateTasks,
+    CurrentUser,
+    DeleteBoard,
+    DeleteGroup,
+    DeleteNote,
+    DeleteProject,
+    DeleteTask,
+    DeleteTasks,
+    Diary,
+    EnableOtp,
+    GenerateNewOtp,
+    GroupsUpdated,
+    Me,
+    MoveTasks,
+    Note,
+    Notes,
+    PersistGroupOrder,
+    PersistPriorityOrder,
+    PersistProjectColumnOrder,
+    PersistProjectOrder,
+    PersistTaskOrder,
+    PrioritizeTasks,
+    Project,
+    ProjectColumns,
+    Projects,
+    RegisterUser,
+    Search,
+    SpringProject,
+    TagTask,
+    Tags,
+    Tasks,
+    TasksCreated,
+    TasksUpdated,
+    UnarchiveBoard,
+    UncompleteProject,
+    UncompleteTask,
+    UnprioritizeTasks,
+    UnspringProject,
+    UpdateBoard,
+    UpdateContainer,
+    UpdateDiary,
+    UpdateGroup,
+    UpdateNote,
+    UpdateProject,
+    UpdateProjectColumn,
+    UpdateTask,
+    UpdateUserSettings,
+}
+
+impl Operation {
+    /// Returns the metadata of the operation.
+    pub fn info(self) -> &'static crate::OperationInfo {
+        &OPERATIONS[self as usize]
+    }
+
+    /// Returns the name of the operation.
+    pub fn name(self) -> &'static str {
+        self.info().name
+    }
+}
+
+impl std::str::FromStr for Operation {
+    type Err = crate::UnknownOperationError;
+
+    /// Parses an operation from its name, such as `Tasks`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "ArchiveBoard" => Ok(Self::ArchiveBoard),
+            "Board" => Ok(Self::Board),
+            "Boards" => Ok(Self::Boards),
+            "CompleteProject" => Ok(Self::CompleteProject),
+            "CompleteTask" => Ok(Self::CompleteTask),
+            "Container" => Ok(Self::Container),
+            "CreateBoard" => Ok(Self::CreateBoard),
+            "CreateBoards" => Ok(Self::CreateBoards),
+            "CreateGroups" => Ok(Self::CreateGroups),
+            "CreateNote" => Ok(Self::CreateNote),
+            "CreateProject" => Ok(Self::CreateProject),
+            "CreateProjectColumn" => Ok(Self::CreateProjectColumn),
+            "CreateProjects" => Ok(Self::CreateProjects),
+            "CreateTasks" => Ok(Self::CreateTasks),
+            "CurrentUser" => Ok(Self::CurrentUser),
+            "DeleteBoard" => Ok(Self::DeleteBoard),
+            "DeleteGroup" => Ok(Self::DeleteGroup),
+            "DeleteNote" => Ok(Self::DeleteNote),
+            "DeleteProject" => Ok(Self::DeleteProject),
+            "DeleteTask" => Ok(Self::DeleteTask),
+            "DeleteTasks" => Ok(Self::DeleteTasks),
+            "Diary" => Ok(Self::Diary),
+            "EnableOtp" => Ok(Self::EnableOtp),
+            "GenerateNewOtp" => Ok(Self::GenerateNewOtp),
+            "GroupsUpdated" => Ok(Self::GroupsUpdated),
+            "Me" => Ok(Self::Me),
+            "MoveTasks" => Ok(Self::MoveTasks),
+            "Note" => Ok(Self::Note),
+            "Notes" => Ok(Self::Notes),
+            "PersistGroupOrder" => Ok(Self::PersistGroupOrder),
+            "PersistPriorityOrder" => Ok(Self::PersistPriorityOrder),
+            "PersistProjectColumnOrder" => Ok(Self::PersistProjectColumnOrder),
+            "PersistProjectOrder" => Ok(Self::PersistProjectOrder),
+            "PersistTaskOrder" => Ok(Self::PersistTaskOrder),
+            "PrioritizeTasks" => Ok(Self::PrioritizeTasks),
+            "Project" => Ok(Self::Project),
+            "ProjectColumns" => Ok(Self::ProjectColumns),
+            "Projects" => Ok(Self::Projects),
+            "RegisterUser" => Ok(Self::RegisterUser),
+            "Search" => Ok(Self::Search),
+            "SpringProject" => Ok(Self::SpringProject),
+            "TagTask" => Ok(Self::TagTask),
+            "Tags" => Ok(Self::Tags),
+            "Tasks" => Ok(Self::Tasks),
+            "TasksCreated" => Ok(Self::TasksCreated),
+            "TasksUpdated" => Ok(Self::TasksUpdated),
+            "UnarchiveBoard" => Ok(Self::UnarchiveBoard),
+            "UncompleteProject" => Ok(Self::UncompleteProject),
+            "UncompleteTask" => Ok(Self::UncompleteTask),
+            "UnprioritizeTasks" => Ok(Self::UnprioritizeTasks),
+            "UnspringProject" => Ok(Self::UnspringProject),
+            "UpdateBoard" => Ok(Self::UpdateBoard),
+            "UpdateContainer" => Ok(Self::UpdateContainer),
+            "UpdateDiary" => Ok(Self::UpdateDiary),
+            "UpdateGroup" => Ok(Self::UpdateGroup),
+            "UpdateNote" => Ok(Self::UpdateNote),
+            "UpdateProject" => Ok(Self::UpdateProject),
+            "UpdateProjectColumn" => Ok(Self::UpdateProjectColumn),
+            "UpdateTask" => Ok(Self::UpdateTask),
+            "UpdateUserSettings" => Ok(Self::UpdateUserSettings),
+            _ => Err(crate::UnknownOperationError(s.to_owned())),
+        }
+    }
+}
@@ -1,3 +1,10 @@
+#[cfg(feature = "validated-scalars")]
+mod date;
+
+#[cfg(feature = "validated-scalars")]
+pub use date::*;
+
+#[cfg(not(feature = "validated-scalars"))]
 pub type Date = String;
 
 pub type DateTime = u64;
@@ -0,0 +1,114 @@
+use std::error::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// An ISO 8601 calendar date, such as `2023-09-01`.
+///
+/// Values are validated when they are constructed or deserialized, so a malformed date
+/// from the server results in a deserialization error instead of being passed along.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS), ts(type = "string"))]
+pub struct Date(String);
+
+impl Date {
+    /// Returns the date as a string.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Display for Date {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl TryFrom<String> for Date {
+    type Error = InvalidDateError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        match validate_date(&value) {
+            Ok(()) => Ok(Self(value)),
+            Err(reason) => Err(InvalidDateError { value, reason }),
+        }
+    }
+}
+
+impl FromStr for Date {
+    type Err = InvalidDateError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::try_from(value.to_string())
+    }
+}
+
+impl From<Date> for String {
+    fn from(value: Date) -> Self {
+        value.0
+    }
+}
+
+/// An error indicating that a value is not a valid [`Date`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidDateError {
+    value: String,
+    reason: &'static str,
+}
+
+impl Display for InvalidDateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid date `{}`: {}", self.value, self.reason)
+    }
+}
+
+impl Error for InvalidDateError {}
+
+impl Serialize for Date {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for Date {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+
+        Self::try_from(value).map_err(serde::de::Error::custom)
+    }
+}
+
+/// Checks that the value is a `YYYY-MM-DD` date that exists in the calendar.
+fn validate_date(value: &str) -> Result<(), &'static str> {
+    let mut parts = value.splitn(3, '-');
+    let (Some(year), Some(month), Some(day)) = (parts.next(), parts.next(), parts.next()) else {
+        return Err("expected a date in the format `YYYY-MM-DD`");
+    };
+
+    let is_numeric = |part: &str, len: usize| {
+        part.len() == len && part.bytes().all(|byte| byte.is_ascii_digit())
+    };
+    if !is_numeric(year, 4) || !is_numeric(month, 2) || !is_numeric(day, 2) {
+        return Err("expected a date in the format `YYYY-MM-DD`");
+    }
+
+    let year: u32 = year.parse().unwrap();
+    let month: u32 = month.parse().unwrap();
+    let day: u32 = day.parse().unwrap();
+
+    let is_leap_year = year % 4 == 0 && (year % 100 != 0 || year % 400 == 0);
+    let days_in_month = match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if is_leap_year => 29,
+        2 => 28,
+        _ => return Err("month is out of range"),
+    };
+
+    if day == 0 || day > days_in_month {
+        return Err("day is out of range");
+    }
+
+    Ok(())
+}
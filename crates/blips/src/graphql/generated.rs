@@ -22,6 +22,7 @@ pub mod delete_tasks;
 pub mod diary;
 pub mod enable_otp;
 pub mod generate_new_otp;
+pub mod groups_updated;
 pub mod me;
 pub mod move_tasks;
 pub mod note;
@@ -41,6 +42,8 @@ pub mod spring_project;
 pub mod tag_task;
 pub mod tags;
 pub mod tasks;
+pub mod tasks_created;
+pub mod tasks_updated;
 pub mod unarchive_board;
 pub mod uncomplete_project;
 pub mod uncomplete_task;
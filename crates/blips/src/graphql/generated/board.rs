@@ -4,6 +4,7 @@ pub mod board {
     #![allow(dead_code)]
     use std::result::Result;
     pub const OPERATION_NAME: &str = "Board";
+    pub const OPERATION_KIND: crate::OperationKind = crate::OperationKind::Query;
     pub const QUERY : & str = "query Board($board_id: ID) {\n    board(boardId: $board_id) {\n        ...Board\n    }\n}\n\nfragment Board on Board {\n    __typename\n    archivedAt\n    emoji\n    id\n    lastViewedAt\n    name\n    projectCompletedProjectColumnId\n    taskCompletedProjectColumnId\n}" ;
     use super::*;
     use serde::{Deserialize, Serialize};
@@ -16,18 +17,34 @@ pub mod board {
     #[allow(dead_code)]
     type ID = String;
     type DateTime = crate::graphql::custom_scalars::DateTime;
-    #[derive(Serialize)]
+    #[derive(Serialize, Clone, Default)]
     pub struct Variables {
         pub board_id: Option<ID>,
     }
-    impl Variables {}
-    #[derive(Deserialize, Debug)]
+    impl Variables {
+        /// Returns a new [`Variables`] from the arguments of `board`.
+        ///
+        /// The parameters are in the order the arguments are declared in the schema.
+        pub fn new(board_id: Option<ID>) -> Self {
+            Self { board_id }
+        }
+
+        /// Returns the variables with `boardId` set to the provided value.
+        pub fn with_board_id(mut self, board_id: impl Into<Option<ID>>) -> Self {
+            self.board_id = board_id.into();
+            self
+        }
+    }
+    #[derive(Deserialize, Debug, Serialize)]
+    #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
     pub struct Board {
         #[serde(rename = "archivedAt")]
+        #[cfg_attr(feature = "ts-rs", ts(as = "Option<f64>"))]
         pub archived_at: Option<DateTime>,
         pub emoji: Option<String>,
         pub id: ID,
         #[serde(rename = "lastViewedAt")]
+        #[cfg_attr(feature = "ts-rs", ts(as = "Option<f64>"))]
         pub last_viewed_at: Option<DateTime>,
         pub name: String,
         #[serde(rename = "projectCompletedProjectColumnId")]
@@ -35,20 +52,98 @@ pub mod board {
         #[serde(rename = "taskCompletedProjectColumnId")]
         pub task_completed_project_column_id: Option<ID>,
     }
-    #[derive(Deserialize, Debug)]
+    #[derive(Deserialize, Debug, Serialize)]
+    #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
     pub struct ResponseData {
         pub board: Option<BoardBoard>,
     }
     pub type BoardBoard = Board;
 }
-impl graphql_client::GraphQLQuery for Board {
+impl crate::runtime::GraphQLQuery for Board {
     type Variables = board::Variables;
     type ResponseData = board::ResponseData;
-    fn build_query(variables: Self::Variables) -> ::graphql_client::QueryBody<Self::Variables> {
-        graphql_client::QueryBody {
+    fn build_query(variables: Self::Variables) -> crate::runtime::QueryBody<Self::Variables> {
+        crate::runtime::QueryBody {
             variables,
             query: board::QUERY,
             operation_name: board::OPERATION_NAME,
         }
     }
 }
+
+impl TryFrom<serde_json::Value> for board::ResponseData {
+    type Error = crate::BlipsError;
+
+    fn try_from(value: serde_json::Value) -> Result<Self, Self::Error> {
+        crate::data_from_value(board::OPERATION_NAME, value)
+    }
+}
+
+/// The type of the `board` field of the response.
+pub type BoardPayload = board::BoardBoard;
+
+/// A field of the `Board` returned by [`Board`], for selecting with
+/// [`crate::BlipsClient::execute_partial`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BoardField {
+    ArchivedAt,
+    Emoji,
+    Id,
+    LastViewedAt,
+    Name,
+    ProjectCompletedProjectColumnId,
+    TaskCompletedProjectColumnId,
+}
+
+impl BoardField {
+    /// Every field, for selecting the whole fragment.
+    pub const ALL: &'static [Self] = &[
+        Self::ArchivedAt,
+        Self::Emoji,
+        Self::Id,
+        Self::LastViewedAt,
+        Self::Name,
+        Self::ProjectCompletedProjectColumnId,
+        Self::TaskCompletedProjectColumnId,
+    ];
+}
+
+/// The `Board` returned by [`Board`], with the fields selected by
+/// [`crate::BlipsClient::execute_partial`].
+///
+/// A field is `None` both when it wasn't selected and when the server returned `null` for it.
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+#[serde(default)]
+pub struct BoardPartial {
+    #[serde(rename = "archivedAt")]
+    pub archived_at: Option<crate::graphql::custom_scalars::DateTime>,
+    pub emoji: Option<String>,
+    pub id: Option<String>,
+    #[serde(rename = "lastViewedAt")]
+    pub last_viewed_at: Option<crate::graphql::custom_scalars::DateTime>,
+    pub name: Option<String>,
+    #[serde(rename = "projectCompletedProjectColumnId")]
+    pub project_completed_project_column_id: Option<String>,
+    #[serde(rename = "taskCompletedProjectColumnId")]
+    pub task_completed_project_column_id: Option<String>,
+}
+
+impl crate::PartialQuery for Board {
+    type Field = BoardField;
+    type Partial = Option<BoardPartial>;
+
+    const FRAGMENT_NAME: &'static str = "Board";
+    const ROOT_FIELD: &'static str = "board";
+
+    fn field_name(field: Self::Field) -> &'static str {
+        match field {
+            BoardField::ArchivedAt => "archivedAt",
+            BoardField::Emoji => "emoji",
+            BoardField::Id => "id",
+            BoardField::LastViewedAt => "lastViewedAt",
+            BoardField::Name => "name",
+            BoardField::ProjectCompletedProjectColumnId => "projectCompletedProjectColumnId",
+            BoardField::TaskCompletedProjectColumnId => "taskCompletedProjectColumnId",
+        }
+    }
+}
@@ -4,6 +4,7 @@ pub mod boards {
     #![allow(dead_code)]
     use std::result::Result;
     pub const OPERATION_NAME: &str = "Boards";
+    pub const OPERATION_KIND: crate::OperationKind = crate::OperationKind::Query;
     pub const QUERY : & str = "query Boards {\n    boards {\n        ...Board\n    }\n}\n\nfragment Board on Board {\n    __typename\n    archivedAt\n    emoji\n    id\n    lastViewedAt\n    name\n    projectCompletedProjectColumnId\n    taskCompletedProjectColumnId\n}" ;
     use super::*;
     use serde::{Deserialize, Serialize};
@@ -16,15 +17,18 @@ pub mod boards {
     #[allow(dead_code)]
     type ID = String;
     type DateTime = crate::graphql::custom_scalars::DateTime;
-    #[derive(Serialize)]
+    #[derive(Serialize, Clone, Default)]
     pub struct Variables;
-    #[derive(Deserialize, Debug)]
+    #[derive(Deserialize, Debug, Serialize)]
+    #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
     pub struct Board {
         #[serde(rename = "archivedAt")]
+        #[cfg_attr(feature = "ts-rs", ts(as = "Option<f64>"))]
         pub archived_at: Option<DateTime>,
         pub emoji: Option<String>,
         pub id: ID,
         #[serde(rename = "lastViewedAt")]
+        #[cfg_attr(feature = "ts-rs", ts(as = "Option<f64>"))]
         pub last_viewed_at: Option<DateTime>,
         pub name: String,
         #[serde(rename = "projectCompletedProjectColumnId")]
@@ -32,20 +36,114 @@ pub mod boards {
         #[serde(rename = "taskCompletedProjectColumnId")]
         pub task_completed_project_column_id: Option<ID>,
     }
-    #[derive(Deserialize, Debug)]
+    #[derive(Deserialize, Debug, Serialize)]
+    #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
     pub struct ResponseData {
         pub boards: Vec<BoardsBoards>,
     }
     pub type BoardsBoards = Board;
 }
-impl graphql_client::GraphQLQuery for Boards {
+impl crate::runtime::GraphQLQuery for Boards {
     type Variables = boards::Variables;
     type ResponseData = boards::ResponseData;
-    fn build_query(variables: Self::Variables) -> ::graphql_client::QueryBody<Self::Variables> {
-        graphql_client::QueryBody {
+    fn build_query(variables: Self::Variables) -> crate::runtime::QueryBody<Self::Variables> {
+        crate::runtime::QueryBody {
             variables,
             query: boards::QUERY,
             operation_name: boards::OPERATION_NAME,
         }
     }
 }
+
+impl TryFrom<serde_json::Value> for boards::ResponseData {
+    type Error = crate::BlipsError;
+
+    fn try_from(value: serde_json::Value) -> Result<Self, Self::Error> {
+        crate::data_from_value(boards::OPERATION_NAME, value)
+    }
+}
+
+/// The type of the items in the `boards` list of the response.
+pub type BoardsPayload = boards::BoardsBoards;
+
+impl boards::ResponseData {
+    /// Returns the items in the `boards` list.
+    pub fn items(&self) -> &[boards::BoardsBoards] {
+        &self.boards
+    }
+}
+
+impl IntoIterator for boards::ResponseData {
+    type Item = boards::BoardsBoards;
+    type IntoIter = std::vec::IntoIter<boards::BoardsBoards>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.boards.into_iter()
+    }
+}
+
+/// A field of the `Board` returned by [`Boards`], for selecting with
+/// [`crate::BlipsClient::execute_partial`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BoardsField {
+    ArchivedAt,
+    Emoji,
+    Id,
+    LastViewedAt,
+    Name,
+    ProjectCompletedProjectColumnId,
+    TaskCompletedProjectColumnId,
+}
+
+impl BoardsField {
+    /// Every field, for selecting the whole fragment.
+    pub const ALL: &'static [Self] = &[
+        Self::ArchivedAt,
+        Self::Emoji,
+        Self::Id,
+        Self::LastViewedAt,
+        Self::Name,
+        Self::ProjectCompletedProjectColumnId,
+        Self::TaskCompletedProjectColumnId,
+    ];
+}
+
+/// The `Board` returned by [`Boards`], with the fields selected by
+/// [`crate::BlipsClient::execute_partial`].
+///
+/// A field is `None` both when it wasn't selected and when the server returned `null` for it.
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+#[serde(default)]
+pub struct BoardsPartial {
+    #[serde(rename = "archivedAt")]
+    pub archived_at: Option<crate::graphql::custom_scalars::DateTime>,
+    pub emoji: Option<String>,
+    pub id: Option<String>,
+    #[serde(rename = "lastViewedAt")]
+    pub last_viewed_at: Option<crate::graphql::custom_scalars::DateTime>,
+    pub name: Option<String>,
+    #[serde(rename = "projectCompletedProjectColumnId")]
+    pub project_completed_project_column_id: Option<String>,
+    #[serde(rename = "taskCompletedProjectColumnId")]
+    pub task_completed_project_column_id: Option<String>,
+}
+
+impl crate::PartialQuery for Boards {
+    type Field = BoardsField;
+    type Partial = Vec<BoardsPartial>;
+
+    const FRAGMENT_NAME: &'static str = "Board";
+    const ROOT_FIELD: &'static str = "boards";
+
+    fn field_name(field: Self::Field) -> &'static str {
+        match field {
+            BoardsField::ArchivedAt => "archivedAt",
+            BoardsField::Emoji => "emoji",
+            BoardsField::Id => "id",
+            BoardsField::LastViewedAt => "lastViewedAt",
+            BoardsField::Name => "name",
+            BoardsField::ProjectCompletedProjectColumnId => "projectCompletedProjectColumnId",
+            BoardsField::TaskCompletedProjectColumnId => "taskCompletedProjectColumnId",
+        }
+    }
+}
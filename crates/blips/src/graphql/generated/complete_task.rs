@@ -4,6 +4,7 @@ pub mod complete_task {
     #![allow(dead_code)]
     use std::result::Result;
     pub const OPERATION_NAME: &str = "CompleteTask";
+    pub const OPERATION_KIND: crate::OperationKind = crate::OperationKind::Mutation;
     pub const QUERY : & str = "mutation CompleteTask($task_id: ID!) {\n    completeTask(taskId: $task_id) {\n        ...Task\n    }\n}\n\nfragment Task on Task {\n    __typename\n    completed\n    completedAt\n    date\n    description\n    dueDate\n    groupIds\n    id\n    isRecurring\n    link\n    name\n    priorityOrder\n    spring\n}" ;
     use super::*;
     use serde::{Deserialize, Serialize};
@@ -17,15 +18,30 @@ pub mod complete_task {
     type ID = String;
     type Date = crate::graphql::custom_scalars::Date;
     type DateTime = crate::graphql::custom_scalars::DateTime;
-    #[derive(Serialize)]
+    #[derive(Serialize, Clone)]
     pub struct Variables {
         pub task_id: ID,
     }
-    impl Variables {}
-    #[derive(Deserialize, Debug)]
+    impl Variables {
+        /// Returns a new [`Variables`] from the arguments of `completeTask`.
+        ///
+        /// The parameters are in the order the arguments are declared in the schema.
+        pub fn new(task_id: ID) -> Self {
+            Self { task_id }
+        }
+
+        /// Returns the variables with `taskId` set to the provided value.
+        pub fn with_task_id(mut self, task_id: ID) -> Self {
+            self.task_id = task_id;
+            self
+        }
+    }
+    #[derive(Deserialize, Debug, Serialize)]
+    #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
     pub struct Task {
         pub completed: Boolean,
         #[serde(rename = "completedAt")]
+        #[cfg_attr(feature = "ts-rs", ts(as = "Option<f64>"))]
         pub completed_at: Option<DateTime>,
         pub date: Option<Date>,
         pub description: Option<String>,
@@ -39,24 +55,256 @@ pub mod complete_task {
         pub link: Option<String>,
         pub name: String,
         #[serde(rename = "priorityOrder")]
+        #[cfg_attr(feature = "ts-rs", ts(as = "Option<i32>"))]
         pub priority_order: Option<Int>,
         pub spring: Boolean,
     }
-    #[derive(Deserialize, Debug)]
+    #[derive(Deserialize, Debug, Serialize)]
+    #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
     pub struct ResponseData {
         #[serde(rename = "completeTask")]
         pub complete_task: Vec<CompleteTaskCompleteTask>,
     }
     pub type CompleteTaskCompleteTask = Task;
 }
-impl graphql_client::GraphQLQuery for CompleteTask {
+impl crate::runtime::GraphQLQuery for CompleteTask {
     type Variables = complete_task::Variables;
     type ResponseData = complete_task::ResponseData;
-    fn build_query(variables: Self::Variables) -> ::graphql_client::QueryBody<Self::Variables> {
-        graphql_client::QueryBody {
+    fn build_query(variables: Self::Variables) -> crate::runtime::QueryBody<Self::Variables> {
+        crate::runtime::QueryBody {
             variables,
             query: complete_task::QUERY,
             operation_name: complete_task::OPERATION_NAME,
         }
     }
 }
+
+impl TryFrom<serde_json::Value> for complete_task::ResponseData {
+    type Error = crate::BlipsError;
+
+    fn try_from(value: serde_json::Value) -> Result<Self, Self::Error> {
+        crate::data_from_value(complete_task::OPERATION_NAME, value)
+    }
+}
+
+/// The type of the items in the `completeTask` list of the response.
+pub type CompleteTaskPayload = complete_task::CompleteTaskCompleteTask;
+
+impl complete_task::ResponseData {
+    /// Returns the items in the `completeTask` list.
+    pub fn items(&self) -> &[complete_task::CompleteTaskCompleteTask] {
+        &self.complete_task
+    }
+}
+
+impl IntoIterator for complete_task::ResponseData {
+    type Item = complete_task::CompleteTaskCompleteTask;
+    type IntoIter = std::vec::IntoIter<complete_task::CompleteTaskCompleteTask>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.complete_task.into_iter()
+    }
+}
+
+impl complete_task::Task {
+    /// Compares two values by `priorityOrder`, sorting values without one last.
+    pub fn by_priority_order(a: &Self, b: &Self) -> std::cmp::Ordering {
+        match (a.priority_order, b.priority_order) {
+            (Some(a), Some(b)) => a.cmp(&b),
+            (Some(_), None) => std::cmp::Ordering::Less,
+            (None, Some(_)) => std::cmp::Ordering::Greater,
+            (None, None) => std::cmp::Ordering::Equal,
+        }
+    }
+}
+
+impl complete_task::Task {
+    /// Returns the state of the task, derived from its flags.
+    pub fn state(&self) -> crate::TaskState {
+        crate::TaskState::from_flags(self.completed, self.is_recurring, self.spring)
+    }
+}
+
+impl complete_task::Task {
+    /// Returns whether the task is completed.
+    ///
+    /// `completed` and `completedAt` can disagree due to races or server bugs. When they
+    /// do, `completed` is trusted and `completedAt` is ignored. Use
+    /// [`Self::validate_consistency`] to detect the disagreement.
+    pub fn is_effectively_completed(&self) -> bool {
+        self.completed
+    }
+
+    /// Returns warnings for the fields of the task that contradict each other.
+    pub fn validate_consistency(&self) -> Vec<crate::ConsistencyWarning> {
+        crate::ConsistencyWarning::for_completion(self.completed, self.completed_at.is_some())
+            .into_iter()
+            .collect()
+    }
+}
+
+impl complete_task::Task {
+    /// Returns the fields that differ between this task and `other`, in the order
+    /// they are selected.
+    pub fn diff(&self, other: &Self) -> Vec<crate::FieldChange> {
+        let mut changes = Vec::new();
+
+        if self.completed != other.completed {
+            changes.push(crate::FieldChange::new(
+                "completed",
+                &self.completed,
+                &other.completed,
+            ));
+        }
+        if self.completed_at != other.completed_at {
+            changes.push(crate::FieldChange::new(
+                "completedAt",
+                &self.completed_at,
+                &other.completed_at,
+            ));
+        }
+        if self.date != other.date {
+            changes.push(crate::FieldChange::new("date", &self.date, &other.date));
+        }
+        if self.description != other.description {
+            changes.push(crate::FieldChange::new(
+                "description",
+                &self.description,
+                &other.description,
+            ));
+        }
+        if self.due_date != other.due_date {
+            changes.push(crate::FieldChange::new(
+                "dueDate",
+                &self.due_date,
+                &other.due_date,
+            ));
+        }
+        if self.group_ids != other.group_ids {
+            changes.push(crate::FieldChange::new(
+                "groupIds",
+                &self.group_ids,
+                &other.group_ids,
+            ));
+        }
+        if self.id != other.id {
+            changes.push(crate::FieldChange::new("id", &self.id, &other.id));
+        }
+        if self.is_recurring != other.is_recurring {
+            changes.push(crate::FieldChange::new(
+                "isRecurring",
+                &self.is_recurring,
+                &other.is_recurring,
+            ));
+        }
+        if self.link != other.link {
+            changes.push(crate::FieldChange::new("link", &self.link, &other.link));
+        }
+        if self.name != other.name {
+            changes.push(crate::FieldChange::new("name", &self.name, &other.name));
+        }
+        if self.priority_order != other.priority_order {
+            changes.push(crate::FieldChange::new(
+                "priorityOrder",
+                &self.priority_order,
+                &other.priority_order,
+            ));
+        }
+        if self.spring != other.spring {
+            changes.push(crate::FieldChange::new(
+                "spring",
+                &self.spring,
+                &other.spring,
+            ));
+        }
+
+        changes
+    }
+}
+
+/// A field of the `Task` returned by [`CompleteTask`], for selecting with
+/// [`crate::BlipsClient::execute_partial`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CompleteTaskField {
+    Completed,
+    CompletedAt,
+    Date,
+    Description,
+    DueDate,
+    GroupIds,
+    Id,
+    IsRecurring,
+    Link,
+    Name,
+    PriorityOrder,
+    Spring,
+}
+
+impl CompleteTaskField {
+    /// Every field, for selecting the whole fragment.
+    pub const ALL: &'static [Self] = &[
+        Self::Completed,
+        Self::CompletedAt,
+        Self::Date,
+        Self::Description,
+        Self::DueDate,
+        Self::GroupIds,
+        Self::Id,
+        Self::IsRecurring,
+        Self::Link,
+        Self::Name,
+        Self::PriorityOrder,
+        Self::Spring,
+    ];
+}
+
+/// The `Task` returned by [`CompleteTask`], with the fields selected by
+/// [`crate::BlipsClient::execute_partial`].
+///
+/// A field is `None` both when it wasn't selected and when the server returned `null` for it.
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+#[serde(default)]
+pub struct CompleteTaskPartial {
+    pub completed: Option<bool>,
+    #[serde(rename = "completedAt")]
+    pub completed_at: Option<crate::graphql::custom_scalars::DateTime>,
+    pub date: Option<crate::graphql::custom_scalars::Date>,
+    pub description: Option<String>,
+    #[serde(rename = "dueDate")]
+    pub due_date: Option<crate::graphql::custom_scalars::Date>,
+    #[serde(rename = "groupIds")]
+    pub group_ids: Option<Vec<String>>,
+    pub id: Option<String>,
+    #[serde(rename = "isRecurring")]
+    pub is_recurring: Option<bool>,
+    pub link: Option<String>,
+    pub name: Option<String>,
+    #[serde(rename = "priorityOrder")]
+    pub priority_order: Option<i64>,
+    pub spring: Option<bool>,
+}
+
+impl crate::PartialQuery for CompleteTask {
+    type Field = CompleteTaskField;
+    type Partial = Vec<CompleteTaskPartial>;
+
+    const FRAGMENT_NAME: &'static str = "Task";
+    const ROOT_FIELD: &'static str = "completeTask";
+
+    fn field_name(field: Self::Field) -> &'static str {
+        match field {
+            CompleteTaskField::Completed => "completed",
+            CompleteTaskField::CompletedAt => "completedAt",
+            CompleteTaskField::Date => "date",
+            CompleteTaskField::Description => "description",
+            CompleteTaskField::DueDate => "dueDate",
+            CompleteTaskField::GroupIds => "groupIds",
+            CompleteTaskField::Id => "id",
+            CompleteTaskField::IsRecurring => "isRecurring",
+            CompleteTaskField::Link => "link",
+            CompleteTaskField::Name => "name",
+            CompleteTaskField::PriorityOrder => "priorityOrder",
+            CompleteTaskField::Spring => "spring",
+        }
+    }
+}
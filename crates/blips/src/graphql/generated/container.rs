@@ -4,7 +4,8 @@ pub mod container {
     #![allow(dead_code)]
     use std::result::Result;
     pub const OPERATION_NAME: &str = "Container";
-    pub const QUERY : & str = "query Container($date: Date, $inbox: Boolean, $project_id: ID) {\n    container(date: $date, inbox: $inbox, projectId: $project_id) {\n        ...Container\n    }\n}\n\nfragment Container on Container {\n    __typename\n    \n}" ;
+    pub const OPERATION_KIND: crate::OperationKind = crate::OperationKind::Query;
+    pub const QUERY : & str = "query Container($date: Date, $inbox: Boolean, $project_id: ID) {\n    container(date: $date, inbox: $inbox, projectId: $project_id) {\n        ...Container\n    }\n}\n\nfragment Container on Container {\n    __typename\n    collapseCompleted\n    id\n    noteBody\n    supportsNotes\n    ... on Diary {\n        diaryDate: date\n    }\n    ... on Project {\n        completed\n        completedAt\n        projectDate: date\n        endDate\n        link\n        name\n        order\n        springEnabled\n    }\n}" ;
     use super::*;
     use serde::{Deserialize, Serialize};
     #[allow(dead_code)]
@@ -16,34 +17,117 @@ pub mod container {
     #[allow(dead_code)]
     type ID = String;
     type Date = crate::graphql::custom_scalars::Date;
-    #[derive(Serialize)]
+    type DateTime = crate::graphql::custom_scalars::DateTime;
+    #[derive(Serialize, Clone, Default)]
     pub struct Variables {
         pub date: Option<Date>,
         pub inbox: Option<Boolean>,
         pub project_id: Option<ID>,
     }
-    impl Variables {}
-    #[derive(Deserialize, Debug)]
+    impl Variables {
+        /// Returns a new [`Variables`] from the arguments of `container`.
+        ///
+        /// The parameters are in the order the arguments are declared in the schema.
+        pub fn new(date: Option<Date>, inbox: Option<Boolean>, project_id: Option<ID>) -> Self {
+            Self {
+                date,
+                inbox,
+                project_id,
+            }
+        }
+
+        /// Returns the variables with `date` set to the provided value.
+        pub fn with_date(mut self, date: impl Into<Option<Date>>) -> Self {
+            self.date = date.into();
+            self
+        }
+
+        /// Returns the variables with `inbox` set to the provided value.
+        pub fn with_inbox(mut self, inbox: impl Into<Option<Boolean>>) -> Self {
+            self.inbox = inbox.into();
+            self
+        }
+
+        /// Returns the variables with `projectId` set to the provided value.
+        pub fn with_project_id(mut self, project_id: impl Into<Option<ID>>) -> Self {
+            self.project_id = project_id.into();
+            self
+        }
+    }
+    #[derive(Deserialize, Debug, Serialize)]
+    #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+    pub struct Container {
+        #[serde(rename = "collapseCompleted")]
+        pub collapse_completed: Boolean,
+        pub id: ID,
+        #[serde(rename = "noteBody")]
+        pub note_body: Option<String>,
+        #[serde(rename = "supportsNotes")]
+        pub supports_notes: Boolean,
+        #[serde(flatten)]
+        pub on: ContainerOn,
+    }
+    #[derive(Deserialize, Debug, Serialize)]
+    #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
     #[serde(tag = "__typename")]
-    pub enum Container {
-        Diary,
+    pub enum ContainerOn {
+        Diary(ContainerOnDiary),
         Inbox,
-        Project,
+        Project(ContainerOnProject),
+        #[cfg_attr(feature = "ts-rs", ts(skip))]
+        #[serde(other)]
+        Unknown,
+    }
+    #[derive(Deserialize, Debug, Serialize)]
+    #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+    pub struct ContainerOnDiary {
+        #[serde(rename = "diaryDate")]
+        pub diary_date: Date,
+    }
+    #[derive(Deserialize, Debug, Serialize)]
+    #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+    pub struct ContainerOnProject {
+        pub completed: Boolean,
+        #[serde(rename = "completedAt")]
+        #[cfg_attr(feature = "ts-rs", ts(as = "Option<f64>"))]
+        pub completed_at: Option<DateTime>,
+        #[serde(rename = "projectDate")]
+        pub project_date: Option<Date>,
+        #[serde(rename = "endDate")]
+        pub end_date: Option<Date>,
+        pub link: Option<String>,
+        pub name: String,
+        #[cfg_attr(feature = "ts-rs", ts(as = "Option<i32>"))]
+        pub order: Option<Int>,
+        #[serde(rename = "springEnabled")]
+        pub spring_enabled: Boolean,
     }
-    #[derive(Deserialize, Debug)]
+    #[derive(Deserialize, Debug, Serialize)]
+    #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
     pub struct ResponseData {
         pub container: ContainerContainer,
     }
     pub type ContainerContainer = Container;
 }
-impl graphql_client::GraphQLQuery for Container {
+impl crate::runtime::GraphQLQuery for Container {
     type Variables = container::Variables;
     type ResponseData = container::ResponseData;
-    fn build_query(variables: Self::Variables) -> ::graphql_client::QueryBody<Self::Variables> {
-        graphql_client::QueryBody {
+    fn build_query(variables: Self::Variables) -> crate::runtime::QueryBody<Self::Variables> {
+        crate::runtime::QueryBody {
             variables,
             query: container::QUERY,
             operation_name: container::OPERATION_NAME,
         }
     }
 }
+
+impl TryFrom<serde_json::Value> for container::ResponseData {
+    type Error = crate::BlipsError;
+
+    fn try_from(value: serde_json::Value) -> Result<Self, Self::Error> {
+        crate::data_from_value(container::OPERATION_NAME, value)
+    }
+}
+
+/// The type of the `container` field of the response.
+pub type ContainerPayload = container::ContainerContainer;
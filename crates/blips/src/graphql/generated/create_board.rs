@@ -4,6 +4,7 @@ pub mod create_board {
     #![allow(dead_code)]
     use std::result::Result;
     pub const OPERATION_NAME: &str = "CreateBoard";
+    pub const OPERATION_KIND: crate::OperationKind = crate::OperationKind::Mutation;
     pub const QUERY : & str = "mutation CreateBoard {\n    createBoard {\n        ...Board\n    }\n}\n\nfragment Board on Board {\n    __typename\n    archivedAt\n    emoji\n    id\n    lastViewedAt\n    name\n    projectCompletedProjectColumnId\n    taskCompletedProjectColumnId\n}" ;
     use super::*;
     use serde::{Deserialize, Serialize};
@@ -16,15 +17,18 @@ pub mod create_board {
     #[allow(dead_code)]
     type ID = String;
     type DateTime = crate::graphql::custom_scalars::DateTime;
-    #[derive(Serialize)]
+    #[derive(Serialize, Clone, Default)]
     pub struct Variables;
-    #[derive(Deserialize, Debug)]
+    #[derive(Deserialize, Debug, Serialize)]
+    #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
     pub struct Board {
         #[serde(rename = "archivedAt")]
+        #[cfg_attr(feature = "ts-rs", ts(as = "Option<f64>"))]
         pub archived_at: Option<DateTime>,
         pub emoji: Option<String>,
         pub id: ID,
         #[serde(rename = "lastViewedAt")]
+        #[cfg_attr(feature = "ts-rs", ts(as = "Option<f64>"))]
         pub last_viewed_at: Option<DateTime>,
         pub name: String,
         #[serde(rename = "projectCompletedProjectColumnId")]
@@ -32,21 +36,99 @@ pub mod create_board {
         #[serde(rename = "taskCompletedProjectColumnId")]
         pub task_completed_project_column_id: Option<ID>,
     }
-    #[derive(Deserialize, Debug)]
+    #[derive(Deserialize, Debug, Serialize)]
+    #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
     pub struct ResponseData {
         #[serde(rename = "createBoard")]
         pub create_board: CreateBoardCreateBoard,
     }
     pub type CreateBoardCreateBoard = Board;
 }
-impl graphql_client::GraphQLQuery for CreateBoard {
+impl crate::runtime::GraphQLQuery for CreateBoard {
     type Variables = create_board::Variables;
     type ResponseData = create_board::ResponseData;
-    fn build_query(variables: Self::Variables) -> ::graphql_client::QueryBody<Self::Variables> {
-        graphql_client::QueryBody {
+    fn build_query(variables: Self::Variables) -> crate::runtime::QueryBody<Self::Variables> {
+        crate::runtime::QueryBody {
             variables,
             query: create_board::QUERY,
             operation_name: create_board::OPERATION_NAME,
         }
     }
 }
+
+impl TryFrom<serde_json::Value> for create_board::ResponseData {
+    type Error = crate::BlipsError;
+
+    fn try_from(value: serde_json::Value) -> Result<Self, Self::Error> {
+        crate::data_from_value(create_board::OPERATION_NAME, value)
+    }
+}
+
+/// The type of the `createBoard` field of the response.
+pub type CreateBoardPayload = create_board::CreateBoardCreateBoard;
+
+/// A field of the `Board` returned by [`CreateBoard`], for selecting with
+/// [`crate::BlipsClient::execute_partial`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CreateBoardField {
+    ArchivedAt,
+    Emoji,
+    Id,
+    LastViewedAt,
+    Name,
+    ProjectCompletedProjectColumnId,
+    TaskCompletedProjectColumnId,
+}
+
+impl CreateBoardField {
+    /// Every field, for selecting the whole fragment.
+    pub const ALL: &'static [Self] = &[
+        Self::ArchivedAt,
+        Self::Emoji,
+        Self::Id,
+        Self::LastViewedAt,
+        Self::Name,
+        Self::ProjectCompletedProjectColumnId,
+        Self::TaskCompletedProjectColumnId,
+    ];
+}
+
+/// The `Board` returned by [`CreateBoard`], with the fields selected by
+/// [`crate::BlipsClient::execute_partial`].
+///
+/// A field is `None` both when it wasn't selected and when the server returned `null` for it.
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+#[serde(default)]
+pub struct CreateBoardPartial {
+    #[serde(rename = "archivedAt")]
+    pub archived_at: Option<crate::graphql::custom_scalars::DateTime>,
+    pub emoji: Option<String>,
+    pub id: Option<String>,
+    #[serde(rename = "lastViewedAt")]
+    pub last_viewed_at: Option<crate::graphql::custom_scalars::DateTime>,
+    pub name: Option<String>,
+    #[serde(rename = "projectCompletedProjectColumnId")]
+    pub project_completed_project_column_id: Option<String>,
+    #[serde(rename = "taskCompletedProjectColumnId")]
+    pub task_completed_project_column_id: Option<String>,
+}
+
+impl crate::PartialQuery for CreateBoard {
+    type Field = CreateBoardField;
+    type Partial = CreateBoardPartial;
+
+    const FRAGMENT_NAME: &'static str = "Board";
+    const ROOT_FIELD: &'static str = "createBoard";
+
+    fn field_name(field: Self::Field) -> &'static str {
+        match field {
+            CreateBoardField::ArchivedAt => "archivedAt",
+            CreateBoardField::Emoji => "emoji",
+            CreateBoardField::Id => "id",
+            CreateBoardField::LastViewedAt => "lastViewedAt",
+            CreateBoardField::Name => "name",
+            CreateBoardField::ProjectCompletedProjectColumnId => "projectCompletedProjectColumnId",
+            CreateBoardField::TaskCompletedProjectColumnId => "taskCompletedProjectColumnId",
+        }
+    }
+}
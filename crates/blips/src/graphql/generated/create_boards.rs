@@ -4,6 +4,7 @@ pub mod create_boards {
     #![allow(dead_code)]
     use std::result::Result;
     pub const OPERATION_NAME: &str = "CreateBoards";
+    pub const OPERATION_KIND: crate::OperationKind = crate::OperationKind::Mutation;
     pub const QUERY : & str = "mutation CreateBoards($names: [String!]!) {\n    createBoards(names: $names) {\n        ...Board\n    }\n}\n\nfragment Board on Board {\n    __typename\n    archivedAt\n    emoji\n    id\n    lastViewedAt\n    name\n    projectCompletedProjectColumnId\n    taskCompletedProjectColumnId\n}" ;
     use super::*;
     use serde::{Deserialize, Serialize};
@@ -16,18 +17,36 @@ pub mod create_boards {
     #[allow(dead_code)]
     type ID = String;
     type DateTime = crate::graphql::custom_scalars::DateTime;
-    #[derive(Serialize)]
+    #[derive(Serialize, Clone)]
     pub struct Variables {
         pub names: Vec<String>,
     }
-    impl Variables {}
-    #[derive(Deserialize, Debug)]
+    impl Variables {
+        /// Returns a new [`Variables`] from the arguments of `createBoards`.
+        ///
+        /// The parameters are in the order the arguments are declared in the schema.
+        pub fn new(names: Vec<String>) -> Self {
+            Self { names }
+        }
+
+        /// Returns the variables with `names` set to the provided value.
+        ///
+        /// A single value is sent as a list with one element.
+        pub fn with_names(mut self, names: impl Into<crate::OneOrMany<String>>) -> Self {
+            self.names = names.into().into_vec();
+            self
+        }
+    }
+    #[derive(Deserialize, Debug, Serialize)]
+    #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
     pub struct Board {
         #[serde(rename = "archivedAt")]
+        #[cfg_attr(feature = "ts-rs", ts(as = "Option<f64>"))]
         pub archived_at: Option<DateTime>,
         pub emoji: Option<String>,
         pub id: ID,
         #[serde(rename = "lastViewedAt")]
+        #[cfg_attr(feature = "ts-rs", ts(as = "Option<f64>"))]
         pub last_viewed_at: Option<DateTime>,
         pub name: String,
         #[serde(rename = "projectCompletedProjectColumnId")]
@@ -35,21 +54,115 @@ pub mod create_boards {
         #[serde(rename = "taskCompletedProjectColumnId")]
         pub task_completed_project_column_id: Option<ID>,
     }
-    #[derive(Deserialize, Debug)]
+    #[derive(Deserialize, Debug, Serialize)]
+    #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
     pub struct ResponseData {
         #[serde(rename = "createBoards")]
         pub create_boards: Vec<CreateBoardsCreateBoards>,
     }
     pub type CreateBoardsCreateBoards = Board;
 }
-impl graphql_client::GraphQLQuery for CreateBoards {
+impl crate::runtime::GraphQLQuery for CreateBoards {
     type Variables = create_boards::Variables;
     type ResponseData = create_boards::ResponseData;
-    fn build_query(variables: Self::Variables) -> ::graphql_client::QueryBody<Self::Variables> {
-        graphql_client::QueryBody {
+    fn build_query(variables: Self::Variables) -> crate::runtime::QueryBody<Self::Variables> {
+        crate::runtime::QueryBody {
             variables,
             query: create_boards::QUERY,
             operation_name: create_boards::OPERATION_NAME,
         }
     }
 }
+
+impl TryFrom<serde_json::Value> for create_boards::ResponseData {
+    type Error = crate::BlipsError;
+
+    fn try_from(value: serde_json::Value) -> Result<Self, Self::Error> {
+        crate::data_from_value(create_boards::OPERATION_NAME, value)
+    }
+}
+
+/// The type of the items in the `createBoards` list of the response.
+pub type CreateBoardsPayload = create_boards::CreateBoardsCreateBoards;
+
+impl create_boards::ResponseData {
+    /// Returns the items in the `createBoards` list.
+    pub fn items(&self) -> &[create_boards::CreateBoardsCreateBoards] {
+        &self.create_boards
+    }
+}
+
+impl IntoIterator for create_boards::ResponseData {
+    type Item = create_boards::CreateBoardsCreateBoards;
+    type IntoIter = std::vec::IntoIter<create_boards::CreateBoardsCreateBoards>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.create_boards.into_iter()
+    }
+}
+
+/// A field of the `Board` returned by [`CreateBoards`], for selecting with
+/// [`crate::BlipsClient::execute_partial`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CreateBoardsField {
+    ArchivedAt,
+    Emoji,
+    Id,
+    LastViewedAt,
+    Name,
+    ProjectCompletedProjectColumnId,
+    TaskCompletedProjectColumnId,
+}
+
+impl CreateBoardsField {
+    /// Every field, for selecting the whole fragment.
+    pub const ALL: &'static [Self] = &[
+        Self::ArchivedAt,
+        Self::Emoji,
+        Self::Id,
+        Self::LastViewedAt,
+        Self::Name,
+        Self::ProjectCompletedProjectColumnId,
+        Self::TaskCompletedProjectColumnId,
+    ];
+}
+
+/// The `Board` returned by [`CreateBoards`], with the fields selected by
+/// [`crate::BlipsClient::execute_partial`].
+///
+/// A field is `None` both when it wasn't selected and when the server returned `null` for it.
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+#[serde(default)]
+pub struct CreateBoardsPartial {
+    #[serde(rename = "archivedAt")]
+    pub archived_at: Option<crate::graphql::custom_scalars::DateTime>,
+    pub emoji: Option<String>,
+    pub id: Option<String>,
+    #[serde(rename = "lastViewedAt")]
+    pub last_viewed_at: Option<crate::graphql::custom_scalars::DateTime>,
+    pub name: Option<String>,
+    #[serde(rename = "projectCompletedProjectColumnId")]
+    pub project_completed_project_column_id: Option<String>,
+    #[serde(rename = "taskCompletedProjectColumnId")]
+    pub task_completed_project_column_id: Option<String>,
+}
+
+impl crate::PartialQuery for CreateBoards {
+    type Field = CreateBoardsField;
+    type Partial = Vec<CreateBoardsPartial>;
+
+    const FRAGMENT_NAME: &'static str = "Board";
+    const ROOT_FIELD: &'static str = "createBoards";
+
+    fn field_name(field: Self::Field) -> &'static str {
+        match field {
+            CreateBoardsField::ArchivedAt => "archivedAt",
+            CreateBoardsField::Emoji => "emoji",
+            CreateBoardsField::Id => "id",
+            CreateBoardsField::LastViewedAt => "lastViewedAt",
+            CreateBoardsField::Name => "name",
+            CreateBoardsField::ProjectCompletedProjectColumnId => "projectCompletedProjectColumnId",
+            CreateBoardsField::TaskCompletedProjectColumnId => "taskCompletedProjectColumnId",
+        }
+    }
+}
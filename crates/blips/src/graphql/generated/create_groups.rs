@@ -4,6 +4,7 @@ pub mod create_groups {
     #![allow(dead_code)]
     use std::result::Result;
     pub const OPERATION_NAME: &str = "CreateGroups";
+    pub const OPERATION_KIND: crate::OperationKind = crate::OperationKind::Mutation;
     pub const QUERY : & str = "mutation CreateGroups($container_id: ID!, $container_type: ContainerTypeEnum!, $names: [String!]!) {\n    createGroups(containerId: $container_id, containerType: $container_type, names: $names) {\n        ...Group\n    }\n}\n\nfragment Group on Group {\n    __typename\n    collapsed\n    date\n    id\n    keepTasks\n    name\n    order\n    projectId\n}" ;
     use super::*;
     use serde::{Deserialize, Serialize};
@@ -16,42 +17,107 @@ pub mod create_groups {
     #[allow(dead_code)]
     type ID = String;
     type Date = crate::graphql::custom_scalars::Date;
-    #[derive(Debug)]
+    #[derive(Clone, Debug)]
     pub enum ContainerTypeEnum {
-        DIARY,
-        INBOX,
-        PROJECT,
+        Diary,
+        Inbox,
+        Project,
         Other(String),
     }
     impl ::serde::Serialize for ContainerTypeEnum {
         fn serialize<S: serde::Serializer>(&self, ser: S) -> Result<S::Ok, S::Error> {
             ser.serialize_str(match *self {
-                ContainerTypeEnum::DIARY => "DIARY",
-                ContainerTypeEnum::INBOX => "INBOX",
-                ContainerTypeEnum::PROJECT => "PROJECT",
+                ContainerTypeEnum::Diary => "DIARY",
+                ContainerTypeEnum::Inbox => "INBOX",
+                ContainerTypeEnum::Project => "PROJECT",
                 ContainerTypeEnum::Other(ref s) => &s,
             })
         }
     }
+    impl ContainerTypeEnum {
+        /// Returns the value as the schema declares it, such as for logging or metric labels,
+        /// without allocating.
+        ///
+        /// Values that the schema didn't declare when the SDK was generated are returned as
+        /// `"__OTHER"`. Use [`ContainerTypeEnum::other_value`] to get them.
+        pub fn as_str(&self) -> &'static str {
+            match self {
+                ContainerTypeEnum::Diary => "DIARY",
+                ContainerTypeEnum::Inbox => "INBOX",
+                ContainerTypeEnum::Project => "PROJECT",
+                ContainerTypeEnum::Other(_) => "__OTHER",
+            }
+        }
+
+        /// Returns the value of a variant that the schema didn't declare when the SDK was
+        /// generated, or `None` for the declared variants.
+        pub fn other_value(&self) -> Option<&str> {
+            match self {
+                ContainerTypeEnum::Other(value) => Some(value),
+                _ => None,
+            }
+        }
+    }
+    impl From<ContainerTypeEnum> for &'static str {
+        fn from(value: ContainerTypeEnum) -> Self {
+            value.as_str()
+        }
+    }
     impl<'de> ::serde::Deserialize<'de> for ContainerTypeEnum {
         fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
             let s: String = ::serde::Deserialize::deserialize(deserializer)?;
             match s.as_str() {
-                "DIARY" => Ok(ContainerTypeEnum::DIARY),
-                "INBOX" => Ok(ContainerTypeEnum::INBOX),
-                "PROJECT" => Ok(ContainerTypeEnum::PROJECT),
+                "DIARY" => Ok(ContainerTypeEnum::Diary),
+                "INBOX" => Ok(ContainerTypeEnum::Inbox),
+                "PROJECT" => Ok(ContainerTypeEnum::Project),
                 _ => Ok(ContainerTypeEnum::Other(s)),
             }
         }
     }
-    #[derive(Serialize)]
+    #[derive(Serialize, Clone)]
     pub struct Variables {
         pub container_id: ID,
         pub container_type: ContainerTypeEnum,
         pub names: Vec<String>,
     }
-    impl Variables {}
-    #[derive(Deserialize, Debug)]
+    impl Variables {
+        /// Returns a new [`Variables`] from the arguments of `createGroups`.
+        ///
+        /// The parameters are in the order the arguments are declared in the schema.
+        pub fn new(
+            container_id: ID,
+            container_type: ContainerTypeEnum,
+            names: Vec<String>,
+        ) -> Self {
+            Self {
+                container_id,
+                container_type,
+                names,
+            }
+        }
+
+        /// Returns the variables with `containerId` set to the provided value.
+        pub fn with_container_id(mut self, container_id: ID) -> Self {
+            self.container_id = container_id;
+            self
+        }
+
+        /// Returns the variables with `containerType` set to the provided value.
+        pub fn with_container_type(mut self, container_type: ContainerTypeEnum) -> Self {
+            self.container_type = container_type;
+            self
+        }
+
+        /// Returns the variables with `names` set to the provided value.
+        ///
+        /// A single value is sent as a list with one element.
+        pub fn with_names(mut self, names: impl Into<crate::OneOrMany<String>>) -> Self {
+            self.names = names.into().into_vec();
+            self
+        }
+    }
+    #[derive(Deserialize, Debug, Serialize)]
+    #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
     pub struct Group {
         pub collapsed: Option<Boolean>,
         pub date: Option<Date>,
@@ -59,25 +125,130 @@ pub mod create_groups {
         #[serde(rename = "keepTasks")]
         pub keep_tasks: Boolean,
         pub name: String,
+        #[cfg_attr(feature = "ts-rs", ts(as = "Option<i32>"))]
         pub order: Option<Int>,
         #[serde(rename = "projectId")]
         pub project_id: Option<ID>,
     }
-    #[derive(Deserialize, Debug)]
+    #[derive(Deserialize, Debug, Serialize)]
+    #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
     pub struct ResponseData {
         #[serde(rename = "createGroups")]
         pub create_groups: Vec<CreateGroupsCreateGroups>,
     }
     pub type CreateGroupsCreateGroups = Group;
 }
-impl graphql_client::GraphQLQuery for CreateGroups {
+impl crate::runtime::GraphQLQuery for CreateGroups {
     type Variables = create_groups::Variables;
     type ResponseData = create_groups::ResponseData;
-    fn build_query(variables: Self::Variables) -> ::graphql_client::QueryBody<Self::Variables> {
-        graphql_client::QueryBody {
+    fn build_query(variables: Self::Variables) -> crate::runtime::QueryBody<Self::Variables> {
+        crate::runtime::QueryBody {
             variables,
             query: create_groups::QUERY,
             operation_name: create_groups::OPERATION_NAME,
         }
     }
 }
+
+impl TryFrom<serde_json::Value> for create_groups::ResponseData {
+    type Error = crate::BlipsError;
+
+    fn try_from(value: serde_json::Value) -> Result<Self, Self::Error> {
+        crate::data_from_value(create_groups::OPERATION_NAME, value)
+    }
+}
+
+/// The type of the items in the `createGroups` list of the response.
+pub type CreateGroupsPayload = create_groups::CreateGroupsCreateGroups;
+
+impl create_groups::ResponseData {
+    /// Returns the items in the `createGroups` list.
+    pub fn items(&self) -> &[create_groups::CreateGroupsCreateGroups] {
+        &self.create_groups
+    }
+}
+
+impl IntoIterator for create_groups::ResponseData {
+    type Item = create_groups::CreateGroupsCreateGroups;
+    type IntoIter = std::vec::IntoIter<create_groups::CreateGroupsCreateGroups>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.create_groups.into_iter()
+    }
+}
+
+impl create_groups::Group {
+    /// Compares two values by `order`, sorting values without one last.
+    pub fn by_order(a: &Self, b: &Self) -> std::cmp::Ordering {
+        match (a.order, b.order) {
+            (Some(a), Some(b)) => a.cmp(&b),
+            (Some(_), None) => std::cmp::Ordering::Less,
+            (None, Some(_)) => std::cmp::Ordering::Greater,
+            (None, None) => std::cmp::Ordering::Equal,
+        }
+    }
+}
+
+/// A field of the `Group` returned by [`CreateGroups`], for selecting with
+/// [`crate::BlipsClient::execute_partial`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CreateGroupsField {
+    Collapsed,
+    Date,
+    Id,
+    KeepTasks,
+    Name,
+    Order,
+    ProjectId,
+}
+
+impl CreateGroupsField {
+    /// Every field, for selecting the whole fragment.
+    pub const ALL: &'static [Self] = &[
+        Self::Collapsed,
+        Self::Date,
+        Self::Id,
+        Self::KeepTasks,
+        Self::Name,
+        Self::Order,
+        Self::ProjectId,
+    ];
+}
+
+/// The `Group` returned by [`CreateGroups`], with the fields selected by
+/// [`crate::BlipsClient::execute_partial`].
+///
+/// A field is `None` both when it wasn't selected and when the server returned `null` for it.
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+#[serde(default)]
+pub struct CreateGroupsPartial {
+    pub collapsed: Option<bool>,
+    pub date: Option<crate::graphql::custom_scalars::Date>,
+    pub id: Option<String>,
+    #[serde(rename = "keepTasks")]
+    pub keep_tasks: Option<bool>,
+    pub name: Option<String>,
+    pub order: Option<i64>,
+    #[serde(rename = "projectId")]
+    pub project_id: Option<String>,
+}
+
+impl crate::PartialQuery for CreateGroups {
+    type Field = CreateGroupsField;
+    type Partial = Vec<CreateGroupsPartial>;
+
+    const FRAGMENT_NAME: &'static str = "Group";
+    const ROOT_FIELD: &'static str = "createGroups";
+
+    fn field_name(field: Self::Field) -> &'static str {
+        match field {
+            CreateGroupsField::Collapsed => "collapsed",
+            CreateGroupsField::Date => "date",
+            CreateGroupsField::Id => "id",
+            CreateGroupsField::KeepTasks => "keepTasks",
+            CreateGroupsField::Name => "name",
+            CreateGroupsField::Order => "order",
+            CreateGroupsField::ProjectId => "projectId",
+        }
+    }
+}
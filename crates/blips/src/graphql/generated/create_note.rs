@@ -4,6 +4,7 @@ pub mod create_note {
     #![allow(dead_code)]
     use std::result::Result;
     pub const OPERATION_NAME: &str = "CreateNote";
+    pub const OPERATION_KIND: crate::OperationKind = crate::OperationKind::Mutation;
     pub const QUERY : & str = "mutation CreateNote($date: Date, $name: String, $project_id: ID) {\n    createNote(date: $date, name: $name, projectId: $project_id) {\n        ...Note\n    }\n}\n\nfragment Note on Note {\n    __typename\n    body\n    date\n    endDate\n    hidePreview\n    id\n    name\n    updatedAt\n}" ;
     use super::*;
     use serde::{Deserialize, Serialize};
@@ -17,14 +18,44 @@ pub mod create_note {
     type ID = String;
     type Date = crate::graphql::custom_scalars::Date;
     type DateTime = crate::graphql::custom_scalars::DateTime;
-    #[derive(Serialize)]
+    #[derive(Serialize, Clone, Default)]
     pub struct Variables {
         pub date: Option<Date>,
         pub name: Option<String>,
         pub project_id: Option<ID>,
     }
-    impl Variables {}
-    #[derive(Deserialize, Debug)]
+    impl Variables {
+        /// Returns a new [`Variables`] from the arguments of `createNote`.
+        ///
+        /// The parameters are in the order the arguments are declared in the schema.
+        pub fn new(date: Option<Date>, name: Option<String>, project_id: Option<ID>) -> Self {
+            Self {
+                date,
+                name,
+                project_id,
+            }
+        }
+
+        /// Returns the variables with `date` set to the provided value.
+        pub fn with_date(mut self, date: impl Into<Option<Date>>) -> Self {
+            self.date = date.into();
+            self
+        }
+
+        /// Returns the variables with `name` set to the provided value.
+        pub fn with_name(mut self, name: impl Into<Option<String>>) -> Self {
+            self.name = name.into();
+            self
+        }
+
+        /// Returns the variables with `projectId` set to the provided value.
+        pub fn with_project_id(mut self, project_id: impl Into<Option<ID>>) -> Self {
+            self.project_id = project_id.into();
+            self
+        }
+    }
+    #[derive(Deserialize, Debug, Serialize)]
+    #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
     pub struct Note {
         pub body: Option<String>,
         pub date: Option<Date>,
@@ -35,23 +66,101 @@ pub mod create_note {
         pub id: ID,
         pub name: String,
         #[serde(rename = "updatedAt")]
+        #[cfg_attr(feature = "ts-rs", ts(as = "f64"))]
         pub updated_at: DateTime,
     }
-    #[derive(Deserialize, Debug)]
+    #[derive(Deserialize, Debug, Serialize)]
+    #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
     pub struct ResponseData {
         #[serde(rename = "createNote")]
         pub create_note: CreateNoteCreateNote,
     }
     pub type CreateNoteCreateNote = Note;
 }
-impl graphql_client::GraphQLQuery for CreateNote {
+impl crate::runtime::GraphQLQuery for CreateNote {
     type Variables = create_note::Variables;
     type ResponseData = create_note::ResponseData;
-    fn build_query(variables: Self::Variables) -> ::graphql_client::QueryBody<Self::Variables> {
-        graphql_client::QueryBody {
+    fn build_query(variables: Self::Variables) -> crate::runtime::QueryBody<Self::Variables> {
+        crate::runtime::QueryBody {
             variables,
             query: create_note::QUERY,
             operation_name: create_note::OPERATION_NAME,
         }
     }
 }
+
+impl TryFrom<serde_json::Value> for create_note::ResponseData {
+    type Error = crate::BlipsError;
+
+    fn try_from(value: serde_json::Value) -> Result<Self, Self::Error> {
+        crate::data_from_value(create_note::OPERATION_NAME, value)
+    }
+}
+
+/// The type of the `createNote` field of the response.
+pub type CreateNotePayload = create_note::CreateNoteCreateNote;
+
+/// A field of the `Note` returned by [`CreateNote`], for selecting with
+/// [`crate::BlipsClient::execute_partial`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CreateNoteField {
+    Body,
+    Date,
+    EndDate,
+    HidePreview,
+    Id,
+    Name,
+    UpdatedAt,
+}
+
+impl CreateNoteField {
+    /// Every field, for selecting the whole fragment.
+    pub const ALL: &'static [Self] = &[
+        Self::Body,
+        Self::Date,
+        Self::EndDate,
+        Self::HidePreview,
+        Self::Id,
+        Self::Name,
+        Self::UpdatedAt,
+    ];
+}
+
+/// The `Note` returned by [`CreateNote`], with the fields selected by
+/// [`crate::BlipsClient::execute_partial`].
+///
+/// A field is `None` both when it wasn't selected and when the server returned `null` for it.
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+#[serde(default)]
+pub struct CreateNotePartial {
+    pub body: Option<String>,
+    pub date: Option<crate::graphql::custom_scalars::Date>,
+    #[serde(rename = "endDate")]
+    pub end_date: Option<crate::graphql::custom_scalars::Date>,
+    #[serde(rename = "hidePreview")]
+    pub hide_preview: Option<bool>,
+    pub id: Option<String>,
+    pub name: Option<String>,
+    #[serde(rename = "updatedAt")]
+    pub updated_at: Option<crate::graphql::custom_scalars::DateTime>,
+}
+
+impl crate::PartialQuery for CreateNote {
+    type Field = CreateNoteField;
+    type Partial = CreateNotePartial;
+
+    const FRAGMENT_NAME: &'static str = "Note";
+    const ROOT_FIELD: &'static str = "createNote";
+
+    fn field_name(field: Self::Field) -> &'static str {
+        match field {
+            CreateNoteField::Body => "body",
+            CreateNoteField::Date => "date",
+            CreateNoteField::EndDate => "endDate",
+            CreateNoteField::HidePreview => "hidePreview",
+            CreateNoteField::Id => "id",
+            CreateNoteField::Name => "name",
+            CreateNoteField::UpdatedAt => "updatedAt",
+        }
+    }
+}
@@ -4,6 +4,7 @@ pub mod create_project_column {
     #![allow(dead_code)]
     use std::result::Result;
     pub const OPERATION_NAME: &str = "CreateProjectColumn";
+    pub const OPERATION_KIND: crate::OperationKind = crate::OperationKind::Mutation;
     pub const QUERY : & str = "mutation CreateProjectColumn($board_id: ID!, $name: String!) {\n    createProjectColumn(boardId: $board_id, name: $name) {\n        ...ProjectColumn\n    }\n}\n\nfragment ProjectColumn on ProjectColumn {\n    __typename\n    collapsed\n    id\n    name\n    order\n}" ;
     use super::*;
     use serde::{Deserialize, Serialize};
@@ -15,34 +16,119 @@ pub mod create_project_column {
     type Int = i64;
     #[allow(dead_code)]
     type ID = String;
-    #[derive(Serialize)]
+    #[derive(Serialize, Clone)]
     pub struct Variables {
         pub board_id: ID,
         pub name: String,
     }
-    impl Variables {}
-    #[derive(Deserialize, Debug)]
+    impl Variables {
+        /// Returns a new [`Variables`] from the arguments of `createProjectColumn`.
+        ///
+        /// The parameters are in the order the arguments are declared in the schema.
+        pub fn new(board_id: ID, name: String) -> Self {
+            Self { board_id, name }
+        }
+
+        /// Returns the variables with `boardId` set to the provided value.
+        pub fn with_board_id(mut self, board_id: ID) -> Self {
+            self.board_id = board_id;
+            self
+        }
+
+        /// Returns the variables with `name` set to the provided value.
+        pub fn with_name(mut self, name: String) -> Self {
+            self.name = name;
+            self
+        }
+    }
+    #[derive(Deserialize, Debug, Serialize)]
+    #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
     pub struct ProjectColumn {
         pub collapsed: Boolean,
         pub id: ID,
         pub name: String,
+        #[cfg_attr(feature = "ts-rs", ts(as = "i32"))]
         pub order: Int,
     }
-    #[derive(Deserialize, Debug)]
+    #[derive(Deserialize, Debug, Serialize)]
+    #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
     pub struct ResponseData {
         #[serde(rename = "createProjectColumn")]
         pub create_project_column: CreateProjectColumnCreateProjectColumn,
     }
     pub type CreateProjectColumnCreateProjectColumn = ProjectColumn;
 }
-impl graphql_client::GraphQLQuery for CreateProjectColumn {
+impl crate::runtime::GraphQLQuery for CreateProjectColumn {
     type Variables = create_project_column::Variables;
     type ResponseData = create_project_column::ResponseData;
-    fn build_query(variables: Self::Variables) -> ::graphql_client::QueryBody<Self::Variables> {
-        graphql_client::QueryBody {
+    fn build_query(variables: Self::Variables) -> crate::runtime::QueryBody<Self::Variables> {
+        crate::runtime::QueryBody {
             variables,
             query: create_project_column::QUERY,
             operation_name: create_project_column::OPERATION_NAME,
         }
     }
 }
+
+impl TryFrom<serde_json::Value> for create_project_column::ResponseData {
+    type Error = crate::BlipsError;
+
+    fn try_from(value: serde_json::Value) -> Result<Self, Self::Error> {
+        crate::data_from_value(create_project_column::OPERATION_NAME, value)
+    }
+}
+
+/// The type of the `createProjectColumn` field of the response.
+pub type CreateProjectColumnPayload = create_project_column::CreateProjectColumnCreateProjectColumn;
+
+impl create_project_column::ProjectColumn {
+    /// Compares two values by `order`.
+    pub fn by_order(a: &Self, b: &Self) -> std::cmp::Ordering {
+        a.order.cmp(&b.order)
+    }
+}
+
+/// A field of the `ProjectColumn` returned by [`CreateProjectColumn`], for selecting with
+/// [`crate::BlipsClient::execute_partial`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CreateProjectColumnField {
+    Collapsed,
+    Id,
+    Name,
+    Order,
+}
+
+impl CreateProjectColumnField {
+    /// Every field, for selecting the whole fragment.
+    pub const ALL: &'static [Self] = &[Self::Collapsed, Self::Id, Self::Name, Self::Order];
+}
+
+/// The `ProjectColumn` returned by [`CreateProjectColumn`], with the fields selected by
+/// [`crate::BlipsClient::execute_partial`].
+///
+/// A field is `None` both when it wasn't selected and when the server returned `null` for it.
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+#[serde(default)]
+pub struct CreateProjectColumnPartial {
+    pub collapsed: Option<bool>,
+    pub id: Option<String>,
+    pub name: Option<String>,
+    pub order: Option<i64>,
+}
+
+impl crate::PartialQuery for CreateProjectColumn {
+    type Field = CreateProjectColumnField;
+    type Partial = CreateProjectColumnPartial;
+
+    const FRAGMENT_NAME: &'static str = "ProjectColumn";
+    const ROOT_FIELD: &'static str = "createProjectColumn";
+
+    fn field_name(field: Self::Field) -> &'static str {
+        match field {
+            CreateProjectColumnField::Collapsed => "collapsed",
+            CreateProjectColumnField::Id => "id",
+            CreateProjectColumnField::Name => "name",
+            CreateProjectColumnField::Order => "order",
+        }
+    }
+}
@@ -4,6 +4,7 @@ pub mod create_projects {
     #![allow(dead_code)]
     use std::result::Result;
     pub const OPERATION_NAME: &str = "CreateProjects";
+    pub const OPERATION_KIND: crate::OperationKind = crate::OperationKind::Mutation;
     pub const QUERY : & str = "mutation CreateProjects($board_id: ID, $date: Date, $names: [String!]!, $project_column_id: ID) {\n    createProjects(boardId: $board_id, date: $date, names: $names, projectColumnId: $project_column_id) {\n        ...Project\n    }\n}\n\nfragment Project on Project {\n    __typename\n    collapseCompleted\n    completed\n    completedAt\n    date\n    endDate\n    id\n    link\n    name\n    noteBody\n    order\n    springEnabled\n    supportsNotes\n}" ;
     use super::*;
     use serde::{Deserialize, Serialize};
@@ -17,20 +18,65 @@ pub mod create_projects {
     type ID = String;
     type Date = crate::graphql::custom_scalars::Date;
     type DateTime = crate::graphql::custom_scalars::DateTime;
-    #[derive(Serialize)]
+    #[derive(Serialize, Clone)]
     pub struct Variables {
         pub board_id: Option<ID>,
         pub date: Option<Date>,
         pub names: Vec<String>,
         pub project_column_id: Option<ID>,
     }
-    impl Variables {}
-    #[derive(Deserialize, Debug)]
+    impl Variables {
+        /// Returns a new [`Variables`] from the arguments of `createProjects`.
+        ///
+        /// The parameters are in the order the arguments are declared in the schema.
+        pub fn new(
+            board_id: Option<ID>,
+            date: Option<Date>,
+            names: Vec<String>,
+            project_column_id: Option<ID>,
+        ) -> Self {
+            Self {
+                board_id,
+                date,
+                names,
+                project_column_id,
+            }
+        }
+
+        /// Returns the variables with `boardId` set to the provided value.
+        pub fn with_board_id(mut self, board_id: impl Into<Option<ID>>) -> Self {
+            self.board_id = board_id.into();
+            self
+        }
+
+        /// Returns the variables with `date` set to the provided value.
+        pub fn with_date(mut self, date: impl Into<Option<Date>>) -> Self {
+            self.date = date.into();
+            self
+        }
+
+        /// Returns the variables with `names` set to the provided value.
+        ///
+        /// A single value is sent as a list with one element.
+        pub fn with_names(mut self, names: impl Into<crate::OneOrMany<String>>) -> Self {
+            self.names = names.into().into_vec();
+            self
+        }
+
+        /// Returns the variables with `projectColumnId` set to the provided value.
+        pub fn with_project_column_id(mut self, project_column_id: impl Into<Option<ID>>) -> Self {
+            self.project_column_id = project_column_id.into();
+            self
+        }
+    }
+    #[derive(Deserialize, Debug, Serialize)]
+    #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
     pub struct Project {
         #[serde(rename = "collapseCompleted")]
         pub collapse_completed: Boolean,
         pub completed: Boolean,
         #[serde(rename = "completedAt")]
+        #[cfg_attr(feature = "ts-rs", ts(as = "Option<f64>"))]
         pub completed_at: Option<DateTime>,
         pub date: Option<Date>,
         #[serde(rename = "endDate")]
@@ -40,27 +86,256 @@ pub mod create_projects {
         pub name: String,
         #[serde(rename = "noteBody")]
         pub note_body: Option<String>,
+        #[cfg_attr(feature = "ts-rs", ts(as = "Option<i32>"))]
         pub order: Option<Int>,
         #[serde(rename = "springEnabled")]
         pub spring_enabled: Boolean,
         #[serde(rename = "supportsNotes")]
         pub supports_notes: Boolean,
     }
-    #[derive(Deserialize, Debug)]
+    #[derive(Deserialize, Debug, Serialize)]
+    #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
     pub struct ResponseData {
         #[serde(rename = "createProjects")]
         pub create_projects: Vec<CreateProjectsCreateProjects>,
     }
     pub type CreateProjectsCreateProjects = Project;
 }
-impl graphql_client::GraphQLQuery for CreateProjects {
+impl crate::runtime::GraphQLQuery for CreateProjects {
     type Variables = create_projects::Variables;
     type ResponseData = create_projects::ResponseData;
-    fn build_query(variables: Self::Variables) -> ::graphql_client::QueryBody<Self::Variables> {
-        graphql_client::QueryBody {
+    fn build_query(variables: Self::Variables) -> crate::runtime::QueryBody<Self::Variables> {
+        crate::runtime::QueryBody {
             variables,
             query: create_projects::QUERY,
             operation_name: create_projects::OPERATION_NAME,
         }
     }
 }
+
+impl TryFrom<serde_json::Value> for create_projects::ResponseData {
+    type Error = crate::BlipsError;
+
+    fn try_from(value: serde_json::Value) -> Result<Self, Self::Error> {
+        crate::data_from_value(create_projects::OPERATION_NAME, value)
+    }
+}
+
+/// The type of the items in the `createProjects` list of the response.
+pub type CreateProjectsPayload = create_projects::CreateProjectsCreateProjects;
+
+impl create_projects::ResponseData {
+    /// Returns the items in the `createProjects` list.
+    pub fn items(&self) -> &[create_projects::CreateProjectsCreateProjects] {
+        &self.create_projects
+    }
+}
+
+impl IntoIterator for create_projects::ResponseData {
+    type Item = create_projects::CreateProjectsCreateProjects;
+    type IntoIter = std::vec::IntoIter<create_projects::CreateProjectsCreateProjects>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.create_projects.into_iter()
+    }
+}
+
+impl create_projects::Project {
+    /// Compares two values by `order`, sorting values without one last.
+    pub fn by_order(a: &Self, b: &Self) -> std::cmp::Ordering {
+        match (a.order, b.order) {
+            (Some(a), Some(b)) => a.cmp(&b),
+            (Some(_), None) => std::cmp::Ordering::Less,
+            (None, Some(_)) => std::cmp::Ordering::Greater,
+            (None, None) => std::cmp::Ordering::Equal,
+        }
+    }
+}
+
+impl create_projects::Project {
+    /// Returns the state of the project, derived from its flags.
+    pub fn state(&self) -> crate::ProjectState {
+        crate::ProjectState::from_flags(self.completed, self.spring_enabled)
+    }
+}
+
+impl create_projects::Project {
+    /// Returns whether the project is completed.
+    ///
+    /// `completed` and `completedAt` can disagree due to races or server bugs. When they
+    /// do, `completed` is trusted and `completedAt` is ignored. Use
+    /// [`Self::validate_consistency`] to detect the disagreement.
+    pub fn is_effectively_completed(&self) -> bool {
+        self.completed
+    }
+
+    /// Returns warnings for the fields of the project that contradict each other.
+    pub fn validate_consistency(&self) -> Vec<crate::ConsistencyWarning> {
+        crate::ConsistencyWarning::for_completion(self.completed, self.completed_at.is_some())
+            .into_iter()
+            .collect()
+    }
+}
+
+impl create_projects::Project {
+    /// Returns the fields that differ between this project and `other`, in the order
+    /// they are selected.
+    pub fn diff(&self, other: &Self) -> Vec<crate::FieldChange> {
+        let mut changes = Vec::new();
+
+        if self.collapse_completed != other.collapse_completed {
+            changes.push(crate::FieldChange::new(
+                "collapseCompleted",
+                &self.collapse_completed,
+                &other.collapse_completed,
+            ));
+        }
+        if self.completed != other.completed {
+            changes.push(crate::FieldChange::new(
+                "completed",
+                &self.completed,
+                &other.completed,
+            ));
+        }
+        if self.completed_at != other.completed_at {
+            changes.push(crate::FieldChange::new(
+                "completedAt",
+                &self.completed_at,
+                &other.completed_at,
+            ));
+        }
+        if self.date != other.date {
+            changes.push(crate::FieldChange::new("date", &self.date, &other.date));
+        }
+        if self.end_date != other.end_date {
+            changes.push(crate::FieldChange::new(
+                "endDate",
+                &self.end_date,
+                &other.end_date,
+            ));
+        }
+        if self.id != other.id {
+            changes.push(crate::FieldChange::new("id", &self.id, &other.id));
+        }
+        if self.link != other.link {
+            changes.push(crate::FieldChange::new("link", &self.link, &other.link));
+        }
+        if self.name != other.name {
+            changes.push(crate::FieldChange::new("name", &self.name, &other.name));
+        }
+        if self.note_body != other.note_body {
+            changes.push(crate::FieldChange::new(
+                "noteBody",
+                &self.note_body,
+                &other.note_body,
+            ));
+        }
+        if self.order != other.order {
+            changes.push(crate::FieldChange::new("order", &self.order, &other.order));
+        }
+        if self.spring_enabled != other.spring_enabled {
+            changes.push(crate::FieldChange::new(
+                "springEnabled",
+                &self.spring_enabled,
+                &other.spring_enabled,
+            ));
+        }
+        if self.supports_notes != other.supports_notes {
+            changes.push(crate::FieldChange::new(
+                "supportsNotes",
+                &self.supports_notes,
+                &other.supports_notes,
+            ));
+        }
+
+        changes
+    }
+}
+
+/// A field of the `Project` returned by [`CreateProjects`], for selecting with
+/// [`crate::BlipsClient::execute_partial`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CreateProjectsField {
+    CollapseCompleted,
+    Completed,
+    CompletedAt,
+    Date,
+    EndDate,
+    Id,
+    Link,
+    Name,
+    NoteBody,
+    Order,
+    SpringEnabled,
+    SupportsNotes,
+}
+
+impl CreateProjectsField {
+    /// Every field, for selecting the whole fragment.
+    pub const ALL: &'static [Self] = &[
+        Self::CollapseCompleted,
+        Self::Completed,
+        Self::CompletedAt,
+        Self::Date,
+        Self::EndDate,
+        Self::Id,
+        Self::Link,
+        Self::Name,
+        Self::NoteBody,
+        Self::Order,
+        Self::SpringEnabled,
+        Self::SupportsNotes,
+    ];
+}
+
+/// The `Project` returned by [`CreateProjects`], with the fields selected by
+/// [`crate::BlipsClient::execute_partial`].
+///
+/// A field is `None` both when it wasn't selected and when the server returned `null` for it.
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+#[serde(default)]
+pub struct CreateProjectsPartial {
+    #[serde(rename = "collapseCompleted")]
+    pub collapse_completed: Option<bool>,
+    pub completed: Option<bool>,
+    #[serde(rename = "completedAt")]
+    pub completed_at: Option<crate::graphql::custom_scalars::DateTime>,
+    pub date: Option<crate::graphql::custom_scalars::Date>,
+    #[serde(rename = "endDate")]
+    pub end_date: Option<crate::graphql::custom_scalars::Date>,
+    pub id: Option<String>,
+    pub link: Option<String>,
+    pub name: Option<String>,
+    #[serde(rename = "noteBody")]
+    pub note_body: Option<String>,
+    pub order: Option<i64>,
+    #[serde(rename = "springEnabled")]
+    pub spring_enabled: Option<bool>,
+    #[serde(rename = "supportsNotes")]
+    pub supports_notes: Option<bool>,
+}
+
+impl crate::PartialQuery for CreateProjects {
+    type Field = CreateProjectsField;
+    type Partial = Vec<CreateProjectsPartial>;
+
+    const FRAGMENT_NAME: &'static str = "Project";
+    const ROOT_FIELD: &'static str = "createProjects";
+
+    fn field_name(field: Self::Field) -> &'static str {
+        match field {
+            CreateProjectsField::CollapseCompleted => "collapseCompleted",
+            CreateProjectsField::Completed => "completed",
+            CreateProjectsField::CompletedAt => "completedAt",
+            CreateProjectsField::Date => "date",
+            CreateProjectsField::EndDate => "endDate",
+            CreateProjectsField::Id => "id",
+            CreateProjectsField::Link => "link",
+            CreateProjectsField::Name => "name",
+            CreateProjectsField::NoteBody => "noteBody",
+            CreateProjectsField::Order => "order",
+            CreateProjectsField::SpringEnabled => "springEnabled",
+            CreateProjectsField::SupportsNotes => "supportsNotes",
+        }
+    }
+}
@@ -4,6 +4,7 @@ pub mod create_tasks {
     #![allow(dead_code)]
     use std::result::Result;
     pub const OPERATION_NAME: &str = "CreateTasks";
+    pub const OPERATION_KIND: crate::OperationKind = crate::OperationKind::Mutation;
     pub const QUERY : & str = "mutation CreateTasks($after: ID, $date: Date, $group_id: ID, $link: String, $due_date: Date, $names: [String!]!, $prioritized: Boolean, $project_id: ID, $tag_slug: String) {\n    createTasks(after: $after, date: $date, groupId: $group_id, link: $link, dueDate: $due_date, names: $names, prioritized: $prioritized, projectId: $project_id, tagSlug: $tag_slug) {\n        ...Task\n    }\n}\n\nfragment Task on Task {\n    __typename\n    completed\n    completedAt\n    date\n    description\n    dueDate\n    groupIds\n    id\n    isRecurring\n    link\n    name\n    priorityOrder\n    spring\n}" ;
     use super::*;
     use serde::{Deserialize, Serialize};
@@ -17,7 +18,7 @@ pub mod create_tasks {
     type ID = String;
     type Date = crate::graphql::custom_scalars::Date;
     type DateTime = crate::graphql::custom_scalars::DateTime;
-    #[derive(Serialize)]
+    #[derive(Serialize, Clone)]
     pub struct Variables {
         pub after: Option<ID>,
         pub date: Option<Date>,
@@ -29,11 +30,96 @@ pub mod create_tasks {
         pub project_id: Option<ID>,
         pub tag_slug: Option<String>,
     }
-    impl Variables {}
-    #[derive(Deserialize, Debug)]
+    impl Variables {
+        /// Returns a new [`Variables`] from the arguments of `createTasks`.
+        ///
+        /// The parameters are in the order the arguments are declared in the schema.
+        pub fn new(
+            after: Option<ID>,
+            date: Option<Date>,
+            group_id: Option<ID>,
+            link: Option<String>,
+            due_date: Option<Date>,
+            names: Vec<String>,
+            prioritized: Option<Boolean>,
+            project_id: Option<ID>,
+            tag_slug: Option<String>,
+        ) -> Self {
+            Self {
+                after,
+                date,
+                group_id,
+                link,
+                due_date,
+                names,
+                prioritized,
+                project_id,
+                tag_slug,
+            }
+        }
+
+        /// Returns the variables with `after` set to the provided value.
+        pub fn with_after(mut self, after: impl Into<Option<ID>>) -> Self {
+            self.after = after.into();
+            self
+        }
+
+        /// Returns the variables with `date` set to the provided value.
+        pub fn with_date(mut self, date: impl Into<Option<Date>>) -> Self {
+            self.date = date.into();
+            self
+        }
+
+        /// Returns the variables with `groupId` set to the provided value.
+        pub fn with_group_id(mut self, group_id: impl Into<Option<ID>>) -> Self {
+            self.group_id = group_id.into();
+            self
+        }
+
+        /// Returns the variables with `link` set to the provided value.
+        pub fn with_link(mut self, link: impl Into<Option<String>>) -> Self {
+            self.link = link.into();
+            self
+        }
+
+        /// Returns the variables with `dueDate` set to the provided value.
+        pub fn with_due_date(mut self, due_date: impl Into<Option<Date>>) -> Self {
+            self.due_date = due_date.into();
+            self
+        }
+
+        /// Returns the variables with `names` set to the provided value.
+        ///
+        /// A single value is sent as a list with one element.
+        pub fn with_names(mut self, names: impl Into<crate::OneOrMany<String>>) -> Self {
+            self.names = names.into().into_vec();
+            self
+        }
+
+        /// Returns the variables with `prioritized` set to the provided value.
+        pub fn with_prioritized(mut self, prioritized: impl Into<Option<Boolean>>) -> Self {
+            self.prioritized = prioritized.into();
+            self
+        }
+
+        /// Returns the variables with `projectId` set to the provided value.
+        pub fn with_project_id(mut self, project_id: impl Into<Option<ID>>) -> Self {
+            self.project_id = project_id.into();
+            self
+        }
+
+        /// Returns the variables with `tagSlug` set to the provided value.
+        pub fn with_tag_slug(mut self, tag_slug: impl Into<Option<String>>) -> Self {
+            self.tag_slug = tag_slug.into();
+            self
+        }
+    }
+    #[derive(Deserialize, Debug, Serialize)]
+    #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
     pub struct Task {
         pub completed: Boolean,
         #[serde(rename = "completedAt")]
+        #[cfg_attr(feature = "ts-rs", ts(as = "Option<f64>"))]
         pub completed_at: Option<DateTime>,
         pub date: Option<Date>,
         pub description: Option<String>,
@@ -47,24 +133,256 @@ pub mod create_tasks {
         pub link: Option<String>,
         pub name: String,
         #[serde(rename = "priorityOrder")]
+        #[cfg_attr(feature = "ts-rs", ts(as = "Option<i32>"))]
         pub priority_order: Option<Int>,
         pub spring: Boolean,
     }
-    #[derive(Deserialize, Debug)]
+    #[derive(Deserialize, Debug, Serialize)]
+    #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
     pub struct ResponseData {
         #[serde(rename = "createTasks")]
         pub create_tasks: Vec<CreateTasksCreateTasks>,
     }
     pub type CreateTasksCreateTasks = Task;
 }
-impl graphql_client::GraphQLQuery for CreateTasks {
+impl crate::runtime::GraphQLQuery for CreateTasks {
     type Variables = create_tasks::Variables;
     type ResponseData = create_tasks::ResponseData;
-    fn build_query(variables: Self::Variables) -> ::graphql_client::QueryBody<Self::Variables> {
-        graphql_client::QueryBody {
+    fn build_query(variables: Self::Variables) -> crate::runtime::QueryBody<Self::Variables> {
+        crate::runtime::QueryBody {
             variables,
             query: create_tasks::QUERY,
             operation_name: create_tasks::OPERATION_NAME,
         }
     }
 }
+
+impl TryFrom<serde_json::Value> for create_tasks::ResponseData {
+    type Error = crate::BlipsError;
+
+    fn try_from(value: serde_json::Value) -> Result<Self, Self::Error> {
+        crate::data_from_value(create_tasks::OPERATION_NAME, value)
+    }
+}
+
+/// The type of the items in the `createTasks` list of the response.
+pub type CreateTasksPayload = create_tasks::CreateTasksCreateTasks;
+
+impl create_tasks::ResponseData {
+    /// Returns the items in the `createTasks` list.
+    pub fn items(&self) -> &[create_tasks::CreateTasksCreateTasks] {
+        &self.create_tasks
+    }
+}
+
+impl IntoIterator for create_tasks::ResponseData {
+    type Item = create_tasks::CreateTasksCreateTasks;
+    type IntoIter = std::vec::IntoIter<create_tasks::CreateTasksCreateTasks>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.create_tasks.into_iter()
+    }
+}
+
+impl create_tasks::Task {
+    /// Compares two values by `priorityOrder`, sorting values without one last.
+    pub fn by_priority_order(a: &Self, b: &Self) -> std::cmp::Ordering {
+        match (a.priority_order, b.priority_order) {
+            (Some(a), Some(b)) => a.cmp(&b),
+            (Some(_), None) => std::cmp::Ordering::Less,
+            (None, Some(_)) => std::cmp::Ordering::Greater,
+            (None, None) => std::cmp::Ordering::Equal,
+        }
+    }
+}
+
+impl create_tasks::Task {
+    /// Returns the state of the task, derived from its flags.
+    pub fn state(&self) -> crate::TaskState {
+        crate::TaskState::from_flags(self.completed, self.is_recurring, self.spring)
+    }
+}
+
+impl create_tasks::Task {
+    /// Returns whether the task is completed.
+    ///
+    /// `completed` and `completedAt` can disagree due to races or server bugs. When they
+    /// do, `completed` is trusted and `completedAt` is ignored. Use
+    /// [`Self::validate_consistency`] to detect the disagreement.
+    pub fn is_effectively_completed(&self) -> bool {
+        self.completed
+    }
+
+    /// Returns warnings for the fields of the task that contradict each other.
+    pub fn validate_consistency(&self) -> Vec<crate::ConsistencyWarning> {
+        crate::ConsistencyWarning::for_completion(self.completed, self.completed_at.is_some())
+            .into_iter()
+            .collect()
+    }
+}
+
+impl create_tasks::Task {
+    /// Returns the fields that differ between this task and `other`, in the order
+    /// they are selected.
+    pub fn diff(&self, other: &Self) -> Vec<crate::FieldChange> {
+        let mut changes = Vec::new();
+
+        if self.completed != other.completed {
+            changes.push(crate::FieldChange::new(
+                "completed",
+                &self.completed,
+                &other.completed,
+            ));
+        }
+        if self.completed_at != other.completed_at {
+            changes.push(crate::FieldChange::new(
+                "completedAt",
+                &self.completed_at,
+                &other.completed_at,
+            ));
+        }
+        if self.date != other.date {
+            changes.push(crate::FieldChange::new("date", &self.date, &other.date));
+        }
+        if self.description != other.description {
+            changes.push(crate::FieldChange::new(
+                "description",
+                &self.description,
+                &other.description,
+            ));
+        }
+        if self.due_date != other.due_date {
+            changes.push(crate::FieldChange::new(
+                "dueDate",
+                &self.due_date,
+                &other.due_date,
+            ));
+        }
+        if self.group_ids != other.group_ids {
+            changes.push(crate::FieldChange::new(
+                "groupIds",
+                &self.group_ids,
+                &other.group_ids,
+            ));
+        }
+        if self.id != other.id {
+            changes.push(crate::FieldChange::new("id", &self.id, &other.id));
+        }
+        if self.is_recurring != other.is_recurring {
+            changes.push(crate::FieldChange::new(
+                "isRecurring",
+                &self.is_recurring,
+                &other.is_recurring,
+            ));
+        }
+        if self.link != other.link {
+            changes.push(crate::FieldChange::new("link", &self.link, &other.link));
+        }
+        if self.name != other.name {
+            changes.push(crate::FieldChange::new("name", &self.name, &other.name));
+        }
+        if self.priority_order != other.priority_order {
+            changes.push(crate::FieldChange::new(
+                "priorityOrder",
+                &self.priority_order,
+                &other.priority_order,
+            ));
+        }
+        if self.spring != other.spring {
+            changes.push(crate::FieldChange::new(
+                "spring",
+                &self.spring,
+                &other.spring,
+            ));
+        }
+
+        changes
+    }
+}
+
+/// A field of the `Task` returned by [`CreateTasks`], for selecting with
+/// [`crate::BlipsClient::execute_partial`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CreateTasksField {
+    Completed,
+    CompletedAt,
+    Date,
+    Description,
+    DueDate,
+    GroupIds,
+    Id,
+    IsRecurring,
+    Link,
+    Name,
+    PriorityOrder,
+    Spring,
+}
+
+impl CreateTasksField {
+    /// Every field, for selecting the whole fragment.
+    pub const ALL: &'static [Self] = &[
+        Self::Completed,
+        Self::CompletedAt,
+        Self::Date,
+        Self::Description,
+        Self::DueDate,
+        Self::GroupIds,
+        Self::Id,
+        Self::IsRecurring,
+        Self::Link,
+        Self::Name,
+        Self::PriorityOrder,
+        Self::Spring,
+    ];
+}
+
+/// The `Task` returned by [`CreateTasks`], with the fields selected by
+/// [`crate::BlipsClient::execute_partial`].
+///
+/// A field is `None` both when it wasn't selected and when the server returned `null` for it.
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+#[serde(default)]
+pub struct CreateTasksPartial {
+    pub completed: Option<bool>,
+    #[serde(rename = "completedAt")]
+    pub completed_at: Option<crate::graphql::custom_scalars::DateTime>,
+    pub date: Option<crate::graphql::custom_scalars::Date>,
+    pub description: Option<String>,
+    #[serde(rename = "dueDate")]
+    pub due_date: Option<crate::graphql::custom_scalars::Date>,
+    #[serde(rename = "groupIds")]
+    pub group_ids: Option<Vec<String>>,
+    pub id: Option<String>,
+    #[serde(rename = "isRecurring")]
+    pub is_recurring: Option<bool>,
+    pub link: Option<String>,
+    pub name: Option<String>,
+    #[serde(rename = "priorityOrder")]
+    pub priority_order: Option<i64>,
+    pub spring: Option<bool>,
+}
+
+impl crate::PartialQuery for CreateTasks {
+    type Field = CreateTasksField;
+    type Partial = Vec<CreateTasksPartial>;
+
+    const FRAGMENT_NAME: &'static str = "Task";
+    const ROOT_FIELD: &'static str = "createTasks";
+
+    fn field_name(field: Self::Field) -> &'static str {
+        match field {
+            CreateTasksField::Completed => "completed",
+            CreateTasksField::CompletedAt => "completedAt",
+            CreateTasksField::Date => "date",
+            CreateTasksField::Description => "description",
+            CreateTasksField::DueDate => "dueDate",
+            CreateTasksField::GroupIds => "groupIds",
+            CreateTasksField::Id => "id",
+            CreateTasksField::IsRecurring => "isRecurring",
+            CreateTasksField::Link => "link",
+            CreateTasksField::Name => "name",
+            CreateTasksField::PriorityOrder => "priorityOrder",
+            CreateTasksField::Spring => "spring",
+        }
+    }
+}
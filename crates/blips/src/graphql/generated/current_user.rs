@@ -4,6 +4,7 @@ pub mod current_user {
     #![allow(dead_code)]
     use std::result::Result;
     pub const OPERATION_NAME: &str = "CurrentUser";
+    pub const OPERATION_KIND: crate::OperationKind = crate::OperationKind::Query;
     pub const QUERY : & str = "query CurrentUser {\n    currentUser {\n        ...User\n    }\n}\n\nfragment User on User {\n    __typename\n    email\n    id\n    isMfaEnabled\n}" ;
     use super::*;
     use serde::{Deserialize, Serialize};
@@ -15,30 +16,86 @@ pub mod current_user {
     type Int = i64;
     #[allow(dead_code)]
     type ID = String;
-    #[derive(Serialize)]
+    #[derive(Serialize, Clone, Default)]
     pub struct Variables;
-    #[derive(Deserialize, Debug)]
+    #[derive(Deserialize, Debug, Serialize)]
+    #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
     pub struct User {
         pub email: String,
         pub id: ID,
         #[serde(rename = "isMfaEnabled")]
         pub is_mfa_enabled: Boolean,
     }
-    #[derive(Deserialize, Debug)]
+    #[derive(Deserialize, Debug, Serialize)]
+    #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
     pub struct ResponseData {
         #[serde(rename = "currentUser")]
         pub current_user: CurrentUserCurrentUser,
     }
     pub type CurrentUserCurrentUser = User;
 }
-impl graphql_client::GraphQLQuery for CurrentUser {
+impl crate::runtime::GraphQLQuery for CurrentUser {
     type Variables = current_user::Variables;
     type ResponseData = current_user::ResponseData;
-    fn build_query(variables: Self::Variables) -> ::graphql_client::QueryBody<Self::Variables> {
-        graphql_client::QueryBody {
+    fn build_query(variables: Self::Variables) -> crate::runtime::QueryBody<Self::Variables> {
+        crate::runtime::QueryBody {
             variables,
             query: current_user::QUERY,
             operation_name: current_user::OPERATION_NAME,
         }
     }
 }
+
+impl TryFrom<serde_json::Value> for current_user::ResponseData {
+    type Error = crate::BlipsError;
+
+    fn try_from(value: serde_json::Value) -> Result<Self, Self::Error> {
+        crate::data_from_value(current_user::OPERATION_NAME, value)
+    }
+}
+
+/// The type of the `currentUser` field of the response.
+pub type CurrentUserPayload = current_user::CurrentUserCurrentUser;
+
+/// A field of the `User` returned by [`CurrentUser`], for selecting with
+/// [`crate::BlipsClient::execute_partial`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CurrentUserField {
+    Email,
+    Id,
+    IsMfaEnabled,
+}
+
+impl CurrentUserField {
+    /// Every field, for selecting the whole fragment.
+    pub const ALL: &'static [Self] = &[Self::Email, Self::Id, Self::IsMfaEnabled];
+}
+
+/// The `User` returned by [`CurrentUser`], with the fields selected by
+/// [`crate::BlipsClient::execute_partial`].
+///
+/// A field is `None` both when it wasn't selected and when the server returned `null` for it.
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+#[serde(default)]
+pub struct CurrentUserPartial {
+    pub email: Option<String>,
+    pub id: Option<String>,
+    #[serde(rename = "isMfaEnabled")]
+    pub is_mfa_enabled: Option<bool>,
+}
+
+impl crate::PartialQuery for CurrentUser {
+    type Field = CurrentUserField;
+    type Partial = CurrentUserPartial;
+
+    const FRAGMENT_NAME: &'static str = "User";
+    const ROOT_FIELD: &'static str = "currentUser";
+
+    fn field_name(field: Self::Field) -> &'static str {
+        match field {
+            CurrentUserField::Email => "email",
+            CurrentUserField::Id => "id",
+            CurrentUserField::IsMfaEnabled => "isMfaEnabled",
+        }
+    }
+}
@@ -4,6 +4,7 @@ pub mod delete_board {
     #![allow(dead_code)]
     use std::result::Result;
     pub const OPERATION_NAME: &str = "DeleteBoard";
+    pub const OPERATION_KIND: crate::OperationKind = crate::OperationKind::Mutation;
     pub const QUERY : & str = "mutation DeleteBoard($board_id: ID!) {\n    deleteBoard(boardId: $board_id) {\n        ...Board\n    }\n}\n\nfragment Board on Board {\n    __typename\n    archivedAt\n    emoji\n    id\n    lastViewedAt\n    name\n    projectCompletedProjectColumnId\n    taskCompletedProjectColumnId\n}" ;
     use super::*;
     use serde::{Deserialize, Serialize};
@@ -16,18 +17,34 @@ pub mod delete_board {
     #[allow(dead_code)]
     type ID = String;
     type DateTime = crate::graphql::custom_scalars::DateTime;
-    #[derive(Serialize)]
+    #[derive(Serialize, Clone)]
     pub struct Variables {
         pub board_id: ID,
     }
-    impl Variables {}
-    #[derive(Deserialize, Debug)]
+    impl Variables {
+        /// Returns a new [`Variables`] from the arguments of `deleteBoard`.
+        ///
+        /// The parameters are in the order the arguments are declared in the schema.
+        pub fn new(board_id: ID) -> Self {
+            Self { board_id }
+        }
+
+        /// Returns the variables with `boardId` set to the provided value.
+        pub fn with_board_id(mut self, board_id: ID) -> Self {
+            self.board_id = board_id;
+            self
+        }
+    }
+    #[derive(Deserialize, Debug, Serialize)]
+    #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
     pub struct Board {
         #[serde(rename = "archivedAt")]
+        #[cfg_attr(feature = "ts-rs", ts(as = "Option<f64>"))]
         pub archived_at: Option<DateTime>,
         pub emoji: Option<String>,
         pub id: ID,
         #[serde(rename = "lastViewedAt")]
+        #[cfg_attr(feature = "ts-rs", ts(as = "Option<f64>"))]
         pub last_viewed_at: Option<DateTime>,
         pub name: String,
         #[serde(rename = "projectCompletedProjectColumnId")]
@@ -35,21 +52,99 @@ pub mod delete_board {
         #[serde(rename = "taskCompletedProjectColumnId")]
         pub task_completed_project_column_id: Option<ID>,
     }
-    #[derive(Deserialize, Debug)]
+    #[derive(Deserialize, Debug, Serialize)]
+    #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
     pub struct ResponseData {
         #[serde(rename = "deleteBoard")]
         pub delete_board: DeleteBoardDeleteBoard,
     }
     pub type DeleteBoardDeleteBoard = Board;
 }
-impl graphql_client::GraphQLQuery for DeleteBoard {
+impl crate::runtime::GraphQLQuery for DeleteBoard {
     type Variables = delete_board::Variables;
     type ResponseData = delete_board::ResponseData;
-    fn build_query(variables: Self::Variables) -> ::graphql_client::QueryBody<Self::Variables> {
-        graphql_client::QueryBody {
+    fn build_query(variables: Self::Variables) -> crate::runtime::QueryBody<Self::Variables> {
+        crate::runtime::QueryBody {
             variables,
             query: delete_board::QUERY,
             operation_name: delete_board::OPERATION_NAME,
         }
     }
 }
+
+impl TryFrom<serde_json::Value> for delete_board::ResponseData {
+    type Error = crate::BlipsError;
+
+    fn try_from(value: serde_json::Value) -> Result<Self, Self::Error> {
+        crate::data_from_value(delete_board::OPERATION_NAME, value)
+    }
+}
+
+/// The type of the `deleteBoard` field of the response.
+pub type DeleteBoardPayload = delete_board::DeleteBoardDeleteBoard;
+
+/// A field of the `Board` returned by [`DeleteBoard`], for selecting with
+/// [`crate::BlipsClient::execute_partial`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DeleteBoardField {
+    ArchivedAt,
+    Emoji,
+    Id,
+    LastViewedAt,
+    Name,
+    ProjectCompletedProjectColumnId,
+    TaskCompletedProjectColumnId,
+}
+
+impl DeleteBoardField {
+    /// Every field, for selecting the whole fragment.
+    pub const ALL: &'static [Self] = &[
+        Self::ArchivedAt,
+        Self::Emoji,
+        Self::Id,
+        Self::LastViewedAt,
+        Self::Name,
+        Self::ProjectCompletedProjectColumnId,
+        Self::TaskCompletedProjectColumnId,
+    ];
+}
+
+/// The `Board` returned by [`DeleteBoard`], with the fields selected by
+/// [`crate::BlipsClient::execute_partial`].
+///
+/// A field is `None` both when it wasn't selected and when the server returned `null` for it.
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+#[serde(default)]
+pub struct DeleteBoardPartial {
+    #[serde(rename = "archivedAt")]
+    pub archived_at: Option<crate::graphql::custom_scalars::DateTime>,
+    pub emoji: Option<String>,
+    pub id: Option<String>,
+    #[serde(rename = "lastViewedAt")]
+    pub last_viewed_at: Option<crate::graphql::custom_scalars::DateTime>,
+    pub name: Option<String>,
+    #[serde(rename = "projectCompletedProjectColumnId")]
+    pub project_completed_project_column_id: Option<String>,
+    #[serde(rename = "taskCompletedProjectColumnId")]
+    pub task_completed_project_column_id: Option<String>,
+}
+
+impl crate::PartialQuery for DeleteBoard {
+    type Field = DeleteBoardField;
+    type Partial = DeleteBoardPartial;
+
+    const FRAGMENT_NAME: &'static str = "Board";
+    const ROOT_FIELD: &'static str = "deleteBoard";
+
+    fn field_name(field: Self::Field) -> &'static str {
+        match field {
+            DeleteBoardField::ArchivedAt => "archivedAt",
+            DeleteBoardField::Emoji => "emoji",
+            DeleteBoardField::Id => "id",
+            DeleteBoardField::LastViewedAt => "lastViewedAt",
+            DeleteBoardField::Name => "name",
+            DeleteBoardField::ProjectCompletedProjectColumnId => "projectCompletedProjectColumnId",
+            DeleteBoardField::TaskCompletedProjectColumnId => "taskCompletedProjectColumnId",
+        }
+    }
+}
@@ -4,6 +4,7 @@ pub mod delete_group {
     #![allow(dead_code)]
     use std::result::Result;
     pub const OPERATION_NAME: &str = "DeleteGroup";
+    pub const OPERATION_KIND: crate::OperationKind = crate::OperationKind::Mutation;
     pub const QUERY : & str = "mutation DeleteGroup($delete_tasks: Boolean, $group_id: ID!) {\n    deleteGroup(deleteTasks: $delete_tasks, groupId: $group_id) {\n        ...Group\n    }\n}\n\nfragment Group on Group {\n    __typename\n    collapsed\n    date\n    id\n    keepTasks\n    name\n    order\n    projectId\n}" ;
     use super::*;
     use serde::{Deserialize, Serialize};
@@ -16,13 +17,36 @@ pub mod delete_group {
     #[allow(dead_code)]
     type ID = String;
     type Date = crate::graphql::custom_scalars::Date;
-    #[derive(Serialize)]
+    #[derive(Serialize, Clone)]
     pub struct Variables {
         pub delete_tasks: Option<Boolean>,
         pub group_id: ID,
     }
-    impl Variables {}
-    #[derive(Deserialize, Debug)]
+    impl Variables {
+        /// Returns a new [`Variables`] from the arguments of `deleteGroup`.
+        ///
+        /// The parameters are in the order the arguments are declared in the schema.
+        pub fn new(delete_tasks: Option<Boolean>, group_id: ID) -> Self {
+            Self {
+                delete_tasks,
+                group_id,
+            }
+        }
+
+        /// Returns the variables with `deleteTasks` set to the provided value.
+        pub fn with_delete_tasks(mut self, delete_tasks: impl Into<Option<Boolean>>) -> Self {
+            self.delete_tasks = delete_tasks.into();
+            self
+        }
+
+        /// Returns the variables with `groupId` set to the provided value.
+        pub fn with_group_id(mut self, group_id: ID) -> Self {
+            self.group_id = group_id;
+            self
+        }
+    }
+    #[derive(Deserialize, Debug, Serialize)]
+    #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
     pub struct Group {
         pub collapsed: Option<Boolean>,
         pub date: Option<Date>,
@@ -30,25 +54,114 @@ pub mod delete_group {
         #[serde(rename = "keepTasks")]
         pub keep_tasks: Boolean,
         pub name: String,
+        #[cfg_attr(feature = "ts-rs", ts(as = "Option<i32>"))]
         pub order: Option<Int>,
         #[serde(rename = "projectId")]
         pub project_id: Option<ID>,
     }
-    #[derive(Deserialize, Debug)]
+    #[derive(Deserialize, Debug, Serialize)]
+    #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
     pub struct ResponseData {
         #[serde(rename = "deleteGroup")]
         pub delete_group: DeleteGroupDeleteGroup,
     }
     pub type DeleteGroupDeleteGroup = Group;
 }
-impl graphql_client::GraphQLQuery for DeleteGroup {
+impl crate::runtime::GraphQLQuery for DeleteGroup {
     type Variables = delete_group::Variables;
     type ResponseData = delete_group::ResponseData;
-    fn build_query(variables: Self::Variables) -> ::graphql_client::QueryBody<Self::Variables> {
-        graphql_client::QueryBody {
+    fn build_query(variables: Self::Variables) -> crate::runtime::QueryBody<Self::Variables> {
+        crate::runtime::QueryBody {
             variables,
             query: delete_group::QUERY,
             operation_name: delete_group::OPERATION_NAME,
         }
     }
 }
+
+impl TryFrom<serde_json::Value> for delete_group::ResponseData {
+    type Error = crate::BlipsError;
+
+    fn try_from(value: serde_json::Value) -> Result<Self, Self::Error> {
+        crate::data_from_value(delete_group::OPERATION_NAME, value)
+    }
+}
+
+/// The type of the `deleteGroup` field of the response.
+pub type DeleteGroupPayload = delete_group::DeleteGroupDeleteGroup;
+
+impl delete_group::Group {
+    /// Compares two values by `order`, sorting values without one last.
+    pub fn by_order(a: &Self, b: &Self) -> std::cmp::Ordering {
+        match (a.order, b.order) {
+            (Some(a), Some(b)) => a.cmp(&b),
+            (Some(_), None) => std::cmp::Ordering::Less,
+            (None, Some(_)) => std::cmp::Ordering::Greater,
+            (None, None) => std::cmp::Ordering::Equal,
+        }
+    }
+}
+
+/// A field of the `Group` returned by [`DeleteGroup`], for selecting with
+/// [`crate::BlipsClient::execute_partial`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DeleteGroupField {
+    Collapsed,
+    Date,
+    Id,
+    KeepTasks,
+    Name,
+    Order,
+    ProjectId,
+}
+
+impl DeleteGroupField {
+    /// Every field, for selecting the whole fragment.
+    pub const ALL: &'static [Self] = &[
+        Self::Collapsed,
+        Self::Date,
+        Self::Id,
+        Self::KeepTasks,
+        Self::Name,
+        Self::Order,
+        Self::ProjectId,
+    ];
+}
+
+/// The `Group` returned by [`DeleteGroup`], with the fields selected by
+/// [`crate::BlipsClient::execute_partial`].
+///
+/// A field is `None` both when it wasn't selected and when the server returned `null` for it.
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+#[serde(default)]
+pub struct DeleteGroupPartial {
+    pub collapsed: Option<bool>,
+    pub date: Option<crate::graphql::custom_scalars::Date>,
+    pub id: Option<String>,
+    #[serde(rename = "keepTasks")]
+    pub keep_tasks: Option<bool>,
+    pub name: Option<String>,
+    pub order: Option<i64>,
+    #[serde(rename = "projectId")]
+    pub project_id: Option<String>,
+}
+
+impl crate::PartialQuery for DeleteGroup {
+    type Field = DeleteGroupField;
+    type Partial = DeleteGroupPartial;
+
+    const FRAGMENT_NAME: &'static str = "Group";
+    const ROOT_FIELD: &'static str = "deleteGroup";
+
+    fn field_name(field: Self::Field) -> &'static str {
+        match field {
+            DeleteGroupField::Collapsed => "collapsed",
+            DeleteGroupField::Date => "date",
+            DeleteGroupField::Id => "id",
+            DeleteGroupField::KeepTasks => "keepTasks",
+            DeleteGroupField::Name => "name",
+            DeleteGroupField::Order => "order",
+            DeleteGroupField::ProjectId => "projectId",
+        }
+    }
+}
@@ -4,6 +4,7 @@ pub mod delete_tasks {
     #![allow(dead_code)]
     use std::result::Result;
     pub const OPERATION_NAME: &str = "DeleteTasks";
+    pub const OPERATION_KIND: crate::OperationKind = crate::OperationKind::Mutation;
     pub const QUERY : & str = "mutation DeleteTasks($task_ids: [ID!]!) {\n    deleteTasks(taskIds: $task_ids) {\n        ...Task\n    }\n}\n\nfragment Task on Task {\n    __typename\n    completed\n    completedAt\n    date\n    description\n    dueDate\n    groupIds\n    id\n    isRecurring\n    link\n    name\n    priorityOrder\n    spring\n}" ;
     use super::*;
     use serde::{Deserialize, Serialize};
@@ -17,15 +18,32 @@ pub mod delete_tasks {
     type ID = String;
     type Date = crate::graphql::custom_scalars::Date;
     type DateTime = crate::graphql::custom_scalars::DateTime;
-    #[derive(Serialize)]
+    #[derive(Serialize, Clone)]
     pub struct Variables {
         pub task_ids: Vec<ID>,
     }
-    impl Variables {}
-    #[derive(Deserialize, Debug)]
+    impl Variables {
+        /// Returns a new [`Variables`] from the arguments of `deleteTasks`.
+        ///
+        /// The parameters are in the order the arguments are declared in the schema.
+        pub fn new(task_ids: Vec<ID>) -> Self {
+            Self { task_ids }
+        }
+
+        /// Returns the variables with `taskIds` set to the provided value.
+        ///
+        /// A single value is sent as a list with one element.
+        pub fn with_task_ids(mut self, task_ids: impl Into<crate::OneOrMany<ID>>) -> Self {
+            self.task_ids = task_ids.into().into_vec();
+            self
+        }
+    }
+    #[derive(Deserialize, Debug, Serialize)]
+    #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
     pub struct Task {
         pub completed: Boolean,
         #[serde(rename = "completedAt")]
+        #[cfg_attr(feature = "ts-rs", ts(as = "Option<f64>"))]
         pub completed_at: Option<DateTime>,
         pub date: Option<Date>,
         pub description: Option<String>,
@@ -39,24 +57,256 @@ pub mod delete_tasks {
         pub link: Option<String>,
         pub name: String,
         #[serde(rename = "priorityOrder")]
+        #[cfg_attr(feature = "ts-rs", ts(as = "Option<i32>"))]
         pub priority_order: Option<Int>,
         pub spring: Boolean,
     }
-    #[derive(Deserialize, Debug)]
+    #[derive(Deserialize, Debug, Serialize)]
+    #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
     pub struct ResponseData {
         #[serde(rename = "deleteTasks")]
         pub delete_tasks: Vec<DeleteTasksDeleteTasks>,
     }
     pub type DeleteTasksDeleteTasks = Task;
 }
-impl graphql_client::GraphQLQuery for DeleteTasks {
+impl crate::runtime::GraphQLQuery for DeleteTasks {
     type Variables = delete_tasks::Variables;
     type ResponseData = delete_tasks::ResponseData;
-    fn build_query(variables: Self::Variables) -> ::graphql_client::QueryBody<Self::Variables> {
-        graphql_client::QueryBody {
+    fn build_query(variables: Self::Variables) -> crate::runtime::QueryBody<Self::Variables> {
+        crate::runtime::QueryBody {
             variables,
             query: delete_tasks::QUERY,
             operation_name: delete_tasks::OPERATION_NAME,
         }
     }
 }
+
+impl TryFrom<serde_json::Value> for delete_tasks::ResponseData {
+    type Error = crate::BlipsError;
+
+    fn try_from(value: serde_json::Value) -> Result<Self, Self::Error> {
+        crate::data_from_value(delete_tasks::OPERATION_NAME, value)
+    }
+}
+
+/// The type of the items in the `deleteTasks` list of the response.
+pub type DeleteTasksPayload = delete_tasks::DeleteTasksDeleteTasks;
+
+impl delete_tasks::ResponseData {
+    /// Returns the items in the `deleteTasks` list.
+    pub fn items(&self) -> &[delete_tasks::DeleteTasksDeleteTasks] {
+        &self.delete_tasks
+    }
+}
+
+impl IntoIterator for delete_tasks::ResponseData {
+    type Item = delete_tasks::DeleteTasksDeleteTasks;
+    type IntoIter = std::vec::IntoIter<delete_tasks::DeleteTasksDeleteTasks>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.delete_tasks.into_iter()
+    }
+}
+
+impl delete_tasks::Task {
+    /// Compares two values by `priorityOrder`, sorting values without one last.
+    pub fn by_priority_order(a: &Self, b: &Self) -> std::cmp::Ordering {
+        match (a.priority_order, b.priority_order) {
+            (Some(a), Some(b)) => a.cmp(&b),
+            (Some(_), None) => std::cmp::Ordering::Less,
+            (None, Some(_)) => std::cmp::Ordering::Greater,
+            (None, None) => std::cmp::Ordering::Equal,
+        }
+    }
+}
+
+impl delete_tasks::Task {
+    /// Returns the state of the task, derived from its flags.
+    pub fn state(&self) -> crate::TaskState {
+        crate::TaskState::from_flags(self.completed, self.is_recurring, self.spring)
+    }
+}
+
+impl delete_tasks::Task {
+    /// Returns whether the task is completed.
+    ///
+    /// `completed` and `completedAt` can disagree due to races or server bugs. When they
+    /// do, `completed` is trusted and `completedAt` is ignored. Use
+    /// [`Self::validate_consistency`] to detect the disagreement.
+    pub fn is_effectively_completed(&self) -> bool {
+        self.completed
+    }
+
+    /// Returns warnings for the fields of the task that contradict each other.
+    pub fn validate_consistency(&self) -> Vec<crate::ConsistencyWarning> {
+        crate::ConsistencyWarning::for_completion(self.completed, self.completed_at.is_some())
+            .into_iter()
+            .collect()
+    }
+}
+
+impl delete_tasks::Task {
+    /// Returns the fields that differ between this task and `other`, in the order
+    /// they are selected.
+    pub fn diff(&self, other: &Self) -> Vec<crate::FieldChange> {
+        let mut changes = Vec::new();
+
+        if self.completed != other.completed {
+            changes.push(crate::FieldChange::new(
+                "completed",
+                &self.completed,
+                &other.completed,
+            ));
+        }
+        if self.completed_at != other.completed_at {
+            changes.push(crate::FieldChange::new(
+                "completedAt",
+                &self.completed_at,
+                &other.completed_at,
+            ));
+        }
+        if self.date != other.date {
+            changes.push(crate::FieldChange::new("date", &self.date, &other.date));
+        }
+        if self.description != other.description {
+            changes.push(crate::FieldChange::new(
+                "description",
+                &self.description,
+                &other.description,
+            ));
+        }
+        if self.due_date != other.due_date {
+            changes.push(crate::FieldChange::new(
+                "dueDate",
+                &self.due_date,
+                &other.due_date,
+            ));
+        }
+        if self.group_ids != other.group_ids {
+            changes.push(crate::FieldChange::new(
+                "groupIds",
+                &self.group_ids,
+                &other.group_ids,
+            ));
+        }
+        if self.id != other.id {
+            changes.push(crate::FieldChange::new("id", &self.id, &other.id));
+        }
+        if self.is_recurring != other.is_recurring {
+            changes.push(crate::FieldChange::new(
+                "isRecurring",
+                &self.is_recurring,
+                &other.is_recurring,
+            ));
+        }
+        if self.link != other.link {
+            changes.push(crate::FieldChange::new("link", &self.link, &other.link));
+        }
+        if self.name != other.name {
+            changes.push(crate::FieldChange::new("name", &self.name, &other.name));
+        }
+        if self.priority_order != other.priority_order {
+            changes.push(crate::FieldChange::new(
+                "priorityOrder",
+                &self.priority_order,
+                &other.priority_order,
+            ));
+        }
+        if self.spring != other.spring {
+            changes.push(crate::FieldChange::new(
+                "spring",
+                &self.spring,
+                &other.spring,
+            ));
+        }
+
+        changes
+    }
+}
+
+/// A field of the `Task` returned by [`DeleteTasks`], for selecting with
+/// [`crate::BlipsClient::execute_partial`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DeleteTasksField {
+    Completed,
+    CompletedAt,
+    Date,
+    Description,
+    DueDate,
+    GroupIds,
+    Id,
+    IsRecurring,
+    Link,
+    Name,
+    PriorityOrder,
+    Spring,
+}
+
+impl DeleteTasksField {
+    /// Every field, for selecting the whole fragment.
+    pub const ALL: &'static [Self] = &[
+        Self::Completed,
+        Self::CompletedAt,
+        Self::Date,
+        Self::Description,
+        Self::DueDate,
+        Self::GroupIds,
+        Self::Id,
+        Self::IsRecurring,
+        Self::Link,
+        Self::Name,
+        Self::PriorityOrder,
+        Self::Spring,
+    ];
+}
+
+/// The `Task` returned by [`DeleteTasks`], with the fields selected by
+/// [`crate::BlipsClient::execute_partial`].
+///
+/// A field is `None` both when it wasn't selected and when the server returned `null` for it.
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+#[serde(default)]
+pub struct DeleteTasksPartial {
+    pub completed: Option<bool>,
+    #[serde(rename = "completedAt")]
+    pub completed_at: Option<crate::graphql::custom_scalars::DateTime>,
+    pub date: Option<crate::graphql::custom_scalars::Date>,
+    pub description: Option<String>,
+    #[serde(rename = "dueDate")]
+    pub due_date: Option<crate::graphql::custom_scalars::Date>,
+    #[serde(rename = "groupIds")]
+    pub group_ids: Option<Vec<String>>,
+    pub id: Option<String>,
+    #[serde(rename = "isRecurring")]
+    pub is_recurring: Option<bool>,
+    pub link: Option<String>,
+    pub name: Option<String>,
+    #[serde(rename = "priorityOrder")]
+    pub priority_order: Option<i64>,
+    pub spring: Option<bool>,
+}
+
+impl crate::PartialQuery for DeleteTasks {
+    type Field = DeleteTasksField;
+    type Partial = Vec<DeleteTasksPartial>;
+
+    const FRAGMENT_NAME: &'static str = "Task";
+    const ROOT_FIELD: &'static str = "deleteTasks";
+
+    fn field_name(field: Self::Field) -> &'static str {
+        match field {
+            DeleteTasksField::Completed => "completed",
+            DeleteTasksField::CompletedAt => "completedAt",
+            DeleteTasksField::Date => "date",
+            DeleteTasksField::Description => "description",
+            DeleteTasksField::DueDate => "dueDate",
+            DeleteTasksField::GroupIds => "groupIds",
+            DeleteTasksField::Id => "id",
+            DeleteTasksField::IsRecurring => "isRecurring",
+            DeleteTasksField::Link => "link",
+            DeleteTasksField::Name => "name",
+            DeleteTasksField::PriorityOrder => "priorityOrder",
+            DeleteTasksField::Spring => "spring",
+        }
+    }
+}
@@ -4,6 +4,7 @@ pub mod diary {
     #![allow(dead_code)]
     use std::result::Result;
     pub const OPERATION_NAME: &str = "Diary";
+    pub const OPERATION_KIND: crate::OperationKind = crate::OperationKind::Query;
     pub const QUERY : & str = "query Diary($date: Date!) {\n    diary(date: $date) {\n        ...Diary\n    }\n}\n\nfragment Diary on Diary {\n    __typename\n    collapseCompleted\n    date\n    id\n    noteBody\n    supportsNotes\n}" ;
     use super::*;
     use serde::{Deserialize, Serialize};
@@ -16,12 +17,26 @@ pub mod diary {
     #[allow(dead_code)]
     type ID = String;
     type Date = crate::graphql::custom_scalars::Date;
-    #[derive(Serialize)]
+    #[derive(Serialize, Clone)]
     pub struct Variables {
         pub date: Date,
     }
-    impl Variables {}
-    #[derive(Deserialize, Debug)]
+    impl Variables {
+        /// Returns a new [`Variables`] from the arguments of `diary`.
+        ///
+        /// The parameters are in the order the arguments are declared in the schema.
+        pub fn new(date: Date) -> Self {
+            Self { date }
+        }
+
+        /// Returns the variables with `date` set to the provided value.
+        pub fn with_date(mut self, date: Date) -> Self {
+            self.date = date;
+            self
+        }
+    }
+    #[derive(Deserialize, Debug, Serialize)]
+    #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
     pub struct Diary {
         #[serde(rename = "collapseCompleted")]
         pub collapse_completed: Boolean,
@@ -32,20 +47,89 @@ pub mod diary {
         #[serde(rename = "supportsNotes")]
         pub supports_notes: Boolean,
     }
-    #[derive(Deserialize, Debug)]
+    #[derive(Deserialize, Debug, Serialize)]
+    #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
     pub struct ResponseData {
         pub diary: DiaryDiary,
     }
     pub type DiaryDiary = Diary;
 }
-impl graphql_client::GraphQLQuery for Diary {
+impl crate::runtime::GraphQLQuery for Diary {
     type Variables = diary::Variables;
     type ResponseData = diary::ResponseData;
-    fn build_query(variables: Self::Variables) -> ::graphql_client::QueryBody<Self::Variables> {
-        graphql_client::QueryBody {
+    fn build_query(variables: Self::Variables) -> crate::runtime::QueryBody<Self::Variables> {
+        crate::runtime::QueryBody {
             variables,
             query: diary::QUERY,
             operation_name: diary::OPERATION_NAME,
         }
     }
 }
+
+impl TryFrom<serde_json::Value> for diary::ResponseData {
+    type Error = crate::BlipsError;
+
+    fn try_from(value: serde_json::Value) -> Result<Self, Self::Error> {
+        crate::data_from_value(diary::OPERATION_NAME, value)
+    }
+}
+
+/// The type of the `diary` field of the response.
+pub type DiaryPayload = diary::DiaryDiary;
+
+/// A field of the `Diary` returned by [`Diary`], for selecting with
+/// [`crate::BlipsClient::execute_partial`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DiaryField {
+    CollapseCompleted,
+    Date,
+    Id,
+    NoteBody,
+    SupportsNotes,
+}
+
+impl DiaryField {
+    /// Every field, for selecting the whole fragment.
+    pub const ALL: &'static [Self] = &[
+        Self::CollapseCompleted,
+        Self::Date,
+        Self::Id,
+        Self::NoteBody,
+        Self::SupportsNotes,
+    ];
+}
+
+/// The `Diary` returned by [`Diary`], with the fields selected by
+/// [`crate::BlipsClient::execute_partial`].
+///
+/// A field is `None` both when it wasn't selected and when the server returned `null` for it.
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+#[serde(default)]
+pub struct DiaryPartial {
+    #[serde(rename = "collapseCompleted")]
+    pub collapse_completed: Option<bool>,
+    pub date: Option<crate::graphql::custom_scalars::Date>,
+    pub id: Option<String>,
+    #[serde(rename = "noteBody")]
+    pub note_body: Option<String>,
+    #[serde(rename = "supportsNotes")]
+    pub supports_notes: Option<bool>,
+}
+
+impl crate::PartialQuery for Diary {
+    type Field = DiaryField;
+    type Partial = DiaryPartial;
+
+    const FRAGMENT_NAME: &'static str = "Diary";
+    const ROOT_FIELD: &'static str = "diary";
+
+    fn field_name(field: Self::Field) -> &'static str {
+        match field {
+            DiaryField::CollapseCompleted => "collapseCompleted",
+            DiaryField::Date => "date",
+            DiaryField::Id => "id",
+            DiaryField::NoteBody => "noteBody",
+            DiaryField::SupportsNotes => "supportsNotes",
+        }
+    }
+}
@@ -4,7 +4,8 @@ pub mod enable_otp {
     #![allow(dead_code)]
     use std::result::Result;
     pub const OPERATION_NAME: &str = "EnableOtp";
-    pub const QUERY : & str = "mutation EnableOtp($otp_attempt: String!, $otp_secret: String!) {\n    enableOtp(otpAttempt: $otp_attempt, otpSecret: $otp_secret) {\n        ...EnableOtpResult\n    }\n}\n\nfragment EnableOtpResult on EnableOtpResult {\n    __typename\n    \n}" ;
+    pub const OPERATION_KIND: crate::OperationKind = crate::OperationKind::Mutation;
+    pub const QUERY : & str = "mutation EnableOtp($otp_attempt: String!, $otp_secret: String!) {\n    enableOtp(otpAttempt: $otp_attempt, otpSecret: $otp_secret) {\n        ...EnableOtpResult\n    }\n}\n\nfragment EnableOtpResult on EnableOtpResult {\n    __typename\n    ... on InvalidOtpAttempt {\n        message\n    }\n    ... on OtpEnabled {\n        message\n    }\n    ... on UserAlreadyHasOtp {\n        message\n    }\n}" ;
     use super::*;
     use serde::{Deserialize, Serialize};
     #[allow(dead_code)]
@@ -15,34 +16,87 @@ pub mod enable_otp {
     type Int = i64;
     #[allow(dead_code)]
     type ID = String;
-    #[derive(Serialize)]
+    #[derive(Serialize, Clone)]
     pub struct Variables {
         pub otp_attempt: String,
         pub otp_secret: String,
     }
-    impl Variables {}
-    #[derive(Deserialize, Debug)]
+    impl Variables {
+        /// Returns a new [`Variables`] from the arguments of `enableOtp`.
+        ///
+        /// The parameters are in the order the arguments are declared in the schema.
+        pub fn new(otp_attempt: String, otp_secret: String) -> Self {
+            Self {
+                otp_attempt,
+                otp_secret,
+            }
+        }
+
+        /// Returns the variables with `otpAttempt` set to the provided value.
+        pub fn with_otp_attempt(mut self, otp_attempt: String) -> Self {
+            self.otp_attempt = otp_attempt;
+            self
+        }
+
+        /// Returns the variables with `otpSecret` set to the provided value.
+        pub fn with_otp_secret(mut self, otp_secret: String) -> Self {
+            self.otp_secret = otp_secret;
+            self
+        }
+    }
+    #[derive(Deserialize, Debug, Serialize)]
+    #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
     #[serde(tag = "__typename")]
     pub enum EnableOtpResult {
-        InvalidOtpAttempt,
-        OtpEnabled,
-        UserAlreadyHasOtp,
+        InvalidOtpAttempt(EnableOtpResultOnInvalidOtpAttempt),
+        OtpEnabled(EnableOtpResultOnOtpEnabled),
+        UserAlreadyHasOtp(EnableOtpResultOnUserAlreadyHasOtp),
+        #[cfg_attr(feature = "ts-rs", ts(skip))]
+        #[serde(other)]
+        Unknown,
+    }
+    #[derive(Deserialize, Debug, Serialize)]
+    #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+    pub struct EnableOtpResultOnInvalidOtpAttempt {
+        pub message: String,
     }
-    #[derive(Deserialize, Debug)]
+    #[derive(Deserialize, Debug, Serialize)]
+    #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+    pub struct EnableOtpResultOnOtpEnabled {
+        pub message: String,
+    }
+    #[derive(Deserialize, Debug, Serialize)]
+    #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+    pub struct EnableOtpResultOnUserAlreadyHasOtp {
+        pub message: String,
+    }
+    #[derive(Deserialize, Debug, Serialize)]
+    #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
     pub struct ResponseData {
         #[serde(rename = "enableOtp")]
         pub enable_otp: EnableOtpEnableOtp,
     }
     pub type EnableOtpEnableOtp = EnableOtpResult;
 }
-impl graphql_client::GraphQLQuery for EnableOtp {
+impl crate::runtime::GraphQLQuery for EnableOtp {
     type Variables = enable_otp::Variables;
     type ResponseData = enable_otp::ResponseData;
-    fn build_query(variables: Self::Variables) -> ::graphql_client::QueryBody<Self::Variables> {
-        graphql_client::QueryBody {
+    fn build_query(variables: Self::Variables) -> crate::runtime::QueryBody<Self::Variables> {
+        crate::runtime::QueryBody {
             variables,
             query: enable_otp::QUERY,
             operation_name: enable_otp::OPERATION_NAME,
         }
     }
 }
+
+impl TryFrom<serde_json::Value> for enable_otp::ResponseData {
+    type Error = crate::BlipsError;
+
+    fn try_from(value: serde_json::Value) -> Result<Self, Self::Error> {
+        crate::data_from_value(enable_otp::OPERATION_NAME, value)
+    }
+}
+
+/// The type of the `enableOtp` field of the response.
+pub type EnableOtpPayload = enable_otp::EnableOtpEnableOtp;
@@ -4,7 +4,8 @@ pub mod generate_new_otp {
     #![allow(dead_code)]
     use std::result::Result;
     pub const OPERATION_NAME: &str = "GenerateNewOtp";
-    pub const QUERY : & str = "mutation GenerateNewOtp {\n    generateNewOtp {\n        ...GenerateNewOtpResult\n    }\n}\n\nfragment GenerateNewOtpResult on GenerateNewOtpResult {\n    __typename\n    \n}" ;
+    pub const OPERATION_KIND: crate::OperationKind = crate::OperationKind::Mutation;
+    pub const QUERY : & str = "mutation GenerateNewOtp {\n    generateNewOtp {\n        ...GenerateNewOtpResult\n    }\n}\n\nfragment GenerateNewOtpResult on GenerateNewOtpResult {\n    __typename\n    ... on NewOtpGenerated {\n        darkQrCode\n        lightQrCode\n        otpSecret\n    }\n    ... on UserAlreadyHasOtp {\n        message\n    }\n}" ;
     use super::*;
     use serde::{Deserialize, Serialize};
     #[allow(dead_code)]
@@ -15,29 +16,60 @@ pub mod generate_new_otp {
     type Int = i64;
     #[allow(dead_code)]
     type ID = String;
-    #[derive(Serialize)]
+    #[derive(Serialize, Clone, Default)]
     pub struct Variables;
-    #[derive(Deserialize, Debug)]
+    #[derive(Deserialize, Debug, Serialize)]
+    #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
     #[serde(tag = "__typename")]
     pub enum GenerateNewOtpResult {
-        NewOtpGenerated,
-        UserAlreadyHasOtp,
+        NewOtpGenerated(GenerateNewOtpResultOnNewOtpGenerated),
+        UserAlreadyHasOtp(GenerateNewOtpResultOnUserAlreadyHasOtp),
+        #[cfg_attr(feature = "ts-rs", ts(skip))]
+        #[serde(other)]
+        Unknown,
     }
-    #[derive(Deserialize, Debug)]
+    #[derive(Deserialize, Debug, Serialize)]
+    #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+    pub struct GenerateNewOtpResultOnNewOtpGenerated {
+        #[serde(rename = "darkQrCode")]
+        pub dark_qr_code: String,
+        #[serde(rename = "lightQrCode")]
+        pub light_qr_code: String,
+        #[serde(rename = "otpSecret")]
+        pub otp_secret: String,
+    }
+    #[derive(Deserialize, Debug, Serialize)]
+    #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+    pub struct GenerateNewOtpResultOnUserAlreadyHasOtp {
+        pub message: String,
+    }
+    #[derive(Deserialize, Debug, Serialize)]
+    #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
     pub struct ResponseData {
         #[serde(rename = "generateNewOtp")]
         pub generate_new_otp: GenerateNewOtpGenerateNewOtp,
     }
     pub type GenerateNewOtpGenerateNewOtp = GenerateNewOtpResult;
 }
-impl graphql_client::GraphQLQuery for GenerateNewOtp {
+impl crate::runtime::GraphQLQuery for GenerateNewOtp {
     type Variables = generate_new_otp::Variables;
     type ResponseData = generate_new_otp::ResponseData;
-    fn build_query(variables: Self::Variables) -> ::graphql_client::QueryBody<Self::Variables> {
-        graphql_client::QueryBody {
+    fn build_query(variables: Self::Variables) -> crate::runtime::QueryBody<Self::Variables> {
+        crate::runtime::QueryBody {
             variables,
             query: generate_new_otp::QUERY,
             operation_name: generate_new_otp::OPERATION_NAME,
         }
     }
 }
+
+impl TryFrom<serde_json::Value> for generate_new_otp::ResponseData {
+    type Error = crate::BlipsError;
+
+    fn try_from(value: serde_json::Value) -> Result<Self, Self::Error> {
+        crate::data_from_value(generate_new_otp::OPERATION_NAME, value)
+    }
+}
+
+/// The type of the `generateNewOtp` field of the response.
+pub type GenerateNewOtpPayload = generate_new_otp::GenerateNewOtpGenerateNewOtp;
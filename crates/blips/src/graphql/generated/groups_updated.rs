@@ -0,0 +1,93 @@
+#![allow(clippy::all, warnings)]
+pub struct GroupsUpdated;
+pub mod groups_updated {
+    #![allow(dead_code)]
+    use std::result::Result;
+    pub const OPERATION_NAME: &str = "GroupsUpdated";
+    pub const OPERATION_KIND: crate::OperationKind = crate::OperationKind::Subscription;
+    pub const QUERY : & str = "subscription GroupsUpdated {\n    groupsUpdated {\n        ...Group\n    }\n}\n\nfragment Group on Group {\n    __typename\n    collapsed\n    date\n    id\n    keepTasks\n    name\n    order\n    projectId\n}" ;
+    use super::*;
+    use serde::{Deserialize, Serialize};
+    #[allow(dead_code)]
+    type Boolean = bool;
+    #[allow(dead_code)]
+    type Float = f64;
+    #[allow(dead_code)]
+    type Int = i64;
+    #[allow(dead_code)]
+    type ID = String;
+    type Date = crate::graphql::custom_scalars::Date;
+    #[derive(Serialize, Clone, Default)]
+    pub struct Variables;
+    #[derive(Deserialize, Debug, Serialize)]
+    #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+    pub struct Group {
+        pub collapsed: Option<Boolean>,
+        pub date: Option<Date>,
+        pub id: ID,
+        #[serde(rename = "keepTasks")]
+        pub keep_tasks: Boolean,
+        pub name: String,
+        #[cfg_attr(feature = "ts-rs", ts(as = "Option<i32>"))]
+        pub order: Option<Int>,
+        #[serde(rename = "projectId")]
+        pub project_id: Option<ID>,
+    }
+    #[derive(Deserialize, Debug, Serialize)]
+    #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+    pub struct ResponseData {
+        #[serde(rename = "groupsUpdated")]
+        pub groups_updated: Vec<GroupsUpdatedGroupsUpdated>,
+    }
+    pub type GroupsUpdatedGroupsUpdated = Group;
+}
+impl crate::runtime::GraphQLQuery for GroupsUpdated {
+    type Variables = groups_updated::Variables;
+    type ResponseData = groups_updated::ResponseData;
+    fn build_query(variables: Self::Variables) -> crate::runtime::QueryBody<Self::Variables> {
+        crate::runtime::QueryBody {
+            variables,
+            query: groups_updated::QUERY,
+            operation_name: groups_updated::OPERATION_NAME,
+        }
+    }
+}
+
+impl TryFrom<serde_json::Value> for groups_updated::ResponseData {
+    type Error = crate::BlipsError;
+
+    fn try_from(value: serde_json::Value) -> Result<Self, Self::Error> {
+        crate::data_from_value(groups_updated::OPERATION_NAME, value)
+    }
+}
+
+/// The type of the items in the `groupsUpdated` list of the response.
+pub type GroupsUpdatedPayload = groups_updated::GroupsUpdatedGroupsUpdated;
+
+impl groups_updated::ResponseData {
+    /// Returns the items in the `groupsUpdated` list.
+    pub fn items(&self) -> &[groups_updated::GroupsUpdatedGroupsUpdated] {
+        &self.groups_updated
+    }
+}
+
+impl IntoIterator for groups_updated::ResponseData {
+    type Item = groups_updated::GroupsUpdatedGroupsUpdated;
+    type IntoIter = std::vec::IntoIter<groups_updated::GroupsUpdatedGroupsUpdated>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.groups_updated.into_iter()
+    }
+}
+
+impl groups_updated::Group {
+    /// Compares two values by `order`, sorting values without one last.
+    pub fn by_order(a: &Self, b: &Self) -> std::cmp::Ordering {
+        match (a.order, b.order) {
+            (Some(a), Some(b)) => a.cmp(&b),
+            (Some(_), None) => std::cmp::Ordering::Less,
+            (None, Some(_)) => std::cmp::Ordering::Greater,
+            (None, None) => std::cmp::Ordering::Equal,
+        }
+    }
+}
@@ -4,6 +4,7 @@ pub mod me {
     #![allow(dead_code)]
     use std::result::Result;
     pub const OPERATION_NAME: &str = "Me";
+    pub const OPERATION_KIND: crate::OperationKind = crate::OperationKind::Query;
     pub const QUERY : & str = "query Me {\n    me {\n        ...User\n    }\n}\n\nfragment User on User {\n    __typename\n    email\n    id\n    isMfaEnabled\n}" ;
     use super::*;
     use serde::{Deserialize, Serialize};
@@ -15,29 +16,85 @@ pub mod me {
     type Int = i64;
     #[allow(dead_code)]
     type ID = String;
-    #[derive(Serialize)]
+    #[derive(Serialize, Clone, Default)]
     pub struct Variables;
-    #[derive(Deserialize, Debug)]
+    #[derive(Deserialize, Debug, Serialize)]
+    #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
     pub struct User {
         pub email: String,
         pub id: ID,
         #[serde(rename = "isMfaEnabled")]
         pub is_mfa_enabled: Boolean,
     }
-    #[derive(Deserialize, Debug)]
+    #[derive(Deserialize, Debug, Serialize)]
+    #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
     pub struct ResponseData {
         pub me: Option<MeMe>,
     }
     pub type MeMe = User;
 }
-impl graphql_client::GraphQLQuery for Me {
+impl crate::runtime::GraphQLQuery for Me {
     type Variables = me::Variables;
     type ResponseData = me::ResponseData;
-    fn build_query(variables: Self::Variables) -> ::graphql_client::QueryBody<Self::Variables> {
-        graphql_client::QueryBody {
+    fn build_query(variables: Self::Variables) -> crate::runtime::QueryBody<Self::Variables> {
+        crate::runtime::QueryBody {
             variables,
             query: me::QUERY,
             operation_name: me::OPERATION_NAME,
         }
     }
 }
+
+impl TryFrom<serde_json::Value> for me::ResponseData {
+    type Error = crate::BlipsError;
+
+    fn try_from(value: serde_json::Value) -> Result<Self, Self::Error> {
+        crate::data_from_value(me::OPERATION_NAME, value)
+    }
+}
+
+/// The type of the `me` field of the response.
+pub type MePayload = me::MeMe;
+
+/// A field of the `User` returned by [`Me`], for selecting with
+/// [`crate::BlipsClient::execute_partial`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MeField {
+    Email,
+    Id,
+    IsMfaEnabled,
+}
+
+impl MeField {
+    /// Every field, for selecting the whole fragment.
+    pub const ALL: &'static [Self] = &[Self::Email, Self::Id, Self::IsMfaEnabled];
+}
+
+/// The `User` returned by [`Me`], with the fields selected by
+/// [`crate::BlipsClient::execute_partial`].
+///
+/// A field is `None` both when it wasn't selected and when the server returned `null` for it.
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+#[serde(default)]
+pub struct MePartial {
+    pub email: Option<String>,
+    pub id: Option<String>,
+    #[serde(rename = "isMfaEnabled")]
+    pub is_mfa_enabled: Option<bool>,
+}
+
+impl crate::PartialQuery for Me {
+    type Field = MeField;
+    type Partial = Option<MePartial>;
+
+    const FRAGMENT_NAME: &'static str = "User";
+    const ROOT_FIELD: &'static str = "me";
+
+    fn field_name(field: Self::Field) -> &'static str {
+        match field {
+            MeField::Email => "email",
+            MeField::Id => "id",
+            MeField::IsMfaEnabled => "isMfaEnabled",
+        }
+    }
+}
@@ -4,6 +4,7 @@ pub mod note {
     #![allow(dead_code)]
     use std::result::Result;
     pub const OPERATION_NAME: &str = "Note";
+    pub const OPERATION_KIND: crate::OperationKind = crate::OperationKind::Query;
     pub const QUERY : & str = "query Note($note_id: ID!) {\n    note(noteId: $note_id) {\n        ...Note\n    }\n}\n\nfragment Note on Note {\n    __typename\n    body\n    date\n    endDate\n    hidePreview\n    id\n    name\n    updatedAt\n}" ;
     use super::*;
     use serde::{Deserialize, Serialize};
@@ -17,12 +18,26 @@ pub mod note {
     type ID = String;
     type Date = crate::graphql::custom_scalars::Date;
     type DateTime = crate::graphql::custom_scalars::DateTime;
-    #[derive(Serialize)]
+    #[derive(Serialize, Clone)]
     pub struct Variables {
         pub note_id: ID,
     }
-    impl Variables {}
-    #[derive(Deserialize, Debug)]
+    impl Variables {
+        /// Returns a new [`Variables`] from the arguments of `note`.
+        ///
+        /// The parameters are in the order the arguments are declared in the schema.
+        pub fn new(note_id: ID) -> Self {
+            Self { note_id }
+        }
+
+        /// Returns the variables with `noteId` set to the provided value.
+        pub fn with_note_id(mut self, note_id: ID) -> Self {
+            self.note_id = note_id;
+            self
+        }
+    }
+    #[derive(Deserialize, Debug, Serialize)]
+    #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
     pub struct Note {
         pub body: Option<String>,
         pub date: Option<Date>,
@@ -33,22 +48,100 @@ pub mod note {
         pub id: ID,
         pub name: String,
         #[serde(rename = "updatedAt")]
+        #[cfg_attr(feature = "ts-rs", ts(as = "f64"))]
         pub updated_at: DateTime,
     }
-    #[derive(Deserialize, Debug)]
+    #[derive(Deserialize, Debug, Serialize)]
+    #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
     pub struct ResponseData {
         pub note: Option<NoteNote>,
     }
     pub type NoteNote = Note;
 }
-impl graphql_client::GraphQLQuery for Note {
+impl crate::runtime::GraphQLQuery for Note {
     type Variables = note::Variables;
     type ResponseData = note::ResponseData;
-    fn build_query(variables: Self::Variables) -> ::graphql_client::QueryBody<Self::Variables> {
-        graphql_client::QueryBody {
+    fn build_query(variables: Self::Variables) -> crate::runtime::QueryBody<Self::Variables> {
+        crate::runtime::QueryBody {
             variables,
             query: note::QUERY,
             operation_name: note::OPERATION_NAME,
         }
     }
 }
+
+impl TryFrom<serde_json::Value> for note::ResponseData {
+    type Error = crate::BlipsError;
+
+    fn try_from(value: serde_json::Value) -> Result<Self, Self::Error> {
+        crate::data_from_value(note::OPERATION_NAME, value)
+    }
+}
+
+/// The type of the `note` field of the response.
+pub type NotePayload = note::NoteNote;
+
+/// A field of the `Note` returned by [`Note`], for selecting with
+/// [`crate::BlipsClient::execute_partial`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NoteField {
+    Body,
+    Date,
+    EndDate,
+    HidePreview,
+    Id,
+    Name,
+    UpdatedAt,
+}
+
+impl NoteField {
+    /// Every field, for selecting the whole fragment.
+    pub const ALL: &'static [Self] = &[
+        Self::Body,
+        Self::Date,
+        Self::EndDate,
+        Self::HidePreview,
+        Self::Id,
+        Self::Name,
+        Self::UpdatedAt,
+    ];
+}
+
+/// The `Note` returned by [`Note`], with the fields selected by
+/// [`crate::BlipsClient::execute_partial`].
+///
+/// A field is `None` both when it wasn't selected and when the server returned `null` for it.
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+#[serde(default)]
+pub struct NotePartial {
+    pub body: Option<String>,
+    pub date: Option<crate::graphql::custom_scalars::Date>,
+    #[serde(rename = "endDate")]
+    pub end_date: Option<crate::graphql::custom_scalars::Date>,
+    #[serde(rename = "hidePreview")]
+    pub hide_preview: Option<bool>,
+    pub id: Option<String>,
+    pub name: Option<String>,
+    #[serde(rename = "updatedAt")]
+    pub updated_at: Option<crate::graphql::custom_scalars::DateTime>,
+}
+
+impl crate::PartialQuery for Note {
+    type Field = NoteField;
+    type Partial = Option<NotePartial>;
+
+    const FRAGMENT_NAME: &'static str = "Note";
+    const ROOT_FIELD: &'static str = "note";
+
+    fn field_name(field: Self::Field) -> &'static str {
+        match field {
+            NoteField::Body => "body",
+            NoteField::Date => "date",
+            NoteField::EndDate => "endDate",
+            NoteField::HidePreview => "hidePreview",
+            NoteField::Id => "id",
+            NoteField::Name => "name",
+            NoteField::UpdatedAt => "updatedAt",
+        }
+    }
+}
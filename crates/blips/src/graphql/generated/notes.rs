@@ -4,6 +4,7 @@ pub mod notes {
     #![allow(dead_code)]
     use std::result::Result;
     pub const OPERATION_NAME: &str = "Notes";
+    pub const OPERATION_KIND: crate::OperationKind = crate::OperationKind::Query;
     pub const QUERY : & str = "query Notes($date: Date, $project_id: ID, $query: String) {\n    notes(date: $date, projectId: $project_id, query: $query) {\n        ...Note\n    }\n}\n\nfragment Note on Note {\n    __typename\n    body\n    date\n    endDate\n    hidePreview\n    id\n    name\n    updatedAt\n}" ;
     use super::*;
     use serde::{Deserialize, Serialize};
@@ -17,14 +18,44 @@ pub mod notes {
     type ID = String;
     type Date = crate::graphql::custom_scalars::Date;
     type DateTime = crate::graphql::custom_scalars::DateTime;
-    #[derive(Serialize)]
+    #[derive(Serialize, Clone, Default)]
     pub struct Variables {
         pub date: Option<Date>,
         pub project_id: Option<ID>,
         pub query: Option<String>,
     }
-    impl Variables {}
-    #[derive(Deserialize, Debug)]
+    impl Variables {
+        /// Returns a new [`Variables`] from the arguments of `notes`.
+        ///
+        /// The parameters are in the order the arguments are declared in the schema.
+        pub fn new(date: Option<Date>, project_id: Option<ID>, query: Option<String>) -> Self {
+            Self {
+                date,
+                project_id,
+                query,
+            }
+        }
+
+        /// Returns the variables with `date` set to the provided value.
+        pub fn with_date(mut self, date: impl Into<Option<Date>>) -> Self {
+            self.date = date.into();
+            self
+        }
+
+        /// Returns the variables with `projectId` set to the provided value.
+        pub fn with_project_id(mut self, project_id: impl Into<Option<ID>>) -> Self {
+            self.project_id = project_id.into();
+            self
+        }
+
+        /// Returns the variables with `query` set to the provided value.
+        pub fn with_query(mut self, query: impl Into<Option<String>>) -> Self {
+            self.query = query.into();
+            self
+        }
+    }
+    #[derive(Deserialize, Debug, Serialize)]
+    #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
     pub struct Note {
         pub body: Option<String>,
         pub date: Option<Date>,
@@ -35,22 +66,116 @@ pub mod notes {
         pub id: ID,
         pub name: String,
         #[serde(rename = "updatedAt")]
+        #[cfg_attr(feature = "ts-rs", ts(as = "f64"))]
         pub updated_at: DateTime,
     }
-    #[derive(Deserialize, Debug)]
+    #[derive(Deserialize, Debug, Serialize)]
+    #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
     pub struct ResponseData {
         pub notes: Option<Vec<NotesNotes>>,
     }
     pub type NotesNotes = Note;
 }
-impl graphql_client::GraphQLQuery for Notes {
+impl crate::runtime::GraphQLQuery for Notes {
     type Variables = notes::Variables;
     type ResponseData = notes::ResponseData;
-    fn build_query(variables: Self::Variables) -> ::graphql_client::QueryBody<Self::Variables> {
-        graphql_client::QueryBody {
+    fn build_query(variables: Self::Variables) -> crate::runtime::QueryBody<Self::Variables> {
+        crate::runtime::QueryBody {
             variables,
             query: notes::QUERY,
             operation_name: notes::OPERATION_NAME,
         }
     }
 }
+
+impl TryFrom<serde_json::Value> for notes::ResponseData {
+    type Error = crate::BlipsError;
+
+    fn try_from(value: serde_json::Value) -> Result<Self, Self::Error> {
+        crate::data_from_value(notes::OPERATION_NAME, value)
+    }
+}
+
+/// The type of the items in the `notes` list of the response.
+pub type NotesPayload = notes::NotesNotes;
+
+impl notes::ResponseData {
+    /// Returns the items in the `notes` list.
+    pub fn items(&self) -> &[notes::NotesNotes] {
+        self.notes.as_deref().unwrap_or_default()
+    }
+}
+
+impl IntoIterator for notes::ResponseData {
+    type Item = notes::NotesNotes;
+    type IntoIter = std::vec::IntoIter<notes::NotesNotes>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.notes.unwrap_or_default().into_iter()
+    }
+}
+
+/// A field of the `Note` returned by [`Notes`], for selecting with
+/// [`crate::BlipsClient::execute_partial`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NotesField {
+    Body,
+    Date,
+    EndDate,
+    HidePreview,
+    Id,
+    Name,
+    UpdatedAt,
+}
+
+impl NotesField {
+    /// Every field, for selecting the whole fragment.
+    pub const ALL: &'static [Self] = &[
+        Self::Body,
+        Self::Date,
+        Self::EndDate,
+        Self::HidePreview,
+        Self::Id,
+        Self::Name,
+        Self::UpdatedAt,
+    ];
+}
+
+/// The `Note` returned by [`Notes`], with the fields selected by
+/// [`crate::BlipsClient::execute_partial`].
+///
+/// A field is `None` both when it wasn't selected and when the server returned `null` for it.
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+#[serde(default)]
+pub struct NotesPartial {
+    pub body: Option<String>,
+    pub date: Option<crate::graphql::custom_scalars::Date>,
+    #[serde(rename = "endDate")]
+    pub end_date: Option<crate::graphql::custom_scalars::Date>,
+    #[serde(rename = "hidePreview")]
+    pub hide_preview: Option<bool>,
+    pub id: Option<String>,
+    pub name: Option<String>,
+    #[serde(rename = "updatedAt")]
+    pub updated_at: Option<crate::graphql::custom_scalars::DateTime>,
+}
+
+impl crate::PartialQuery for Notes {
+    type Field = NotesField;
+    type Partial = Option<Vec<NotesPartial>>;
+
+    const FRAGMENT_NAME: &'static str = "Note";
+    const ROOT_FIELD: &'static str = "notes";
+
+    fn field_name(field: Self::Field) -> &'static str {
+        match field {
+            NotesField::Body => "body",
+            NotesField::Date => "date",
+            NotesField::EndDate => "endDate",
+            NotesField::HidePreview => "hidePreview",
+            NotesField::Id => "id",
+            NotesField::Name => "name",
+            NotesField::UpdatedAt => "updatedAt",
+        }
+    }
+}
@@ -4,6 +4,7 @@ pub mod persist_group_order {
     #![allow(dead_code)]
     use std::result::Result;
     pub const OPERATION_NAME: &str = "PersistGroupOrder";
+    pub const OPERATION_KIND: crate::OperationKind = crate::OperationKind::Mutation;
     pub const QUERY : & str = "mutation PersistGroupOrder($order: [OrderInput!]) {\n    persistGroupOrder(order: $order) {\n        ...Group\n    }\n}\n\nfragment Group on Group {\n    __typename\n    collapsed\n    date\n    id\n    keepTasks\n    name\n    order\n    projectId\n}" ;
     use super::*;
     use serde::{Deserialize, Serialize};
@@ -16,23 +17,37 @@ pub mod persist_group_order {
     #[allow(dead_code)]
     type ID = String;
     type Date = crate::graphql::custom_scalars::Date;
-    #[derive(Serialize)]
+    #[derive(Serialize, Clone)]
+    #[serde(rename_all = "camelCase")]
     pub struct OrderInput {
         pub id: Option<ID>,
         pub order: Option<Int>,
-        #[serde(rename = "destinationGroupId")]
         pub destination_group_id: Option<ID>,
-        #[serde(rename = "sourceGroupId")]
         pub source_group_id: Option<ID>,
-        #[serde(rename = "projectColumnId")]
         pub project_column_id: Option<ID>,
     }
-    #[derive(Serialize)]
+    #[derive(Serialize, Clone, Default)]
     pub struct Variables {
         pub order: Option<Vec<OrderInput>>,
     }
-    impl Variables {}
-    #[derive(Deserialize, Debug)]
+    impl Variables {
+        /// Returns a new [`Variables`] from the arguments of `persistGroupOrder`.
+        ///
+        /// The parameters are in the order the arguments are declared in the schema.
+        pub fn new(order: Option<Vec<OrderInput>>) -> Self {
+            Self { order }
+        }
+
+        /// Returns the variables with `order` set to the provided value.
+        ///
+        /// A single value is sent as a list with one element.
+        pub fn with_order(mut self, order: impl Into<crate::OneOrMany<OrderInput>>) -> Self {
+            self.order = Some(order.into().into_vec());
+            self
+        }
+    }
+    #[derive(Deserialize, Debug, Serialize)]
+    #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
     pub struct Group {
         pub collapsed: Option<Boolean>,
         pub date: Option<Date>,
@@ -40,25 +55,130 @@ pub mod persist_group_order {
         #[serde(rename = "keepTasks")]
         pub keep_tasks: Boolean,
         pub name: String,
+        #[cfg_attr(feature = "ts-rs", ts(as = "Option<i32>"))]
         pub order: Option<Int>,
         #[serde(rename = "projectId")]
         pub project_id: Option<ID>,
     }
-    #[derive(Deserialize, Debug)]
+    #[derive(Deserialize, Debug, Serialize)]
+    #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
     pub struct ResponseData {
         #[serde(rename = "persistGroupOrder")]
         pub persist_group_order: Vec<PersistGroupOrderPersistGroupOrder>,
     }
     pub type PersistGroupOrderPersistGroupOrder = Group;
 }
-impl graphql_client::GraphQLQuery for PersistGroupOrder {
+impl crate::runtime::GraphQLQuery for PersistGroupOrder {
     type Variables = persist_group_order::Variables;
     type ResponseData = persist_group_order::ResponseData;
-    fn build_query(variables: Self::Variables) -> ::graphql_client::QueryBody<Self::Variables> {
-        graphql_client::QueryBody {
+    fn build_query(variables: Self::Variables) -> crate::runtime::QueryBody<Self::Variables> {
+        crate::runtime::QueryBody {
             variables,
             query: persist_group_order::QUERY,
             operation_name: persist_group_order::OPERATION_NAME,
         }
     }
 }
+
+impl TryFrom<serde_json::Value> for persist_group_order::ResponseData {
+    type Error = crate::BlipsError;
+
+    fn try_from(value: serde_json::Value) -> Result<Self, Self::Error> {
+        crate::data_from_value(persist_group_order::OPERATION_NAME, value)
+    }
+}
+
+/// The type of the items in the `persistGroupOrder` list of the response.
+pub type PersistGroupOrderPayload = persist_group_order::PersistGroupOrderPersistGroupOrder;
+
+impl persist_group_order::ResponseData {
+    /// Returns the items in the `persistGroupOrder` list.
+    pub fn items(&self) -> &[persist_group_order::PersistGroupOrderPersistGroupOrder] {
+        &self.persist_group_order
+    }
+}
+
+impl IntoIterator for persist_group_order::ResponseData {
+    type Item = persist_group_order::PersistGroupOrderPersistGroupOrder;
+    type IntoIter = std::vec::IntoIter<persist_group_order::PersistGroupOrderPersistGroupOrder>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.persist_group_order.into_iter()
+    }
+}
+
+impl persist_group_order::Group {
+    /// Compares two values by `order`, sorting values without one last.
+    pub fn by_order(a: &Self, b: &Self) -> std::cmp::Ordering {
+        match (a.order, b.order) {
+            (Some(a), Some(b)) => a.cmp(&b),
+            (Some(_), None) => std::cmp::Ordering::Less,
+            (None, Some(_)) => std::cmp::Ordering::Greater,
+            (None, None) => std::cmp::Ordering::Equal,
+        }
+    }
+}
+
+/// A field of the `Group` returned by [`PersistGroupOrder`], for selecting with
+/// [`crate::BlipsClient::execute_partial`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PersistGroupOrderField {
+    Collapsed,
+    Date,
+    Id,
+    KeepTasks,
+    Name,
+    Order,
+    ProjectId,
+}
+
+impl PersistGroupOrderField {
+    /// Every field, for selecting the whole fragment.
+    pub const ALL: &'static [Self] = &[
+        Self::Collapsed,
+        Self::Date,
+        Self::Id,
+        Self::KeepTasks,
+        Self::Name,
+        Self::Order,
+        Self::ProjectId,
+    ];
+}
+
+/// The `Group` returned by [`PersistGroupOrder`], with the fields selected by
+/// [`crate::BlipsClient::execute_partial`].
+///
+/// A field is `None` both when it wasn't selected and when the server returned `null` for it.
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+#[serde(default)]
+pub struct PersistGroupOrderPartial {
+    pub collapsed: Option<bool>,
+    pub date: Option<crate::graphql::custom_scalars::Date>,
+    pub id: Option<String>,
+    #[serde(rename = "keepTasks")]
+    pub keep_tasks: Option<bool>,
+    pub name: Option<String>,
+    pub order: Option<i64>,
+    #[serde(rename = "projectId")]
+    pub project_id: Option<String>,
+}
+
+impl crate::PartialQuery for PersistGroupOrder {
+    type Field = PersistGroupOrderField;
+    type Partial = Vec<PersistGroupOrderPartial>;
+
+    const FRAGMENT_NAME: &'static str = "Group";
+    const ROOT_FIELD: &'static str = "persistGroupOrder";
+
+    fn field_name(field: Self::Field) -> &'static str {
+        match field {
+            PersistGroupOrderField::Collapsed => "collapsed",
+            PersistGroupOrderField::Date => "date",
+            PersistGroupOrderField::Id => "id",
+            PersistGroupOrderField::KeepTasks => "keepTasks",
+            PersistGroupOrderField::Name => "name",
+            PersistGroupOrderField::Order => "order",
+            PersistGroupOrderField::ProjectId => "projectId",
+        }
+    }
+}
@@ -4,6 +4,7 @@ pub mod persist_project_column_order {
     #![allow(dead_code)]
     use std::result::Result;
     pub const OPERATION_NAME: &str = "PersistProjectColumnOrder";
+    pub const OPERATION_KIND: crate::OperationKind = crate::OperationKind::Mutation;
     pub const QUERY : & str = "mutation PersistProjectColumnOrder($order: [OrderInput!]) {\n    persistProjectColumnOrder(order: $order) {\n        ...ProjectColumn\n    }\n}\n\nfragment ProjectColumn on ProjectColumn {\n    __typename\n    collapsed\n    id\n    name\n    order\n}" ;
     use super::*;
     use serde::{Deserialize, Serialize};
@@ -15,44 +16,144 @@ pub mod persist_project_column_order {
     type Int = i64;
     #[allow(dead_code)]
     type ID = String;
-    #[derive(Serialize)]
+    #[derive(Serialize, Clone)]
+    #[serde(rename_all = "camelCase")]
     pub struct OrderInput {
         pub id: Option<ID>,
         pub order: Option<Int>,
-        #[serde(rename = "destinationGroupId")]
         pub destination_group_id: Option<ID>,
-        #[serde(rename = "sourceGroupId")]
         pub source_group_id: Option<ID>,
-        #[serde(rename = "projectColumnId")]
         pub project_column_id: Option<ID>,
     }
-    #[derive(Serialize)]
+    #[derive(Serialize, Clone, Default)]
     pub struct Variables {
         pub order: Option<Vec<OrderInput>>,
     }
-    impl Variables {}
-    #[derive(Deserialize, Debug)]
+    impl Variables {
+        /// Returns a new [`Variables`] from the arguments of `persistProjectColumnOrder`.
+        ///
+        /// The parameters are in the order the arguments are declared in the schema.
+        pub fn new(order: Option<Vec<OrderInput>>) -> Self {
+            Self { order }
+        }
+
+        /// Returns the variables with `order` set to the provided value.
+        ///
+        /// A single value is sent as a list with one element.
+        pub fn with_order(mut self, order: impl Into<crate::OneOrMany<OrderInput>>) -> Self {
+            self.order = Some(order.into().into_vec());
+            self
+        }
+    }
+    #[derive(Deserialize, Debug, Serialize)]
+    #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
     pub struct ProjectColumn {
         pub collapsed: Boolean,
         pub id: ID,
         pub name: String,
+        #[cfg_attr(feature = "ts-rs", ts(as = "i32"))]
         pub order: Int,
     }
-    #[derive(Deserialize, Debug)]
+    #[derive(Deserialize, Debug, Serialize)]
+    #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
     pub struct ResponseData {
         #[serde(rename = "persistProjectColumnOrder")]
         pub persist_project_column_order: Vec<PersistProjectColumnOrderPersistProjectColumnOrder>,
     }
     pub type PersistProjectColumnOrderPersistProjectColumnOrder = ProjectColumn;
 }
-impl graphql_client::GraphQLQuery for PersistProjectColumnOrder {
+impl crate::runtime::GraphQLQuery for PersistProjectColumnOrder {
     type Variables = persist_project_column_order::Variables;
     type ResponseData = persist_project_column_order::ResponseData;
-    fn build_query(variables: Self::Variables) -> ::graphql_client::QueryBody<Self::Variables> {
-        graphql_client::QueryBody {
+    fn build_query(variables: Self::Variables) -> crate::runtime::QueryBody<Self::Variables> {
+        crate::runtime::QueryBody {
             variables,
             query: persist_project_column_order::QUERY,
             operation_name: persist_project_column_order::OPERATION_NAME,
         }
     }
 }
+
+impl TryFrom<serde_json::Value> for persist_project_column_order::ResponseData {
+    type Error = crate::BlipsError;
+
+    fn try_from(value: serde_json::Value) -> Result<Self, Self::Error> {
+        crate::data_from_value(persist_project_column_order::OPERATION_NAME, value)
+    }
+}
+
+/// The type of the items in the `persistProjectColumnOrder` list of the response.
+pub type PersistProjectColumnOrderPayload =
+    persist_project_column_order::PersistProjectColumnOrderPersistProjectColumnOrder;
+
+impl persist_project_column_order::ResponseData {
+    /// Returns the items in the `persistProjectColumnOrder` list.
+    pub fn items(
+        &self,
+    ) -> &[persist_project_column_order::PersistProjectColumnOrderPersistProjectColumnOrder] {
+        &self.persist_project_column_order
+    }
+}
+
+impl IntoIterator for persist_project_column_order::ResponseData {
+    type Item = persist_project_column_order::PersistProjectColumnOrderPersistProjectColumnOrder;
+    type IntoIter = std::vec::IntoIter<
+        persist_project_column_order::PersistProjectColumnOrderPersistProjectColumnOrder,
+    >;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.persist_project_column_order.into_iter()
+    }
+}
+
+impl persist_project_column_order::ProjectColumn {
+    /// Compares two values by `order`.
+    pub fn by_order(a: &Self, b: &Self) -> std::cmp::Ordering {
+        a.order.cmp(&b.order)
+    }
+}
+
+/// A field of the `ProjectColumn` returned by [`PersistProjectColumnOrder`], for selecting with
+/// [`crate::BlipsClient::execute_partial`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PersistProjectColumnOrderField {
+    Collapsed,
+    Id,
+    Name,
+    Order,
+}
+
+impl PersistProjectColumnOrderField {
+    /// Every field, for selecting the whole fragment.
+    pub const ALL: &'static [Self] = &[Self::Collapsed, Self::Id, Self::Name, Self::Order];
+}
+
+/// The `ProjectColumn` returned by [`PersistProjectColumnOrder`], with the fields selected by
+/// [`crate::BlipsClient::execute_partial`].
+///
+/// A field is `None` both when it wasn't selected and when the server returned `null` for it.
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+#[serde(default)]
+pub struct PersistProjectColumnOrderPartial {
+    pub collapsed: Option<bool>,
+    pub id: Option<String>,
+    pub name: Option<String>,
+    pub order: Option<i64>,
+}
+
+impl crate::PartialQuery for PersistProjectColumnOrder {
+    type Field = PersistProjectColumnOrderField;
+    type Partial = Vec<PersistProjectColumnOrderPartial>;
+
+    const FRAGMENT_NAME: &'static str = "ProjectColumn";
+    const ROOT_FIELD: &'static str = "persistProjectColumnOrder";
+
+    fn field_name(field: Self::Field) -> &'static str {
+        match field {
+            PersistProjectColumnOrderField::Collapsed => "collapsed",
+            PersistProjectColumnOrderField::Id => "id",
+            PersistProjectColumnOrderField::Name => "name",
+            PersistProjectColumnOrderField::Order => "order",
+        }
+    }
+}
@@ -4,6 +4,7 @@ pub mod persist_task_order {
     #![allow(dead_code)]
     use std::result::Result;
     pub const OPERATION_NAME: &str = "PersistTaskOrder";
+    pub const OPERATION_KIND: crate::OperationKind = crate::OperationKind::Mutation;
     pub const QUERY : & str = "mutation PersistTaskOrder($task_order: [OrderInput!]) {\n    persistTaskOrder(taskOrder: $task_order) {\n        ...Task\n    }\n}\n\nfragment Task on Task {\n    __typename\n    completed\n    completedAt\n    date\n    description\n    dueDate\n    groupIds\n    id\n    isRecurring\n    link\n    name\n    priorityOrder\n    spring\n}" ;
     use super::*;
     use serde::{Deserialize, Serialize};
@@ -17,26 +18,44 @@ pub mod persist_task_order {
     type ID = String;
     type Date = crate::graphql::custom_scalars::Date;
     type DateTime = crate::graphql::custom_scalars::DateTime;
-    #[derive(Serialize)]
+    #[derive(Serialize, Clone)]
+    #[serde(rename_all = "camelCase")]
     pub struct OrderInput {
         pub id: Option<ID>,
         pub order: Option<Int>,
-        #[serde(rename = "destinationGroupId")]
         pub destination_group_id: Option<ID>,
-        #[serde(rename = "sourceGroupId")]
         pub source_group_id: Option<ID>,
-        #[serde(rename = "projectColumnId")]
         pub project_column_id: Option<ID>,
     }
-    #[derive(Serialize)]
+    #[derive(Serialize, Clone, Default)]
     pub struct Variables {
         pub task_order: Option<Vec<OrderInput>>,
     }
-    impl Variables {}
-    #[derive(Deserialize, Debug)]
+    impl Variables {
+        /// Returns a new [`Variables`] from the arguments of `persistTaskOrder`.
+        ///
+        /// The parameters are in the order the arguments are declared in the schema.
+        pub fn new(task_order: Option<Vec<OrderInput>>) -> Self {
+            Self { task_order }
+        }
+
+        /// Returns the variables with `taskOrder` set to the provided value.
+        ///
+        /// A single value is sent as a list with one element.
+        pub fn with_task_order(
+            mut self,
+            task_order: impl Into<crate::OneOrMany<OrderInput>>,
+        ) -> Self {
+            self.task_order = Some(task_order.into().into_vec());
+            self
+        }
+    }
+    #[derive(Deserialize, Debug, Serialize)]
+    #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
     pub struct Task {
         pub completed: Boolean,
         #[serde(rename = "completedAt")]
+        #[cfg_attr(feature = "ts-rs", ts(as = "Option<f64>"))]
         pub completed_at: Option<DateTime>,
         pub date: Option<Date>,
         pub description: Option<String>,
@@ -50,24 +69,256 @@ pub mod persist_task_order {
         pub link: Option<String>,
         pub name: String,
         #[serde(rename = "priorityOrder")]
+        #[cfg_attr(feature = "ts-rs", ts(as = "Option<i32>"))]
         pub priority_order: Option<Int>,
         pub spring: Boolean,
     }
-    #[derive(Deserialize, Debug)]
+    #[derive(Deserialize, Debug, Serialize)]
+    #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
     pub struct ResponseData {
         #[serde(rename = "persistTaskOrder")]
         pub persist_task_order: Vec<PersistTaskOrderPersistTaskOrder>,
     }
     pub type PersistTaskOrderPersistTaskOrder = Task;
 }
-impl graphql_client::GraphQLQuery for PersistTaskOrder {
+impl crate::runtime::GraphQLQuery for PersistTaskOrder {
     type Variables = persist_task_order::Variables;
     type ResponseData = persist_task_order::ResponseData;
-    fn build_query(variables: Self::Variables) -> ::graphql_client::QueryBody<Self::Variables> {
-        graphql_client::QueryBody {
+    fn build_query(variables: Self::Variables) -> crate::runtime::QueryBody<Self::Variables> {
+        crate::runtime::QueryBody {
             variables,
             query: persist_task_order::QUERY,
             operation_name: persist_task_order::OPERATION_NAME,
         }
     }
 }
+
+impl TryFrom<serde_json::Value> for persist_task_order::ResponseData {
+    type Error = crate::BlipsError;
+
+    fn try_from(value: serde_json::Value) -> Result<Self, Self::Error> {
+        crate::data_from_value(persist_task_order::OPERATION_NAME, value)
+    }
+}
+
+/// The type of the items in the `persistTaskOrder` list of the response.
+pub type PersistTaskOrderPayload = persist_task_order::PersistTaskOrderPersistTaskOrder;
+
+impl persist_task_order::ResponseData {
+    /// Returns the items in the `persistTaskOrder` list.
+    pub fn items(&self) -> &[persist_task_order::PersistTaskOrderPersistTaskOrder] {
+        &self.persist_task_order
+    }
+}
+
+impl IntoIterator for persist_task_order::ResponseData {
+    type Item = persist_task_order::PersistTaskOrderPersistTaskOrder;
+    type IntoIter = std::vec::IntoIter<persist_task_order::PersistTaskOrderPersistTaskOrder>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.persist_task_order.into_iter()
+    }
+}
+
+impl persist_task_order::Task {
+    /// Compares two values by `priorityOrder`, sorting values without one last.
+    pub fn by_priority_order(a: &Self, b: &Self) -> std::cmp::Ordering {
+        match (a.priority_order, b.priority_order) {
+            (Some(a), Some(b)) => a.cmp(&b),
+            (Some(_), None) => std::cmp::Ordering::Less,
+            (None, Some(_)) => std::cmp::Ordering::Greater,
+            (None, None) => std::cmp::Ordering::Equal,
+        }
+    }
+}
+
+impl persist_task_order::Task {
+    /// Returns the state of the task, derived from its flags.
+    pub fn state(&self) -> crate::TaskState {
+        crate::TaskState::from_flags(self.completed, self.is_recurring, self.spring)
+    }
+}
+
+impl persist_task_order::Task {
+    /// Returns whether the task is completed.
+    ///
+    /// `completed` and `completedAt` can disagree due to races or server bugs. When they
+    /// do, `completed` is trusted and `completedAt` is ignored. Use
+    /// [`Self::validate_consistency`] to detect the disagreement.
+    pub fn is_effectively_completed(&self) -> bool {
+        self.completed
+    }
+
+    /// Returns warnings for the fields of the task that contradict each other.
+    pub fn validate_consistency(&self) -> Vec<crate::ConsistencyWarning> {
+        crate::ConsistencyWarning::for_completion(self.completed, self.completed_at.is_some())
+            .into_iter()
+            .collect()
+    }
+}
+
+impl persist_task_order::Task {
+    /// Returns the fields that differ between this task and `other`, in the order
+    /// they are selected.
+    pub fn diff(&self, other: &Self) -> Vec<crate::FieldChange> {
+        let mut changes = Vec::new();
+
+        if self.completed != other.completed {
+            changes.push(crate::FieldChange::new(
+                "completed",
+                &self.completed,
+                &other.completed,
+            ));
+        }
+        if self.completed_at != other.completed_at {
+            changes.push(crate::FieldChange::new(
+                "completedAt",
+                &self.completed_at,
+                &other.completed_at,
+            ));
+        }
+        if self.date != other.date {
+            changes.push(crate::FieldChange::new("date", &self.date, &other.date));
+        }
+        if self.description != other.description {
+            changes.push(crate::FieldChange::new(
+                "description",
+                &self.description,
+                &other.description,
+            ));
+        }
+        if self.due_date != other.due_date {
+            changes.push(crate::FieldChange::new(
+                "dueDate",
+                &self.due_date,
+                &other.due_date,
+            ));
+        }
+        if self.group_ids != other.group_ids {
+            changes.push(crate::FieldChange::new(
+                "groupIds",
+                &self.group_ids,
+                &other.group_ids,
+            ));
+        }
+        if self.id != other.id {
+            changes.push(crate::FieldChange::new("id", &self.id, &other.id));
+        }
+        if self.is_recurring != other.is_recurring {
+            changes.push(crate::FieldChange::new(
+                "isRecurring",
+                &self.is_recurring,
+                &other.is_recurring,
+            ));
+        }
+        if self.link != other.link {
+            changes.push(crate::FieldChange::new("link", &self.link, &other.link));
+        }
+        if self.name != other.name {
+            changes.push(crate::FieldChange::new("name", &self.name, &other.name));
+        }
+        if self.priority_order != other.priority_order {
+            changes.push(crate::FieldChange::new(
+                "priorityOrder",
+                &self.priority_order,
+                &other.priority_order,
+            ));
+        }
+        if self.spring != other.spring {
+            changes.push(crate::FieldChange::new(
+                "spring",
+                &self.spring,
+                &other.spring,
+            ));
+        }
+
+        changes
+    }
+}
+
+/// A field of the `Task` returned by [`PersistTaskOrder`], for selecting with
+/// [`crate::BlipsClient::execute_partial`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PersistTaskOrderField {
+    Completed,
+    CompletedAt,
+    Date,
+    Description,
+    DueDate,
+    GroupIds,
+    Id,
+    IsRecurring,
+    Link,
+    Name,
+    PriorityOrder,
+    Spring,
+}
+
+impl PersistTaskOrderField {
+    /// Every field, for selecting the whole fragment.
+    pub const ALL: &'static [Self] = &[
+        Self::Completed,
+        Self::CompletedAt,
+        Self::Date,
+        Self::Description,
+        Self::DueDate,
+        Self::GroupIds,
+        Self::Id,
+        Self::IsRecurring,
+        Self::Link,
+        Self::Name,
+        Self::PriorityOrder,
+        Self::Spring,
+    ];
+}
+
+/// The `Task` returned by [`PersistTaskOrder`], with the fields selected by
+/// [`crate::BlipsClient::execute_partial`].
+///
+/// A field is `None` both when it wasn't selected and when the server returned `null` for it.
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+#[serde(default)]
+pub struct PersistTaskOrderPartial {
+    pub completed: Option<bool>,
+    #[serde(rename = "completedAt")]
+    pub completed_at: Option<crate::graphql::custom_scalars::DateTime>,
+    pub date: Option<crate::graphql::custom_scalars::Date>,
+    pub description: Option<String>,
+    #[serde(rename = "dueDate")]
+    pub due_date: Option<crate::graphql::custom_scalars::Date>,
+    #[serde(rename = "groupIds")]
+    pub group_ids: Option<Vec<String>>,
+    pub id: Option<String>,
+    #[serde(rename = "isRecurring")]
+    pub is_recurring: Option<bool>,
+    pub link: Option<String>,
+    pub name: Option<String>,
+    #[serde(rename = "priorityOrder")]
+    pub priority_order: Option<i64>,
+    pub spring: Option<bool>,
+}
+
+impl crate::PartialQuery for PersistTaskOrder {
+    type Field = PersistTaskOrderField;
+    type Partial = Vec<PersistTaskOrderPartial>;
+
+    const FRAGMENT_NAME: &'static str = "Task";
+    const ROOT_FIELD: &'static str = "persistTaskOrder";
+
+    fn field_name(field: Self::Field) -> &'static str {
+        match field {
+            PersistTaskOrderField::Completed => "completed",
+            PersistTaskOrderField::CompletedAt => "completedAt",
+            PersistTaskOrderField::Date => "date",
+            PersistTaskOrderField::Description => "description",
+            PersistTaskOrderField::DueDate => "dueDate",
+            PersistTaskOrderField::GroupIds => "groupIds",
+            PersistTaskOrderField::Id => "id",
+            PersistTaskOrderField::IsRecurring => "isRecurring",
+            PersistTaskOrderField::Link => "link",
+            PersistTaskOrderField::Name => "name",
+            PersistTaskOrderField::PriorityOrder => "priorityOrder",
+            PersistTaskOrderField::Spring => "spring",
+        }
+    }
+}
@@ -4,6 +4,7 @@ pub mod project_columns {
     #![allow(dead_code)]
     use std::result::Result;
     pub const OPERATION_NAME: &str = "ProjectColumns";
+    pub const OPERATION_KIND: crate::OperationKind = crate::OperationKind::Query;
     pub const QUERY : & str = "query ProjectColumns {\n    projectColumns {\n        ...ProjectColumn\n    }\n}\n\nfragment ProjectColumn on ProjectColumn {\n    __typename\n    collapsed\n    id\n    name\n    order\n}" ;
     use super::*;
     use serde::{Deserialize, Serialize};
@@ -15,30 +16,112 @@ pub mod project_columns {
     type Int = i64;
     #[allow(dead_code)]
     type ID = String;
-    #[derive(Serialize)]
+    #[derive(Serialize, Clone, Default)]
     pub struct Variables;
-    #[derive(Deserialize, Debug)]
+    #[derive(Deserialize, Debug, Serialize)]
+    #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
     pub struct ProjectColumn {
         pub collapsed: Boolean,
         pub id: ID,
         pub name: String,
+        #[cfg_attr(feature = "ts-rs", ts(as = "i32"))]
         pub order: Int,
     }
-    #[derive(Deserialize, Debug)]
+    #[derive(Deserialize, Debug, Serialize)]
+    #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
     pub struct ResponseData {
         #[serde(rename = "projectColumns")]
         pub project_columns: Option<Vec<ProjectColumnsProjectColumns>>,
     }
     pub type ProjectColumnsProjectColumns = ProjectColumn;
 }
-impl graphql_client::GraphQLQuery for ProjectColumns {
+impl crate::runtime::GraphQLQuery for ProjectColumns {
     type Variables = project_columns::Variables;
     type ResponseData = project_columns::ResponseData;
-    fn build_query(variables: Self::Variables) -> ::graphql_client::QueryBody<Self::Variables> {
-        graphql_client::QueryBody {
+    fn build_query(variables: Self::Variables) -> crate::runtime::QueryBody<Self::Variables> {
+        crate::runtime::QueryBody {
             variables,
             query: project_columns::QUERY,
             operation_name: project_columns::OPERATION_NAME,
         }
     }
 }
+
+impl TryFrom<serde_json::Value> for project_columns::ResponseData {
+    type Error = crate::BlipsError;
+
+    fn try_from(value: serde_json::Value) -> Result<Self, Self::Error> {
+        crate::data_from_value(project_columns::OPERATION_NAME, value)
+    }
+}
+
+/// The type of the items in the `projectColumns` list of the response.
+pub type ProjectColumnsPayload = project_columns::ProjectColumnsProjectColumns;
+
+impl project_columns::ResponseData {
+    /// Returns the items in the `projectColumns` list.
+    pub fn items(&self) -> &[project_columns::ProjectColumnsProjectColumns] {
+        self.project_columns.as_deref().unwrap_or_default()
+    }
+}
+
+impl IntoIterator for project_columns::ResponseData {
+    type Item = project_columns::ProjectColumnsProjectColumns;
+    type IntoIter = std::vec::IntoIter<project_columns::ProjectColumnsProjectColumns>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.project_columns.unwrap_or_default().into_iter()
+    }
+}
+
+impl project_columns::ProjectColumn {
+    /// Compares two values by `order`.
+    pub fn by_order(a: &Self, b: &Self) -> std::cmp::Ordering {
+        a.order.cmp(&b.order)
+    }
+}
+
+/// A field of the `ProjectColumn` returned by [`ProjectColumns`], for selecting with
+/// [`crate::BlipsClient::execute_partial`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ProjectColumnsField {
+    Collapsed,
+    Id,
+    Name,
+    Order,
+}
+
+impl ProjectColumnsField {
+    /// Every field, for selecting the whole fragment.
+    pub const ALL: &'static [Self] = &[Self::Collapsed, Self::Id, Self::Name, Self::Order];
+}
+
+/// The `ProjectColumn` returned by [`ProjectColumns`], with the fields selected by
+/// [`crate::BlipsClient::execute_partial`].
+///
+/// A field is `None` both when it wasn't selected and when the server returned `null` for it.
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+#[serde(default)]
+pub struct ProjectColumnsPartial {
+    pub collapsed: Option<bool>,
+    pub id: Option<String>,
+    pub name: Option<String>,
+    pub order: Option<i64>,
+}
+
+impl crate::PartialQuery for ProjectColumns {
+    type Field = ProjectColumnsField;
+    type Partial = Option<Vec<ProjectColumnsPartial>>;
+
+    const FRAGMENT_NAME: &'static str = "ProjectColumn";
+    const ROOT_FIELD: &'static str = "projectColumns";
+
+    fn field_name(field: Self::Field) -> &'static str {
+        match field {
+            ProjectColumnsField::Collapsed => "collapsed",
+            ProjectColumnsField::Id => "id",
+            ProjectColumnsField::Name => "name",
+            ProjectColumnsField::Order => "order",
+        }
+    }
+}
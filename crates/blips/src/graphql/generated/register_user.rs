@@ -4,6 +4,7 @@ pub mod register_user {
     #![allow(dead_code)]
     use std::result::Result;
     pub const OPERATION_NAME: &str = "RegisterUser";
+    pub const OPERATION_KIND: crate::OperationKind = crate::OperationKind::Mutation;
     pub const QUERY : & str = "mutation RegisterUser($email: String!, $password: String!, $secret_code: String!) {\n    registerUser(email: $email, password: $password, secretCode: $secret_code) {\n        ...LoginResponse\n    }\n}\n\nfragment LoginResponse on LoginResponse {\n    __typename\n    accessToken\n}" ;
     use super::*;
     use serde::{Deserialize, Serialize};
@@ -15,33 +16,112 @@ pub mod register_user {
     type Int = i64;
     #[allow(dead_code)]
     type ID = String;
-    #[derive(Serialize)]
+    #[derive(Serialize, Clone)]
     pub struct Variables {
         pub email: String,
         pub password: String,
         pub secret_code: String,
     }
-    impl Variables {}
-    #[derive(Deserialize, Debug)]
+    impl Variables {
+        /// Returns a new [`Variables`] from the arguments of `registerUser`.
+        ///
+        /// The parameters are in the order the arguments are declared in the schema.
+        pub fn new(email: String, password: String, secret_code: String) -> Self {
+            Self {
+                email,
+                password,
+                secret_code,
+            }
+        }
+
+        /// Returns the variables with `email` set to the provided value.
+        pub fn with_email(mut self, email: String) -> Self {
+            self.email = email;
+            self
+        }
+
+        /// Returns the variables with `password` set to the provided value.
+        pub fn with_password(mut self, password: String) -> Self {
+            self.password = password;
+            self
+        }
+
+        /// Returns the variables with `secretCode` set to the provided value.
+        pub fn with_secret_code(mut self, secret_code: String) -> Self {
+            self.secret_code = secret_code;
+            self
+        }
+    }
+    #[derive(Deserialize, Debug, Serialize)]
+    #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
     pub struct LoginResponse {
         #[serde(rename = "accessToken")]
         pub access_token: String,
     }
-    #[derive(Deserialize, Debug)]
+    #[derive(Deserialize, Debug, Serialize)]
+    #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
     pub struct ResponseData {
         #[serde(rename = "registerUser")]
         pub register_user: RegisterUserRegisterUser,
     }
     pub type RegisterUserRegisterUser = LoginResponse;
 }
-impl graphql_client::GraphQLQuery for RegisterUser {
+impl crate::runtime::GraphQLQuery for RegisterUser {
     type Variables = register_user::Variables;
     type ResponseData = register_user::ResponseData;
-    fn build_query(variables: Self::Variables) -> ::graphql_client::QueryBody<Self::Variables> {
-        graphql_client::QueryBody {
+    fn build_query(variables: Self::Variables) -> crate::runtime::QueryBody<Self::Variables> {
+        crate::runtime::QueryBody {
             variables,
             query: register_user::QUERY,
             operation_name: register_user::OPERATION_NAME,
         }
     }
 }
+
+impl TryFrom<serde_json::Value> for register_user::ResponseData {
+    type Error = crate::BlipsError;
+
+    fn try_from(value: serde_json::Value) -> Result<Self, Self::Error> {
+        crate::data_from_value(register_user::OPERATION_NAME, value)
+    }
+}
+
+/// The type of the `registerUser` field of the response.
+pub type RegisterUserPayload = register_user::RegisterUserRegisterUser;
+
+/// A field of the `LoginResponse` returned by [`RegisterUser`], for selecting with
+/// [`crate::BlipsClient::execute_partial`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RegisterUserField {
+    AccessToken,
+}
+
+impl RegisterUserField {
+    /// Every field, for selecting the whole fragment.
+    pub const ALL: &'static [Self] = &[Self::AccessToken];
+}
+
+/// The `LoginResponse` returned by [`RegisterUser`], with the fields selected by
+/// [`crate::BlipsClient::execute_partial`].
+///
+/// A field is `None` both when it wasn't selected and when the server returned `null` for it.
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+#[serde(default)]
+pub struct RegisterUserPartial {
+    #[serde(rename = "accessToken")]
+    pub access_token: Option<String>,
+}
+
+impl crate::PartialQuery for RegisterUser {
+    type Field = RegisterUserField;
+    type Partial = RegisterUserPartial;
+
+    const FRAGMENT_NAME: &'static str = "LoginResponse";
+    const ROOT_FIELD: &'static str = "registerUser";
+
+    fn field_name(field: Self::Field) -> &'static str {
+        match field {
+            RegisterUserField::AccessToken => "accessToken",
+        }
+    }
+}
@@ -4,6 +4,7 @@ pub mod search {
     #![allow(dead_code)]
     use std::result::Result;
     pub const OPERATION_NAME: &str = "Search";
+    pub const OPERATION_KIND: crate::OperationKind = crate::OperationKind::Query;
     pub const QUERY : & str = "query Search($query: String!) {\n    search(query: $query) {\n        ...Search\n    }\n}\n\nfragment Search on Search {\n    __typename\n    \n}" ;
     use super::*;
     use serde::{Deserialize, Serialize};
@@ -15,28 +16,54 @@ pub mod search {
     type Int = i64;
     #[allow(dead_code)]
     type ID = String;
-    #[derive(Serialize)]
+    #[derive(Serialize, Clone)]
     pub struct Variables {
         pub query: String,
     }
-    impl Variables {}
-    #[derive(Deserialize, Debug)]
+    impl Variables {
+        /// Returns a new [`Variables`] from the arguments of `search`.
+        ///
+        /// The parameters are in the order the arguments are declared in the schema.
+        pub fn new(query: String) -> Self {
+            Self { query }
+        }
+
+        /// Returns the variables with `query` set to the provided value.
+        pub fn with_query(mut self, query: String) -> Self {
+            self.query = query;
+            self
+        }
+    }
+    #[derive(Deserialize, Debug, Serialize)]
+    #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
     #[serde(tag = "__typename")]
     pub enum Search {}
-    #[derive(Deserialize, Debug)]
+    #[derive(Deserialize, Debug, Serialize)]
+    #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
     pub struct ResponseData {
         pub search: SearchSearch,
     }
     pub type SearchSearch = Search;
 }
-impl graphql_client::GraphQLQuery for Search {
+impl crate::runtime::GraphQLQuery for Search {
     type Variables = search::Variables;
     type ResponseData = search::ResponseData;
-    fn build_query(variables: Self::Variables) -> ::graphql_client::QueryBody<Self::Variables> {
-        graphql_client::QueryBody {
+    fn build_query(variables: Self::Variables) -> crate::runtime::QueryBody<Self::Variables> {
+        crate::runtime::QueryBody {
             variables,
             query: search::QUERY,
             operation_name: search::OPERATION_NAME,
         }
     }
 }
+
+impl TryFrom<serde_json::Value> for search::ResponseData {
+    type Error = crate::BlipsError;
+
+    fn try_from(value: serde_json::Value) -> Result<Self, Self::Error> {
+        crate::data_from_value(search::OPERATION_NAME, value)
+    }
+}
+
+/// The type of the `search` field of the response.
+pub type SearchPayload = search::SearchSearch;
@@ -4,6 +4,7 @@ pub mod tags {
     #![allow(dead_code)]
     use std::result::Result;
     pub const OPERATION_NAME: &str = "Tags";
+    pub const OPERATION_KIND: crate::OperationKind = crate::OperationKind::Query;
     pub const QUERY : & str = "query Tags {\n    tags {\n        ...Tag\n    }\n}\n\nfragment Tag on Tag {\n    __typename\n    id\n    name\n    slug\n}" ;
     use super::*;
     use serde::{Deserialize, Serialize};
@@ -15,28 +16,99 @@ pub mod tags {
     type Int = i64;
     #[allow(dead_code)]
     type ID = String;
-    #[derive(Serialize)]
+    #[derive(Serialize, Clone, Default)]
     pub struct Variables;
-    #[derive(Deserialize, Debug)]
+    #[derive(Deserialize, Debug, Serialize)]
+    #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
     pub struct Tag {
         pub id: ID,
         pub name: String,
         pub slug: String,
     }
-    #[derive(Deserialize, Debug)]
+    #[derive(Deserialize, Debug, Serialize)]
+    #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
     pub struct ResponseData {
         pub tags: Option<Vec<TagsTags>>,
     }
     pub type TagsTags = Tag;
 }
-impl graphql_client::GraphQLQuery for Tags {
+impl crate::runtime::GraphQLQuery for Tags {
     type Variables = tags::Variables;
     type ResponseData = tags::ResponseData;
-    fn build_query(variables: Self::Variables) -> ::graphql_client::QueryBody<Self::Variables> {
-        graphql_client::QueryBody {
+    fn build_query(variables: Self::Variables) -> crate::runtime::QueryBody<Self::Variables> {
+        crate::runtime::QueryBody {
             variables,
             query: tags::QUERY,
             operation_name: tags::OPERATION_NAME,
         }
     }
 }
+
+impl TryFrom<serde_json::Value> for tags::ResponseData {
+    type Error = crate::BlipsError;
+
+    fn try_from(value: serde_json::Value) -> Result<Self, Self::Error> {
+        crate::data_from_value(tags::OPERATION_NAME, value)
+    }
+}
+
+/// The type of the items in the `tags` list of the response.
+pub type TagsPayload = tags::TagsTags;
+
+impl tags::ResponseData {
+    /// Returns the items in the `tags` list.
+    pub fn items(&self) -> &[tags::TagsTags] {
+        self.tags.as_deref().unwrap_or_default()
+    }
+}
+
+impl IntoIterator for tags::ResponseData {
+    type Item = tags::TagsTags;
+    type IntoIter = std::vec::IntoIter<tags::TagsTags>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.tags.unwrap_or_default().into_iter()
+    }
+}
+
+/// A field of the `Tag` returned by [`Tags`], for selecting with
+/// [`crate::BlipsClient::execute_partial`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TagsField {
+    Id,
+    Name,
+    Slug,
+}
+
+impl TagsField {
+    /// Every field, for selecting the whole fragment.
+    pub const ALL: &'static [Self] = &[Self::Id, Self::Name, Self::Slug];
+}
+
+/// The `Tag` returned by [`Tags`], with the fields selected by
+/// [`crate::BlipsClient::execute_partial`].
+///
+/// A field is `None` both when it wasn't selected and when the server returned `null` for it.
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+#[serde(default)]
+pub struct TagsPartial {
+    pub id: Option<String>,
+    pub name: Option<String>,
+    pub slug: Option<String>,
+}
+
+impl crate::PartialQuery for Tags {
+    type Field = TagsField;
+    type Partial = Option<Vec<TagsPartial>>;
+
+    const FRAGMENT_NAME: &'static str = "Tag";
+    const ROOT_FIELD: &'static str = "tags";
+
+    fn field_name(field: Self::Field) -> &'static str {
+        match field {
+            TagsField::Id => "id",
+            TagsField::Name => "name",
+            TagsField::Slug => "slug",
+        }
+    }
+}
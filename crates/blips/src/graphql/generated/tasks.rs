@@ -4,6 +4,7 @@ pub mod tasks {
     #![allow(dead_code)]
     use std::result::Result;
     pub const OPERATION_NAME: &str = "Tasks";
+    pub const OPERATION_KIND: crate::OperationKind = crate::OperationKind::Query;
     pub const QUERY : & str = "query Tasks($completed: Boolean, $date: Date, $due_date: Date, $focus: Boolean, $inbox: Boolean, $project_id: ID) {\n    tasks(completed: $completed, date: $date, dueDate: $due_date, focus: $focus, inbox: $inbox, projectId: $project_id) {\n        ...Task\n    }\n}\n\nfragment Task on Task {\n    __typename\n    completed\n    completedAt\n    date\n    description\n    dueDate\n    groupIds\n    id\n    isRecurring\n    link\n    name\n    priorityOrder\n    spring\n}" ;
     use super::*;
     use serde::{Deserialize, Serialize};
@@ -17,7 +18,7 @@ pub mod tasks {
     type ID = String;
     type Date = crate::graphql::custom_scalars::Date;
     type DateTime = crate::graphql::custom_scalars::DateTime;
-    #[derive(Serialize)]
+    #[derive(Serialize, Clone, Default)]
     pub struct Variables {
         pub completed: Option<Boolean>,
         pub date: Option<Date>,
@@ -26,11 +27,70 @@ pub mod tasks {
         pub inbox: Option<Boolean>,
         pub project_id: Option<ID>,
     }
-    impl Variables {}
-    #[derive(Deserialize, Debug)]
+    impl Variables {
+        /// Returns a new [`Variables`] from the arguments of `tasks`.
+        ///
+        /// The parameters are in the order the arguments are declared in the schema.
+        pub fn new(
+            completed: Option<Boolean>,
+            date: Option<Date>,
+            due_date: Option<Date>,
+            focus: Option<Boolean>,
+            inbox: Option<Boolean>,
+            project_id: Option<ID>,
+        ) -> Self {
+            Self {
+                completed,
+                date,
+                due_date,
+                focus,
+                inbox,
+                project_id,
+            }
+        }
+
+        /// Returns the variables with `completed` set to the provided value.
+        pub fn with_completed(mut self, completed: impl Into<Option<Boolean>>) -> Self {
+            self.completed = completed.into();
+            self
+        }
+
+        /// Returns the variables with `date` set to the provided value.
+        pub fn with_date(mut self, date: impl Into<Option<Date>>) -> Self {
+            self.date = date.into();
+            self
+        }
+
+        /// Returns the variables with `dueDate` set to the provided value.
+        pub fn with_due_date(mut self, due_date: impl Into<Option<Date>>) -> Self {
+            self.due_date = due_date.into();
+            self
+        }
+
+        /// Returns the variables with `focus` set to the provided value.
+        pub fn with_focus(mut self, focus: impl Into<Option<Boolean>>) -> Self {
+            self.focus = focus.into();
+            self
+        }
+
+        /// Returns the variables with `inbox` set to the provided value.
+        pub fn with_inbox(mut self, inbox: impl Into<Option<Boolean>>) -> Self {
+            self.inbox = inbox.into();
+            self
+        }
+
+        /// Returns the variables with `projectId` set to the provided value.
+        pub fn with_project_id(mut self, project_id: impl Into<Option<ID>>) -> Self {
+            self.project_id = project_id.into();
+            self
+        }
+    }
+    #[derive(Deserialize, Debug, Serialize)]
+    #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
     pub struct Task {
         pub completed: Boolean,
         #[serde(rename = "completedAt")]
+        #[cfg_attr(feature = "ts-rs", ts(as = "Option<f64>"))]
         pub completed_at: Option<DateTime>,
         pub date: Option<Date>,
         pub description: Option<String>,
@@ -44,23 +104,255 @@ pub mod tasks {
         pub link: Option<String>,
         pub name: String,
         #[serde(rename = "priorityOrder")]
+        #[cfg_attr(feature = "ts-rs", ts(as = "Option<i32>"))]
         pub priority_order: Option<Int>,
         pub spring: Boolean,
     }
-    #[derive(Deserialize, Debug)]
+    #[derive(Deserialize, Debug, Serialize)]
+    #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
     pub struct ResponseData {
         pub tasks: Option<Vec<TasksTasks>>,
     }
     pub type TasksTasks = Task;
 }
-impl graphql_client::GraphQLQuery for Tasks {
+impl crate::runtime::GraphQLQuery for Tasks {
     type Variables = tasks::Variables;
     type ResponseData = tasks::ResponseData;
-    fn build_query(variables: Self::Variables) -> ::graphql_client::QueryBody<Self::Variables> {
-        graphql_client::QueryBody {
+    fn build_query(variables: Self::Variables) -> crate::runtime::QueryBody<Self::Variables> {
+        crate::runtime::QueryBody {
             variables,
             query: tasks::QUERY,
             operation_name: tasks::OPERATION_NAME,
         }
     }
 }
+
+impl TryFrom<serde_json::Value> for tasks::ResponseData {
+    type Error = crate::BlipsError;
+
+    fn try_from(value: serde_json::Value) -> Result<Self, Self::Error> {
+        crate::data_from_value(tasks::OPERATION_NAME, value)
+    }
+}
+
+/// The type of the items in the `tasks` list of the response.
+pub type TasksPayload = tasks::TasksTasks;
+
+impl tasks::ResponseData {
+    /// Returns the items in the `tasks` list.
+    pub fn items(&self) -> &[tasks::TasksTasks] {
+        self.tasks.as_deref().unwrap_or_default()
+    }
+}
+
+impl IntoIterator for tasks::ResponseData {
+    type Item = tasks::TasksTasks;
+    type IntoIter = std::vec::IntoIter<tasks::TasksTasks>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.tasks.unwrap_or_default().into_iter()
+    }
+}
+
+impl tasks::Task {
+    /// Compares two values by `priorityOrder`, sorting values without one last.
+    pub fn by_priority_order(a: &Self, b: &Self) -> std::cmp::Ordering {
+        match (a.priority_order, b.priority_order) {
+            (Some(a), Some(b)) => a.cmp(&b),
+            (Some(_), None) => std::cmp::Ordering::Less,
+            (None, Some(_)) => std::cmp::Ordering::Greater,
+            (None, None) => std::cmp::Ordering::Equal,
+        }
+    }
+}
+
+impl tasks::Task {
+    /// Returns the state of the task, derived from its flags.
+    pub fn state(&self) -> crate::TaskState {
+        crate::TaskState::from_flags(self.completed, self.is_recurring, self.spring)
+    }
+}
+
+impl tasks::Task {
+    /// Returns whether the task is completed.
+    ///
+    /// `completed` and `completedAt` can disagree due to races or server bugs. When they
+    /// do, `completed` is trusted and `completedAt` is ignored. Use
+    /// [`Self::validate_consistency`] to detect the disagreement.
+    pub fn is_effectively_completed(&self) -> bool {
+        self.completed
+    }
+
+    /// Returns warnings for the fields of the task that contradict each other.
+    pub fn validate_consistency(&self) -> Vec<crate::ConsistencyWarning> {
+        crate::ConsistencyWarning::for_completion(self.completed, self.completed_at.is_some())
+            .into_iter()
+            .collect()
+    }
+}
+
+impl tasks::Task {
+    /// Returns the fields that differ between this task and `other`, in the order
+    /// they are selected.
+    pub fn diff(&self, other: &Self) -> Vec<crate::FieldChange> {
+        let mut changes = Vec::new();
+
+        if self.completed != other.completed {
+            changes.push(crate::FieldChange::new(
+                "completed",
+                &self.completed,
+                &other.completed,
+            ));
+        }
+        if self.completed_at != other.completed_at {
+            changes.push(crate::FieldChange::new(
+                "completedAt",
+                &self.completed_at,
+                &other.completed_at,
+            ));
+        }
+        if self.date != other.date {
+            changes.push(crate::FieldChange::new("date", &self.date, &other.date));
+        }
+        if self.description != other.description {
+            changes.push(crate::FieldChange::new(
+                "description",
+                &self.description,
+                &other.description,
+            ));
+        }
+        if self.due_date != other.due_date {
+            changes.push(crate::FieldChange::new(
+                "dueDate",
+                &self.due_date,
+                &other.due_date,
+            ));
+        }
+        if self.group_ids != other.group_ids {
+            changes.push(crate::FieldChange::new(
+                "groupIds",
+                &self.group_ids,
+                &other.group_ids,
+            ));
+        }
+        if self.id != other.id {
+            changes.push(crate::FieldChange::new("id", &self.id, &other.id));
+        }
+        if self.is_recurring != other.is_recurring {
+            changes.push(crate::FieldChange::new(
+                "isRecurring",
+                &self.is_recurring,
+                &other.is_recurring,
+            ));
+        }
+        if self.link != other.link {
+            changes.push(crate::FieldChange::new("link", &self.link, &other.link));
+        }
+        if self.name != other.name {
+            changes.push(crate::FieldChange::new("name", &self.name, &other.name));
+        }
+        if self.priority_order != other.priority_order {
+            changes.push(crate::FieldChange::new(
+                "priorityOrder",
+                &self.priority_order,
+                &other.priority_order,
+            ));
+        }
+        if self.spring != other.spring {
+            changes.push(crate::FieldChange::new(
+                "spring",
+                &self.spring,
+                &other.spring,
+            ));
+        }
+
+        changes
+    }
+}
+
+/// A field of the `Task` returned by [`Tasks`], for selecting with
+/// [`crate::BlipsClient::execute_partial`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TasksField {
+    Completed,
+    CompletedAt,
+    Date,
+    Description,
+    DueDate,
+    GroupIds,
+    Id,
+    IsRecurring,
+    Link,
+    Name,
+    PriorityOrder,
+    Spring,
+}
+
+impl TasksField {
+    /// Every field, for selecting the whole fragment.
+    pub const ALL: &'static [Self] = &[
+        Self::Completed,
+        Self::CompletedAt,
+        Self::Date,
+        Self::Description,
+        Self::DueDate,
+        Self::GroupIds,
+        Self::Id,
+        Self::IsRecurring,
+        Self::Link,
+        Self::Name,
+        Self::PriorityOrder,
+        Self::Spring,
+    ];
+}
+
+/// The `Task` returned by [`Tasks`], with the fields selected by
+/// [`crate::BlipsClient::execute_partial`].
+///
+/// A field is `None` both when it wasn't selected and when the server returned `null` for it.
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+#[serde(default)]
+pub struct TasksPartial {
+    pub completed: Option<bool>,
+    #[serde(rename = "completedAt")]
+    pub completed_at: Option<crate::graphql::custom_scalars::DateTime>,
+    pub date: Option<crate::graphql::custom_scalars::Date>,
+    pub description: Option<String>,
+    #[serde(rename = "dueDate")]
+    pub due_date: Option<crate::graphql::custom_scalars::Date>,
+    #[serde(rename = "groupIds")]
+    pub group_ids: Option<Vec<String>>,
+    pub id: Option<String>,
+    #[serde(rename = "isRecurring")]
+    pub is_recurring: Option<bool>,
+    pub link: Option<String>,
+    pub name: Option<String>,
+    #[serde(rename = "priorityOrder")]
+    pub priority_order: Option<i64>,
+    pub spring: Option<bool>,
+}
+
+impl crate::PartialQuery for Tasks {
+    type Field = TasksField;
+    type Partial = Option<Vec<TasksPartial>>;
+
+    const FRAGMENT_NAME: &'static str = "Task";
+    const ROOT_FIELD: &'static str = "tasks";
+
+    fn field_name(field: Self::Field) -> &'static str {
+        match field {
+            TasksField::Completed => "completed",
+            TasksField::CompletedAt => "completedAt",
+            TasksField::Date => "date",
+            TasksField::Description => "description",
+            TasksField::DueDate => "dueDate",
+            TasksField::GroupIds => "groupIds",
+            TasksField::Id => "id",
+            TasksField::IsRecurring => "isRecurring",
+            TasksField::Link => "link",
+            TasksField::Name => "name",
+            TasksField::PriorityOrder => "priorityOrder",
+            TasksField::Spring => "spring",
+        }
+    }
+}
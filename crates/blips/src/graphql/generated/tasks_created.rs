@@ -0,0 +1,207 @@
+#![allow(clippy::all, warnings)]
+pub struct TasksCreated;
+pub mod tasks_created {
+    #![allow(dead_code)]
+    use std::result::Result;
+    pub const OPERATION_NAME: &str = "TasksCreated";
+    pub const OPERATION_KIND: crate::OperationKind = crate::OperationKind::Subscription;
+    pub const QUERY : & str = "subscription TasksCreated {\n    tasksCreated {\n        ...Task\n    }\n}\n\nfragment Task on Task {\n    __typename\n    completed\n    completedAt\n    date\n    description\n    dueDate\n    groupIds\n    id\n    isRecurring\n    link\n    name\n    priorityOrder\n    spring\n}" ;
+    use super::*;
+    use serde::{Deserialize, Serialize};
+    #[allow(dead_code)]
+    type Boolean = bool;
+    #[allow(dead_code)]
+    type Float = f64;
+    #[allow(dead_code)]
+    type Int = i64;
+    #[allow(dead_code)]
+    type ID = String;
+    type Date = crate::graphql::custom_scalars::Date;
+    type DateTime = crate::graphql::custom_scalars::DateTime;
+    #[derive(Serialize, Clone, Default)]
+    pub struct Variables;
+    #[derive(Deserialize, Debug, Serialize)]
+    #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+    pub struct Task {
+        pub completed: Boolean,
+        #[serde(rename = "completedAt")]
+        #[cfg_attr(feature = "ts-rs", ts(as = "Option<f64>"))]
+        pub completed_at: Option<DateTime>,
+        pub date: Option<Date>,
+        pub description: Option<String>,
+        #[serde(rename = "dueDate")]
+        pub due_date: Option<Date>,
+        #[serde(rename = "groupIds")]
+        pub group_ids: Vec<ID>,
+        pub id: ID,
+        #[serde(rename = "isRecurring")]
+        pub is_recurring: Boolean,
+        pub link: Option<String>,
+        pub name: String,
+        #[serde(rename = "priorityOrder")]
+        #[cfg_attr(feature = "ts-rs", ts(as = "Option<i32>"))]
+        pub priority_order: Option<Int>,
+        pub spring: Boolean,
+    }
+    #[derive(Deserialize, Debug, Serialize)]
+    #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+    pub struct ResponseData {
+        #[serde(rename = "tasksCreated")]
+        pub tasks_created: Vec<TasksCreatedTasksCreated>,
+    }
+    pub type TasksCreatedTasksCreated = Task;
+}
+impl crate::runtime::GraphQLQuery for TasksCreated {
+    type Variables = tasks_created::Variables;
+    type ResponseData = tasks_created::ResponseData;
+    fn build_query(variables: Self::Variables) -> crate::runtime::QueryBody<Self::Variables> {
+        crate::runtime::QueryBody {
+            variables,
+            query: tasks_created::QUERY,
+            operation_name: tasks_created::OPERATION_NAME,
+        }
+    }
+}
+
+impl TryFrom<serde_json::Value> for tasks_created::ResponseData {
+    type Error = crate::BlipsError;
+
+    fn try_from(value: serde_json::Value) -> Result<Self, Self::Error> {
+        crate::data_from_value(tasks_created::OPERATION_NAME, value)
+    }
+}
+
+/// The type of the items in the `tasksCreated` list of the response.
+pub type TasksCreatedPayload = tasks_created::TasksCreatedTasksCreated;
+
+impl tasks_created::ResponseData {
+    /// Returns the items in the `tasksCreated` list.
+    pub fn items(&self) -> &[tasks_created::TasksCreatedTasksCreated] {
+        &self.tasks_created
+    }
+}
+
+impl IntoIterator for tasks_created::ResponseData {
+    type Item = tasks_created::TasksCreatedTasksCreated;
+    type IntoIter = std::vec::IntoIter<tasks_created::TasksCreatedTasksCreated>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.tasks_created.into_iter()
+    }
+}
+
+impl tasks_created::Task {
+    /// Compares two values by `priorityOrder`, sorting values without one last.
+    pub fn by_priority_order(a: &Self, b: &Self) -> std::cmp::Ordering {
+        match (a.priority_order, b.priority_order) {
+            (Some(a), Some(b)) => a.cmp(&b),
+            (Some(_), None) => std::cmp::Ordering::Less,
+            (None, Some(_)) => std::cmp::Ordering::Greater,
+            (None, None) => std::cmp::Ordering::Equal,
+        }
+    }
+}
+
+impl tasks_created::Task {
+    /// Returns the state of the task, derived from its flags.
+    pub fn state(&self) -> crate::TaskState {
+        crate::TaskState::from_flags(self.completed, self.is_recurring, self.spring)
+    }
+}
+
+impl tasks_created::Task {
+    /// Returns whether the task is completed.
+    ///
+    /// `completed` and `completedAt` can disagree due to races or server bugs. When they
+    /// do, `completed` is trusted and `completedAt` is ignored. Use
+    /// [`Self::validate_consistency`] to detect the disagreement.
+    pub fn is_effectively_completed(&self) -> bool {
+        self.completed
+    }
+
+    /// Returns warnings for the fields of the task that contradict each other.
+    pub fn validate_consistency(&self) -> Vec<crate::ConsistencyWarning> {
+        crate::ConsistencyWarning::for_completion(self.completed, self.completed_at.is_some())
+            .into_iter()
+            .collect()
+    }
+}
+
+impl tasks_created::Task {
+    /// Returns the fields that differ between this task and `other`, in the order
+    /// they are selected.
+    pub fn diff(&self, other: &Self) -> Vec<crate::FieldChange> {
+        let mut changes = Vec::new();
+
+        if self.completed != other.completed {
+            changes.push(crate::FieldChange::new(
+                "completed",
+                &self.completed,
+                &other.completed,
+            ));
+        }
+        if self.completed_at != other.completed_at {
+            changes.push(crate::FieldChange::new(
+                "completedAt",
+                &self.completed_at,
+                &other.completed_at,
+            ));
+        }
+        if self.date != other.date {
+            changes.push(crate::FieldChange::new("date", &self.date, &other.date));
+        }
+        if self.description != other.description {
+            changes.push(crate::FieldChange::new(
+                "description",
+                &self.description,
+                &other.description,
+            ));
+        }
+        if self.due_date != other.due_date {
+            changes.push(crate::FieldChange::new(
+                "dueDate",
+                &self.due_date,
+                &other.due_date,
+            ));
+        }
+        if self.group_ids != other.group_ids {
+            changes.push(crate::FieldChange::new(
+                "groupIds",
+                &self.group_ids,
+                &other.group_ids,
+            ));
+        }
+        if self.id != other.id {
+            changes.push(crate::FieldChange::new("id", &self.id, &other.id));
+        }
+        if self.is_recurring != other.is_recurring {
+            changes.push(crate::FieldChange::new(
+                "isRecurring",
+                &self.is_recurring,
+                &other.is_recurring,
+            ));
+        }
+        if self.link != other.link {
+            changes.push(crate::FieldChange::new("link", &self.link, &other.link));
+        }
+        if self.name != other.name {
+            changes.push(crate::FieldChange::new("name", &self.name, &other.name));
+        }
+        if self.priority_order != other.priority_order {
+            changes.push(crate::FieldChange::new(
+                "priorityOrder",
+                &self.priority_order,
+                &other.priority_order,
+            ));
+        }
+        if self.spring != other.spring {
+            changes.push(crate::FieldChange::new(
+                "spring",
+                &self.spring,
+                &other.spring,
+            ));
+        }
+
+        changes
+    }
+}
@@ -4,6 +4,7 @@ pub mod update_board {
     #![allow(dead_code)]
     use std::result::Result;
     pub const OPERATION_NAME: &str = "UpdateBoard";
+    pub const OPERATION_KIND: crate::OperationKind = crate::OperationKind::Mutation;
     pub const QUERY : & str = "mutation UpdateBoard($emoji: String, $name: String, $board_id: ID!, $project_completed_project_column_id: ID, $task_completed_project_column_id: ID) {\n    updateBoard(emoji: $emoji, name: $name, boardId: $board_id, projectCompletedProjectColumnId: $project_completed_project_column_id, taskCompletedProjectColumnId: $task_completed_project_column_id) {\n        ...Board\n    }\n}\n\nfragment Board on Board {\n    __typename\n    archivedAt\n    emoji\n    id\n    lastViewedAt\n    name\n    projectCompletedProjectColumnId\n    taskCompletedProjectColumnId\n}" ;
     use super::*;
     use serde::{Deserialize, Serialize};
@@ -16,7 +17,7 @@ pub mod update_board {
     #[allow(dead_code)]
     type ID = String;
     type DateTime = crate::graphql::custom_scalars::DateTime;
-    #[derive(Serialize)]
+    #[derive(Serialize, Clone)]
     pub struct Variables {
         pub emoji: Option<String>,
         pub name: Option<String>,
@@ -24,14 +25,151 @@ pub mod update_board {
         pub project_completed_project_column_id: Option<ID>,
         pub task_completed_project_column_id: Option<ID>,
     }
-    impl Variables {}
-    #[derive(Deserialize, Debug)]
+    impl Variables {
+        /// Returns a new [`Variables`] from the arguments of `updateBoard`.
+        ///
+        /// The parameters are in the order the arguments are declared in the schema.
+        pub fn new(
+            emoji: Option<String>,
+            name: Option<String>,
+            board_id: ID,
+            project_completed_project_column_id: Option<ID>,
+            task_completed_project_column_id: Option<ID>,
+        ) -> Self {
+            Self {
+                emoji,
+                name,
+                board_id,
+                project_completed_project_column_id,
+                task_completed_project_column_id,
+            }
+        }
+
+        /// Returns the variables with `emoji` set to the provided value.
+        pub fn with_emoji(mut self, emoji: impl Into<Option<String>>) -> Self {
+            self.emoji = emoji.into();
+            self
+        }
+
+        /// Returns the variables with `name` set to the provided value.
+        pub fn with_name(mut self, name: impl Into<Option<String>>) -> Self {
+            self.name = name.into();
+            self
+        }
+
+        /// Returns the variables with `boardId` set to the provided value.
+        pub fn with_board_id(mut self, board_id: ID) -> Self {
+            self.board_id = board_id;
+            self
+        }
+
+        /// Returns the variables with `projectCompletedProjectColumnId` set to the provided value.
+        pub fn with_project_completed_project_column_id(
+            mut self,
+            project_completed_project_column_id: impl Into<Option<ID>>,
+        ) -> Self {
+            self.project_completed_project_column_id = project_completed_project_column_id.into();
+            self
+        }
+
+        /// Returns the variables with `taskCompletedProjectColumnId` set to the provided value.
+        pub fn with_task_completed_project_column_id(
+            mut self,
+            task_completed_project_column_id: impl Into<Option<ID>>,
+        ) -> Self {
+            self.task_completed_project_column_id = task_completed_project_column_id.into();
+            self
+        }
+    }
+
+    /// The arguments of `updateBoard` to change, for executing with
+    /// [`crate::BlipsClient::execute_changeset`].
+    ///
+    /// Only the arguments that are set with the `with_*` setters are sent. The rest are left out
+    /// of the request, so the server leaves the fields they update untouched.
+    #[derive(Serialize, Clone)]
+    pub struct Changeset {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        emoji: Option<Option<String>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        name: Option<Option<String>>,
+        board_id: ID,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        project_completed_project_column_id: Option<Option<ID>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        task_completed_project_column_id: Option<Option<ID>>,
+    }
+
+    impl Changeset {
+        /// Returns a new [`Changeset`] with the required arguments of `updateBoard`, which
+        /// doesn't change anything else.
+        pub fn new(board_id: ID) -> Self {
+            Self {
+                emoji: None,
+                name: None,
+                board_id,
+                project_completed_project_column_id: None,
+                task_completed_project_column_id: None,
+            }
+        }
+
+        /// Returns the changeset with `emoji` set to the provided value, which is sent
+        /// as `null` when it is `None`.
+        pub fn with_emoji(mut self, emoji: impl Into<Option<String>>) -> Self {
+            self.emoji = Some(emoji.into());
+            self
+        }
+
+        /// Returns the changeset with `name` set to the provided value, which is sent
+        /// as `null` when it is `None`.
+        pub fn with_name(mut self, name: impl Into<Option<String>>) -> Self {
+            self.name = Some(name.into());
+            self
+        }
+
+        /// Returns the changeset with `projectCompletedProjectColumnId` set to the provided value, which is sent
+        /// as `null` when it is `None`.
+        pub fn with_project_completed_project_column_id(
+            mut self,
+            project_completed_project_column_id: impl Into<Option<ID>>,
+        ) -> Self {
+            self.project_completed_project_column_id =
+                Some(project_completed_project_column_id.into());
+            self
+        }
+
+        /// Returns the changeset with `taskCompletedProjectColumnId` set to the provided value, which is sent
+        /// as `null` when it is `None`.
+        pub fn with_task_completed_project_column_id(
+            mut self,
+            task_completed_project_column_id: impl Into<Option<ID>>,
+        ) -> Self {
+            self.task_completed_project_column_id = Some(task_completed_project_column_id.into());
+            self
+        }
+    }
+
+    impl crate::ChangesetQuery for super::UpdateBoard {
+        type Changeset = Changeset;
+
+        fn build_changeset_query(changeset: Changeset) -> crate::runtime::QueryBody<Changeset> {
+            crate::runtime::QueryBody {
+                variables: changeset,
+                query: QUERY,
+                operation_name: OPERATION_NAME,
+            }
+        }
+    }
+    #[derive(Deserialize, Debug, Serialize)]
+    #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
     pub struct Board {
         #[serde(rename = "archivedAt")]
+        #[cfg_attr(feature = "ts-rs", ts(as = "Option<f64>"))]
         pub archived_at: Option<DateTime>,
         pub emoji: Option<String>,
         pub id: ID,
         #[serde(rename = "lastViewedAt")]
+        #[cfg_attr(feature = "ts-rs", ts(as = "Option<f64>"))]
         pub last_viewed_at: Option<DateTime>,
         pub name: String,
         #[serde(rename = "projectCompletedProjectColumnId")]
@@ -39,21 +177,99 @@ pub mod update_board {
         #[serde(rename = "taskCompletedProjectColumnId")]
         pub task_completed_project_column_id: Option<ID>,
     }
-    #[derive(Deserialize, Debug)]
+    #[derive(Deserialize, Debug, Serialize)]
+    #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
     pub struct ResponseData {
         #[serde(rename = "updateBoard")]
         pub update_board: UpdateBoardUpdateBoard,
     }
     pub type UpdateBoardUpdateBoard = Board;
 }
-impl graphql_client::GraphQLQuery for UpdateBoard {
+impl crate::runtime::GraphQLQuery for UpdateBoard {
     type Variables = update_board::Variables;
     type ResponseData = update_board::ResponseData;
-    fn build_query(variables: Self::Variables) -> ::graphql_client::QueryBody<Self::Variables> {
-        graphql_client::QueryBody {
+    fn build_query(variables: Self::Variables) -> crate::runtime::QueryBody<Self::Variables> {
+        crate::runtime::QueryBody {
             variables,
             query: update_board::QUERY,
             operation_name: update_board::OPERATION_NAME,
         }
     }
 }
+
+impl TryFrom<serde_json::Value> for update_board::ResponseData {
+    type Error = crate::BlipsError;
+
+    fn try_from(value: serde_json::Value) -> Result<Self, Self::Error> {
+        crate::data_from_value(update_board::OPERATION_NAME, value)
+    }
+}
+
+/// The type of the `updateBoard` field of the response.
+pub type UpdateBoardPayload = update_board::UpdateBoardUpdateBoard;
+
+/// A field of the `Board` returned by [`UpdateBoard`], for selecting with
+/// [`crate::BlipsClient::execute_partial`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UpdateBoardField {
+    ArchivedAt,
+    Emoji,
+    Id,
+    LastViewedAt,
+    Name,
+    ProjectCompletedProjectColumnId,
+    TaskCompletedProjectColumnId,
+}
+
+impl UpdateBoardField {
+    /// Every field, for selecting the whole fragment.
+    pub const ALL: &'static [Self] = &[
+        Self::ArchivedAt,
+        Self::Emoji,
+        Self::Id,
+        Self::LastViewedAt,
+        Self::Name,
+        Self::ProjectCompletedProjectColumnId,
+        Self::TaskCompletedProjectColumnId,
+    ];
+}
+
+/// The `Board` returned by [`UpdateBoard`], with the fields selected by
+/// [`crate::BlipsClient::execute_partial`].
+///
+/// A field is `None` both when it wasn't selected and when the server returned `null` for it.
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+#[serde(default)]
+pub struct UpdateBoardPartial {
+    #[serde(rename = "archivedAt")]
+    pub archived_at: Option<crate::graphql::custom_scalars::DateTime>,
+    pub emoji: Option<String>,
+    pub id: Option<String>,
+    #[serde(rename = "lastViewedAt")]
+    pub last_viewed_at: Option<crate::graphql::custom_scalars::DateTime>,
+    pub name: Option<String>,
+    #[serde(rename = "projectCompletedProjectColumnId")]
+    pub project_completed_project_column_id: Option<String>,
+    #[serde(rename = "taskCompletedProjectColumnId")]
+    pub task_completed_project_column_id: Option<String>,
+}
+
+impl crate::PartialQuery for UpdateBoard {
+    type Field = UpdateBoardField;
+    type Partial = UpdateBoardPartial;
+
+    const FRAGMENT_NAME: &'static str = "Board";
+    const ROOT_FIELD: &'static str = "updateBoard";
+
+    fn field_name(field: Self::Field) -> &'static str {
+        match field {
+            UpdateBoardField::ArchivedAt => "archivedAt",
+            UpdateBoardField::Emoji => "emoji",
+            UpdateBoardField::Id => "id",
+            UpdateBoardField::LastViewedAt => "lastViewedAt",
+            UpdateBoardField::Name => "name",
+            UpdateBoardField::ProjectCompletedProjectColumnId => "projectCompletedProjectColumnId",
+            UpdateBoardField::TaskCompletedProjectColumnId => "taskCompletedProjectColumnId",
+        }
+    }
+}
@@ -4,7 +4,8 @@ pub mod update_container {
     #![allow(dead_code)]
     use std::result::Result;
     pub const OPERATION_NAME: &str = "UpdateContainer";
-    pub const QUERY : & str = "mutation UpdateContainer($collapse_completed: Boolean, $date: Date, $note_body: String, $project_id: ID, $state: DiaryStateEnum) {\n    updateContainer(collapseCompleted: $collapse_completed, date: $date, noteBody: $note_body, projectId: $project_id, state: $state) {\n        ...Container\n    }\n}\n\nfragment Container on Container {\n    __typename\n    \n}" ;
+    pub const OPERATION_KIND: crate::OperationKind = crate::OperationKind::Mutation;
+    pub const QUERY : & str = "mutation UpdateContainer($collapse_completed: Boolean, $date: Date, $note_body: String, $project_id: ID, $state: DiaryStateEnum) {\n    updateContainer(collapseCompleted: $collapse_completed, date: $date, noteBody: $note_body, projectId: $project_id, state: $state) {\n        ...Container\n    }\n}\n\nfragment Container on Container {\n    __typename\n    collapseCompleted\n    id\n    noteBody\n    supportsNotes\n    ... on Diary {\n        diaryDate: date\n    }\n    ... on Project {\n        completed\n        completedAt\n        projectDate: date\n        endDate\n        link\n        name\n        order\n        springEnabled\n    }\n}" ;
     use super::*;
     use serde::{Deserialize, Serialize};
     #[allow(dead_code)]
@@ -16,32 +17,61 @@ pub mod update_container {
     #[allow(dead_code)]
     type ID = String;
     type Date = crate::graphql::custom_scalars::Date;
-    #[derive(Debug)]
+    type DateTime = crate::graphql::custom_scalars::DateTime;
+    #[derive(Clone, Debug)]
     pub enum DiaryStateEnum {
-        FRESH,
-        PLANNED,
+        Fresh,
+        Planned,
         Other(String),
     }
     impl ::serde::Serialize for DiaryStateEnum {
         fn serialize<S: serde::Serializer>(&self, ser: S) -> Result<S::Ok, S::Error> {
             ser.serialize_str(match *self {
-                DiaryStateEnum::FRESH => "FRESH",
-                DiaryStateEnum::PLANNED => "PLANNED",
+                DiaryStateEnum::Fresh => "FRESH",
+                DiaryStateEnum::Planned => "PLANNED",
                 DiaryStateEnum::Other(ref s) => &s,
             })
         }
     }
+    impl DiaryStateEnum {
+        /// Returns the value as the schema declares it, such as for logging or metric labels,
+        /// without allocating.
+        ///
+        /// Values that the schema didn't declare when the SDK was generated are returned as
+        /// `"__OTHER"`. Use [`DiaryStateEnum::other_value`] to get them.
+        pub fn as_str(&self) -> &'static str {
+            match self {
+                DiaryStateEnum::Fresh => "FRESH",
+                DiaryStateEnum::Planned => "PLANNED",
+                DiaryStateEnum::Other(_) => "__OTHER",
+            }
+        }
+
+        /// Returns the value of a variant that the schema didn't declare when the SDK was
+        /// generated, or `None` for the declared variants.
+        pub fn other_value(&self) -> Option<&str> {
+            match self {
+                DiaryStateEnum::Other(value) => Some(value),
+                _ => None,
+            }
+        }
+    }
+    impl From<DiaryStateEnum> for &'static str {
+        fn from(value: DiaryStateEnum) -> Self {
+            value.as_str()
+        }
+    }
     impl<'de> ::serde::Deserialize<'de> for DiaryStateEnum {
         fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
             let s: String = ::serde::Deserialize::deserialize(deserializer)?;
             match s.as_str() {
-                "FRESH" => Ok(DiaryStateEnum::FRESH),
-                "PLANNED" => Ok(DiaryStateEnum::PLANNED),
+                "FRESH" => Ok(DiaryStateEnum::Fresh),
+                "PLANNED" => Ok(DiaryStateEnum::Planned),
                 _ => Ok(DiaryStateEnum::Other(s)),
             }
         }
     }
-    #[derive(Serialize)]
+    #[derive(Serialize, Clone, Default)]
     pub struct Variables {
         pub collapse_completed: Option<Boolean>,
         pub date: Option<Date>,
@@ -49,29 +79,217 @@ pub mod update_container {
         pub project_id: Option<ID>,
         pub state: Option<DiaryStateEnum>,
     }
-    impl Variables {}
-    #[derive(Deserialize, Debug)]
+    impl Variables {
+        /// Returns a new [`Variables`] from the arguments of `updateContainer`.
+        ///
+        /// The parameters are in the order the arguments are declared in the schema.
+        pub fn new(
+            collapse_completed: Option<Boolean>,
+            date: Option<Date>,
+            note_body: Option<String>,
+            project_id: Option<ID>,
+            state: Option<DiaryStateEnum>,
+        ) -> Self {
+            Self {
+                collapse_completed,
+                date,
+                note_body,
+                project_id,
+                state,
+            }
+        }
+
+        /// Returns the variables with `collapseCompleted` set to the provided value.
+        pub fn with_collapse_completed(
+            mut self,
+            collapse_completed: impl Into<Option<Boolean>>,
+        ) -> Self {
+            self.collapse_completed = collapse_completed.into();
+            self
+        }
+
+        /// Returns the variables with `date` set to the provided value.
+        pub fn with_date(mut self, date: impl Into<Option<Date>>) -> Self {
+            self.date = date.into();
+            self
+        }
+
+        /// Returns the variables with `noteBody` set to the provided value.
+        pub fn with_note_body(mut self, note_body: impl Into<Option<String>>) -> Self {
+            self.note_body = note_body.into();
+            self
+        }
+
+        /// Returns the variables with `projectId` set to the provided value.
+        pub fn with_project_id(mut self, project_id: impl Into<Option<ID>>) -> Self {
+            self.project_id = project_id.into();
+            self
+        }
+
+        /// Returns the variables with `state` set to the provided value.
+        pub fn with_state(mut self, state: impl Into<Option<DiaryStateEnum>>) -> Self {
+            self.state = state.into();
+            self
+        }
+    }
+
+    /// The arguments of `updateContainer` to change, for executing with
+    /// [`crate::BlipsClient::execute_changeset`].
+    ///
+    /// Only the arguments that are set with the `with_*` setters are sent. The rest are left out
+    /// of the request, so the server leaves the fields they update untouched.
+    #[derive(Serialize, Clone)]
+    pub struct Changeset {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        collapse_completed: Option<Option<Boolean>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        date: Option<Option<Date>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        note_body: Option<Option<String>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        project_id: Option<Option<ID>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        state: Option<Option<DiaryStateEnum>>,
+    }
+
+    impl Changeset {
+        /// Returns a new [`Changeset`] with the required arguments of `updateContainer`, which
+        /// doesn't change anything else.
+        pub fn new() -> Self {
+            Self {
+                collapse_completed: None,
+                date: None,
+                note_body: None,
+                project_id: None,
+                state: None,
+            }
+        }
+
+        /// Returns the changeset with `collapseCompleted` set to the provided value, which is sent
+        /// as `null` when it is `None`.
+        pub fn with_collapse_completed(
+            mut self,
+            collapse_completed: impl Into<Option<Boolean>>,
+        ) -> Self {
+            self.collapse_completed = Some(collapse_completed.into());
+            self
+        }
+
+        /// Returns the changeset with `date` set to the provided value, which is sent
+        /// as `null` when it is `None`.
+        pub fn with_date(mut self, date: impl Into<Option<Date>>) -> Self {
+            self.date = Some(date.into());
+            self
+        }
+
+        /// Returns the changeset with `noteBody` set to the provided value, which is sent
+        /// as `null` when it is `None`.
+        pub fn with_note_body(mut self, note_body: impl Into<Option<String>>) -> Self {
+            self.note_body = Some(note_body.into());
+            self
+        }
+
+        /// Returns the changeset with `projectId` set to the provided value, which is sent
+        /// as `null` when it is `None`.
+        pub fn with_project_id(mut self, project_id: impl Into<Option<ID>>) -> Self {
+            self.project_id = Some(project_id.into());
+            self
+        }
+
+        /// Returns the changeset with `state` set to the provided value, which is sent
+        /// as `null` when it is `None`.
+        pub fn with_state(mut self, state: impl Into<Option<DiaryStateEnum>>) -> Self {
+            self.state = Some(state.into());
+            self
+        }
+    }
+
+    impl crate::ChangesetQuery for super::UpdateContainer {
+        type Changeset = Changeset;
+
+        fn build_changeset_query(changeset: Changeset) -> crate::runtime::QueryBody<Changeset> {
+            crate::runtime::QueryBody {
+                variables: changeset,
+                query: QUERY,
+                operation_name: OPERATION_NAME,
+            }
+        }
+    }
+    #[derive(Deserialize, Debug, Serialize)]
+    #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+    pub struct Container {
+        #[serde(rename = "collapseCompleted")]
+        pub collapse_completed: Boolean,
+        pub id: ID,
+        #[serde(rename = "noteBody")]
+        pub note_body: Option<String>,
+        #[serde(rename = "supportsNotes")]
+        pub supports_notes: Boolean,
+        #[serde(flatten)]
+        pub on: ContainerOn,
+    }
+    #[derive(Deserialize, Debug, Serialize)]
+    #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
     #[serde(tag = "__typename")]
-    pub enum Container {
-        Diary,
+    pub enum ContainerOn {
+        Diary(ContainerOnDiary),
         Inbox,
-        Project,
+        Project(ContainerOnProject),
+        #[cfg_attr(feature = "ts-rs", ts(skip))]
+        #[serde(other)]
+        Unknown,
     }
-    #[derive(Deserialize, Debug)]
+    #[derive(Deserialize, Debug, Serialize)]
+    #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+    pub struct ContainerOnDiary {
+        #[serde(rename = "diaryDate")]
+        pub diary_date: Date,
+    }
+    #[derive(Deserialize, Debug, Serialize)]
+    #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+    pub struct ContainerOnProject {
+        pub completed: Boolean,
+        #[serde(rename = "completedAt")]
+        #[cfg_attr(feature = "ts-rs", ts(as = "Option<f64>"))]
+        pub completed_at: Option<DateTime>,
+        #[serde(rename = "projectDate")]
+        pub project_date: Option<Date>,
+        #[serde(rename = "endDate")]
+        pub end_date: Option<Date>,
+        pub link: Option<String>,
+        pub name: String,
+        #[cfg_attr(feature = "ts-rs", ts(as = "Option<i32>"))]
+        pub order: Option<Int>,
+        #[serde(rename = "springEnabled")]
+        pub spring_enabled: Boolean,
+    }
+    #[derive(Deserialize, Debug, Serialize)]
+    #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
     pub struct ResponseData {
         #[serde(rename = "updateContainer")]
         pub update_container: UpdateContainerUpdateContainer,
     }
     pub type UpdateContainerUpdateContainer = Container;
 }
-impl graphql_client::GraphQLQuery for UpdateContainer {
+impl crate::runtime::GraphQLQuery for UpdateContainer {
     type Variables = update_container::Variables;
     type ResponseData = update_container::ResponseData;
-    fn build_query(variables: Self::Variables) -> ::graphql_client::QueryBody<Self::Variables> {
-        graphql_client::QueryBody {
+    fn build_query(variables: Self::Variables) -> crate::runtime::QueryBody<Self::Variables> {
+        crate::runtime::QueryBody {
             variables,
             query: update_container::QUERY,
             operation_name: update_container::OPERATION_NAME,
         }
     }
 }
+
+impl TryFrom<serde_json::Value> for update_container::ResponseData {
+    type Error = crate::BlipsError;
+
+    fn try_from(value: serde_json::Value) -> Result<Self, Self::Error> {
+        crate::data_from_value(update_container::OPERATION_NAME, value)
+    }
+}
+
+/// The type of the `updateContainer` field of the response.
+pub type UpdateContainerPayload = update_container::UpdateContainerUpdateContainer;
@@ -4,6 +4,7 @@ pub mod update_diary {
     #![allow(dead_code)]
     use std::result::Result;
     pub const OPERATION_NAME: &str = "UpdateDiary";
+    pub const OPERATION_KIND: crate::OperationKind = crate::OperationKind::Mutation;
     pub const QUERY : & str = "mutation UpdateDiary($date: Date!, $note_body: String!) {\n    updateDiary(date: $date, noteBody: $note_body) {\n        ...Diary\n    }\n}\n\nfragment Diary on Diary {\n    __typename\n    collapseCompleted\n    date\n    id\n    noteBody\n    supportsNotes\n}" ;
     use super::*;
     use serde::{Deserialize, Serialize};
@@ -16,13 +17,33 @@ pub mod update_diary {
     #[allow(dead_code)]
     type ID = String;
     type Date = crate::graphql::custom_scalars::Date;
-    #[derive(Serialize)]
+    #[derive(Serialize, Clone)]
     pub struct Variables {
         pub date: Date,
         pub note_body: String,
     }
-    impl Variables {}
-    #[derive(Deserialize, Debug)]
+    impl Variables {
+        /// Returns a new [`Variables`] from the arguments of `updateDiary`.
+        ///
+        /// The parameters are in the order the arguments are declared in the schema.
+        pub fn new(date: Date, note_body: String) -> Self {
+            Self { date, note_body }
+        }
+
+        /// Returns the variables with `date` set to the provided value.
+        pub fn with_date(mut self, date: Date) -> Self {
+            self.date = date;
+            self
+        }
+
+        /// Returns the variables with `noteBody` set to the provided value.
+        pub fn with_note_body(mut self, note_body: String) -> Self {
+            self.note_body = note_body;
+            self
+        }
+    }
+    #[derive(Deserialize, Debug, Serialize)]
+    #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
     pub struct Diary {
         #[serde(rename = "collapseCompleted")]
         pub collapse_completed: Boolean,
@@ -33,21 +54,90 @@ pub mod update_diary {
         #[serde(rename = "supportsNotes")]
         pub supports_notes: Boolean,
     }
-    #[derive(Deserialize, Debug)]
+    #[derive(Deserialize, Debug, Serialize)]
+    #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
     pub struct ResponseData {
         #[serde(rename = "updateDiary")]
         pub update_diary: UpdateDiaryUpdateDiary,
     }
     pub type UpdateDiaryUpdateDiary = Diary;
 }
-impl graphql_client::GraphQLQuery for UpdateDiary {
+impl crate::runtime::GraphQLQuery for UpdateDiary {
     type Variables = update_diary::Variables;
     type ResponseData = update_diary::ResponseData;
-    fn build_query(variables: Self::Variables) -> ::graphql_client::QueryBody<Self::Variables> {
-        graphql_client::QueryBody {
+    fn build_query(variables: Self::Variables) -> crate::runtime::QueryBody<Self::Variables> {
+        crate::runtime::QueryBody {
             variables,
             query: update_diary::QUERY,
             operation_name: update_diary::OPERATION_NAME,
         }
     }
 }
+
+impl TryFrom<serde_json::Value> for update_diary::ResponseData {
+    type Error = crate::BlipsError;
+
+    fn try_from(value: serde_json::Value) -> Result<Self, Self::Error> {
+        crate::data_from_value(update_diary::OPERATION_NAME, value)
+    }
+}
+
+/// The type of the `updateDiary` field of the response.
+pub type UpdateDiaryPayload = update_diary::UpdateDiaryUpdateDiary;
+
+/// A field of the `Diary` returned by [`UpdateDiary`], for selecting with
+/// [`crate::BlipsClient::execute_partial`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UpdateDiaryField {
+    CollapseCompleted,
+    Date,
+    Id,
+    NoteBody,
+    SupportsNotes,
+}
+
+impl UpdateDiaryField {
+    /// Every field, for selecting the whole fragment.
+    pub const ALL: &'static [Self] = &[
+        Self::CollapseCompleted,
+        Self::Date,
+        Self::Id,
+        Self::NoteBody,
+        Self::SupportsNotes,
+    ];
+}
+
+/// The `Diary` returned by [`UpdateDiary`], with the fields selected by
+/// [`crate::BlipsClient::execute_partial`].
+///
+/// A field is `None` both when it wasn't selected and when the server returned `null` for it.
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+#[serde(default)]
+pub struct UpdateDiaryPartial {
+    #[serde(rename = "collapseCompleted")]
+    pub collapse_completed: Option<bool>,
+    pub date: Option<crate::graphql::custom_scalars::Date>,
+    pub id: Option<String>,
+    #[serde(rename = "noteBody")]
+    pub note_body: Option<String>,
+    #[serde(rename = "supportsNotes")]
+    pub supports_notes: Option<bool>,
+}
+
+impl crate::PartialQuery for UpdateDiary {
+    type Field = UpdateDiaryField;
+    type Partial = UpdateDiaryPartial;
+
+    const FRAGMENT_NAME: &'static str = "Diary";
+    const ROOT_FIELD: &'static str = "updateDiary";
+
+    fn field_name(field: Self::Field) -> &'static str {
+        match field {
+            UpdateDiaryField::CollapseCompleted => "collapseCompleted",
+            UpdateDiaryField::Date => "date",
+            UpdateDiaryField::Id => "id",
+            UpdateDiaryField::NoteBody => "noteBody",
+            UpdateDiaryField::SupportsNotes => "supportsNotes",
+        }
+    }
+}
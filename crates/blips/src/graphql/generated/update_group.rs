@@ -4,6 +4,7 @@ pub mod update_group {
     #![allow(dead_code)]
     use std::result::Result;
     pub const OPERATION_NAME: &str = "UpdateGroup";
+    pub const OPERATION_KIND: crate::OperationKind = crate::OperationKind::Mutation;
     pub const QUERY : & str = "mutation UpdateGroup($collapsed: Boolean, $date: Date, $group_id: ID!, $keep_tasks: Boolean, $name: String) {\n    updateGroup(collapsed: $collapsed, date: $date, groupId: $group_id, keepTasks: $keep_tasks, name: $name) {\n        ...Group\n    }\n}\n\nfragment Group on Group {\n    __typename\n    collapsed\n    date\n    id\n    keepTasks\n    name\n    order\n    projectId\n}" ;
     use super::*;
     use serde::{Deserialize, Serialize};
@@ -16,7 +17,7 @@ pub mod update_group {
     #[allow(dead_code)]
     type ID = String;
     type Date = crate::graphql::custom_scalars::Date;
-    #[derive(Serialize)]
+    #[derive(Serialize, Clone)]
     pub struct Variables {
         pub collapsed: Option<Boolean>,
         pub date: Option<Date>,
@@ -24,8 +25,130 @@ pub mod update_group {
         pub keep_tasks: Option<Boolean>,
         pub name: Option<String>,
     }
-    impl Variables {}
-    #[derive(Deserialize, Debug)]
+    impl Variables {
+        /// Returns a new [`Variables`] from the arguments of `updateGroup`.
+        ///
+        /// The parameters are in the order the arguments are declared in the schema.
+        pub fn new(
+            collapsed: Option<Boolean>,
+            date: Option<Date>,
+            group_id: ID,
+            keep_tasks: Option<Boolean>,
+            name: Option<String>,
+        ) -> Self {
+            Self {
+                collapsed,
+                date,
+                group_id,
+                keep_tasks,
+                name,
+            }
+        }
+
+        /// Returns the variables with `collapsed` set to the provided value.
+        pub fn with_collapsed(mut self, collapsed: impl Into<Option<Boolean>>) -> Self {
+            self.collapsed = collapsed.into();
+            self
+        }
+
+        /// Returns the variables with `date` set to the provided value.
+        pub fn with_date(mut self, date: impl Into<Option<Date>>) -> Self {
+            self.date = date.into();
+            self
+        }
+
+        /// Returns the variables with `groupId` set to the provided value.
+        pub fn with_group_id(mut self, group_id: ID) -> Self {
+            self.group_id = group_id;
+            self
+        }
+
+        /// Returns the variables with `keepTasks` set to the provided value.
+        pub fn with_keep_tasks(mut self, keep_tasks: impl Into<Option<Boolean>>) -> Self {
+            self.keep_tasks = keep_tasks.into();
+            self
+        }
+
+        /// Returns the variables with `name` set to the provided value.
+        pub fn with_name(mut self, name: impl Into<Option<String>>) -> Self {
+            self.name = name.into();
+            self
+        }
+    }
+
+    /// The arguments of `updateGroup` to change, for executing with
+    /// [`crate::BlipsClient::execute_changeset`].
+    ///
+    /// Only the arguments that are set with the `with_*` setters are sent. The rest are left out
+    /// of the request, so the server leaves the fields they update untouched.
+    #[derive(Serialize, Clone)]
+    pub struct Changeset {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        collapsed: Option<Option<Boolean>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        date: Option<Option<Date>>,
+        group_id: ID,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        keep_tasks: Option<Option<Boolean>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        name: Option<Option<String>>,
+    }
+
+    impl Changeset {
+        /// Returns a new [`Changeset`] with the required arguments of `updateGroup`, which
+        /// doesn't change anything else.
+        pub fn new(group_id: ID) -> Self {
+            Self {
+                collapsed: None,
+                date: None,
+                group_id,
+                keep_tasks: None,
+                name: None,
+            }
+        }
+
+        /// Returns the changeset with `collapsed` set to the provided value, which is sent
+        /// as `null` when it is `None`.
+        pub fn with_collapsed(mut self, collapsed: impl Into<Option<Boolean>>) -> Self {
+            self.collapsed = Some(collapsed.into());
+            self
+        }
+
+        /// Returns the changeset with `date` set to the provided value, which is sent
+        /// as `null` when it is `None`.
+        pub fn with_date(mut self, date: impl Into<Option<Date>>) -> Self {
+            self.date = Some(date.into());
+            self
+        }
+
+        /// Returns the changeset with `keepTasks` set to the provided value, which is sent
+        /// as `null` when it is `None`.
+        pub fn with_keep_tasks(mut self, keep_tasks: impl Into<Option<Boolean>>) -> Self {
+            self.keep_tasks = Some(keep_tasks.into());
+            self
+        }
+
+        /// Returns the changeset with `name` set to the provided value, which is sent
+        /// as `null` when it is `None`.
+        pub fn with_name(mut self, name: impl Into<Option<String>>) -> Self {
+            self.name = Some(name.into());
+            self
+        }
+    }
+
+    impl crate::ChangesetQuery for super::UpdateGroup {
+        type Changeset = Changeset;
+
+        fn build_changeset_query(changeset: Changeset) -> crate::runtime::QueryBody<Changeset> {
+            crate::runtime::QueryBody {
+                variables: changeset,
+                query: QUERY,
+                operation_name: OPERATION_NAME,
+            }
+        }
+    }
+    #[derive(Deserialize, Debug, Serialize)]
+    #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
     pub struct Group {
         pub collapsed: Option<Boolean>,
         pub date: Option<Date>,
@@ -33,25 +156,114 @@ pub mod update_group {
         #[serde(rename = "keepTasks")]
         pub keep_tasks: Boolean,
         pub name: String,
+        #[cfg_attr(feature = "ts-rs", ts(as = "Option<i32>"))]
         pub order: Option<Int>,
         #[serde(rename = "projectId")]
         pub project_id: Option<ID>,
     }
-    #[derive(Deserialize, Debug)]
+    #[derive(Deserialize, Debug, Serialize)]
+    #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
     pub struct ResponseData {
         #[serde(rename = "updateGroup")]
         pub update_group: UpdateGroupUpdateGroup,
     }
     pub type UpdateGroupUpdateGroup = Group;
 }
-impl graphql_client::GraphQLQuery for UpdateGroup {
+impl crate::runtime::GraphQLQuery for UpdateGroup {
     type Variables = update_group::Variables;
     type ResponseData = update_group::ResponseData;
-    fn build_query(variables: Self::Variables) -> ::graphql_client::QueryBody<Self::Variables> {
-        graphql_client::QueryBody {
+    fn build_query(variables: Self::Variables) -> crate::runtime::QueryBody<Self::Variables> {
+        crate::runtime::QueryBody {
             variables,
             query: update_group::QUERY,
             operation_name: update_group::OPERATION_NAME,
         }
     }
 }
+
+impl TryFrom<serde_json::Value> for update_group::ResponseData {
+    type Error = crate::BlipsError;
+
+    fn try_from(value: serde_json::Value) -> Result<Self, Self::Error> {
+        crate::data_from_value(update_group::OPERATION_NAME, value)
+    }
+}
+
+/// The type of the `updateGroup` field of the response.
+pub type UpdateGroupPayload = update_group::UpdateGroupUpdateGroup;
+
+impl update_group::Group {
+    /// Compares two values by `order`, sorting values without one last.
+    pub fn by_order(a: &Self, b: &Self) -> std::cmp::Ordering {
+        match (a.order, b.order) {
+            (Some(a), Some(b)) => a.cmp(&b),
+            (Some(_), None) => std::cmp::Ordering::Less,
+            (None, Some(_)) => std::cmp::Ordering::Greater,
+            (None, None) => std::cmp::Ordering::Equal,
+        }
+    }
+}
+
+/// A field of the `Group` returned by [`UpdateGroup`], for selecting with
+/// [`crate::BlipsClient::execute_partial`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UpdateGroupField {
+    Collapsed,
+    Date,
+    Id,
+    KeepTasks,
+    Name,
+    Order,
+    ProjectId,
+}
+
+impl UpdateGroupField {
+    /// Every field, for selecting the whole fragment.
+    pub const ALL: &'static [Self] = &[
+        Self::Collapsed,
+        Self::Date,
+        Self::Id,
+        Self::KeepTasks,
+        Self::Name,
+        Self::Order,
+        Self::ProjectId,
+    ];
+}
+
+/// The `Group` returned by [`UpdateGroup`], with the fields selected by
+/// [`crate::BlipsClient::execute_partial`].
+///
+/// A field is `None` both when it wasn't selected and when the server returned `null` for it.
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+#[serde(default)]
+pub struct UpdateGroupPartial {
+    pub collapsed: Option<bool>,
+    pub date: Option<crate::graphql::custom_scalars::Date>,
+    pub id: Option<String>,
+    #[serde(rename = "keepTasks")]
+    pub keep_tasks: Option<bool>,
+    pub name: Option<String>,
+    pub order: Option<i64>,
+    #[serde(rename = "projectId")]
+    pub project_id: Option<String>,
+}
+
+impl crate::PartialQuery for UpdateGroup {
+    type Field = UpdateGroupField;
+    type Partial = UpdateGroupPartial;
+
+    const FRAGMENT_NAME: &'static str = "Group";
+    const ROOT_FIELD: &'static str = "updateGroup";
+
+    fn field_name(field: Self::Field) -> &'static str {
+        match field {
+            UpdateGroupField::Collapsed => "collapsed",
+            UpdateGroupField::Date => "date",
+            UpdateGroupField::Id => "id",
+            UpdateGroupField::KeepTasks => "keepTasks",
+            UpdateGroupField::Name => "name",
+            UpdateGroupField::Order => "order",
+            UpdateGroupField::ProjectId => "projectId",
+        }
+    }
+}
@@ -4,6 +4,7 @@ pub mod update_note {
     #![allow(dead_code)]
     use std::result::Result;
     pub const OPERATION_NAME: &str = "UpdateNote";
+    pub const OPERATION_KIND: crate::OperationKind = crate::OperationKind::Mutation;
     pub const QUERY : & str = "mutation UpdateNote($body: String, $date: Date, $end_date: Date, $hide_preview: Boolean, $last_updated_at: DateTime, $name: String, $note_id: ID!, $project_id: ID) {\n    updateNote(body: $body, date: $date, endDate: $end_date, hidePreview: $hide_preview, lastUpdatedAt: $last_updated_at, name: $name, noteId: $note_id, projectId: $project_id) {\n        ...UpdateNoteResult\n    }\n}\n\nfragment UpdateNoteResult on UpdateNoteResult {\n    __typename\n    \n}" ;
     use super::*;
     use serde::{Deserialize, Serialize};
@@ -17,7 +18,7 @@ pub mod update_note {
     type ID = String;
     type Date = crate::graphql::custom_scalars::Date;
     type DateTime = crate::graphql::custom_scalars::DateTime;
-    #[derive(Serialize)]
+    #[derive(Serialize, Clone)]
     pub struct Variables {
         pub body: Option<String>,
         pub date: Option<Date>,
@@ -28,28 +29,225 @@ pub mod update_note {
         pub note_id: ID,
         pub project_id: Option<ID>,
     }
-    impl Variables {}
-    #[derive(Deserialize, Debug)]
+    impl Variables {
+        /// Returns a new [`Variables`] from the arguments of `updateNote`.
+        ///
+        /// The parameters are in the order the arguments are declared in the schema.
+        pub fn new(
+            body: Option<String>,
+            date: Option<Date>,
+            end_date: Option<Date>,
+            hide_preview: Option<Boolean>,
+            last_updated_at: Option<DateTime>,
+            name: Option<String>,
+            note_id: ID,
+            project_id: Option<ID>,
+        ) -> Self {
+            Self {
+                body,
+                date,
+                end_date,
+                hide_preview,
+                last_updated_at,
+                name,
+                note_id,
+                project_id,
+            }
+        }
+
+        /// Returns the variables with `body` set to the provided value.
+        pub fn with_body(mut self, body: impl Into<Option<String>>) -> Self {
+            self.body = body.into();
+            self
+        }
+
+        /// Returns the variables with `date` set to the provided value.
+        pub fn with_date(mut self, date: impl Into<Option<Date>>) -> Self {
+            self.date = date.into();
+            self
+        }
+
+        /// Returns the variables with `endDate` set to the provided value.
+        pub fn with_end_date(mut self, end_date: impl Into<Option<Date>>) -> Self {
+            self.end_date = end_date.into();
+            self
+        }
+
+        /// Returns the variables with `hidePreview` set to the provided value.
+        pub fn with_hide_preview(mut self, hide_preview: impl Into<Option<Boolean>>) -> Self {
+            self.hide_preview = hide_preview.into();
+            self
+        }
+
+        /// Returns the variables with `lastUpdatedAt` set to the provided value.
+        pub fn with_last_updated_at(
+            mut self,
+            last_updated_at: impl Into<Option<DateTime>>,
+        ) -> Self {
+            self.last_updated_at = last_updated_at.into();
+            self
+        }
+
+        /// Returns the variables with `name` set to the provided value.
+        pub fn with_name(mut self, name: impl Into<Option<String>>) -> Self {
+            self.name = name.into();
+            self
+        }
+
+        /// Returns the variables with `noteId` set to the provided value.
+        pub fn with_note_id(mut self, note_id: ID) -> Self {
+            self.note_id = note_id;
+            self
+        }
+
+        /// Returns the variables with `projectId` set to the provided value.
+        pub fn with_project_id(mut self, project_id: impl Into<Option<ID>>) -> Self {
+            self.project_id = project_id.into();
+            self
+        }
+    }
+
+    /// The arguments of `updateNote` to change, for executing with
+    /// [`crate::BlipsClient::execute_changeset`].
+    ///
+    /// Only the arguments that are set with the `with_*` setters are sent. The rest are left out
+    /// of the request, so the server leaves the fields they update untouched.
+    #[derive(Serialize, Clone)]
+    pub struct Changeset {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        body: Option<Option<String>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        date: Option<Option<Date>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        end_date: Option<Option<Date>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        hide_preview: Option<Option<Boolean>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        last_updated_at: Option<Option<DateTime>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        name: Option<Option<String>>,
+        note_id: ID,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        project_id: Option<Option<ID>>,
+    }
+
+    impl Changeset {
+        /// Returns a new [`Changeset`] with the required arguments of `updateNote`, which
+        /// doesn't change anything else.
+        pub fn new(note_id: ID) -> Self {
+            Self {
+                body: None,
+                date: None,
+                end_date: None,
+                hide_preview: None,
+                last_updated_at: None,
+                name: None,
+                note_id,
+                project_id: None,
+            }
+        }
+
+        /// Returns the changeset with `body` set to the provided value, which is sent
+        /// as `null` when it is `None`.
+        pub fn with_body(mut self, body: impl Into<Option<String>>) -> Self {
+            self.body = Some(body.into());
+            self
+        }
+
+        /// Returns the changeset with `date` set to the provided value, which is sent
+        /// as `null` when it is `None`.
+        pub fn with_date(mut self, date: impl Into<Option<Date>>) -> Self {
+            self.date = Some(date.into());
+            self
+        }
+
+        /// Returns the changeset with `endDate` set to the provided value, which is sent
+        /// as `null` when it is `None`.
+        pub fn with_end_date(mut self, end_date: impl Into<Option<Date>>) -> Self {
+            self.end_date = Some(end_date.into());
+            self
+        }
+
+        /// Returns the changeset with `hidePreview` set to the provided value, which is sent
+        /// as `null` when it is `None`.
+        pub fn with_hide_preview(mut self, hide_preview: impl Into<Option<Boolean>>) -> Self {
+            self.hide_preview = Some(hide_preview.into());
+            self
+        }
+
+        /// Returns the changeset with `lastUpdatedAt` set to the provided value, which is sent
+        /// as `null` when it is `None`.
+        pub fn with_last_updated_at(
+            mut self,
+            last_updated_at: impl Into<Option<DateTime>>,
+        ) -> Self {
+            self.last_updated_at = Some(last_updated_at.into());
+            self
+        }
+
+        /// Returns the changeset with `name` set to the provided value, which is sent
+        /// as `null` when it is `None`.
+        pub fn with_name(mut self, name: impl Into<Option<String>>) -> Self {
+            self.name = Some(name.into());
+            self
+        }
+
+        /// Returns the changeset with `projectId` set to the provided value, which is sent
+        /// as `null` when it is `None`.
+        pub fn with_project_id(mut self, project_id: impl Into<Option<ID>>) -> Self {
+            self.project_id = Some(project_id.into());
+            self
+        }
+    }
+
+    impl crate::ChangesetQuery for super::UpdateNote {
+        type Changeset = Changeset;
+
+        fn build_changeset_query(changeset: Changeset) -> crate::runtime::QueryBody<Changeset> {
+            crate::runtime::QueryBody {
+                variables: changeset,
+                query: QUERY,
+                operation_name: OPERATION_NAME,
+            }
+        }
+    }
+    #[derive(Deserialize, Debug, Serialize)]
+    #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
     #[serde(tag = "__typename")]
     pub enum UpdateNoteResult {
         NoteUpdateOutdated,
         NoteUpdated,
+        #[cfg_attr(feature = "ts-rs", ts(skip))]
+        #[serde(other)]
+        Unknown,
     }
-    #[derive(Deserialize, Debug)]
+    #[derive(Deserialize, Debug, Serialize)]
+    #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
     pub struct ResponseData {
         #[serde(rename = "updateNote")]
         pub update_note: UpdateNoteUpdateNote,
     }
     pub type UpdateNoteUpdateNote = UpdateNoteResult;
 }
-impl graphql_client::GraphQLQuery for UpdateNote {
+impl crate::runtime::GraphQLQuery for UpdateNote {
     type Variables = update_note::Variables;
     type ResponseData = update_note::ResponseData;
-    fn build_query(variables: Self::Variables) -> ::graphql_client::QueryBody<Self::Variables> {
-        graphql_client::QueryBody {
+    fn build_query(variables: Self::Variables) -> crate::runtime::QueryBody<Self::Variables> {
+        crate::runtime::QueryBody {
             variables,
             query: update_note::QUERY,
             operation_name: update_note::OPERATION_NAME,
         }
     }
 }
+
+impl TryFrom<serde_json::Value> for update_note::ResponseData {
+    type Error = crate::BlipsError;
+
+    fn try_from(value: serde_json::Value) -> Result<Self, Self::Error> {
+        crate::data_from_value(update_note::OPERATION_NAME, value)
+    }
+}
+
+/// The type of the `updateNote` field of the response.
+pub type UpdateNotePayload = update_note::UpdateNoteUpdateNote;
@@ -4,6 +4,7 @@ pub mod update_project {
     #![allow(dead_code)]
     use std::result::Result;
     pub const OPERATION_NAME: &str = "UpdateProject";
+    pub const OPERATION_KIND: crate::OperationKind = crate::OperationKind::Mutation;
     pub const QUERY : & str = "mutation UpdateProject($board_id: ID, $date: Date, $end_date: Date, $name: String, $project_id: ID!) {\n    updateProject(boardId: $board_id, date: $date, endDate: $end_date, name: $name, projectId: $project_id) {\n        ...Project\n    }\n}\n\nfragment Project on Project {\n    __typename\n    collapseCompleted\n    completed\n    completedAt\n    date\n    endDate\n    id\n    link\n    name\n    noteBody\n    order\n    springEnabled\n    supportsNotes\n}" ;
     use super::*;
     use serde::{Deserialize, Serialize};
@@ -17,7 +18,7 @@ pub mod update_project {
     type ID = String;
     type Date = crate::graphql::custom_scalars::Date;
     type DateTime = crate::graphql::custom_scalars::DateTime;
-    #[derive(Serialize)]
+    #[derive(Serialize, Clone)]
     pub struct Variables {
         pub board_id: Option<ID>,
         pub date: Option<Date>,
@@ -25,13 +26,136 @@ pub mod update_project {
         pub name: Option<String>,
         pub project_id: ID,
     }
-    impl Variables {}
-    #[derive(Deserialize, Debug)]
+    impl Variables {
+        /// Returns a new [`Variables`] from the arguments of `updateProject`.
+        ///
+        /// The parameters are in the order the arguments are declared in the schema.
+        pub fn new(
+            board_id: Option<ID>,
+            date: Option<Date>,
+            end_date: Option<Date>,
+            name: Option<String>,
+            project_id: ID,
+        ) -> Self {
+            Self {
+                board_id,
+                date,
+                end_date,
+                name,
+                project_id,
+            }
+        }
+
+        /// Returns the variables with `boardId` set to the provided value.
+        pub fn with_board_id(mut self, board_id: impl Into<Option<ID>>) -> Self {
+            self.board_id = board_id.into();
+            self
+        }
+
+        /// Returns the variables with `date` set to the provided value.
+        pub fn with_date(mut self, date: impl Into<Option<Date>>) -> Self {
+            self.date = date.into();
+            self
+        }
+
+        /// Returns the variables with `endDate` set to the provided value.
+        pub fn with_end_date(mut self, end_date: impl Into<Option<Date>>) -> Self {
+            self.end_date = end_date.into();
+            self
+        }
+
+        /// Returns the variables with `name` set to the provided value.
+        pub fn with_name(mut self, name: impl Into<Option<String>>) -> Self {
+            self.name = name.into();
+            self
+        }
+
+        /// Returns the variables with `projectId` set to the provided value.
+        pub fn with_project_id(mut self, project_id: ID) -> Self {
+            self.project_id = project_id;
+            self
+        }
+    }
+
+    /// The arguments of `updateProject` to change, for executing with
+    /// [`crate::BlipsClient::execute_changeset`].
+    ///
+    /// Only the arguments that are set with the `with_*` setters are sent. The rest are left out
+    /// of the request, so the server leaves the fields they update untouched.
+    #[derive(Serialize, Clone)]
+    pub struct Changeset {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        board_id: Option<Option<ID>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        date: Option<Option<Date>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        end_date: Option<Option<Date>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        name: Option<Option<String>>,
+        project_id: ID,
+    }
+
+    impl Changeset {
+        /// Returns a new [`Changeset`] with the required arguments of `updateProject`, which
+        /// doesn't change anything else.
+        pub fn new(project_id: ID) -> Self {
+            Self {
+                board_id: None,
+                date: None,
+                end_date: None,
+                name: None,
+                project_id,
+            }
+        }
+
+        /// Returns the changeset with `boardId` set to the provided value, which is sent
+        /// as `null` when it is `None`.
+        pub fn with_board_id(mut self, board_id: impl Into<Option<ID>>) -> Self {
+            self.board_id = Some(board_id.into());
+            self
+        }
+
+        /// Returns the changeset with `date` set to the provided value, which is sent
+        /// as `null` when it is `None`.
+        pub fn with_date(mut self, date: impl Into<Option<Date>>) -> Self {
+            self.date = Some(date.into());
+            self
+        }
+
+        /// Returns the changeset with `endDate` set to the provided value, which is sent
+        /// as `null` when it is `None`.
+        pub fn with_end_date(mut self, end_date: impl Into<Option<Date>>) -> Self {
+            self.end_date = Some(end_date.into());
+            self
+        }
+
+        /// Returns the changeset with `name` set to the provided value, which is sent
+        /// as `null` when it is `None`.
+        pub fn with_name(mut self, name: impl Into<Option<String>>) -> Self {
+            self.name = Some(name.into());
+            self
+        }
+    }
+
+    impl crate::ChangesetQuery for super::UpdateProject {
+        type Changeset = Changeset;
+
+        fn build_changeset_query(changeset: Changeset) -> crate::runtime::QueryBody<Changeset> {
+            crate::runtime::QueryBody {
+                variables: changeset,
+                query: QUERY,
+                operation_name: OPERATION_NAME,
+            }
+        }
+    }
+    #[derive(Deserialize, Debug, Serialize)]
+    #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
     pub struct Project {
         #[serde(rename = "collapseCompleted")]
         pub collapse_completed: Boolean,
         pub completed: Boolean,
         #[serde(rename = "completedAt")]
+        #[cfg_attr(feature = "ts-rs", ts(as = "Option<f64>"))]
         pub completed_at: Option<DateTime>,
         pub date: Option<Date>,
         #[serde(rename = "endDate")]
@@ -41,27 +165,240 @@ pub mod update_project {
         pub name: String,
         #[serde(rename = "noteBody")]
         pub note_body: Option<String>,
+        #[cfg_attr(feature = "ts-rs", ts(as = "Option<i32>"))]
         pub order: Option<Int>,
         #[serde(rename = "springEnabled")]
         pub spring_enabled: Boolean,
         #[serde(rename = "supportsNotes")]
         pub supports_notes: Boolean,
     }
-    #[derive(Deserialize, Debug)]
+    #[derive(Deserialize, Debug, Serialize)]
+    #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
     pub struct ResponseData {
         #[serde(rename = "updateProject")]
         pub update_project: UpdateProjectUpdateProject,
     }
     pub type UpdateProjectUpdateProject = Project;
 }
-impl graphql_client::GraphQLQuery for UpdateProject {
+impl crate::runtime::GraphQLQuery for UpdateProject {
     type Variables = update_project::Variables;
     type ResponseData = update_project::ResponseData;
-    fn build_query(variables: Self::Variables) -> ::graphql_client::QueryBody<Self::Variables> {
-        graphql_client::QueryBody {
+    fn build_query(variables: Self::Variables) -> crate::runtime::QueryBody<Self::Variables> {
+        crate::runtime::QueryBody {
             variables,
             query: update_project::QUERY,
             operation_name: update_project::OPERATION_NAME,
         }
     }
 }
+
+impl TryFrom<serde_json::Value> for update_project::ResponseData {
+    type Error = crate::BlipsError;
+
+    fn try_from(value: serde_json::Value) -> Result<Self, Self::Error> {
+        crate::data_from_value(update_project::OPERATION_NAME, value)
+    }
+}
+
+/// The type of the `updateProject` field of the response.
+pub type UpdateProjectPayload = update_project::UpdateProjectUpdateProject;
+
+impl update_project::Project {
+    /// Compares two values by `order`, sorting values without one last.
+    pub fn by_order(a: &Self, b: &Self) -> std::cmp::Ordering {
+        match (a.order, b.order) {
+            (Some(a), Some(b)) => a.cmp(&b),
+            (Some(_), None) => std::cmp::Ordering::Less,
+            (None, Some(_)) => std::cmp::Ordering::Greater,
+            (None, None) => std::cmp::Ordering::Equal,
+        }
+    }
+}
+
+impl update_project::Project {
+    /// Returns the state of the project, derived from its flags.
+    pub fn state(&self) -> crate::ProjectState {
+        crate::ProjectState::from_flags(self.completed, self.spring_enabled)
+    }
+}
+
+impl update_project::Project {
+    /// Returns whether the project is completed.
+    ///
+    /// `completed` and `completedAt` can disagree due to races or server bugs. When they
+    /// do, `completed` is trusted and `completedAt` is ignored. Use
+    /// [`Self::validate_consistency`] to detect the disagreement.
+    pub fn is_effectively_completed(&self) -> bool {
+        self.completed
+    }
+
+    /// Returns warnings for the fields of the project that contradict each other.
+    pub fn validate_consistency(&self) -> Vec<crate::ConsistencyWarning> {
+        crate::ConsistencyWarning::for_completion(self.completed, self.completed_at.is_some())
+            .into_iter()
+            .collect()
+    }
+}
+
+impl update_project::Project {
+    /// Returns the fields that differ between this project and `other`, in the order
+    /// they are selected.
+    pub fn diff(&self, other: &Self) -> Vec<crate::FieldChange> {
+        let mut changes = Vec::new();
+
+        if self.collapse_completed != other.collapse_completed {
+            changes.push(crate::FieldChange::new(
+                "collapseCompleted",
+                &self.collapse_completed,
+                &other.collapse_completed,
+            ));
+        }
+        if self.completed != other.completed {
+            changes.push(crate::FieldChange::new(
+                "completed",
+                &self.completed,
+                &other.completed,
+            ));
+        }
+        if self.completed_at != other.completed_at {
+            changes.push(crate::FieldChange::new(
+                "completedAt",
+                &self.completed_at,
+                &other.completed_at,
+            ));
+        }
+        if self.date != other.date {
+            changes.push(crate::FieldChange::new("date", &self.date, &other.date));
+        }
+        if self.end_date != other.end_date {
+            changes.push(crate::FieldChange::new(
+                "endDate",
+                &self.end_date,
+                &other.end_date,
+            ));
+        }
+        if self.id != other.id {
+            changes.push(crate::FieldChange::new("id", &self.id, &other.id));
+        }
+        if self.link != other.link {
+            changes.push(crate::FieldChange::new("link", &self.link, &other.link));
+        }
+        if self.name != other.name {
+            changes.push(crate::FieldChange::new("name", &self.name, &other.name));
+        }
+        if self.note_body != other.note_body {
+            changes.push(crate::FieldChange::new(
+                "noteBody",
+                &self.note_body,
+                &other.note_body,
+            ));
+        }
+        if self.order != other.order {
+            changes.push(crate::FieldChange::new("order", &self.order, &other.order));
+        }
+        if self.spring_enabled != other.spring_enabled {
+            changes.push(crate::FieldChange::new(
+                "springEnabled",
+                &self.spring_enabled,
+                &other.spring_enabled,
+            ));
+        }
+        if self.supports_notes != other.supports_notes {
+            changes.push(crate::FieldChange::new(
+                "supportsNotes",
+                &self.supports_notes,
+                &other.supports_notes,
+            ));
+        }
+
+        changes
+    }
+}
+
+/// A field of the `Project` returned by [`UpdateProject`], for selecting with
+/// [`crate::BlipsClient::execute_partial`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UpdateProjectField {
+    CollapseCompleted,
+    Completed,
+    CompletedAt,
+    Date,
+    EndDate,
+    Id,
+    Link,
+    Name,
+    NoteBody,
+    Order,
+    SpringEnabled,
+    SupportsNotes,
+}
+
+impl UpdateProjectField {
+    /// Every field, for selecting the whole fragment.
+    pub const ALL: &'static [Self] = &[
+        Self::CollapseCompleted,
+        Self::Completed,
+        Self::CompletedAt,
+        Self::Date,
+        Self::EndDate,
+        Self::Id,
+        Self::Link,
+        Self::Name,
+        Self::NoteBody,
+        Self::Order,
+        Self::SpringEnabled,
+        Self::SupportsNotes,
+    ];
+}
+
+/// The `Project` returned by [`UpdateProject`], with the fields selected by
+/// [`crate::BlipsClient::execute_partial`].
+///
+/// A field is `None` both when it wasn't selected and when the server returned `null` for it.
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+#[serde(default)]
+pub struct UpdateProjectPartial {
+    #[serde(rename = "collapseCompleted")]
+    pub collapse_completed: Option<bool>,
+    pub completed: Option<bool>,
+    #[serde(rename = "completedAt")]
+    pub completed_at: Option<crate::graphql::custom_scalars::DateTime>,
+    pub date: Option<crate::graphql::custom_scalars::Date>,
+    #[serde(rename = "endDate")]
+    pub end_date: Option<crate::graphql::custom_scalars::Date>,
+    pub id: Option<String>,
+    pub link: Option<String>,
+    pub name: Option<String>,
+    #[serde(rename = "noteBody")]
+    pub note_body: Option<String>,
+    pub order: Option<i64>,
+    #[serde(rename = "springEnabled")]
+    pub spring_enabled: Option<bool>,
+    #[serde(rename = "supportsNotes")]
+    pub supports_notes: Option<bool>,
+}
+
+impl crate::PartialQuery for UpdateProject {
+    type Field = UpdateProjectField;
+    type Partial = UpdateProjectPartial;
+
+    const FRAGMENT_NAME: &'static str = "Project";
+    const ROOT_FIELD: &'static str = "updateProject";
+
+    fn field_name(field: Self::Field) -> &'static str {
+        match field {
+            UpdateProjectField::CollapseCompleted => "collapseCompleted",
+            UpdateProjectField::Completed => "completed",
+            UpdateProjectField::CompletedAt => "completedAt",
+            UpdateProjectField::Date => "date",
+            UpdateProjectField::EndDate => "endDate",
+            UpdateProjectField::Id => "id",
+            UpdateProjectField::Link => "link",
+            UpdateProjectField::Name => "name",
+            UpdateProjectField::NoteBody => "noteBody",
+            UpdateProjectField::Order => "order",
+            UpdateProjectField::SpringEnabled => "springEnabled",
+            UpdateProjectField::SupportsNotes => "supportsNotes",
+        }
+    }
+}
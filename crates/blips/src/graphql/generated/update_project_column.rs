@@ -4,6 +4,7 @@ pub mod update_project_column {
     #![allow(dead_code)]
     use std::result::Result;
     pub const OPERATION_NAME: &str = "UpdateProjectColumn";
+    pub const OPERATION_KIND: crate::OperationKind = crate::OperationKind::Mutation;
     pub const QUERY : & str = "mutation UpdateProjectColumn($collapsed: Boolean, $name: String, $project_column_id: ID!) {\n    updateProjectColumn(collapsed: $collapsed, name: $name, projectColumnId: $project_column_id) {\n        ...ProjectColumn\n    }\n}\n\nfragment ProjectColumn on ProjectColumn {\n    __typename\n    collapsed\n    id\n    name\n    order\n}" ;
     use super::*;
     use serde::{Deserialize, Serialize};
@@ -15,35 +16,186 @@ pub mod update_project_column {
     type Int = i64;
     #[allow(dead_code)]
     type ID = String;
-    #[derive(Serialize)]
+    #[derive(Serialize, Clone)]
     pub struct Variables {
         pub collapsed: Option<Boolean>,
         pub name: Option<String>,
         pub project_column_id: ID,
     }
-    impl Variables {}
-    #[derive(Deserialize, Debug)]
+    impl Variables {
+        /// Returns a new [`Variables`] from the arguments of `updateProjectColumn`.
+        ///
+        /// The parameters are in the order the arguments are declared in the schema.
+        pub fn new(
+            collapsed: Option<Boolean>,
+            name: Option<String>,
+            project_column_id: ID,
+        ) -> Self {
+            Self {
+                collapsed,
+                name,
+                project_column_id,
+            }
+        }
+
+        /// Returns the variables with `collapsed` set to the provided value.
+        pub fn with_collapsed(mut self, collapsed: impl Into<Option<Boolean>>) -> Self {
+            self.collapsed = collapsed.into();
+            self
+        }
+
+        /// Returns the variables with `name` set to the provided value.
+        pub fn with_name(mut self, name: impl Into<Option<String>>) -> Self {
+            self.name = name.into();
+            self
+        }
+
+        /// Returns the variables with `projectColumnId` set to the provided value.
+        pub fn with_project_column_id(mut self, project_column_id: ID) -> Self {
+            self.project_column_id = project_column_id;
+            self
+        }
+    }
+
+    /// The arguments of `updateProjectColumn` to change, for executing with
+    /// [`crate::BlipsClient::execute_changeset`].
+    ///
+    /// Only the arguments that are set with the `with_*` setters are sent. The rest are left out
+    /// of the request, so the server leaves the fields they update untouched.
+    #[derive(Serialize, Clone)]
+    pub struct Changeset {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        collapsed: Option<Option<Boolean>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        name: Option<Option<String>>,
+        project_column_id: ID,
+    }
+
+    impl Changeset {
+        /// Returns a new [`Changeset`] with the required arguments of `updateProjectColumn`, which
+        /// doesn't change anything else.
+        pub fn new(project_column_id: ID) -> Self {
+            Self {
+                collapsed: None,
+                name: None,
+                project_column_id,
+            }
+        }
+
+        /// Returns the changeset with `collapsed` set to the provided value, which is sent
+        /// as `null` when it is `None`.
+        pub fn with_collapsed(mut self, collapsed: impl Into<Option<Boolean>>) -> Self {
+            self.collapsed = Some(collapsed.into());
+            self
+        }
+
+        /// Returns the changeset with `name` set to the provided value, which is sent
+        /// as `null` when it is `None`.
+        pub fn with_name(mut self, name: impl Into<Option<String>>) -> Self {
+            self.name = Some(name.into());
+            self
+        }
+    }
+
+    impl crate::ChangesetQuery for super::UpdateProjectColumn {
+        type Changeset = Changeset;
+
+        fn build_changeset_query(changeset: Changeset) -> crate::runtime::QueryBody<Changeset> {
+            crate::runtime::QueryBody {
+                variables: changeset,
+                query: QUERY,
+                operation_name: OPERATION_NAME,
+            }
+        }
+    }
+    #[derive(Deserialize, Debug, Serialize)]
+    #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
     pub struct ProjectColumn {
         pub collapsed: Boolean,
         pub id: ID,
         pub name: String,
+        #[cfg_attr(feature = "ts-rs", ts(as = "i32"))]
         pub order: Int,
     }
-    #[derive(Deserialize, Debug)]
+    #[derive(Deserialize, Debug, Serialize)]
+    #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
     pub struct ResponseData {
         #[serde(rename = "updateProjectColumn")]
         pub update_project_column: UpdateProjectColumnUpdateProjectColumn,
     }
     pub type UpdateProjectColumnUpdateProjectColumn = ProjectColumn;
 }
-impl graphql_client::GraphQLQuery for UpdateProjectColumn {
+impl crate::runtime::GraphQLQuery for UpdateProjectColumn {
     type Variables = update_project_column::Variables;
     type ResponseData = update_project_column::ResponseData;
-    fn build_query(variables: Self::Variables) -> ::graphql_client::QueryBody<Self::Variables> {
-        graphql_client::QueryBody {
+    fn build_query(variables: Self::Variables) -> crate::runtime::QueryBody<Self::Variables> {
+        crate::runtime::QueryBody {
             variables,
             query: update_project_column::QUERY,
             operation_name: update_project_column::OPERATION_NAME,
         }
     }
 }
+
+impl TryFrom<serde_json::Value> for update_project_column::ResponseData {
+    type Error = crate::BlipsError;
+
+    fn try_from(value: serde_json::Value) -> Result<Self, Self::Error> {
+        crate::data_from_value(update_project_column::OPERATION_NAME, value)
+    }
+}
+
+/// The type of the `updateProjectColumn` field of the response.
+pub type UpdateProjectColumnPayload = update_project_column::UpdateProjectColumnUpdateProjectColumn;
+
+impl update_project_column::ProjectColumn {
+    /// Compares two values by `order`.
+    pub fn by_order(a: &Self, b: &Self) -> std::cmp::Ordering {
+        a.order.cmp(&b.order)
+    }
+}
+
+/// A field of the `ProjectColumn` returned by [`UpdateProjectColumn`], for selecting with
+/// [`crate::BlipsClient::execute_partial`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UpdateProjectColumnField {
+    Collapsed,
+    Id,
+    Name,
+    Order,
+}
+
+impl UpdateProjectColumnField {
+    /// Every field, for selecting the whole fragment.
+    pub const ALL: &'static [Self] = &[Self::Collapsed, Self::Id, Self::Name, Self::Order];
+}
+
+/// The `ProjectColumn` returned by [`UpdateProjectColumn`], with the fields selected by
+/// [`crate::BlipsClient::execute_partial`].
+///
+/// A field is `None` both when it wasn't selected and when the server returned `null` for it.
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+#[serde(default)]
+pub struct UpdateProjectColumnPartial {
+    pub collapsed: Option<bool>,
+    pub id: Option<String>,
+    pub name: Option<String>,
+    pub order: Option<i64>,
+}
+
+impl crate::PartialQuery for UpdateProjectColumn {
+    type Field = UpdateProjectColumnField;
+    type Partial = UpdateProjectColumnPartial;
+
+    const FRAGMENT_NAME: &'static str = "ProjectColumn";
+    const ROOT_FIELD: &'static str = "updateProjectColumn";
+
+    fn field_name(field: Self::Field) -> &'static str {
+        match field {
+            UpdateProjectColumnField::Collapsed => "collapsed",
+            UpdateProjectColumnField::Id => "id",
+            UpdateProjectColumnField::Name => "name",
+            UpdateProjectColumnField::Order => "order",
+        }
+    }
+}
@@ -4,6 +4,7 @@ pub mod update_task {
     #![allow(dead_code)]
     use std::result::Result;
     pub const OPERATION_NAME: &str = "UpdateTask";
+    pub const OPERATION_KIND: crate::OperationKind = crate::OperationKind::Mutation;
     pub const QUERY : & str = "mutation UpdateTask($date: Date, $description: String, $due_date: Date, $link: String, $name: String, $project_id: ID, $recurrence: RecurrenceInput, $task_id: ID!) {\n    updateTask(date: $date, description: $description, dueDate: $due_date, link: $link, name: $name, projectId: $project_id, recurrence: $recurrence, taskId: $task_id) {\n        ...Task\n    }\n}\n\nfragment Task on Task {\n    __typename\n    completed\n    completedAt\n    date\n    description\n    dueDate\n    groupIds\n    id\n    isRecurring\n    link\n    name\n    priorityOrder\n    spring\n}" ;
     use super::*;
     use serde::{Deserialize, Serialize};
@@ -17,7 +18,7 @@ pub mod update_task {
     type ID = String;
     type Date = crate::graphql::custom_scalars::Date;
     type DateTime = crate::graphql::custom_scalars::DateTime;
-    #[derive(Serialize)]
+    #[derive(Serialize, Clone)]
     pub struct RecurrenceInput {
         pub id: Option<ID>,
         pub friday: Option<Boolean>,
@@ -31,7 +32,7 @@ pub mod update_task {
         pub tuesday: Option<Boolean>,
         pub wednesday: Option<Boolean>,
     }
-    #[derive(Serialize)]
+    #[derive(Serialize, Clone)]
     pub struct Variables {
         pub date: Option<Date>,
         pub description: Option<String>,
@@ -42,11 +43,188 @@ pub mod update_task {
         pub recurrence: Option<RecurrenceInput>,
         pub task_id: ID,
     }
-    impl Variables {}
-    #[derive(Deserialize, Debug)]
+    impl Variables {
+        /// Returns a new [`Variables`] from the arguments of `updateTask`.
+        ///
+        /// The parameters are in the order the arguments are declared in the schema.
+        pub fn new(
+            date: Option<Date>,
+            description: Option<String>,
+            due_date: Option<Date>,
+            link: Option<String>,
+            name: Option<String>,
+            project_id: Option<ID>,
+            recurrence: Option<RecurrenceInput>,
+            task_id: ID,
+        ) -> Self {
+            Self {
+                date,
+                description,
+                due_date,
+                link,
+                name,
+                project_id,
+                recurrence,
+                task_id,
+            }
+        }
+
+        /// Returns the variables with `date` set to the provided value.
+        pub fn with_date(mut self, date: impl Into<Option<Date>>) -> Self {
+            self.date = date.into();
+            self
+        }
+
+        /// Returns the variables with `description` set to the provided value.
+        pub fn with_description(mut self, description: impl Into<Option<String>>) -> Self {
+            self.description = description.into();
+            self
+        }
+
+        /// Returns the variables with `dueDate` set to the provided value.
+        pub fn with_due_date(mut self, due_date: impl Into<Option<Date>>) -> Self {
+            self.due_date = due_date.into();
+            self
+        }
+
+        /// Returns the variables with `link` set to the provided value.
+        pub fn with_link(mut self, link: impl Into<Option<String>>) -> Self {
+            self.link = link.into();
+            self
+        }
+
+        /// Returns the variables with `name` set to the provided value.
+        pub fn with_name(mut self, name: impl Into<Option<String>>) -> Self {
+            self.name = name.into();
+            self
+        }
+
+        /// Returns the variables with `projectId` set to the provided value.
+        pub fn with_project_id(mut self, project_id: impl Into<Option<ID>>) -> Self {
+            self.project_id = project_id.into();
+            self
+        }
+
+        /// Returns the variables with `recurrence` set to the provided value.
+        pub fn with_recurrence(mut self, recurrence: impl Into<Option<RecurrenceInput>>) -> Self {
+            self.recurrence = recurrence.into();
+            self
+        }
+
+        /// Returns the variables with `taskId` set to the provided value.
+        pub fn with_task_id(mut self, task_id: ID) -> Self {
+            self.task_id = task_id;
+            self
+        }
+    }
+
+    /// The arguments of `updateTask` to change, for executing with
+    /// [`crate::BlipsClient::execute_changeset`].
+    ///
+    /// Only the arguments that are set with the `with_*` setters are sent. The rest are left out
+    /// of the request, so the server leaves the fields they update untouched.
+    #[derive(Serialize, Clone)]
+    pub struct Changeset {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        date: Option<Option<Date>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        description: Option<Option<String>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        due_date: Option<Option<Date>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        link: Option<Option<String>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        name: Option<Option<String>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        project_id: Option<Option<ID>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        recurrence: Option<Option<RecurrenceInput>>,
+        task_id: ID,
+    }
+
+    impl Changeset {
+        /// Returns a new [`Changeset`] with the required arguments of `updateTask`, which
+        /// doesn't change anything else.
+        pub fn new(task_id: ID) -> Self {
+            Self {
+                date: None,
+                description: None,
+                due_date: None,
+                link: None,
+                name: None,
+                project_id: None,
+                recurrence: None,
+                task_id,
+            }
+        }
+
+        /// Returns the changeset with `date` set to the provided value, which is sent
+        /// as `null` when it is `None`.
+        pub fn with_date(mut self, date: impl Into<Option<Date>>) -> Self {
+            self.date = Some(date.into());
+            self
+        }
+
+        /// Returns the changeset with `description` set to the provided value, which is sent
+        /// as `null` when it is `None`.
+        pub fn with_description(mut self, description: impl Into<Option<String>>) -> Self {
+            self.description = Some(description.into());
+            self
+        }
+
+        /// Returns the changeset with `dueDate` set to the provided value, which is sent
+        /// as `null` when it is `None`.
+        pub fn with_due_date(mut self, due_date: impl Into<Option<Date>>) -> Self {
+            self.due_date = Some(due_date.into());
+            self
+        }
+
+        /// Returns the changeset with `link` set to the provided value, which is sent
+        /// as `null` when it is `None`.
+        pub fn with_link(mut self, link: impl Into<Option<String>>) -> Self {
+            self.link = Some(link.into());
+            self
+        }
+
+        /// Returns the changeset with `name` set to the provided value, which is sent
+        /// as `null` when it is `None`.
+        pub fn with_name(mut self, name: impl Into<Option<String>>) -> Self {
+            self.name = Some(name.into());
+            self
+        }
+
+        /// Returns the changeset with `projectId` set to the provided value, which is sent
+        /// as `null` when it is `None`.
+        pub fn with_project_id(mut self, project_id: impl Into<Option<ID>>) -> Self {
+            self.project_id = Some(project_id.into());
+            self
+        }
+
+        /// Returns the changeset with `recurrence` set to the provided value, which is sent
+        /// as `null` when it is `None`.
+        pub fn with_recurrence(mut self, recurrence: impl Into<Option<RecurrenceInput>>) -> Self {
+            self.recurrence = Some(recurrence.into());
+            self
+        }
+    }
+
+    impl crate::ChangesetQuery for super::UpdateTask {
+        type Changeset = Changeset;
+
+        fn build_changeset_query(changeset: Changeset) -> crate::runtime::QueryBody<Changeset> {
+            crate::runtime::QueryBody {
+                variables: changeset,
+                query: QUERY,
+                operation_name: OPERATION_NAME,
+            }
+        }
+    }
+    #[derive(Deserialize, Debug, Serialize)]
+    #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
     pub struct Task {
         pub completed: Boolean,
         #[serde(rename = "completedAt")]
+        #[cfg_attr(feature = "ts-rs", ts(as = "Option<f64>"))]
         pub completed_at: Option<DateTime>,
         pub date: Option<Date>,
         pub description: Option<String>,
@@ -60,24 +238,240 @@ pub mod update_task {
         pub link: Option<String>,
         pub name: String,
         #[serde(rename = "priorityOrder")]
+        #[cfg_attr(feature = "ts-rs", ts(as = "Option<i32>"))]
         pub priority_order: Option<Int>,
         pub spring: Boolean,
     }
-    #[derive(Deserialize, Debug)]
+    #[derive(Deserialize, Debug, Serialize)]
+    #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
     pub struct ResponseData {
         #[serde(rename = "updateTask")]
         pub update_task: UpdateTaskUpdateTask,
     }
     pub type UpdateTaskUpdateTask = Task;
 }
-impl graphql_client::GraphQLQuery for UpdateTask {
+impl crate::runtime::GraphQLQuery for UpdateTask {
     type Variables = update_task::Variables;
     type ResponseData = update_task::ResponseData;
-    fn build_query(variables: Self::Variables) -> ::graphql_client::QueryBody<Self::Variables> {
-        graphql_client::QueryBody {
+    fn build_query(variables: Self::Variables) -> crate::runtime::QueryBody<Self::Variables> {
+        crate::runtime::QueryBody {
             variables,
             query: update_task::QUERY,
             operation_name: update_task::OPERATION_NAME,
         }
     }
 }
+
+impl TryFrom<serde_json::Value> for update_task::ResponseData {
+    type Error = crate::BlipsError;
+
+    fn try_from(value: serde_json::Value) -> Result<Self, Self::Error> {
+        crate::data_from_value(update_task::OPERATION_NAME, value)
+    }
+}
+
+/// The type of the `updateTask` field of the response.
+pub type UpdateTaskPayload = update_task::UpdateTaskUpdateTask;
+
+impl update_task::Task {
+    /// Compares two values by `priorityOrder`, sorting values without one last.
+    pub fn by_priority_order(a: &Self, b: &Self) -> std::cmp::Ordering {
+        match (a.priority_order, b.priority_order) {
+            (Some(a), Some(b)) => a.cmp(&b),
+            (Some(_), None) => std::cmp::Ordering::Less,
+            (None, Some(_)) => std::cmp::Ordering::Greater,
+            (None, None) => std::cmp::Ordering::Equal,
+        }
+    }
+}
+
+impl update_task::Task {
+    /// Returns the state of the task, derived from its flags.
+    pub fn state(&self) -> crate::TaskState {
+        crate::TaskState::from_flags(self.completed, self.is_recurring, self.spring)
+    }
+}
+
+impl update_task::Task {
+    /// Returns whether the task is completed.
+    ///
+    /// `completed` and `completedAt` can disagree due to races or server bugs. When they
+    /// do, `completed` is trusted and `completedAt` is ignored. Use
+    /// [`Self::validate_consistency`] to detect the disagreement.
+    pub fn is_effectively_completed(&self) -> bool {
+        self.completed
+    }
+
+    /// Returns warnings for the fields of the task that contradict each other.
+    pub fn validate_consistency(&self) -> Vec<crate::ConsistencyWarning> {
+        crate::ConsistencyWarning::for_completion(self.completed, self.completed_at.is_some())
+            .into_iter()
+            .collect()
+    }
+}
+
+impl update_task::Task {
+    /// Returns the fields that differ between this task and `other`, in the order
+    /// they are selected.
+    pub fn diff(&self, other: &Self) -> Vec<crate::FieldChange> {
+        let mut changes = Vec::new();
+
+        if self.completed != other.completed {
+            changes.push(crate::FieldChange::new(
+                "completed",
+                &self.completed,
+                &other.completed,
+            ));
+        }
+        if self.completed_at != other.completed_at {
+            changes.push(crate::FieldChange::new(
+                "completedAt",
+                &self.completed_at,
+                &other.completed_at,
+            ));
+        }
+        if self.date != other.date {
+            changes.push(crate::FieldChange::new("date", &self.date, &other.date));
+        }
+        if self.description != other.description {
+            changes.push(crate::FieldChange::new(
+                "description",
+                &self.description,
+                &other.description,
+            ));
+        }
+        if self.due_date != other.due_date {
+            changes.push(crate::FieldChange::new(
+                "dueDate",
+                &self.due_date,
+                &other.due_date,
+            ));
+        }
+        if self.group_ids != other.group_ids {
+            changes.push(crate::FieldChange::new(
+                "groupIds",
+                &self.group_ids,
+                &other.group_ids,
+            ));
+        }
+        if self.id != other.id {
+            changes.push(crate::FieldChange::new("id", &self.id, &other.id));
+        }
+        if self.is_recurring != other.is_recurring {
+            changes.push(crate::FieldChange::new(
+                "isRecurring",
+                &self.is_recurring,
+                &other.is_recurring,
+            ));
+        }
+        if self.link != other.link {
+            changes.push(crate::FieldChange::new("link", &self.link, &other.link));
+        }
+        if self.name != other.name {
+            changes.push(crate::FieldChange::new("name", &self.name, &other.name));
+        }
+        if self.priority_order != other.priority_order {
+            changes.push(crate::FieldChange::new(
+                "priorityOrder",
+                &self.priority_order,
+                &other.priority_order,
+            ));
+        }
+        if self.spring != other.spring {
+            changes.push(crate::FieldChange::new(
+                "spring",
+                &self.spring,
+                &other.spring,
+            ));
+        }
+
+        changes
+    }
+}
+
+/// A field of the `Task` returned by [`UpdateTask`], for selecting with
+/// [`crate::BlipsClient::execute_partial`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UpdateTaskField {
+    Completed,
+    CompletedAt,
+    Date,
+    Description,
+    DueDate,
+    GroupIds,
+    Id,
+    IsRecurring,
+    Link,
+    Name,
+    PriorityOrder,
+    Spring,
+}
+
+impl UpdateTaskField {
+    /// Every field, for selecting the whole fragment.
+    pub const ALL: &'static [Self] = &[
+        Self::Completed,
+        Self::CompletedAt,
+        Self::Date,
+        Self::Description,
+        Self::DueDate,
+        Self::GroupIds,
+        Self::Id,
+        Self::IsRecurring,
+        Self::Link,
+        Self::Name,
+        Self::PriorityOrder,
+        Self::Spring,
+    ];
+}
+
+/// The `Task` returned by [`UpdateTask`], with the fields selected by
+/// [`crate::BlipsClient::execute_partial`].
+///
+/// A field is `None` both when it wasn't selected and when the server returned `null` for it.
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+#[serde(default)]
+pub struct UpdateTaskPartial {
+    pub completed: Option<bool>,
+    #[serde(rename = "completedAt")]
+    pub completed_at: Option<crate::graphql::custom_scalars::DateTime>,
+    pub date: Option<crate::graphql::custom_scalars::Date>,
+    pub description: Option<String>,
+    #[serde(rename = "dueDate")]
+    pub due_date: Option<crate::graphql::custom_scalars::Date>,
+    #[serde(rename = "groupIds")]
+    pub group_ids: Option<Vec<String>>,
+    pub id: Option<String>,
+    #[serde(rename = "isRecurring")]
+    pub is_recurring: Option<bool>,
+    pub link: Option<String>,
+    pub name: Option<String>,
+    #[serde(rename = "priorityOrder")]
+    pub priority_order: Option<i64>,
+    pub spring: Option<bool>,
+}
+
+impl crate::PartialQuery for UpdateTask {
+    type Field = UpdateTaskField;
+    type Partial = UpdateTaskPartial;
+
+    const FRAGMENT_NAME: &'static str = "Task";
+    const ROOT_FIELD: &'static str = "updateTask";
+
+    fn field_name(field: Self::Field) -> &'static str {
+        match field {
+            UpdateTaskField::Completed => "completed",
+            UpdateTaskField::CompletedAt => "completedAt",
+            UpdateTaskField::Date => "date",
+            UpdateTaskField::Description => "description",
+            UpdateTaskField::DueDate => "dueDate",
+            UpdateTaskField::GroupIds => "groupIds",
+            UpdateTaskField::Id => "id",
+            UpdateTaskField::IsRecurring => "isRecurring",
+            UpdateTaskField::Link => "link",
+            UpdateTaskField::Name => "name",
+            UpdateTaskField::PriorityOrder => "priorityOrder",
+            UpdateTaskField::Spring => "spring",
+        }
+    }
+}
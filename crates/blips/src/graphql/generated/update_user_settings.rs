@@ -4,6 +4,7 @@ pub mod update_user_settings {
     #![allow(dead_code)]
     use std::result::Result;
     pub const OPERATION_NAME: &str = "UpdateUserSettings";
+    pub const OPERATION_KIND: crate::OperationKind = crate::OperationKind::Mutation;
     pub const QUERY : & str = "mutation UpdateUserSettings($badge_count_mode: BadgeCountModeEnum) {\n    updateUserSettings(badgeCountMode: $badge_count_mode) {\n        ...User\n    }\n}\n\nfragment User on User {\n    __typename\n    email\n    id\n    isMfaEnabled\n}" ;
     use super::*;
     use serde::{Deserialize, Serialize};
@@ -15,64 +16,209 @@ pub mod update_user_settings {
     type Int = i64;
     #[allow(dead_code)]
     type ID = String;
-    #[derive(Debug)]
+    #[derive(Clone, Debug)]
     pub enum BadgeCountModeEnum {
-        DUE,
-        NONE,
-        PRIORITY,
-        PRIORITY_DUE,
+        Due,
+        None,
+        Priority,
+        PriorityDue,
         Other(String),
     }
     impl ::serde::Serialize for BadgeCountModeEnum {
         fn serialize<S: serde::Serializer>(&self, ser: S) -> Result<S::Ok, S::Error> {
             ser.serialize_str(match *self {
-                BadgeCountModeEnum::DUE => "DUE",
-                BadgeCountModeEnum::NONE => "NONE",
-                BadgeCountModeEnum::PRIORITY => "PRIORITY",
-                BadgeCountModeEnum::PRIORITY_DUE => "PRIORITY_DUE",
+                BadgeCountModeEnum::Due => "DUE",
+                BadgeCountModeEnum::None => "NONE",
+                BadgeCountModeEnum::Priority => "PRIORITY",
+                BadgeCountModeEnum::PriorityDue => "PRIORITY_DUE",
                 BadgeCountModeEnum::Other(ref s) => &s,
             })
         }
     }
+    impl BadgeCountModeEnum {
+        /// Returns the value as the schema declares it, such as for logging or metric labels,
+        /// without allocating.
+        ///
+        /// Values that the schema didn't declare when the SDK was generated are returned as
+        /// `"__OTHER"`. Use [`BadgeCountModeEnum::other_value`] to get them.
+        pub fn as_str(&self) -> &'static str {
+            match self {
+                BadgeCountModeEnum::Due => "DUE",
+                BadgeCountModeEnum::None => "NONE",
+                BadgeCountModeEnum::Priority => "PRIORITY",
+                BadgeCountModeEnum::PriorityDue => "PRIORITY_DUE",
+                BadgeCountModeEnum::Other(_) => "__OTHER",
+            }
+        }
+
+        /// Returns the value of a variant that the schema didn't declare when the SDK was
+        /// generated, or `None` for the declared variants.
+        pub fn other_value(&self) -> Option<&str> {
+            match self {
+                BadgeCountModeEnum::Other(value) => Some(value),
+                _ => None,
+            }
+        }
+    }
+    impl From<BadgeCountModeEnum> for &'static str {
+        fn from(value: BadgeCountModeEnum) -> Self {
+            value.as_str()
+        }
+    }
     impl<'de> ::serde::Deserialize<'de> for BadgeCountModeEnum {
         fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
             let s: String = ::serde::Deserialize::deserialize(deserializer)?;
             match s.as_str() {
-                "DUE" => Ok(BadgeCountModeEnum::DUE),
-                "NONE" => Ok(BadgeCountModeEnum::NONE),
-                "PRIORITY" => Ok(BadgeCountModeEnum::PRIORITY),
-                "PRIORITY_DUE" => Ok(BadgeCountModeEnum::PRIORITY_DUE),
+                "DUE" => Ok(BadgeCountModeEnum::Due),
+                "NONE" => Ok(BadgeCountModeEnum::None),
+                "PRIORITY" => Ok(BadgeCountModeEnum::Priority),
+                "PRIORITY_DUE" => Ok(BadgeCountModeEnum::PriorityDue),
                 _ => Ok(BadgeCountModeEnum::Other(s)),
             }
         }
     }
-    #[derive(Serialize)]
+    #[derive(Serialize, Clone, Default)]
     pub struct Variables {
         pub badge_count_mode: Option<BadgeCountModeEnum>,
     }
-    impl Variables {}
-    #[derive(Deserialize, Debug)]
+    impl Variables {
+        /// Returns a new [`Variables`] from the arguments of `updateUserSettings`.
+        ///
+        /// The parameters are in the order the arguments are declared in the schema.
+        pub fn new(badge_count_mode: Option<BadgeCountModeEnum>) -> Self {
+            Self { badge_count_mode }
+        }
+
+        /// Returns the variables with `badgeCountMode` set to the provided value.
+        pub fn with_badge_count_mode(
+            mut self,
+            badge_count_mode: impl Into<Option<BadgeCountModeEnum>>,
+        ) -> Self {
+            self.badge_count_mode = badge_count_mode.into();
+            self
+        }
+    }
+
+    /// The arguments of `updateUserSettings` to change, for executing with
+    /// [`crate::BlipsClient::execute_changeset`].
+    ///
+    /// Only the arguments that are set with the `with_*` setters are sent. The rest are left out
+    /// of the request, so the server leaves the fields they update untouched.
+    #[derive(Serialize, Clone)]
+    pub struct Changeset {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        badge_count_mode: Option<Option<BadgeCountModeEnum>>,
+    }
+
+    impl Changeset {
+        /// Returns a new [`Changeset`] with the required arguments of `updateUserSettings`, which
+        /// doesn't change anything else.
+        pub fn new() -> Self {
+            Self {
+                badge_count_mode: None,
+            }
+        }
+
+        /// Returns the changeset with `badgeCountMode` set to the provided value, which is sent
+        /// as `null` when it is `None`.
+        pub fn with_badge_count_mode(
+            mut self,
+            badge_count_mode: impl Into<Option<BadgeCountModeEnum>>,
+        ) -> Self {
+            self.badge_count_mode = Some(badge_count_mode.into());
+            self
+        }
+    }
+
+    impl crate::ChangesetQuery for super::UpdateUserSettings {
+        type Changeset = Changeset;
+
+        fn build_changeset_query(changeset: Changeset) -> crate::runtime::QueryBody<Changeset> {
+            crate::runtime::QueryBody {
+                variables: changeset,
+                query: QUERY,
+                operation_name: OPERATION_NAME,
+            }
+        }
+    }
+    #[derive(Deserialize, Debug, Serialize)]
+    #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
     pub struct User {
         pub email: String,
         pub id: ID,
         #[serde(rename = "isMfaEnabled")]
         pub is_mfa_enabled: Boolean,
     }
-    #[derive(Deserialize, Debug)]
+    #[derive(Deserialize, Debug, Serialize)]
+    #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
     pub struct ResponseData {
         #[serde(rename = "updateUserSettings")]
         pub update_user_settings: UpdateUserSettingsUpdateUserSettings,
     }
     pub type UpdateUserSettingsUpdateUserSettings = User;
 }
-impl graphql_client::GraphQLQuery for UpdateUserSettings {
+impl crate::runtime::GraphQLQuery for UpdateUserSettings {
     type Variables = update_user_settings::Variables;
     type ResponseData = update_user_settings::ResponseData;
-    fn build_query(variables: Self::Variables) -> ::graphql_client::QueryBody<Self::Variables> {
-        graphql_client::QueryBody {
+    fn build_query(variables: Self::Variables) -> crate::runtime::QueryBody<Self::Variables> {
+        crate::runtime::QueryBody {
             variables,
             query: update_user_settings::QUERY,
             operation_name: update_user_settings::OPERATION_NAME,
         }
     }
 }
+
+impl TryFrom<serde_json::Value> for update_user_settings::ResponseData {
+    type Error = crate::BlipsError;
+
+    fn try_from(value: serde_json::Value) -> Result<Self, Self::Error> {
+        crate::data_from_value(update_user_settings::OPERATION_NAME, value)
+    }
+}
+
+/// The type of the `updateUserSettings` field of the response.
+pub type UpdateUserSettingsPayload = update_user_settings::UpdateUserSettingsUpdateUserSettings;
+
+/// A field of the `User` returned by [`UpdateUserSettings`], for selecting with
+/// [`crate::BlipsClient::execute_partial`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UpdateUserSettingsField {
+    Email,
+    Id,
+    IsMfaEnabled,
+}
+
+impl UpdateUserSettingsField {
+    /// Every field, for selecting the whole fragment.
+    pub const ALL: &'static [Self] = &[Self::Email, Self::Id, Self::IsMfaEnabled];
+}
+
+/// The `User` returned by [`UpdateUserSettings`], with the fields selected by
+/// [`crate::BlipsClient::execute_partial`].
+///
+/// A field is `None` both when it wasn't selected and when the server returned `null` for it.
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+#[serde(default)]
+pub struct UpdateUserSettingsPartial {
+    pub email: Option<String>,
+    pub id: Option<String>,
+    #[serde(rename = "isMfaEnabled")]
+    pub is_mfa_enabled: Option<bool>,
+}
+
+impl crate::PartialQuery for UpdateUserSettings {
+    type Field = UpdateUserSettingsField;
+    type Partial = UpdateUserSettingsPartial;
+
+    const FRAGMENT_NAME: &'static str = "User";
+    const ROOT_FIELD: &'static str = "updateUserSettings";
+
+    fn field_name(field: Self::Field) -> &'static str {
+        match field {
+            UpdateUserSettingsField::Email => "email",
+            UpdateUserSettingsField::Id => "id",
+            UpdateUserSettingsField::IsMfaEnabled => "isMfaEnabled",
+        }
+    }
+}
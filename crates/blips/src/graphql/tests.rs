@@ -0,0 +1,178 @@
+//! Tests of the generated operations, which live here rather than in the generated modules
+//! so that regenerating them keeps the tests.
+
+use serde_json::json;
+
+use super::container::{ContainerOn, ResponseData as ContainerData};
+use super::update_user_settings::BadgeCountModeEnum;
+use super::{project, projects, tasks, unspring_project, UnspringProjectPayload};
+use crate::BlipsError;
+
+#[test]
+fn response_data_round_trips_through_a_value() {
+    let value = json!({
+        "tasks": [
+            {
+                "completed": true,
+                "completedAt": 1693526400000_u64,
+                "date": "2023-09-01",
+                "description": null,
+                "dueDate": null,
+                "groupIds": ["group-1", "group-2"],
+                "id": "task-1",
+                "isRecurring": false,
+                "link": "https://example.com",
+                "name": "Write tests",
+                "priorityOrder": 3,
+                "spring": false,
+            },
+        ],
+    });
+
+    let data = tasks::ResponseData::try_from(value.clone()).unwrap();
+
+    assert_eq!(serde_json::to_value(data).unwrap(), value);
+}
+
+#[test]
+fn response_data_from_a_mismatched_value_is_a_deserialize_error() {
+    let err = tasks::ResponseData::try_from(json!({ "tasks": [{ "id": 1 }] })).unwrap_err();
+
+    assert!(matches!(
+        err,
+        BlipsError::Deserialize { ref operation_name, ref path, .. }
+            if operation_name == "Tasks" && path == "tasks[0].id"
+    ));
+}
+
+/// Returns the JSON of a project with the given ID, as the server returns it.
+fn project_json(id: &str) -> serde_json::Value {
+    json!({
+        "collapseCompleted": false,
+        "completed": false,
+        "completedAt": null,
+        "date": "2023-09-01",
+        "endDate": null,
+        "id": id,
+        "link": null,
+        "name": "Launch",
+        "noteBody": null,
+        "order": 2,
+        "springEnabled": true,
+        "supportsNotes": true,
+    })
+}
+
+#[test]
+fn project_deserializes_as_the_unspring_project_payload() {
+    let value = json!({ "unspringProject": project_json("project-1") });
+
+    let data: unspring_project::ResponseData = serde_json::from_value(value.clone()).unwrap();
+    let payload: &UnspringProjectPayload = &data.unspring_project;
+
+    assert_eq!(payload.id, "project-1");
+    assert!(payload.spring_enabled);
+    assert_eq!(payload.order, Some(2));
+    assert_eq!(serde_json::to_value(&data).unwrap(), value);
+}
+
+#[test]
+fn project_deserializes_nested_in_the_responses_of_other_operations() {
+    let value = json!({ "projects": [project_json("project-1"), project_json("project-2")] });
+
+    let data: projects::ResponseData = serde_json::from_value(value.clone()).unwrap();
+
+    let ids = data
+        .projects
+        .as_deref()
+        .unwrap_or_default()
+        .iter()
+        .map(|project| project.id.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(ids, ["project-1", "project-2"]);
+    assert_eq!(serde_json::to_value(&data).unwrap(), value);
+
+    let data: project::ResponseData =
+        serde_json::from_value(json!({ "project": project_json("project-1") })).unwrap();
+    assert_eq!(
+        data.project.map(|project| project.id).as_deref(),
+        Some("project-1")
+    );
+
+    let data: projects::ResponseData = serde_json::from_value(json!({ "projects": null })).unwrap();
+    assert!(data.projects.is_none());
+    let data: project::ResponseData = serde_json::from_value(json!({ "project": null })).unwrap();
+    assert!(data.project.is_none());
+}
+
+#[test]
+fn enum_values_round_trip_through_their_renamed_variants() {
+    let mode: BadgeCountModeEnum = serde_json::from_value(json!("PRIORITY_DUE")).unwrap();
+
+    assert!(matches!(mode, BadgeCountModeEnum::PriorityDue));
+    assert_eq!(serde_json::to_value(mode).unwrap(), json!("PRIORITY_DUE"));
+}
+
+#[test]
+fn enum_variants_are_their_values_as_static_strs() {
+    let values = [
+        (BadgeCountModeEnum::Due, "DUE"),
+        (BadgeCountModeEnum::None, "NONE"),
+        (BadgeCountModeEnum::Priority, "PRIORITY"),
+        (BadgeCountModeEnum::PriorityDue, "PRIORITY_DUE"),
+    ];
+
+    for (mode, value) in values {
+        assert_eq!(mode.as_str(), value);
+        assert_eq!(mode.other_value(), None);
+        assert_eq!(<&'static str>::from(mode), value);
+    }
+}
+
+#[test]
+fn undeclared_enum_values_are_available_from_other_value() {
+    let mode: BadgeCountModeEnum = serde_json::from_value(json!("WEEKLY")).unwrap();
+
+    assert_eq!(mode.as_str(), "__OTHER");
+    assert_eq!(mode.other_value(), Some("WEEKLY"));
+}
+
+#[test]
+fn interface_fields_are_shared_and_implementation_fields_are_in_the_on_enum() {
+    let diary = json!({
+        "__typename": "Diary",
+        "collapseCompleted": false,
+        "id": "diary-1",
+        "noteBody": null,
+        "supportsNotes": true,
+        "diaryDate": "2023-09-01",
+    });
+    let project = json!({
+        "__typename": "Project",
+        "collapseCompleted": true,
+        "id": "project-1",
+        "noteBody": "Notes",
+        "supportsNotes": true,
+        "completed": false,
+        "completedAt": null,
+        "projectDate": null,
+        "endDate": null,
+        "link": null,
+        "name": "Launch",
+        "order": 2,
+        "springEnabled": false,
+    });
+
+    let data: ContainerData = serde_json::from_value(json!({ "container": diary })).unwrap();
+    assert_eq!(data.container.id, "diary-1");
+    assert!(matches!(data.container.on, ContainerOn::Diary(_)));
+
+    let data: ContainerData = serde_json::from_value(json!({ "container": project })).unwrap();
+    assert_eq!(data.container.id, "project-1");
+    assert_eq!(data.container.note_body.as_deref(), Some("Notes"));
+    let ContainerOn::Project(project) = data.container.on else {
+        panic!("expected a project, got {:?}", data.container.on);
+    };
+    assert_eq!(project.name, "Launch");
+    assert_eq!(project.order, Some(2));
+}
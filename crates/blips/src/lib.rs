@@ -1,9 +1,38 @@
 #![doc = include_str!("../README.md")]
 
+#[cfg(feature = "blocking")]
+pub mod blocking;
+mod bulk;
+mod changeset;
 mod client;
 mod client_generated;
+mod clock;
+mod config;
 mod core;
+mod error;
 pub mod graphql;
+mod partial;
+pub mod prelude;
+mod request_validation;
+mod retry;
+pub mod runtime;
+#[cfg(feature = "schema-reflection")]
+pub mod schema;
+#[cfg(feature = "vcr")]
+mod vcr;
 
 pub use crate::core::*;
+pub use bulk::*;
+pub use changeset::ChangesetQuery;
 pub use client::*;
+#[cfg(feature = "test-util")]
+pub use clock::MockClock;
+pub use config::{ClientConfig, CredentialConfig};
+pub use error::*;
+pub use partial::PartialQuery;
+pub use retry::{Jitter, RetryConfig};
+#[cfg(feature = "vcr")]
+pub use vcr::VcrMode;
+// The generated client only exports items when generated with a dedicated client type.
+#[allow(unused_imports)]
+pub use client_generated::*;
@@ -0,0 +1,195 @@
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::client::{data_from_value, response_data};
+use crate::runtime::GraphQLQuery;
+use crate::{BlipsClient, BlipsError};
+
+/// A generated operation whose fragment can be trimmed to a subset of its fields with
+/// [`BlipsClient::execute_partial`].
+///
+/// This is implemented for the operations whose fragment is generated, rather than read
+/// from an override file.
+pub trait PartialQuery: GraphQLQuery {
+    /// The fields of the fragment that can be selected.
+    type Field: Copy;
+
+    /// The value of the operation's root field, with every field of the fragment optional.
+    type Partial: DeserializeOwned;
+
+    /// The name of the fragment the operation spreads.
+    const FRAGMENT_NAME: &'static str;
+
+    /// The name of the operation's root field.
+    const ROOT_FIELD: &'static str;
+
+    /// Returns the name of the field in the schema.
+    fn field_name(field: Self::Field) -> &'static str;
+}
+
+/// The body of a request for an operation with a trimmed fragment.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PartialQueryBody<'a, V> {
+    variables: &'a V,
+    query: &'a str,
+    operation_name: &'a str,
+}
+
+impl BlipsClient {
+    /// Executes the GraphQL operation `Q` with the provided variables, selecting only the
+    /// given fields of its fragment, and returns the value of its root field.
+    ///
+    /// Every field of the returned value is an `Option`, which is `None` both when the
+    /// field wasn't selected and when the server returned `null` for it. The operation's
+    /// `__typename` is always selected, and selecting no fields is allowed.
+    ///
+    /// The document is trimmed at runtime, so the operation is always sent as a full
+    /// document, even when persisted documents are enabled.
+    pub async fn execute_partial<Q: PartialQuery>(
+        &self,
+        variables: Q::Variables,
+        fields: &[Q::Field],
+    ) -> Result<Q::Partial, BlipsError> {
+        let body = Q::build_query(variables);
+        let query = partial_document(
+            body.operation_name,
+            body.query,
+            Q::FRAGMENT_NAME,
+            fields.iter().map(|field| Q::field_name(*field)),
+        )?;
+
+        let partial_body = PartialQueryBody {
+            variables: &body.variables,
+            query: &query,
+            operation_name: body.operation_name,
+        };
+
+        let response = self
+            .post_graphql::<_, serde_json::Value>(body.operation_name, &partial_body, None, None)
+            .await?;
+        let mut data = response_data(body.operation_name, response)?;
+
+        let root_field = data
+            .get_mut(Q::ROOT_FIELD)
+            .map(serde_json::Value::take)
+            .unwrap_or_default();
+
+        data_from_value(body.operation_name, root_field)
+    }
+}
+
+/// Returns the document with the fragment with the given name replaced by one that only
+/// selects the given fields, or a [`BlipsError::InvalidRequest`] if the document doesn't
+/// define the fragment.
+///
+/// The generated documents end with the fragment their operation spreads.
+fn partial_document<'a>(
+    operation_name: &str,
+    document: &str,
+    fragment_name: &str,
+    field_names: impl Iterator<Item = &'a str>,
+) -> Result<String, BlipsError> {
+    let (start, type_condition) =
+        find_fragment(document, fragment_name).ok_or_else(|| BlipsError::InvalidRequest {
+            operation_name: operation_name.to_string(),
+            reason: format!("the document doesn't define the fragment `{fragment_name}`"),
+        })?;
+
+    let mut partial_document = format!(
+        "{}fragment {fragment_name} on {type_condition} {{\n    __typename",
+        &document[..start]
+    );
+    for field_name in field_names {
+        partial_document.push_str("\n    ");
+        partial_document.push_str(field_name);
+    }
+    partial_document.push_str("\n}");
+
+    Ok(partial_document)
+}
+
+/// Returns the offset of the definition of the fragment with the given name in the
+/// document, along with the type condition of the fragment.
+fn find_fragment<'a>(document: &'a str, fragment_name: &str) -> Option<(usize, &'a str)> {
+    const KEYWORD: &str = "fragment";
+
+    document.match_indices(KEYWORD).find_map(|(start, _)| {
+        let is_name_char = |c: char| c.is_alphanumeric() || c == '_';
+        if document[..start].ends_with(is_name_char) {
+            return None;
+        }
+
+        let rest = &document[start + KEYWORD.len()..];
+        if !rest.starts_with(char::is_whitespace) {
+            return None;
+        }
+
+        let header = &rest[..rest.find('{')?];
+        match header.split_whitespace().collect::<Vec<_>>()[..] {
+            [name, "on", type_condition] if name == fragment_name => Some((start, type_condition)),
+            _ => None,
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DOCUMENT: &str =
+        "query Task($id: ID!) {\n    task(id: $id) {\n        ...Task\n    }\n}\n\n\
+                            fragment Task on Task {\n    __typename\n    id\n    name\n}";
+
+    #[test]
+    fn partial_document_selects_only_the_given_fields() {
+        assert_eq!(
+            partial_document("Task", DOCUMENT, "Task", ["name"].into_iter()).unwrap(),
+            "query Task($id: ID!) {\n    task(id: $id) {\n        ...Task\n    }\n}\n\n\
+             fragment Task on Task {\n    __typename\n    name\n}"
+        );
+    }
+
+    #[test]
+    fn partial_document_keeps_the_type_condition_of_the_fragment() {
+        let document = "query Node {\n    node {\n        ...TaskFields\n    }\n}\n\n\
+                        fragment TaskFields\n  on   Task{\n    id\n}";
+
+        assert_eq!(
+            partial_document("Node", document, "TaskFields", std::iter::empty()).unwrap(),
+            "query Node {\n    node {\n        ...TaskFields\n    }\n}\n\n\
+             fragment TaskFields on Task {\n    __typename\n}"
+        );
+    }
+
+    #[test]
+    fn partial_document_skips_fragments_with_other_names() {
+        let document = "query Task {\n    task {\n        ...Task\n    }\n}\n\n\
+                        fragment TaskSummary on Task {\n    id\n}\n\n\
+                        fragment Task on Task {\n    id\n}";
+
+        assert_eq!(
+            partial_document("Task", document, "Task", ["id"].into_iter()).unwrap(),
+            "query Task {\n    task {\n        ...Task\n    }\n}\n\n\
+             fragment TaskSummary on Task {\n    id\n}\n\n\
+             fragment Task on Task {\n    __typename\n    id\n}"
+        );
+    }
+
+    #[test]
+    fn partial_document_without_the_fragment_is_an_invalid_request() {
+        let err = partial_document(
+            "Task",
+            "query Task {\n    task {\n        id\n    }\n}",
+            "Task",
+            ["id"].into_iter(),
+        )
+        .unwrap_err();
+
+        assert!(matches!(
+            err,
+            BlipsError::InvalidRequest { ref operation_name, ref reason }
+                if operation_name == "Task" && reason == "the document doesn't define the fragment `Task`"
+        ));
+    }
+}
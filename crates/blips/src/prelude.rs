@@ -0,0 +1,7 @@
+//! The types needed by most users of the client, for importing them all at once:
+//!
+//! ```
+//! use blips::prelude::*;
+//! ```
+
+pub use crate::{BlipsClient, BlipsClientBuilder, BlipsError, CsrfToken, Result, SessionCookie};
@@ -0,0 +1,125 @@
+use serde_json::Value;
+
+/// Validates an outgoing request body against the document of its operation.
+///
+/// Checks that the operation name matches the document, that every required variable is
+/// present, and that the variables roughly match their declared types. Only the built-in
+/// scalars and lists are type-checked; enums, input objects, and custom scalars are
+/// accepted as-is. Ad-hoc queries, which have no operation name, are not validated.
+pub(crate) fn validate_request(operation_name: &str, body: &Value) -> Result<(), String> {
+    let document = match body.get("query").and_then(Value::as_str) {
+        Some(_) if body.get("operationName").is_none() => return Ok(()),
+        Some(document) => document,
+        // Persisted document requests don't include the document, so we look it up.
+        None => match crate::graphql::OPERATIONS
+            .iter()
+            .find(|operation| operation.name == operation_name)
+        {
+            Some(operation) => operation.document,
+            None => return Ok(()),
+        },
+    };
+
+    let variable_definitions =
+        find_variable_definitions(document, operation_name).ok_or_else(|| {
+            format!(
+                "operation name `{}` doesn't match any operation in the document",
+                operation_name
+            )
+        })?;
+
+    let empty_variables = serde_json::Map::new();
+    let variables = body
+        .get("variables")
+        .and_then(Value::as_object)
+        .unwrap_or(&empty_variables);
+
+    for definition in variable_definitions.split('$').skip(1) {
+        let Some((name, rest)) = definition.split_once(':') else {
+            continue;
+        };
+        let name = name.trim();
+        let (graphql_type, default_value) = match rest.split_once('=') {
+            Some((graphql_type, default_value)) => (graphql_type, Some(default_value)),
+            None => (rest, None),
+        };
+        let graphql_type = graphql_type.trim().trim_end_matches(',').trim();
+
+        match variables.get(name).filter(|value| !value.is_null()) {
+            Some(value) if !matches_type(graphql_type, value) => {
+                return Err(format!(
+                    "variable `${}` should be a `{}`, but is `{}`",
+                    name, graphql_type, value
+                ));
+            }
+            None if graphql_type.ends_with('!') && default_value.is_none() => {
+                return Err(format!(
+                    "required variable `${}` of type `{}` is missing",
+                    name, graphql_type
+                ));
+            }
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns the variable definitions of the named operation in the document, without the
+/// surrounding parentheses, or `None` if the document doesn't define the operation.
+fn find_variable_definitions<'a>(document: &'a str, operation_name: &str) -> Option<&'a str> {
+    ["query", "mutation", "subscription"]
+        .iter()
+        .find_map(|keyword| {
+            let header = format!("{} {}", keyword, operation_name);
+            document.match_indices(&header).find_map(|(index, _)| {
+                let rest = &document[index + header.len()..];
+                let is_whole_name = rest
+                    .chars()
+                    .next()
+                    .is_none_or(|next| !next.is_alphanumeric() && next != '_');
+                is_whole_name.then_some(rest)
+            })
+        })
+        .map(|rest| {
+            let rest = rest.trim_start();
+            match rest.strip_prefix('(') {
+                Some(definitions) => definitions
+                    .split_once(')')
+                    .map_or(definitions, |(definitions, _)| definitions),
+                None => "",
+            }
+        })
+}
+
+/// Returns whether the value roughly matches the GraphQL type.
+fn matches_type(graphql_type: &str, value: &Value) -> bool {
+    let (graphql_type, is_non_null) = match graphql_type.strip_suffix('!') {
+        Some(graphql_type) => (graphql_type, true),
+        None => (graphql_type, false),
+    };
+
+    if value.is_null() {
+        return !is_non_null;
+    }
+
+    if let Some(item_type) = graphql_type
+        .strip_prefix('[')
+        .and_then(|graphql_type| graphql_type.strip_suffix(']'))
+    {
+        // A single value is coerced to a list containing it.
+        return match value {
+            Value::Array(items) => items.iter().all(|item| matches_type(item_type, item)),
+            value => matches_type(item_type, value),
+        };
+    }
+
+    match graphql_type {
+        "Int" => value.is_i64() || value.is_u64(),
+        "Float" => value.is_number(),
+        "String" => value.is_string(),
+        "Boolean" => value.is_boolean(),
+        "ID" => value.is_string() || value.is_i64() || value.is_u64(),
+        _ => true,
+    }
+}
@@ -0,0 +1,350 @@
+use std::time::Duration;
+
+use reqwest::StatusCode;
+use serde::Deserialize;
+
+use crate::TransportErrorKind;
+
+/// How random jitter is applied to the delays between retries.
+///
+/// Jitter keeps many clients that fail at the same time from retrying in lockstep. See
+/// [Exponential Backoff And Jitter](https://aws.amazon.com/blogs/architecture/exponential-backoff-and-jitter/)
+/// for a comparison of the strategies.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Jitter {
+    /// Uses the exponential backoff delay as-is.
+    None,
+
+    /// Uses a random delay between zero and the exponential backoff delay.
+    #[default]
+    Full,
+
+    /// Uses half of the exponential backoff delay, plus a random delay of up to the other
+    /// half.
+    Equal,
+
+    /// Uses a random delay between the base delay and three times the previous delay.
+    Decorrelated,
+}
+
+/// The configuration for retrying failed requests.
+///
+/// Requests are retried when they fail to connect or time out, and when the server
+/// responds with `429 Too Many Requests`, `502 Bad Gateway`, `503 Service Unavailable`,
+/// or `504 Gateway Timeout`. Other transport errors, such as failing to send the request
+/// body, are never retried, as the server may have already received the request.
+///
+/// A request that times out, or gets a `502` or `504` response, may also have been
+/// processed by the server, so only queries are retried for these by default. Sending a
+/// mutation again could apply it twice, such as creating the same tasks twice, so
+/// retrying mutations for these failures is opt-in with [`RetryConfig::retry_mutations`].
+///
+/// When deserialized, the delays are given in seconds and any missing fields use their
+/// defaults.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct RetryConfig {
+    max_retries: u32,
+    #[serde(deserialize_with = "crate::config::deserialize_seconds")]
+    base_delay: Duration,
+    #[serde(deserialize_with = "crate::config::deserialize_seconds")]
+    max_delay: Duration,
+    jitter: Jitter,
+    retry_timeouts: bool,
+    retry_connect_errors: bool,
+    retry_mutations: bool,
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_secs(10),
+            jitter: Jitter::default(),
+            retry_timeouts: true,
+            retry_connect_errors: true,
+            retry_mutations: false,
+        }
+    }
+}
+
+impl RetryConfig {
+    /// Returns the default [`RetryConfig`], which retries up to 3 times with full jitter,
+    /// starting from a base delay of 100 milliseconds.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the maximum number of times a request is retried.
+    pub fn max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Sets the delay before the first retry, which doubles with each retry after it.
+    pub fn base_delay(mut self, base_delay: Duration) -> Self {
+        self.base_delay = base_delay;
+        self
+    }
+
+    /// Sets the longest delay between retries.
+    pub fn max_delay(mut self, max_delay: Duration) -> Self {
+        self.max_delay = max_delay;
+        self
+    }
+
+    /// Sets the jitter strategy applied to the delays between retries.
+    pub fn jitter(mut self, jitter: Jitter) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Sets whether requests that time out are retried, which they are by default.
+    ///
+    /// A request that times out while waiting for the response may have been received by
+    /// the server, so only queries are retried unless [`RetryConfig::retry_mutations`] is
+    /// set, and this can be turned off for queries too.
+    pub fn retry_timeouts(mut self, retry_timeouts: bool) -> Self {
+        self.retry_timeouts = retry_timeouts;
+        self
+    }
+
+    /// Sets whether requests that fail to connect are retried, which they are by default.
+    pub fn retry_connect_errors(mut self, retry_connect_errors: bool) -> Self {
+        self.retry_connect_errors = retry_connect_errors;
+        self
+    }
+
+    /// Sets whether operations other than queries are retried after failures that the
+    /// server may have processed the request for, which they aren't by default.
+    ///
+    /// These failures are timeouts and `502 Bad Gateway` and `504 Gateway Timeout`
+    /// responses. Only turn this on when the mutations are safe to apply twice. Ad-hoc
+    /// operations, whose kind isn't known, are treated as mutations. Failures that the
+    /// server can't have processed the request for, such as failing to connect, are
+    /// retried for every operation.
+    pub fn retry_mutations(mut self, retry_mutations: bool) -> Self {
+        self.retry_mutations = retry_mutations;
+        self
+    }
+
+    /// Returns whether a transport error of the given class should be retried, for a query
+    /// if `is_query` is set.
+    pub(crate) fn retries(&self, kind: TransportErrorKind, is_query: bool) -> bool {
+        match kind {
+            TransportErrorKind::Timeout => {
+                self.retry_timeouts && (is_query || self.retry_mutations)
+            }
+            TransportErrorKind::Connect => self.retry_connect_errors,
+            TransportErrorKind::Request
+            | TransportErrorKind::Body
+            | TransportErrorKind::Decode
+            | TransportErrorKind::Other => false,
+        }
+    }
+
+    /// Returns whether a response with the given status should be retried, for a query if
+    /// `is_query` is set.
+    pub(crate) fn retries_status(&self, status: StatusCode, is_query: bool) -> bool {
+        is_retryable_status(status)
+            && (is_query || self.retry_mutations || !is_ambiguous_status(status))
+    }
+}
+
+/// Returns whether a response with the given status is transient, so that the request may
+/// succeed when it is retried.
+pub(crate) fn is_retryable_status(status: StatusCode) -> bool {
+    matches!(
+        status,
+        StatusCode::TOO_MANY_REQUESTS
+            | StatusCode::BAD_GATEWAY
+            | StatusCode::SERVICE_UNAVAILABLE
+            | StatusCode::GATEWAY_TIMEOUT
+    )
+}
+
+/// Returns whether a response with the given status may have been sent after the server
+/// processed the request, such as a gateway timing out while waiting for the server.
+fn is_ambiguous_status(status: StatusCode) -> bool {
+    matches!(
+        status,
+        StatusCode::BAD_GATEWAY | StatusCode::GATEWAY_TIMEOUT
+    )
+}
+
+/// The delays between the retries of a single request.
+pub(crate) struct Backoff {
+    config: RetryConfig,
+    retries: u32,
+    previous_delay: Duration,
+}
+
+impl Backoff {
+    pub(crate) fn new(config: RetryConfig) -> Self {
+        Self {
+            config,
+            retries: 0,
+            previous_delay: config.base_delay,
+        }
+    }
+
+    /// Returns the delay before the next retry, or `None` if there are no retries left.
+    pub(crate) fn next_delay(&mut self) -> Option<Duration> {
+        if self.retries >= self.config.max_retries {
+            return None;
+        }
+
+        let exponential_delay = self
+            .config
+            .base_delay
+            .saturating_mul(2u32.saturating_pow(self.retries))
+            .min(self.config.max_delay);
+
+        let delay = match self.config.jitter {
+            Jitter::None => exponential_delay,
+            Jitter::Full => random_duration(Duration::ZERO, exponential_delay),
+            Jitter::Equal => {
+                let half = exponential_delay / 2;
+                half + random_duration(Duration::ZERO, exponential_delay - half)
+            }
+            Jitter::Decorrelated => random_duration(
+                self.config.base_delay,
+                self.previous_delay.saturating_mul(3),
+            )
+            .min(self.config.max_delay),
+        };
+
+        self.retries += 1;
+        self.previous_delay = delay;
+
+        Some(delay)
+    }
+}
+
+/// Returns a random duration between `min` and `max`, inclusive.
+fn random_duration(min: Duration, max: Duration) -> Duration {
+    let min = min.as_nanos().min(u64::MAX as u128) as u64;
+    let max = max.as_nanos().min(u64::MAX as u128) as u64;
+
+    Duration::from_nanos(fastrand::u64(min..=max.max(min)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns a config with the given jitter that retries 5 times, starting from 100ms and
+    /// capped at 1s.
+    fn config(jitter: Jitter) -> RetryConfig {
+        RetryConfig::new()
+            .max_retries(5)
+            .base_delay(Duration::from_millis(100))
+            .max_delay(Duration::from_secs(1))
+            .jitter(jitter)
+    }
+
+    /// Returns the delays of a backoff until it runs out of retries.
+    fn delays(config: RetryConfig) -> Vec<Duration> {
+        let mut backoff = Backoff::new(config);
+
+        std::iter::from_fn(|| backoff.next_delay()).collect()
+    }
+
+    #[test]
+    fn only_throttling_and_gateway_statuses_are_retryable() {
+        for status in [429, 502, 503, 504] {
+            assert!(
+                is_retryable_status(StatusCode::from_u16(status).unwrap()),
+                "for {status}"
+            );
+        }
+        for status in [200, 400, 401, 404, 409, 412, 500, 501] {
+            assert!(
+                !is_retryable_status(StatusCode::from_u16(status).unwrap()),
+                "for {status}"
+            );
+        }
+    }
+
+    #[test]
+    fn ambiguous_failures_are_only_retried_for_queries_by_default() {
+        let config = RetryConfig::new();
+        let opted_in = RetryConfig::new().retry_mutations(true);
+
+        assert!(config.retries(TransportErrorKind::Timeout, true));
+        assert!(!config.retries(TransportErrorKind::Timeout, false));
+        assert!(opted_in.retries(TransportErrorKind::Timeout, false));
+        assert!(config.retries(TransportErrorKind::Connect, false));
+        for status in [StatusCode::BAD_GATEWAY, StatusCode::GATEWAY_TIMEOUT] {
+            assert!(config.retries_status(status, true), "for {status}");
+            assert!(!config.retries_status(status, false), "for {status}");
+            assert!(opted_in.retries_status(status, false), "for {status}");
+        }
+        for status in [
+            StatusCode::TOO_MANY_REQUESTS,
+            StatusCode::SERVICE_UNAVAILABLE,
+        ] {
+            assert!(config.retries_status(status, false), "for {status}");
+        }
+    }
+
+    #[test]
+    fn delays_without_jitter_double_up_to_the_max_delay() {
+        let millis = [100, 200, 400, 800, 1000];
+
+        assert_eq!(
+            delays(config(Jitter::None)),
+            millis.map(Duration::from_millis)
+        );
+    }
+
+    #[test]
+    fn delays_with_jitter_stay_within_their_bounds() {
+        let exponential_delays = [100, 200, 400, 800, 1000].map(Duration::from_millis);
+
+        // The delays are random, so each strategy is checked over many backoffs.
+        for _ in 0..100 {
+            for (delay, max) in delays(config(Jitter::Full))
+                .into_iter()
+                .zip(exponential_delays)
+            {
+                assert!(delay <= max, "full jitter delay {delay:?} exceeds {max:?}");
+            }
+            for (delay, max) in delays(config(Jitter::Equal))
+                .into_iter()
+                .zip(exponential_delays)
+            {
+                assert!(
+                    max / 2 <= delay && delay <= max,
+                    "equal jitter delay {delay:?} is outside {:?}..={max:?}",
+                    max / 2
+                );
+            }
+
+            let mut previous_delay = Duration::from_millis(100);
+            for delay in delays(config(Jitter::Decorrelated)) {
+                let max = (previous_delay * 3).min(Duration::from_secs(1));
+                assert!(
+                    Duration::from_millis(100) <= delay && delay <= max,
+                    "decorrelated jitter delay {delay:?} is outside 100ms..={max:?}"
+                );
+                previous_delay = delay;
+            }
+        }
+    }
+
+    #[test]
+    fn backoff_runs_out_after_max_retries() {
+        for max_retries in [0, 1, 3] {
+            let mut backoff = Backoff::new(config(Jitter::Full).max_retries(max_retries));
+
+            for _ in 0..max_retries {
+                assert!(backoff.next_delay().is_some());
+            }
+            assert_eq!(backoff.next_delay(), None);
+            assert_eq!(backoff.next_delay(), None);
+        }
+    }
+}
@@ -0,0 +1,135 @@
+//! The types the generated operations are built on, such as [`GraphQLQuery`].
+//!
+//! These have the same shape as the types of the same name in `graphql_client`. With the
+//! `graphql-client-compat` feature, which is enabled by default, they are re-exported from
+//! `graphql_client`, so the generated operations implement its `GraphQLQuery` trait.
+//! Without it, they are defined here, and `graphql_client` isn't a dependency.
+
+#[cfg(feature = "graphql-client-compat")]
+pub use graphql_client::{Error, GraphQLQuery, Location, PathFragment, QueryBody, Response};
+
+#[cfg(not(feature = "graphql-client-compat"))]
+pub use standalone::*;
+
+#[cfg(not(feature = "graphql-client-compat"))]
+mod standalone {
+    use std::collections::HashMap;
+    use std::fmt::{self, Display};
+
+    use serde::{Deserialize, Serialize};
+
+    /// A GraphQL operation, with the types of its variables and its response data.
+    pub trait GraphQLQuery {
+        /// The variables of the operation.
+        type Variables: Serialize;
+
+        /// The data of a successful response to the operation.
+        type ResponseData: for<'de> Deserialize<'de>;
+
+        /// Returns the body of a request for the operation with the given variables.
+        fn build_query(variables: Self::Variables) -> QueryBody<Self::Variables>;
+    }
+
+    /// The body of a request for a GraphQL operation.
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct QueryBody<Variables> {
+        /// The variables of the operation.
+        pub variables: Variables,
+
+        /// The GraphQL document of the operation.
+        pub query: &'static str,
+
+        /// The name of the operation in the document.
+        #[serde(rename = "operationName")]
+        pub operation_name: &'static str,
+    }
+
+    /// A location in a GraphQL document.
+    #[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+    pub struct Location {
+        /// The line, starting from 1.
+        pub line: i32,
+
+        /// The column, starting from 1.
+        pub column: i32,
+    }
+
+    /// A segment of the path of a GraphQL error.
+    #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+    #[serde(untagged)]
+    pub enum PathFragment {
+        /// The name of a field.
+        Key(String),
+
+        /// The index of an item in a list.
+        Index(i32),
+    }
+
+    impl Display for PathFragment {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                Self::Key(key) => write!(f, "{}", key),
+                Self::Index(index) => write!(f, "{}", index),
+            }
+        }
+    }
+
+    /// An error in a GraphQL response.
+    #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+    pub struct Error {
+        /// The description of the error.
+        pub message: String,
+
+        /// The locations in the document the error refers to.
+        pub locations: Option<Vec<Location>>,
+
+        /// The path of the field the error belongs to.
+        pub path: Option<Vec<PathFragment>>,
+
+        /// Additional information about the error, such as a `code`.
+        pub extensions: Option<HashMap<String, serde_json::Value>>,
+    }
+
+    impl Display for Error {
+        /// Formats the error as `path:line:column: message`, like `graphql_client` does.
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            let path = self
+                .path
+                .as_ref()
+                .map(|fragments| {
+                    fragments
+                        .iter()
+                        .map(ToString::to_string)
+                        .collect::<Vec<_>>()
+                        .join("/")
+                })
+                .unwrap_or_else(|| "<query>".to_string());
+
+            let location = self
+                .locations
+                .as_ref()
+                .and_then(|locations| locations.first())
+                .copied()
+                .unwrap_or_default();
+
+            write!(
+                f,
+                "{}:{}:{}: {}",
+                path, location.line, location.column, self.message
+            )
+        }
+    }
+
+    /// A GraphQL response.
+    #[derive(Debug, Serialize, Deserialize, PartialEq, Eq)]
+    pub struct Response<Data> {
+        /// The data of the response, which is absent when the operation failed entirely.
+        pub data: Option<Data>,
+
+        /// The errors that occurred while executing the operation.
+        pub errors: Option<Vec<Error>>,
+
+        /// Additional information about the response.
+        pub extensions: Option<HashMap<String, serde_json::Value>>,
+    }
+}
@@ -0,0 +1,2473 @@
+/// The types in the schema, sorted by name.
+pub static TYPES: &[crate::TypeInfo] = &[
+    crate::TypeInfo {
+        name: "BadgeCountModeEnum",
+        kind: crate::TypeKind::Enum,
+        fields: &[],
+        input_fields: &[],
+        enum_values: &["DUE", "NONE", "PRIORITY", "PRIORITY_DUE"],
+        possible_types: &[],
+    },
+    crate::TypeInfo {
+        name: "Board",
+        kind: crate::TypeKind::Object,
+        fields: &[
+            crate::FieldInfo {
+                name: "archivedAt",
+                graphql_type: "DateTime",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "emoji",
+                graphql_type: "String",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "id",
+                graphql_type: "ID!",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "lastViewedAt",
+                graphql_type: "DateTime",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "name",
+                graphql_type: "String!",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "projectColumns",
+                graphql_type: "[ProjectColumn!]!",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "projectCompletedProjectColumnId",
+                graphql_type: "ID",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "taskCompletedProjectColumnId",
+                graphql_type: "ID",
+                arguments: &[],
+                is_deprecated: false,
+            },
+        ],
+        input_fields: &[],
+        enum_values: &[],
+        possible_types: &[],
+    },
+    crate::TypeInfo {
+        name: "Boolean",
+        kind: crate::TypeKind::Scalar,
+        fields: &[],
+        input_fields: &[],
+        enum_values: &[],
+        possible_types: &[],
+    },
+    crate::TypeInfo {
+        name: "Container",
+        kind: crate::TypeKind::Interface,
+        fields: &[
+            crate::FieldInfo {
+                name: "collapseCompleted",
+                graphql_type: "Boolean!",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "containerType",
+                graphql_type: "ContainerTypeEnum!",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "groups",
+                graphql_type: "[Group!]!",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "id",
+                graphql_type: "ID!",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "noteBody",
+                graphql_type: "String",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "state",
+                graphql_type: "DiaryStateEnum",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "supportsNotes",
+                graphql_type: "Boolean!",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "tasks",
+                graphql_type: "TaskConnection!",
+                arguments: &[
+                    crate::InputValueInfo {
+                        name: "after",
+                        graphql_type: "String",
+                        default_value: None,
+                    },
+                    crate::InputValueInfo {
+                        name: "before",
+                        graphql_type: "String",
+                        default_value: None,
+                    },
+                    crate::InputValueInfo {
+                        name: "first",
+                        graphql_type: "Int",
+                        default_value: None,
+                    },
+                    crate::InputValueInfo {
+                        name: "last",
+                        graphql_type: "Int",
+                        default_value: None,
+                    },
+                ],
+                is_deprecated: false,
+            },
+        ],
+        input_fields: &[],
+        enum_values: &[],
+        possible_types: &["Diary", "Inbox", "Project"],
+    },
+    crate::TypeInfo {
+        name: "ContainerTypeEnum",
+        kind: crate::TypeKind::Enum,
+        fields: &[],
+        input_fields: &[],
+        enum_values: &["DIARY", "INBOX", "PROJECT"],
+        possible_types: &[],
+    },
+    crate::TypeInfo {
+        name: "Date",
+        kind: crate::TypeKind::Scalar,
+        fields: &[],
+        input_fields: &[],
+        enum_values: &[],
+        possible_types: &[],
+    },
+    crate::TypeInfo {
+        name: "DateTime",
+        kind: crate::TypeKind::Scalar,
+        fields: &[],
+        input_fields: &[],
+        enum_values: &[],
+        possible_types: &[],
+    },
+    crate::TypeInfo {
+        name: "Diary",
+        kind: crate::TypeKind::Object,
+        fields: &[
+            crate::FieldInfo {
+                name: "collapseCompleted",
+                graphql_type: "Boolean!",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "containerType",
+                graphql_type: "ContainerTypeEnum!",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "date",
+                graphql_type: "Date!",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "groups",
+                graphql_type: "[Group!]!",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "id",
+                graphql_type: "ID!",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "noteBody",
+                graphql_type: "String",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "notes",
+                graphql_type: "[Note!]!",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "state",
+                graphql_type: "DiaryStateEnum",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "supportsNotes",
+                graphql_type: "Boolean!",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "tasks",
+                graphql_type: "TaskConnection!",
+                arguments: &[
+                    crate::InputValueInfo {
+                        name: "after",
+                        graphql_type: "String",
+                        default_value: None,
+                    },
+                    crate::InputValueInfo {
+                        name: "before",
+                        graphql_type: "String",
+                        default_value: None,
+                    },
+                    crate::InputValueInfo {
+                        name: "first",
+                        graphql_type: "Int",
+                        default_value: None,
+                    },
+                    crate::InputValueInfo {
+                        name: "last",
+                        graphql_type: "Int",
+                        default_value: None,
+                    },
+                ],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "user",
+                graphql_type: "User!",
+                arguments: &[],
+                is_deprecated: false,
+            },
+        ],
+        input_fields: &[],
+        enum_values: &[],
+        possible_types: &[],
+    },
+    crate::TypeInfo {
+        name: "DiaryStateEnum",
+        kind: crate::TypeKind::Enum,
+        fields: &[],
+        input_fields: &[],
+        enum_values: &["FRESH", "PLANNED"],
+        possible_types: &[],
+    },
+    crate::TypeInfo {
+        name: "EnableOtpResult",
+        kind: crate::TypeKind::Union,
+        fields: &[],
+        input_fields: &[],
+        enum_values: &[],
+        possible_types: &["InvalidOtpAttempt", "OtpEnabled", "UserAlreadyHasOtp"],
+    },
+    crate::TypeInfo {
+        name: "GenerateNewOtpResult",
+        kind: crate::TypeKind::Union,
+        fields: &[],
+        input_fields: &[],
+        enum_values: &[],
+        possible_types: &["NewOtpGenerated", "UserAlreadyHasOtp"],
+    },
+    crate::TypeInfo {
+        name: "Group",
+        kind: crate::TypeKind::Object,
+        fields: &[
+            crate::FieldInfo {
+                name: "collapsed",
+                graphql_type: "Boolean",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "date",
+                graphql_type: "Date",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "id",
+                graphql_type: "ID!",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "keepTasks",
+                graphql_type: "Boolean!",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "name",
+                graphql_type: "String!",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "order",
+                graphql_type: "Int",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "projectId",
+                graphql_type: "ID",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "tasks",
+                graphql_type: "TaskConnection!",
+                arguments: &[
+                    crate::InputValueInfo {
+                        name: "after",
+                        graphql_type: "String",
+                        default_value: None,
+                    },
+                    crate::InputValueInfo {
+                        name: "before",
+                        graphql_type: "String",
+                        default_value: None,
+                    },
+                    crate::InputValueInfo {
+                        name: "first",
+                        graphql_type: "Int",
+                        default_value: None,
+                    },
+                    crate::InputValueInfo {
+                        name: "last",
+                        graphql_type: "Int",
+                        default_value: None,
+                    },
+                ],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "user",
+                graphql_type: "User!",
+                arguments: &[],
+                is_deprecated: false,
+            },
+        ],
+        input_fields: &[],
+        enum_values: &[],
+        possible_types: &[],
+    },
+    crate::TypeInfo {
+        name: "ID",
+        kind: crate::TypeKind::Scalar,
+        fields: &[],
+        input_fields: &[],
+        enum_values: &[],
+        possible_types: &[],
+    },
+    crate::TypeInfo {
+        name: "Inbox",
+        kind: crate::TypeKind::Object,
+        fields: &[
+            crate::FieldInfo {
+                name: "collapseCompleted",
+                graphql_type: "Boolean!",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "containerType",
+                graphql_type: "ContainerTypeEnum!",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "groups",
+                graphql_type: "[Group!]!",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "id",
+                graphql_type: "ID!",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "noteBody",
+                graphql_type: "String",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "state",
+                graphql_type: "DiaryStateEnum",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "supportsNotes",
+                graphql_type: "Boolean!",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "tasks",
+                graphql_type: "TaskConnection!",
+                arguments: &[
+                    crate::InputValueInfo {
+                        name: "after",
+                        graphql_type: "String",
+                        default_value: None,
+                    },
+                    crate::InputValueInfo {
+                        name: "before",
+                        graphql_type: "String",
+                        default_value: None,
+                    },
+                    crate::InputValueInfo {
+                        name: "first",
+                        graphql_type: "Int",
+                        default_value: None,
+                    },
+                    crate::InputValueInfo {
+                        name: "last",
+                        graphql_type: "Int",
+                        default_value: None,
+                    },
+                ],
+                is_deprecated: false,
+            },
+        ],
+        input_fields: &[],
+        enum_values: &[],
+        possible_types: &[],
+    },
+    crate::TypeInfo {
+        name: "Int",
+        kind: crate::TypeKind::Scalar,
+        fields: &[],
+        input_fields: &[],
+        enum_values: &[],
+        possible_types: &[],
+    },
+    crate::TypeInfo {
+        name: "InvalidOtpAttempt",
+        kind: crate::TypeKind::Object,
+        fields: &[crate::FieldInfo {
+            name: "message",
+            graphql_type: "String!",
+            arguments: &[],
+            is_deprecated: false,
+        }],
+        input_fields: &[],
+        enum_values: &[],
+        possible_types: &[],
+    },
+    crate::TypeInfo {
+        name: "LoginResponse",
+        kind: crate::TypeKind::Object,
+        fields: &[
+            crate::FieldInfo {
+                name: "accessToken",
+                graphql_type: "String!",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "user",
+                graphql_type: "User!",
+                arguments: &[],
+                is_deprecated: false,
+            },
+        ],
+        input_fields: &[],
+        enum_values: &[],
+        possible_types: &[],
+    },
+    crate::TypeInfo {
+        name: "Mutation",
+        kind: crate::TypeKind::Object,
+        fields: &[
+            crate::FieldInfo {
+                name: "archiveBoard",
+                graphql_type: "[Board!]!",
+                arguments: &[crate::InputValueInfo {
+                    name: "boardId",
+                    graphql_type: "ID!",
+                    default_value: None,
+                }],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "completeProject",
+                graphql_type: "Project!",
+                arguments: &[crate::InputValueInfo {
+                    name: "projectId",
+                    graphql_type: "ID!",
+                    default_value: None,
+                }],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "completeTask",
+                graphql_type: "[Task!]!",
+                arguments: &[crate::InputValueInfo {
+                    name: "taskId",
+                    graphql_type: "ID!",
+                    default_value: None,
+                }],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "createBoard",
+                graphql_type: "Board!",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "createBoards",
+                graphql_type: "[Board!]!",
+                arguments: &[crate::InputValueInfo {
+                    name: "names",
+                    graphql_type: "[String!]!",
+                    default_value: None,
+                }],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "createGroups",
+                graphql_type: "[Group!]!",
+                arguments: &[
+                    crate::InputValueInfo {
+                        name: "containerId",
+                        graphql_type: "ID!",
+                        default_value: None,
+                    },
+                    crate::InputValueInfo {
+                        name: "containerType",
+                        graphql_type: "ContainerTypeEnum!",
+                        default_value: None,
+                    },
+                    crate::InputValueInfo {
+                        name: "names",
+                        graphql_type: "[String!]!",
+                        default_value: None,
+                    },
+                ],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "createNote",
+                graphql_type: "Note!",
+                arguments: &[
+                    crate::InputValueInfo {
+                        name: "date",
+                        graphql_type: "Date",
+                        default_value: None,
+                    },
+                    crate::InputValueInfo {
+                        name: "name",
+                        graphql_type: "String",
+                        default_value: None,
+                    },
+                    crate::InputValueInfo {
+                        name: "projectId",
+                        graphql_type: "ID",
+                        default_value: None,
+                    },
+                ],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "createProject",
+                graphql_type: "Project!",
+                arguments: &[
+                    crate::InputValueInfo {
+                        name: "boardId",
+                        graphql_type: "ID",
+                        default_value: None,
+                    },
+                    crate::InputValueInfo {
+                        name: "name",
+                        graphql_type: "String",
+                        default_value: None,
+                    },
+                    crate::InputValueInfo {
+                        name: "projectColumnId",
+                        graphql_type: "ID",
+                        default_value: None,
+                    },
+                    crate::InputValueInfo {
+                        name: "sourceTaskId",
+                        graphql_type: "ID",
+                        default_value: None,
+                    },
+                ],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "createProjectColumn",
+                graphql_type: "ProjectColumn!",
+                arguments: &[
+                    crate::InputValueInfo {
+                        name: "boardId",
+                        graphql_type: "ID!",
+                        default_value: None,
+                    },
+                    crate::InputValueInfo {
+                        name: "name",
+                        graphql_type: "String!",
+                        default_value: None,
+                    },
+                ],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "createProjects",
+                graphql_type: "[Project!]!",
+                arguments: &[
+                    crate::InputValueInfo {
+                        name: "boardId",
+                        graphql_type: "ID",
+                        default_value: None,
+                    },
+                    crate::InputValueInfo {
+                        name: "date",
+                        graphql_type: "Date",
+                        default_value: None,
+                    },
+                    crate::InputValueInfo {
+                        name: "names",
+                        graphql_type: "[String!]!",
+                        default_value: None,
+                    },
+                    crate::InputValueInfo {
+                        name: "projectColumnId",
+                        graphql_type: "ID",
+                        default_value: None,
+                    },
+                ],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "createTasks",
+                graphql_type: "[Task!]!",
+                arguments: &[
+                    crate::InputValueInfo {
+                        name: "after",
+                        graphql_type: "ID",
+                        default_value: None,
+                    },
+                    crate::InputValueInfo {
+                        name: "date",
+                        graphql_type: "Date",
+                        default_value: None,
+                    },
+                    crate::InputValueInfo {
+                        name: "groupId",
+                        graphql_type: "ID",
+                        default_value: None,
+                    },
+                    crate::InputValueInfo {
+                        name: "link",
+                        graphql_type: "String",
+                        default_value: None,
+                    },
+                    crate::InputValueInfo {
+                        name: "dueDate",
+                        graphql_type: "Date",
+                        default_value: None,
+                    },
+                    crate::InputValueInfo {
+                        name: "names",
+                        graphql_type: "[String!]!",
+                        default_value: None,
+                    },
+                    crate::InputValueInfo {
+                        name: "prioritized",
+                        graphql_type: "Boolean",
+                        default_value: None,
+                    },
+                    crate::InputValueInfo {
+                        name: "projectId",
+                        graphql_type: "ID",
+                        default_value: None,
+                    },
+                    crate::InputValueInfo {
+                        name: "tagSlug",
+                        graphql_type: "String",
+                        default_value: None,
+                    },
+                ],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "deleteBoard",
+                graphql_type: "Board!",
+                arguments: &[crate::InputValueInfo {
+                    name: "boardId",
+                    graphql_type: "ID!",
+                    default_value: None,
+                }],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "deleteGroup",
+                graphql_type: "Group!",
+                arguments: &[
+                    crate::InputValueInfo {
+                        name: "deleteTasks",
+                        graphql_type: "Boolean",
+                        default_value: Some("false"),
+                    },
+                    crate::InputValueInfo {
+                        name: "groupId",
+                        graphql_type: "ID!",
+                        default_value: None,
+                    },
+                ],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "deleteNote",
+                graphql_type: "Note!",
+                arguments: &[crate::InputValueInfo {
+                    name: "noteId",
+                    graphql_type: "ID!",
+                    default_value: None,
+                }],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "deleteProject",
+                graphql_type: "Project!",
+                arguments: &[
+                    crate::InputValueInfo {
+                        name: "deleteTasks",
+                        graphql_type: "Boolean",
+                        default_value: Some("false"),
+                    },
+                    crate::InputValueInfo {
+                        name: "projectId",
+                        graphql_type: "ID!",
+                        default_value: None,
+                    },
+                ],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "deleteTask",
+                graphql_type: "Task!",
+                arguments: &[crate::InputValueInfo {
+                    name: "taskId",
+                    graphql_type: "ID!",
+                    default_value: None,
+                }],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "deleteTasks",
+                graphql_type: "[Task!]!",
+                arguments: &[crate::InputValueInfo {
+                    name: "taskIds",
+                    graphql_type: "[ID!]!",
+                    default_value: None,
+                }],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "enableOtp",
+                graphql_type: "EnableOtpResult!",
+                arguments: &[
+                    crate::InputValueInfo {
+                        name: "otpAttempt",
+                        graphql_type: "String!",
+                        default_value: None,
+                    },
+                    crate::InputValueInfo {
+                        name: "otpSecret",
+                        graphql_type: "String!",
+                        default_value: None,
+                    },
+                ],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "generateNewOtp",
+                graphql_type: "GenerateNewOtpResult!",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "moveTasks",
+                graphql_type: "[Task!]!",
+                arguments: &[
+                    crate::InputValueInfo {
+                        name: "date",
+                        graphql_type: "Date!",
+                        default_value: None,
+                    },
+                    crate::InputValueInfo {
+                        name: "taskIds",
+                        graphql_type: "[ID!]!",
+                        default_value: None,
+                    },
+                ],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "persistGroupOrder",
+                graphql_type: "[Group!]!",
+                arguments: &[crate::InputValueInfo {
+                    name: "order",
+                    graphql_type: "[OrderInput!]",
+                    default_value: None,
+                }],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "persistPriorityOrder",
+                graphql_type: "[Task!]!",
+                arguments: &[crate::InputValueInfo {
+                    name: "order",
+                    graphql_type: "[OrderInput!]",
+                    default_value: None,
+                }],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "persistProjectColumnOrder",
+                graphql_type: "[ProjectColumn!]!",
+                arguments: &[crate::InputValueInfo {
+                    name: "order",
+                    graphql_type: "[OrderInput!]",
+                    default_value: None,
+                }],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "persistProjectOrder",
+                graphql_type: "[ProjectColumn!]!",
+                arguments: &[crate::InputValueInfo {
+                    name: "order",
+                    graphql_type: "[OrderInput!]",
+                    default_value: None,
+                }],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "persistTaskOrder",
+                graphql_type: "[Task!]!",
+                arguments: &[crate::InputValueInfo {
+                    name: "taskOrder",
+                    graphql_type: "[OrderInput!]",
+                    default_value: None,
+                }],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "prioritizeTasks",
+                graphql_type: "[Task!]!",
+                arguments: &[crate::InputValueInfo {
+                    name: "ids",
+                    graphql_type: "[ID!]!",
+                    default_value: None,
+                }],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "registerUser",
+                graphql_type: "LoginResponse!",
+                arguments: &[
+                    crate::InputValueInfo {
+                        name: "email",
+                        graphql_type: "String!",
+                        default_value: None,
+                    },
+                    crate::InputValueInfo {
+                        name: "password",
+                        graphql_type: "String!",
+                        default_value: None,
+                    },
+                    crate::InputValueInfo {
+                        name: "secretCode",
+                        graphql_type: "String!",
+                        default_value: None,
+                    },
+                ],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "springProject",
+                graphql_type: "Project!",
+                arguments: &[crate::InputValueInfo {
+                    name: "projectId",
+                    graphql_type: "ID!",
+                    default_value: None,
+                }],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "tagTask",
+                graphql_type: "Task!",
+                arguments: &[
+                    crate::InputValueInfo {
+                        name: "tagId",
+                        graphql_type: "ID!",
+                        default_value: None,
+                    },
+                    crate::InputValueInfo {
+                        name: "taskId",
+                        graphql_type: "ID!",
+                        default_value: None,
+                    },
+                ],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "unarchiveBoard",
+                graphql_type: "[Board!]!",
+                arguments: &[crate::InputValueInfo {
+                    name: "boardId",
+                    graphql_type: "ID!",
+                    default_value: None,
+                }],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "uncompleteProject",
+                graphql_type: "Project!",
+                arguments: &[crate::InputValueInfo {
+                    name: "projectId",
+                    graphql_type: "ID!",
+                    default_value: None,
+                }],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "uncompleteTask",
+                graphql_type: "Task!",
+                arguments: &[crate::InputValueInfo {
+                    name: "taskId",
+                    graphql_type: "ID!",
+                    default_value: None,
+                }],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "unprioritizeTasks",
+                graphql_type: "[Task!]!",
+                arguments: &[crate::InputValueInfo {
+                    name: "ids",
+                    graphql_type: "[ID!]!",
+                    default_value: None,
+                }],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "unspringProject",
+                graphql_type: "Project!",
+                arguments: &[crate::InputValueInfo {
+                    name: "projectId",
+                    graphql_type: "ID!",
+                    default_value: None,
+                }],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "updateBoard",
+                graphql_type: "Board!",
+                arguments: &[
+                    crate::InputValueInfo {
+                        name: "emoji",
+                        graphql_type: "String",
+                        default_value: None,
+                    },
+                    crate::InputValueInfo {
+                        name: "name",
+                        graphql_type: "String",
+                        default_value: None,
+                    },
+                    crate::InputValueInfo {
+                        name: "boardId",
+                        graphql_type: "ID!",
+                        default_value: None,
+                    },
+                    crate::InputValueInfo {
+                        name: "projectCompletedProjectColumnId",
+                        graphql_type: "ID",
+                        default_value: None,
+                    },
+                    crate::InputValueInfo {
+                        name: "taskCompletedProjectColumnId",
+                        graphql_type: "ID",
+                        default_value: None,
+                    },
+                ],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "updateContainer",
+                graphql_type: "Container!",
+                arguments: &[
+                    crate::InputValueInfo {
+                        name: "collapseCompleted",
+                        graphql_type: "Boolean",
+                        default_value: None,
+                    },
+                    crate::InputValueInfo {
+                        name: "date",
+                        graphql_type: "Date",
+                        default_value: None,
+                    },
+                    crate::InputValueInfo {
+                        name: "noteBody",
+                        graphql_type: "String",
+                        default_value: None,
+                    },
+                    crate::InputValueInfo {
+                        name: "projectId",
+                        graphql_type: "ID",
+                        default_value: None,
+                    },
+                    crate::InputValueInfo {
+                        name: "state",
+                        graphql_type: "DiaryStateEnum",
+                        default_value: None,
+                    },
+                ],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "updateDiary",
+                graphql_type: "Diary!",
+                arguments: &[
+                    crate::InputValueInfo {
+                        name: "date",
+                        graphql_type: "Date!",
+                        default_value: None,
+                    },
+                    crate::InputValueInfo {
+                        name: "noteBody",
+                        graphql_type: "String!",
+                        default_value: None,
+                    },
+                ],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "updateGroup",
+                graphql_type: "Group!",
+                arguments: &[
+                    crate::InputValueInfo {
+                        name: "collapsed",
+                        graphql_type: "Boolean",
+                        default_value: None,
+                    },
+                    crate::InputValueInfo {
+                        name: "date",
+                        graphql_type: "Date",
+                        default_value: None,
+                    },
+                    crate::InputValueInfo {
+                        name: "groupId",
+                        graphql_type: "ID!",
+                        default_value: None,
+                    },
+                    crate::InputValueInfo {
+                        name: "keepTasks",
+                        graphql_type: "Boolean",
+                        default_value: None,
+                    },
+                    crate::InputValueInfo {
+                        name: "name",
+                        graphql_type: "String",
+                        default_value: None,
+                    },
+                ],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "updateNote",
+                graphql_type: "UpdateNoteResult!",
+                arguments: &[
+                    crate::InputValueInfo {
+                        name: "body",
+                        graphql_type: "String",
+                        default_value: None,
+                    },
+                    crate::InputValueInfo {
+                        name: "date",
+                        graphql_type: "Date",
+                        default_value: None,
+                    },
+                    crate::InputValueInfo {
+                        name: "endDate",
+                        graphql_type: "Date",
+                        default_value: None,
+                    },
+                    crate::InputValueInfo {
+                        name: "hidePreview",
+                        graphql_type: "Boolean",
+                        default_value: None,
+                    },
+                    crate::InputValueInfo {
+                        name: "lastUpdatedAt",
+                        graphql_type: "DateTime",
+                        default_value: None,
+                    },
+                    crate::InputValueInfo {
+                        name: "name",
+                        graphql_type: "String",
+                        default_value: None,
+                    },
+                    crate::InputValueInfo {
+                        name: "noteId",
+                        graphql_type: "ID!",
+                        default_value: None,
+                    },
+                    crate::InputValueInfo {
+                        name: "projectId",
+                        graphql_type: "ID",
+                        default_value: None,
+                    },
+                ],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "updateProject",
+                graphql_type: "Project!",
+                arguments: &[
+                    crate::InputValueInfo {
+                        name: "boardId",
+                        graphql_type: "ID",
+                        default_value: None,
+                    },
+                    crate::InputValueInfo {
+                        name: "date",
+                        graphql_type: "Date",
+                        default_value: None,
+                    },
+                    crate::InputValueInfo {
+                        name: "endDate",
+                        graphql_type: "Date",
+                        default_value: None,
+                    },
+                    crate::InputValueInfo {
+                        name: "name",
+                        graphql_type: "String",
+                        default_value: None,
+                    },
+                    crate::InputValueInfo {
+                        name: "projectId",
+                        graphql_type: "ID!",
+                        default_value: None,
+                    },
+                ],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "updateProjectColumn",
+                graphql_type: "ProjectColumn!",
+                arguments: &[
+                    crate::InputValueInfo {
+                        name: "collapsed",
+                        graphql_type: "Boolean",
+                        default_value: None,
+                    },
+                    crate::InputValueInfo {
+                        name: "name",
+                        graphql_type: "String",
+                        default_value: None,
+                    },
+                    crate::InputValueInfo {
+                        name: "projectColumnId",
+                        graphql_type: "ID!",
+                        default_value: None,
+                    },
+                ],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "updateTask",
+                graphql_type: "Task!",
+                arguments: &[
+                    crate::InputValueInfo {
+                        name: "date",
+                        graphql_type: "Date",
+                        default_value: None,
+                    },
+                    crate::InputValueInfo {
+                        name: "description",
+                        graphql_type: "String",
+                        default_value: None,
+                    },
+                    crate::InputValueInfo {
+                        name: "dueDate",
+                        graphql_type: "Date",
+                        default_value: None,
+                    },
+                    crate::InputValueInfo {
+                        name: "link",
+                        graphql_type: "String",
+                        default_value: None,
+                    },
+                    crate::InputValueInfo {
+                        name: "name",
+                        graphql_type: "String",
+                        default_value: None,
+                    },
+                    crate::InputValueInfo {
+                        name: "projectId",
+                        graphql_type: "ID",
+                        default_value: None,
+                    },
+                    crate::InputValueInfo {
+                        name: "recurrence",
+                        graphql_type: "RecurrenceInput",
+                        default_value: None,
+                    },
+                    crate::InputValueInfo {
+                        name: "taskId",
+                        graphql_type: "ID!",
+                        default_value: None,
+                    },
+                ],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "updateUserSettings",
+                graphql_type: "User!",
+                arguments: &[crate::InputValueInfo {
+                    name: "badgeCountMode",
+                    graphql_type: "BadgeCountModeEnum",
+                    default_value: None,
+                }],
+                is_deprecated: false,
+            },
+        ],
+        input_fields: &[],
+        enum_values: &[],
+        possible_types: &[],
+    },
+    crate::TypeInfo {
+        name: "NewOtpGenerated",
+        kind: crate::TypeKind::Object,
+        fields: &[
+            crate::FieldInfo {
+                name: "darkQrCode",
+                graphql_type: "String!",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "lightQrCode",
+                graphql_type: "String!",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "otpSecret",
+                graphql_type: "String!",
+                arguments: &[],
+                is_deprecated: false,
+            },
+        ],
+        input_fields: &[],
+        enum_values: &[],
+        possible_types: &[],
+    },
+    crate::TypeInfo {
+        name: "Notable",
+        kind: crate::TypeKind::Interface,
+        fields: &[crate::FieldInfo {
+            name: "notes",
+            graphql_type: "[Note!]!",
+            arguments: &[],
+            is_deprecated: false,
+        }],
+        input_fields: &[],
+        enum_values: &[],
+        possible_types: &["Diary", "Project"],
+    },
+    crate::TypeInfo {
+        name: "Note",
+        kind: crate::TypeKind::Object,
+        fields: &[
+            crate::FieldInfo {
+                name: "body",
+                graphql_type: "String",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "date",
+                graphql_type: "Date",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "endDate",
+                graphql_type: "Date",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "hidePreview",
+                graphql_type: "Boolean!",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "id",
+                graphql_type: "ID!",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "name",
+                graphql_type: "String!",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "project",
+                graphql_type: "Project",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "updatedAt",
+                graphql_type: "DateTime!",
+                arguments: &[],
+                is_deprecated: false,
+            },
+        ],
+        input_fields: &[],
+        enum_values: &[],
+        possible_types: &[],
+    },
+    crate::TypeInfo {
+        name: "NoteUpdateOutdated",
+        kind: crate::TypeKind::Object,
+        fields: &[crate::FieldInfo {
+            name: "note",
+            graphql_type: "Note!",
+            arguments: &[],
+            is_deprecated: false,
+        }],
+        input_fields: &[],
+        enum_values: &[],
+        possible_types: &[],
+    },
+    crate::TypeInfo {
+        name: "NoteUpdated",
+        kind: crate::TypeKind::Object,
+        fields: &[crate::FieldInfo {
+            name: "note",
+            graphql_type: "Note!",
+            arguments: &[],
+            is_deprecated: false,
+        }],
+        input_fields: &[],
+        enum_values: &[],
+        possible_types: &[],
+    },
+    crate::TypeInfo {
+        name: "OrderInput",
+        kind: crate::TypeKind::InputObject,
+        fields: &[],
+        input_fields: &[
+            crate::InputValueInfo {
+                name: "id",
+                graphql_type: "ID",
+                default_value: None,
+            },
+            crate::InputValueInfo {
+                name: "order",
+                graphql_type: "Int",
+                default_value: None,
+            },
+            crate::InputValueInfo {
+                name: "destinationGroupId",
+                graphql_type: "ID",
+                default_value: None,
+            },
+            crate::InputValueInfo {
+                name: "sourceGroupId",
+                graphql_type: "ID",
+                default_value: None,
+            },
+            crate::InputValueInfo {
+                name: "projectColumnId",
+                graphql_type: "ID",
+                default_value: None,
+            },
+        ],
+        enum_values: &[],
+        possible_types: &[],
+    },
+    crate::TypeInfo {
+        name: "OtpEnabled",
+        kind: crate::TypeKind::Object,
+        fields: &[crate::FieldInfo {
+            name: "message",
+            graphql_type: "String!",
+            arguments: &[],
+            is_deprecated: false,
+        }],
+        input_fields: &[],
+        enum_values: &[],
+        possible_types: &[],
+    },
+    crate::TypeInfo {
+        name: "PageInfo",
+        kind: crate::TypeKind::Object,
+        fields: &[
+            crate::FieldInfo {
+                name: "endCursor",
+                graphql_type: "String",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "hasNextPage",
+                graphql_type: "Boolean!",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "hasPreviousPage",
+                graphql_type: "Boolean!",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "startCursor",
+                graphql_type: "String",
+                arguments: &[],
+                is_deprecated: false,
+            },
+        ],
+        input_fields: &[],
+        enum_values: &[],
+        possible_types: &[],
+    },
+    crate::TypeInfo {
+        name: "Project",
+        kind: crate::TypeKind::Object,
+        fields: &[
+            crate::FieldInfo {
+                name: "collapseCompleted",
+                graphql_type: "Boolean!",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "completed",
+                graphql_type: "Boolean!",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "completedAt",
+                graphql_type: "DateTime",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "containerType",
+                graphql_type: "ContainerTypeEnum!",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "date",
+                graphql_type: "Date",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "endDate",
+                graphql_type: "Date",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "groups",
+                graphql_type: "[Group!]!",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "id",
+                graphql_type: "ID!",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "link",
+                graphql_type: "String",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "name",
+                graphql_type: "String!",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "noteBody",
+                graphql_type: "String",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "notes",
+                graphql_type: "[Note!]!",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "order",
+                graphql_type: "Int",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "projectColumn",
+                graphql_type: "ProjectColumn!",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "springEnabled",
+                graphql_type: "Boolean!",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "state",
+                graphql_type: "DiaryStateEnum",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "supportsNotes",
+                graphql_type: "Boolean!",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "tasks",
+                graphql_type: "TaskConnection!",
+                arguments: &[
+                    crate::InputValueInfo {
+                        name: "after",
+                        graphql_type: "String",
+                        default_value: None,
+                    },
+                    crate::InputValueInfo {
+                        name: "before",
+                        graphql_type: "String",
+                        default_value: None,
+                    },
+                    crate::InputValueInfo {
+                        name: "first",
+                        graphql_type: "Int",
+                        default_value: None,
+                    },
+                    crate::InputValueInfo {
+                        name: "last",
+                        graphql_type: "Int",
+                        default_value: None,
+                    },
+                ],
+                is_deprecated: false,
+            },
+        ],
+        input_fields: &[],
+        enum_values: &[],
+        possible_types: &[],
+    },
+    crate::TypeInfo {
+        name: "ProjectColumn",
+        kind: crate::TypeKind::Object,
+        fields: &[
+            crate::FieldInfo {
+                name: "board",
+                graphql_type: "Board!",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "collapsed",
+                graphql_type: "Boolean!",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "id",
+                graphql_type: "ID!",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "name",
+                graphql_type: "String!",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "order",
+                graphql_type: "Int!",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "projects",
+                graphql_type: "[Project!]",
+                arguments: &[],
+                is_deprecated: false,
+            },
+        ],
+        input_fields: &[],
+        enum_values: &[],
+        possible_types: &[],
+    },
+    crate::TypeInfo {
+        name: "Query",
+        kind: crate::TypeKind::Object,
+        fields: &[
+            crate::FieldInfo {
+                name: "board",
+                graphql_type: "Board",
+                arguments: &[crate::InputValueInfo {
+                    name: "boardId",
+                    graphql_type: "ID",
+                    default_value: Some("null"),
+                }],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "boards",
+                graphql_type: "[Board!]!",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "container",
+                graphql_type: "Container!",
+                arguments: &[
+                    crate::InputValueInfo {
+                        name: "date",
+                        graphql_type: "Date",
+                        default_value: Some("null"),
+                    },
+                    crate::InputValueInfo {
+                        name: "inbox",
+                        graphql_type: "Boolean",
+                        default_value: Some("false"),
+                    },
+                    crate::InputValueInfo {
+                        name: "projectId",
+                        graphql_type: "ID",
+                        default_value: Some("null"),
+                    },
+                ],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "currentUser",
+                graphql_type: "User!",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "diary",
+                graphql_type: "Diary!",
+                arguments: &[crate::InputValueInfo {
+                    name: "date",
+                    graphql_type: "Date!",
+                    default_value: None,
+                }],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "me",
+                graphql_type: "User",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "note",
+                graphql_type: "Note",
+                arguments: &[crate::InputValueInfo {
+                    name: "noteId",
+                    graphql_type: "ID!",
+                    default_value: None,
+                }],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "notes",
+                graphql_type: "[Note!]",
+                arguments: &[
+                    crate::InputValueInfo {
+                        name: "date",
+                        graphql_type: "Date",
+                        default_value: Some("null"),
+                    },
+                    crate::InputValueInfo {
+                        name: "projectId",
+                        graphql_type: "ID",
+                        default_value: Some("null"),
+                    },
+                    crate::InputValueInfo {
+                        name: "query",
+                        graphql_type: "String",
+                        default_value: Some("null"),
+                    },
+                ],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "project",
+                graphql_type: "Project",
+                arguments: &[crate::InputValueInfo {
+                    name: "projectId",
+                    graphql_type: "ID!",
+                    default_value: None,
+                }],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "projectColumns",
+                graphql_type: "[ProjectColumn!]",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "projects",
+                graphql_type: "[Project!]",
+                arguments: &[
+                    crate::InputValueInfo {
+                        name: "date",
+                        graphql_type: "Date",
+                        default_value: Some("null"),
+                    },
+                    crate::InputValueInfo {
+                        name: "limit",
+                        graphql_type: "Int",
+                        default_value: Some("20"),
+                    },
+                    crate::InputValueInfo {
+                        name: "query",
+                        graphql_type: "String",
+                        default_value: Some("null"),
+                    },
+                    crate::InputValueInfo {
+                        name: "boardId",
+                        graphql_type: "ID",
+                        default_value: Some("null"),
+                    },
+                ],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "search",
+                graphql_type: "Search!",
+                arguments: &[crate::InputValueInfo {
+                    name: "query",
+                    graphql_type: "String!",
+                    default_value: None,
+                }],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "tags",
+                graphql_type: "[Tag!]",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "tasks",
+                graphql_type: "[Task!]",
+                arguments: &[
+                    crate::InputValueInfo {
+                        name: "completed",
+                        graphql_type: "Boolean",
+                        default_value: Some("true"),
+                    },
+                    crate::InputValueInfo {
+                        name: "date",
+                        graphql_type: "Date",
+                        default_value: Some("null"),
+                    },
+                    crate::InputValueInfo {
+                        name: "dueDate",
+                        graphql_type: "Date",
+                        default_value: Some("null"),
+                    },
+                    crate::InputValueInfo {
+                        name: "focus",
+                        graphql_type: "Boolean",
+                        default_value: Some("false"),
+                    },
+                    crate::InputValueInfo {
+                        name: "inbox",
+                        graphql_type: "Boolean",
+                        default_value: Some("false"),
+                    },
+                    crate::InputValueInfo {
+                        name: "projectId",
+                        graphql_type: "ID",
+                        default_value: Some("null"),
+                    },
+                ],
+                is_deprecated: false,
+            },
+        ],
+        input_fields: &[],
+        enum_values: &[],
+        possible_types: &[],
+    },
+    crate::TypeInfo {
+        name: "Recurrence",
+        kind: crate::TypeKind::Object,
+        fields: &[
+            crate::FieldInfo {
+                name: "friday",
+                graphql_type: "Boolean!",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "id",
+                graphql_type: "ID!",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "kind",
+                graphql_type: "String!",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "monday",
+                graphql_type: "Boolean!",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "rule",
+                graphql_type: "String",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "saturday",
+                graphql_type: "Boolean!",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "separation",
+                graphql_type: "Int",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "sunday",
+                graphql_type: "Boolean!",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "thursday",
+                graphql_type: "Boolean!",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "tuesday",
+                graphql_type: "Boolean!",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "wednesday",
+                graphql_type: "Boolean!",
+                arguments: &[],
+                is_deprecated: false,
+            },
+        ],
+        input_fields: &[],
+        enum_values: &[],
+        possible_types: &[],
+    },
+    crate::TypeInfo {
+        name: "RecurrenceInput",
+        kind: crate::TypeKind::InputObject,
+        fields: &[],
+        input_fields: &[
+            crate::InputValueInfo {
+                name: "id",
+                graphql_type: "ID",
+                default_value: None,
+            },
+            crate::InputValueInfo {
+                name: "friday",
+                graphql_type: "Boolean",
+                default_value: None,
+            },
+            crate::InputValueInfo {
+                name: "kind",
+                graphql_type: "String!",
+                default_value: None,
+            },
+            crate::InputValueInfo {
+                name: "monday",
+                graphql_type: "Boolean",
+                default_value: None,
+            },
+            crate::InputValueInfo {
+                name: "rule",
+                graphql_type: "String",
+                default_value: None,
+            },
+            crate::InputValueInfo {
+                name: "separation",
+                graphql_type: "Int",
+                default_value: None,
+            },
+            crate::InputValueInfo {
+                name: "saturday",
+                graphql_type: "Boolean",
+                default_value: None,
+            },
+            crate::InputValueInfo {
+                name: "sunday",
+                graphql_type: "Boolean",
+                default_value: None,
+            },
+            crate::InputValueInfo {
+                name: "thursday",
+                graphql_type: "Boolean",
+                default_value: None,
+            },
+            crate::InputValueInfo {
+                name: "tuesday",
+                graphql_type: "Boolean",
+                default_value: None,
+            },
+            crate::InputValueInfo {
+                name: "wednesday",
+                graphql_type: "Boolean",
+                default_value: None,
+            },
+        ],
+        enum_values: &[],
+        possible_types: &[],
+    },
+    crate::TypeInfo {
+        name: "Search",
+        kind: crate::TypeKind::Object,
+        fields: &[
+            crate::FieldInfo {
+                name: "boards",
+                graphql_type: "[Board!]!",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "notes",
+                graphql_type: "[Note!]!",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "projects",
+                graphql_type: "[Project!]!",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "tasks",
+                graphql_type: "[Task!]!",
+                arguments: &[],
+                is_deprecated: false,
+            },
+        ],
+        input_fields: &[],
+        enum_values: &[],
+        possible_types: &[],
+    },
+    crate::TypeInfo {
+        name: "String",
+        kind: crate::TypeKind::Scalar,
+        fields: &[],
+        input_fields: &[],
+        enum_values: &[],
+        possible_types: &[],
+    },
+    crate::TypeInfo {
+        name: "Subscription",
+        kind: crate::TypeKind::Object,
+        fields: &[
+            crate::FieldInfo {
+                name: "groupsUpdated",
+                graphql_type: "[Group!]!",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "tasksCreated",
+                graphql_type: "[Task!]!",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "tasksUpdated",
+                graphql_type: "[Task!]!",
+                arguments: &[],
+                is_deprecated: false,
+            },
+        ],
+        input_fields: &[],
+        enum_values: &[],
+        possible_types: &[],
+    },
+    crate::TypeInfo {
+        name: "Tag",
+        kind: crate::TypeKind::Object,
+        fields: &[
+            crate::FieldInfo {
+                name: "id",
+                graphql_type: "ID!",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "name",
+                graphql_type: "String!",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "slug",
+                graphql_type: "String!",
+                arguments: &[],
+                is_deprecated: false,
+            },
+        ],
+        input_fields: &[],
+        enum_values: &[],
+        possible_types: &[],
+    },
+    crate::TypeInfo {
+        name: "Task",
+        kind: crate::TypeKind::Object,
+        fields: &[
+            crate::FieldInfo {
+                name: "completed",
+                graphql_type: "Boolean!",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "completedAt",
+                graphql_type: "DateTime",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "date",
+                graphql_type: "Date",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "description",
+                graphql_type: "String",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "dueDate",
+                graphql_type: "Date",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "groupIds",
+                graphql_type: "[ID!]!",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "groups",
+                graphql_type: "[Group!]!",
+                arguments: &[],
+                is_deprecated: true,
+            },
+            crate::FieldInfo {
+                name: "id",
+                graphql_type: "ID!",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "isRecurring",
+                graphql_type: "Boolean!",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "link",
+                graphql_type: "String",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "name",
+                graphql_type: "String!",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "orders",
+                graphql_type: "TaskOrder!",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "priorityOrder",
+                graphql_type: "Int",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "project",
+                graphql_type: "Project",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "recurrence",
+                graphql_type: "Recurrence",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "spring",
+                graphql_type: "Boolean!",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "tags",
+                graphql_type: "[Tag!]",
+                arguments: &[],
+                is_deprecated: false,
+            },
+        ],
+        input_fields: &[],
+        enum_values: &[],
+        possible_types: &[],
+    },
+    crate::TypeInfo {
+        name: "TaskConnection",
+        kind: crate::TypeKind::Object,
+        fields: &[
+            crate::FieldInfo {
+                name: "completedCount",
+                graphql_type: "Int!",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "edges",
+                graphql_type: "[TaskWithOrderEdge]",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "nodes",
+                graphql_type: "[Task]",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "pageInfo",
+                graphql_type: "PageInfo!",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "totalCount",
+                graphql_type: "Int!",
+                arguments: &[],
+                is_deprecated: false,
+            },
+        ],
+        input_fields: &[],
+        enum_values: &[],
+        possible_types: &[],
+    },
+    crate::TypeInfo {
+        name: "TaskOrder",
+        kind: crate::TypeKind::Object,
+        fields: &[
+            crate::FieldInfo {
+                name: "dateOrder",
+                graphql_type: "Int",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "projectOrder",
+                graphql_type: "Int",
+                arguments: &[],
+                is_deprecated: false,
+            },
+        ],
+        input_fields: &[],
+        enum_values: &[],
+        possible_types: &[],
+    },
+    crate::TypeInfo {
+        name: "TaskWithOrderEdge",
+        kind: crate::TypeKind::Object,
+        fields: &[
+            crate::FieldInfo {
+                name: "cursor",
+                graphql_type: "String!",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "node",
+                graphql_type: "Task",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "order",
+                graphql_type: "Int",
+                arguments: &[],
+                is_deprecated: false,
+            },
+        ],
+        input_fields: &[],
+        enum_values: &[],
+        possible_types: &[],
+    },
+    crate::TypeInfo {
+        name: "UpdateNoteResult",
+        kind: crate::TypeKind::Union,
+        fields: &[],
+        input_fields: &[],
+        enum_values: &[],
+        possible_types: &["NoteUpdateOutdated", "NoteUpdated"],
+    },
+    crate::TypeInfo {
+        name: "User",
+        kind: crate::TypeKind::Object,
+        fields: &[
+            crate::FieldInfo {
+                name: "email",
+                graphql_type: "String!",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "id",
+                graphql_type: "ID!",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "isMfaEnabled",
+                graphql_type: "Boolean!",
+                arguments: &[],
+                is_deprecated: false,
+            },
+            crate::FieldInfo {
+                name: "settings",
+                graphql_type: "UserSetting!",
+                arguments: &[],
+                is_deprecated: false,
+            },
+        ],
+        input_fields: &[],
+        enum_values: &[],
+        possible_types: &[],
+    },
+    crate::TypeInfo {
+        name: "UserAlreadyHasOtp",
+        kind: crate::TypeKind::Object,
+        fields: &[crate::FieldInfo {
+            name: "message",
+            graphql_type: "String!",
+            arguments: &[],
+            is_deprecated: false,
+        }],
+        input_fields: &[],
+        enum_values: &[],
+        possible_types: &[],
+    },
+    crate::TypeInfo {
+        name: "UserSetting",
+        kind: crate::TypeKind::Object,
+        fields: &[crate::FieldInfo {
+            name: "badgeCountMode",
+            graphql_type: "BadgeCountModeEnum!",
+            arguments: &[],
+            is_deprecated: false,
+        }],
+        input_fields: &[],
+        enum_values: &[],
+        possible_types: &[],
+    },
+];
+
+/// Returns the type with the given name, if the schema has one.
+pub fn find_type(name: &str) -> Option<&'static crate::TypeInfo> {
+    TYPES
+        .binary_search_by(|ty| ty.name.cmp(name))
+        .ok()
+        .map(|index| &TYPES[index])
+}
+
+/// The names of the generated operations that return each type, sorted by type name.
+///
+/// Operations that return an interface or a union are listed under its possible types too.
+pub static TYPE_OPERATIONS: &[(&str, &[&str])] = &[
+    (
+        "Board",
+        &[
+            "ArchiveBoard",
+            "Board",
+            "Boards",
+            "CreateBoard",
+            "CreateBoards",
+            "DeleteBoard",
+            "UnarchiveBoard",
+            "UpdateBoard",
+        ],
+    ),
+    ("Container", &["Container", "UpdateContainer"]),
+    (
+        "Diary",
+        &["Container", "Diary", "UpdateContainer", "UpdateDiary"],
+    ),
+    ("EnableOtpResult", &["EnableOtp"]),
+    ("GenerateNewOtpResult", &["GenerateNewOtp"]),
+    (
+        "Group",
+        &[
+            "CreateGroups",
+            "DeleteGroup",
+            "GroupsUpdated",
+            "PersistGroupOrder",
+            "UpdateGroup",
+        ],
+    ),
+    ("Inbox", &["Container", "UpdateContainer"]),
+    ("InvalidOtpAttempt", &["EnableOtp"]),
+    ("LoginResponse", &["RegisterUser"]),
+    ("NewOtpGenerated", &["GenerateNewOtp"]),
+    ("Note", &["CreateNote", "DeleteNote", "Note", "Notes"]),
+    ("NoteUpdateOutdated", &["UpdateNote"]),
+    ("NoteUpdated", &["UpdateNote"]),
+    ("OtpEnabled", &["EnableOtp"]),
+    (
+        "Project",
+        &[
+            "CompleteProject",
+            "Container",
+            "CreateProject",
+            "CreateProjects",
+            "DeleteProject",
+            "Project",
+            "Projects",
+            "SpringProject",
+            "UncompleteProject",
+            "UnspringProject",
+            "UpdateContainer",
+            "UpdateProject",
+        ],
+    ),
+    (
+        "ProjectColumn",
+        &[
+            "CreateProjectColumn",
+            "PersistProjectColumnOrder",
+            "PersistProjectOrder",
+            "ProjectColumns",
+            "UpdateProjectColumn",
+        ],
+    ),
+    ("Search", &["Search"]),
+    ("Tag", &["Tags"]),
+    (
+        "Task",
+        &[
+            "CompleteTask",
+            "CreateTasks",
+            "DeleteTask",
+            "DeleteTasks",
+            "MoveTasks",
+            "PersistPriorityOrder",
+            "PersistTaskOrder",
+            "PrioritizeTasks",
+            "TagTask",
+            "Tasks",
+            "TasksCreated",
+            "TasksUpdated",
+            "UncompleteTask",
+            "UnprioritizeTasks",
+            "UpdateTask",
+        ],
+    ),
+    ("UpdateNoteResult", &["UpdateNote"]),
+    ("User", &["CurrentUser", "Me", "UpdateUserSettings"]),
+    ("UserAlreadyHasOtp", &["EnableOtp", "GenerateNewOtp"]),
+];
+
+/// Returns the names of the generated operations that return the type with the given name,
+/// such as every operation that returns a `Task`.
+///
+/// This is useful for invalidating cached queries after a mutation. Returns an empty slice
+/// when no operation returns the type.
+pub fn operations_returning(type_name: &str) -> &'static [&'static str] {
+    TYPE_OPERATIONS
+        .binary_search_by(|(name, _)| name.cmp(&type_name))
+        .map_or(&[], |index| TYPE_OPERATIONS[index].1)
+}
@@ -0,0 +1,153 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::future::Future;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use reqwest::header::HeaderMap;
+use reqwest::StatusCode;
+use serde::{Deserialize, Serialize};
+
+use crate::client::HttpResponse;
+use crate::BlipsError;
+
+/// How a cassette records and replays HTTP interactions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VcrMode {
+    /// Sends every request and records its response, replacing any recorded one.
+    Record,
+
+    /// Replays recorded responses without sending any requests.
+    ///
+    /// Requests without a recorded response result in an error.
+    Replay,
+
+    /// Replays recorded responses, and sends and records requests without one.
+    Auto,
+}
+
+impl VcrMode {
+    pub(crate) fn parse(value: &str) -> Option<Self> {
+        match value {
+            "record" => Some(Self::Record),
+            "replay" => Some(Self::Replay),
+            "auto" => Some(Self::Auto),
+            _ => None,
+        }
+    }
+}
+
+/// A recorded response.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Interaction {
+    status: u16,
+    body: String,
+}
+
+/// A file of recorded HTTP interactions, keyed by operation name and variables.
+pub(crate) struct Cassette {
+    path: PathBuf,
+    mode: VcrMode,
+    interactions: Mutex<Option<BTreeMap<String, Interaction>>>,
+}
+
+impl Cassette {
+    pub(crate) fn new(path: PathBuf, mode: VcrMode) -> Self {
+        Self {
+            path,
+            mode,
+            interactions: Mutex::new(None),
+        }
+    }
+
+    /// Replays the recorded response to the request with the given body, or awaits the
+    /// response and records it, depending on the mode.
+    pub(crate) async fn send<B: Serialize>(
+        &self,
+        operation_name: &str,
+        body: &B,
+        response: impl Future<Output = Result<HttpResponse, BlipsError>>,
+    ) -> Result<HttpResponse, BlipsError> {
+        let key = interaction_key(operation_name, body)?;
+
+        if self.mode != VcrMode::Record {
+            if let Some(interaction) = self.interaction(&key)? {
+                return Ok(HttpResponse {
+                    status: StatusCode::from_u16(interaction.status)
+                        .map_err(|err| self.error(err))?,
+                    headers: HeaderMap::new(),
+                    body: interaction.body.into_bytes(),
+                    time_to_first_byte: None,
+                });
+            }
+
+            if self.mode == VcrMode::Replay {
+                return Err(self.error(format!("no recorded interaction for `{}`", key)));
+            }
+        }
+
+        let response = response.await?;
+
+        let interaction = Interaction {
+            status: response.status.as_u16(),
+            body: String::from_utf8_lossy(&response.body).into_owned(),
+        };
+        self.record(key, interaction)?;
+
+        Ok(response)
+    }
+
+    fn interaction(&self, key: &str) -> Result<Option<Interaction>, BlipsError> {
+        let mut interactions = self.interactions.lock().unwrap();
+
+        Ok(self.load(&mut interactions)?.get(key).cloned())
+    }
+
+    fn record(&self, key: String, interaction: Interaction) -> Result<(), BlipsError> {
+        let mut interactions = self.interactions.lock().unwrap();
+
+        let interactions = self.load(&mut interactions)?;
+        interactions.insert(key, interaction);
+
+        let contents = serde_json::to_string_pretty(interactions).map_err(|err| self.error(err))?;
+        fs::write(&self.path, contents).map_err(|err| self.error(err))
+    }
+
+    /// Returns the interactions, reading them from the cassette file on first use.
+    fn load<'a>(
+        &self,
+        interactions: &'a mut Option<BTreeMap<String, Interaction>>,
+    ) -> Result<&'a mut BTreeMap<String, Interaction>, BlipsError> {
+        if interactions.is_none() {
+            let loaded = match fs::read_to_string(&self.path) {
+                Ok(contents) => serde_json::from_str(&contents).map_err(|err| self.error(err))?,
+                Err(err) if err.kind() == std::io::ErrorKind::NotFound => BTreeMap::new(),
+                Err(err) => return Err(self.error(err)),
+            };
+
+            *interactions = Some(loaded);
+        }
+
+        Ok(interactions.as_mut().unwrap())
+    }
+
+    fn error(&self, err: impl std::fmt::Display) -> BlipsError {
+        BlipsError::Config(format!("cassette `{}`: {}", self.path.display(), err))
+    }
+}
+
+/// Returns the key of the interaction for the request with the given body, which is made
+/// of the operation name and the serialized variables.
+///
+/// Ad-hoc queries don't have an operation name of their own, so their document is part of
+/// the key as well.
+fn interaction_key<B: Serialize>(operation_name: &str, body: &B) -> Result<String, BlipsError> {
+    let body = serde_json::to_value(body)
+        .map_err(|err| BlipsError::Config(format!("failed to serialize request: {}", err)))?;
+    let variables = body.get("variables").unwrap_or(&serde_json::Value::Null);
+
+    match (body.get("operationName"), body.get("query")) {
+        (None, Some(query)) => Ok(format!("{} {} {}", operation_name, query, variables)),
+        _ => Ok(format!("{} {}", operation_name, variables)),
+    }
+}
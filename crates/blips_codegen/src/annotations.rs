@@ -0,0 +1,143 @@
+//! Annotations that choose which operations are generated and which fields their
+//! fragments select.
+//!
+//! The annotations are read from a GraphQL SDL file that extends the types of the schema
+//! with `@generate` and `@skip` on their fields. The types of the fields are required by
+//! the SDL syntax, but only their names are used.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use graphql_parser::schema::{parse_schema, Definition, Field, TypeDefinition, TypeExtension};
+
+use crate::introspection_schema::{GraphQlFullType, IntrospectionSchema};
+
+/// The `@generate` and `@skip` annotations on the fields of each type.
+#[derive(Debug, Default)]
+pub struct Annotations {
+    generate: BTreeMap<String, BTreeSet<String>>,
+    skip: BTreeMap<String, BTreeSet<String>>,
+}
+
+impl Annotations {
+    /// Parses the annotations from the given SDL.
+    pub fn parse(source: &str) -> Result<Self, String> {
+        let document = parse_schema::<String>(source)
+            .map_err(|err| format!("Failed to parse annotations: {}", err))?;
+
+        let mut annotations = Self::default();
+        for definition in &document.definitions {
+            let (type_name, fields) = match definition {
+                Definition::TypeDefinition(TypeDefinition::Object(object)) => {
+                    (&object.name, &object.fields)
+                }
+                Definition::TypeDefinition(TypeDefinition::Interface(interface)) => {
+                    (&interface.name, &interface.fields)
+                }
+                Definition::TypeExtension(TypeExtension::Object(object)) => {
+                    (&object.name, &object.fields)
+                }
+                Definition::TypeExtension(TypeExtension::Interface(interface)) => {
+                    (&interface.name, &interface.fields)
+                }
+                _ => continue,
+            };
+
+            for field in fields {
+                annotations.add_field(type_name, field)?;
+            }
+        }
+
+        Ok(annotations)
+    }
+
+    fn add_field(&mut self, type_name: &str, field: &Field<'_, String>) -> Result<(), String> {
+        for directive in &field.directives {
+            let fields = match directive.name.as_str() {
+                "generate" => &mut self.generate,
+                "skip" => &mut self.skip,
+                name => {
+                    return Err(format!(
+                        "Unknown annotation '@{}' on '{}.{}'",
+                        name, type_name, field.name
+                    ))
+                }
+            };
+
+            fields
+                .entry(type_name.to_owned())
+                .or_default()
+                .insert(field.name.clone());
+        }
+
+        Ok(())
+    }
+
+    /// Checks that every annotated field exists in the schema, so that a typo doesn't go
+    /// unnoticed.
+    pub fn validate(&self, schema: &IntrospectionSchema) -> Result<(), String> {
+        for (type_name, field_names) in self.generate.iter().chain(&self.skip) {
+            let fields = match schema
+                .types
+                .iter()
+                .find(|ty| ty.name().as_ref() == Some(type_name))
+            {
+                Some(GraphQlFullType::Object(object)) => &object.fields,
+                Some(GraphQlFullType::Interface(interface)) => &interface.fields,
+                _ => {
+                    return Err(format!(
+                        "Annotated type '{}' isn't in the schema",
+                        type_name
+                    ))
+                }
+            };
+
+            for field_name in field_names {
+                if !fields.iter().any(|field| &field.name == field_name) {
+                    return Err(format!(
+                        "Annotated field '{}.{}' isn't in the schema",
+                        type_name, field_name
+                    ));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns whether the operation for the given field of a root type is generated.
+    ///
+    /// Once any field of a root type is annotated with `@generate`, only the annotated
+    /// fields of that root type are generated. `@skip` takes precedence over `@generate`.
+    pub fn includes_operation(&self, root_type_name: &str, field_name: &str) -> bool {
+        if self.is_skipped(root_type_name, field_name) {
+            return false;
+        }
+
+        self.generate
+            .get(root_type_name)
+            .is_none_or(|fields| fields.contains(field_name))
+    }
+
+    /// Returns whether the given field is left out of the generated fragments.
+    pub fn is_skipped(&self, type_name: &str, field_name: &str) -> bool {
+        self.skip
+            .get(type_name)
+            .is_some_and(|fields| fields.contains(field_name))
+    }
+
+    /// Returns whether any field of the given type is left out of the generated fragments.
+    pub fn skips_fields_of(&self, type_name: &str) -> bool {
+        self.skip.contains_key(type_name)
+    }
+
+    /// Returns the fields of the given type that are annotated with `@generate` and not
+    /// with `@skip`.
+    pub fn generated_fields<'a>(&'a self, type_name: &'a str) -> impl Iterator<Item = &'a str> {
+        self.generate
+            .get(type_name)
+            .into_iter()
+            .flatten()
+            .map(String::as_str)
+            .filter(move |field_name| !self.is_skipped(type_name, field_name))
+    }
+}
@@ -0,0 +1,144 @@
+//! Validation of the generated GraphQL documents against the schema.
+//!
+//! The documents are assembled from strings, so a bug in the generator would otherwise
+//! only surface as an error from the server at runtime.
+
+use graphql_parser::query::{
+    parse_query, Definition, OperationDefinition, Selection, SelectionSet, TypeCondition,
+};
+
+use crate::introspection_schema::{Field, GraphQlFullType, IntrospectionSchema};
+use crate::resolve_type_name;
+
+/// Parses the given document and checks that every field it selects, and every argument
+/// it passes, exists in the schema.
+pub fn validate_document(schema: &IntrospectionSchema, document: &str) -> Result<(), String> {
+    let document = parse_query::<&str>(document)
+        .map_err(|err| format!("Failed to parse generated document: {}", err))?;
+
+    let fragment_names = document
+        .definitions
+        .iter()
+        .filter_map(|definition| match definition {
+            Definition::Fragment(fragment) => Some(fragment.name),
+            Definition::Operation(_) => None,
+        })
+        .collect::<Vec<_>>();
+
+    for definition in &document.definitions {
+        let (type_name, selection_set) = match definition {
+            Definition::Operation(OperationDefinition::Query(query)) => (
+                schema.query_type.as_ref().map(|ty| ty.name.as_str()),
+                &query.selection_set,
+            ),
+            Definition::Operation(OperationDefinition::Mutation(mutation)) => (
+                schema.mutation_type.as_ref().map(|ty| ty.name.as_str()),
+                &mutation.selection_set,
+            ),
+            Definition::Operation(OperationDefinition::Subscription(subscription)) => (
+                schema.subscription_type.as_ref().map(|ty| ty.name.as_str()),
+                &subscription.selection_set,
+            ),
+            Definition::Operation(OperationDefinition::SelectionSet(selection_set)) => (
+                schema.query_type.as_ref().map(|ty| ty.name.as_str()),
+                selection_set,
+            ),
+            Definition::Fragment(fragment) => {
+                let TypeCondition::On(type_name) = fragment.type_condition;
+                (Some(type_name), &fragment.selection_set)
+            }
+        };
+
+        let type_name = type_name.ok_or("Schema does not define the operation's root type")?;
+
+        validate_selection_set(schema, &fragment_names, type_name, selection_set)?;
+    }
+
+    Ok(())
+}
+
+fn validate_selection_set<'a>(
+    schema: &IntrospectionSchema,
+    fragment_names: &[&str],
+    type_name: &str,
+    selection_set: &SelectionSet<'a, &'a str>,
+) -> Result<(), String> {
+    let ty = schema
+        .types
+        .iter()
+        .find(|ty| ty.name().as_deref() == Some(type_name))
+        .ok_or_else(|| format!("Type '{}' does not exist in the schema", type_name))?;
+
+    for selection in &selection_set.items {
+        match selection {
+            Selection::Field(field) => {
+                if field.name == "__typename" {
+                    continue;
+                }
+
+                let schema_field = find_field(ty, field.name).ok_or_else(|| {
+                    format!(
+                        "Field '{}' does not exist on type '{}'",
+                        field.name, type_name
+                    )
+                })?;
+
+                for (argument_name, _) in &field.arguments {
+                    if !schema_field
+                        .args
+                        .iter()
+                        .any(|arg| arg.name == *argument_name)
+                    {
+                        return Err(format!(
+                            "Argument '{}' does not exist on field '{}.{}'",
+                            argument_name, type_name, field.name
+                        ));
+                    }
+                }
+
+                if !field.selection_set.items.is_empty() {
+                    validate_selection_set(
+                        schema,
+                        fragment_names,
+                        resolve_type_name(&schema_field.ty),
+                        &field.selection_set,
+                    )?;
+                }
+            }
+            Selection::FragmentSpread(spread) => {
+                if !fragment_names.contains(&spread.fragment_name) {
+                    return Err(format!(
+                        "Fragment '{}' is not defined",
+                        spread.fragment_name
+                    ));
+                }
+            }
+            Selection::InlineFragment(inline_fragment) => {
+                let type_name = match inline_fragment.type_condition {
+                    Some(TypeCondition::On(type_name)) => type_name,
+                    None => type_name,
+                };
+
+                validate_selection_set(
+                    schema,
+                    fragment_names,
+                    type_name,
+                    &inline_fragment.selection_set,
+                )?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns the field with the given name on an object or interface type.
+fn find_field<'a>(ty: &'a GraphQlFullType, name: &str) -> Option<&'a Field> {
+    let fields = match ty {
+        GraphQlFullType::Object(object) => &object.fields,
+        GraphQlFullType::Interface(interface) => &interface.fields,
+        _ => return None,
+    };
+
+    fields.iter().find(|field| field.name == name)
+}
@@ -5,6 +5,9 @@
 //! - [`introspection_schema.graphql`](https://github.com/graphql-rust/graphql-client/blob/0776197ad7cfde2c658490e7c7e627a21ed622cb/graphql_client_cli/src/graphql/introspection_schema.graphql)
 //! - [`introspection_query.graphql`](https://github.com/graphql-rust/graphql-client/blob/0776197ad7cfde2c658490e7c7e627a21ed622cb/graphql_client_cli/src/graphql/introspection_query.graphql)
 
+// Not every part of the introspection schema is used by the generator yet.
+#![allow(dead_code)]
+
 use serde::Deserialize;
 
 /// The response from the GraphQL introspection call.
@@ -25,8 +28,9 @@ pub struct IntrospectionQuery {
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct IntrospectionSchema {
-    pub query_type: QueryType,
+    pub query_type: Option<QueryType>,
     pub mutation_type: Option<MutationType>,
+    pub subscription_type: Option<SubscriptionType>,
     pub types: Vec<GraphQlFullType>,
 }
 
@@ -40,6 +44,11 @@ pub struct MutationType {
     pub name: String,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct SubscriptionType {
+    pub name: String,
+}
+
 #[derive(Debug, Deserialize)]
 #[serde(tag = "kind", rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum GraphQlFullType {
@@ -69,6 +78,8 @@ impl GraphQlFullType {
 pub struct GraphQlScalarType {
     pub name: String,
     pub description: Option<String>,
+    #[serde(default, alias = "specifiedByURL")]
+    pub specified_by_url: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -84,6 +95,8 @@ pub struct GraphQlObjectType {
 #[serde(rename_all = "camelCase")]
 pub struct GraphQlUnionType {
     pub name: String,
+    #[serde(default)]
+    pub possible_types: Option<Vec<GraphQlTypeRef>>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -142,6 +155,12 @@ pub struct GraphQlInputObjectType {
     pub name: String,
     pub description: Option<String>,
     pub input_fields: Vec<InputValue>,
+
+    /// Whether the input object is a `@oneOf` input object.
+    ///
+    /// Only present in introspection responses from servers that support `@oneOf`.
+    #[serde(default)]
+    pub is_one_of: bool,
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -174,3 +193,236 @@ pub enum GraphQlTypeRef {
 pub struct OfType {
     pub of_type: GraphQlTypeRef,
 }
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::{render_rust_type, render_type_name, resolve_type_name};
+
+    /// Returns the introspection JSON of a named type of the given kind, as servers return
+    /// it, with a `null` `ofType`.
+    fn named(kind: &str, name: &str) -> serde_json::Value {
+        json!({ "kind": kind, "name": name, "ofType": null })
+    }
+
+    /// Returns the introspection JSON of a wrapping type of the given kind, as servers
+    /// return it, with a `null` `name`.
+    fn wrapping(kind: &str, of_type: serde_json::Value) -> serde_json::Value {
+        json!({ "kind": kind, "name": null, "ofType": of_type })
+    }
+
+    fn non_null(of_type: serde_json::Value) -> serde_json::Value {
+        wrapping("NON_NULL", of_type)
+    }
+
+    fn list(of_type: serde_json::Value) -> serde_json::Value {
+        wrapping("LIST", of_type)
+    }
+
+    fn parse_type_ref(ty: serde_json::Value) -> GraphQlTypeRef {
+        serde_json::from_value(ty).unwrap()
+    }
+
+    #[test]
+    fn named_type_refs_of_every_kind() {
+        for kind in [
+            "SCALAR",
+            "OBJECT",
+            "INTERFACE",
+            "UNION",
+            "ENUM",
+            "INPUT_OBJECT",
+        ] {
+            let ty = parse_type_ref(named(kind, "Named"));
+
+            assert_eq!(resolve_type_name(&ty), "Named", "{kind}");
+            assert_eq!(render_type_name(&ty), "Named", "{kind}");
+        }
+    }
+
+    #[test]
+    fn named_type_refs_keep_their_kind() {
+        assert!(matches!(
+            parse_type_ref(named("INTERFACE", "Container")),
+            GraphQlTypeRef::Interface { name } if name == "Container"
+        ));
+        assert!(matches!(
+            parse_type_ref(named("INPUT_OBJECT", "CreateTaskInput")),
+            GraphQlTypeRef::InputObject { name } if name == "CreateTaskInput"
+        ));
+    }
+
+    #[test]
+    fn wrapped_type_refs() {
+        let cases = [
+            (non_null(named("SCALAR", "ID")), "ID", "ID!", "ID"),
+            (
+                list(named("SCALAR", "String")),
+                "String",
+                "[String]",
+                "Option<Vec<Option<String>>>",
+            ),
+            (
+                list(non_null(named("ENUM", "Priority"))),
+                "Priority",
+                "[Priority!]",
+                "Option<Vec<Priority>>",
+            ),
+            (
+                non_null(list(named("UNION", "SearchResult"))),
+                "SearchResult",
+                "[SearchResult]!",
+                "Vec<Option<SearchResult>>",
+            ),
+            (
+                non_null(list(non_null(named("INPUT_OBJECT", "TaskInput")))),
+                "TaskInput",
+                "[TaskInput!]!",
+                "Vec<TaskInput>",
+            ),
+        ];
+
+        for (ty, name, type_name, rust_type) in cases {
+            let ty = parse_type_ref(ty);
+
+            assert_eq!(resolve_type_name(&ty), name);
+            assert_eq!(render_type_name(&ty), type_name);
+            assert_eq!(render_rust_type(&ty), rust_type);
+        }
+    }
+
+    #[test]
+    fn deeply_nested_non_null_lists() {
+        let ty = parse_type_ref(non_null(list(non_null(list(non_null(named(
+            "OBJECT", "Task",
+        )))))));
+
+        assert_eq!(resolve_type_name(&ty), "Task");
+        assert_eq!(render_type_name(&ty), "[[Task!]!]!");
+        assert_eq!(render_rust_type(&ty), "Vec<Vec<Task>>");
+    }
+
+    #[test]
+    fn wrapping_type_refs_require_an_of_type() {
+        assert!(serde_json::from_value::<GraphQlTypeRef>(json!({
+            "kind": "NON_NULL",
+            "name": null,
+        }))
+        .is_err());
+    }
+
+    #[test]
+    fn full_types_of_every_kind() {
+        let schema: IntrospectionSchema = serde_json::from_value(json!({
+            "queryType": { "name": "Query" },
+            "mutationType": null,
+            "subscriptionType": null,
+            "types": [
+                {
+                    "kind": "SCALAR",
+                    "name": "DateTime",
+                    "description": null,
+                    "specifiedByURL": "https://tools.ietf.org/html/rfc3339",
+                },
+                {
+                    "kind": "OBJECT",
+                    "name": "Task",
+                    "description": null,
+                    "fields": [{
+                        "name": "tags",
+                        "description": null,
+                        "args": [],
+                        "type": non_null(list(non_null(named("OBJECT", "Tag")))),
+                        "isDeprecated": false,
+                        "deprecationReason": null,
+                    }],
+                    "ofType": null,
+                },
+                {
+                    "kind": "INTERFACE",
+                    "name": "Container",
+                    "description": null,
+                    "fields": [],
+                    "possibleTypes": [named("OBJECT", "Project")],
+                },
+                {
+                    "kind": "UNION",
+                    "name": "SearchResult",
+                    "possibleTypes": [named("OBJECT", "Task"), named("OBJECT", "Project")],
+                },
+                {
+                    "kind": "ENUM",
+                    "name": "Priority",
+                    "description": null,
+                    "enumValues": [{
+                        "name": "HIGH",
+                        "description": null,
+                        "isDeprecated": false,
+                        "deprecationReason": null,
+                    }],
+                },
+                {
+                    "kind": "INPUT_OBJECT",
+                    "name": "TaskInput",
+                    "description": null,
+                    "inputFields": [{
+                        "name": "dueDate",
+                        "description": null,
+                        "type": named("SCALAR", "Date"),
+                        "defaultValue": null,
+                    }],
+                },
+            ],
+        }))
+        .unwrap();
+
+        let names = schema
+            .types
+            .iter()
+            .map(|ty| ty.name().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            names,
+            [
+                "DateTime",
+                "Task",
+                "Container",
+                "SearchResult",
+                "Priority",
+                "TaskInput"
+            ]
+        );
+
+        let types = &schema.types;
+        assert!(matches!(
+            &types[0],
+            GraphQlFullType::Scalar(scalar)
+                if scalar.specified_by_url.as_deref() == Some("https://tools.ietf.org/html/rfc3339")
+        ));
+        assert!(matches!(
+            &types[1],
+            GraphQlFullType::Object(object) if render_type_name(&object.fields[0].ty) == "[Tag!]!"
+        ));
+        assert!(matches!(
+            &types[2],
+            GraphQlFullType::Interface(interface)
+                if resolve_type_name(&interface.possible_types[0]) == "Project"
+        ));
+        assert!(matches!(
+            &types[3],
+            GraphQlFullType::Union(union) if union.possible_types.as_ref().map(Vec::len) == Some(2)
+        ));
+        assert!(matches!(
+            &types[4],
+            GraphQlFullType::Enum(r#enum) if r#enum.enum_values[0].name == "HIGH"
+        ));
+        assert!(matches!(
+            &types[5],
+            GraphQlFullType::InputObject(input_object)
+                if !input_object.is_one_of
+                    && render_type_name(&input_object.input_fields[0].ty) == "Date"
+        ));
+    }
+}
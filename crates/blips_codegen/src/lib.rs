@@ -0,0 +1,4714 @@
+mod annotations;
+mod document_validation;
+mod introspection_schema;
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs::{self, File, OpenOptions};
+use std::io::{BufReader, Write};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use clap::ValueEnum;
+use heck::{ToLowerCamelCase, ToPascalCase, ToSnakeCase};
+
+use annotations::Annotations;
+use document_validation::validate_document;
+use introspection_schema::{
+    Field, GraphQlFullType, GraphQlInputObjectType, GraphQlObjectType, GraphQlTypeRef, InputValue,
+    IntrospectionResponse, IntrospectionSchema,
+};
+
+/// The name of the client type for the primary (Blips) schema.
+pub const PRIMARY_CLIENT_NAME: &str = "BlipsClient";
+
+/// A naming convention for the variables of the generated operations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum VariableNaming {
+    /// Converts argument names to `camelCase`.
+    CamelCase,
+
+    /// Converts argument names to `snake_case`.
+    SnakeCase,
+
+    /// Uses argument names as they are declared in the schema.
+    None,
+}
+
+impl VariableNaming {
+    /// Returns the name of the variable for the argument with the given name.
+    fn variable_name(self, arg_name: &str) -> String {
+        match self {
+            Self::CamelCase => arg_name.to_lower_camel_case(),
+            Self::SnakeCase => arg_name.to_snake_case(),
+            Self::None => arg_name.to_owned(),
+        }
+    }
+}
+
+/// How the keys of the fields a response type doesn't declare are stored in its `extras`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ExtraFieldKeys {
+    /// Stores the keys as the server sent them, such as `dueDate`.
+    AsReceived,
+
+    /// Converts the keys to `snake_case`, such as `due_date`.
+    SnakeCase,
+}
+
+impl ExtraFieldKeys {
+    /// Returns the path of the function that deserializes the extra fields with these keys.
+    fn deserialize_with(self) -> &'static str {
+        match self {
+            Self::AsReceived => "crate::deserialize_extra_fields",
+            Self::SnakeCase => "crate::deserialize_snake_case_extra_fields",
+        }
+    }
+}
+
+fn resolve_type_name(ty: &GraphQlTypeRef) -> &String {
+    match ty {
+        GraphQlTypeRef::Scalar { name }
+        | GraphQlTypeRef::Object { name }
+        | GraphQlTypeRef::Interface { name }
+        | GraphQlTypeRef::Union { name }
+        | GraphQlTypeRef::Enum { name }
+        | GraphQlTypeRef::InputObject { name } => name,
+        GraphQlTypeRef::NonNull(boxed) | GraphQlTypeRef::List(boxed) => {
+            resolve_type_name(&boxed.of_type)
+        }
+    }
+}
+
+fn render_type_name(ty: &GraphQlTypeRef) -> String {
+    match ty {
+        GraphQlTypeRef::Scalar { name }
+        | GraphQlTypeRef::Object { name }
+        | GraphQlTypeRef::Interface { name }
+        | GraphQlTypeRef::Union { name }
+        | GraphQlTypeRef::Enum { name }
+        | GraphQlTypeRef::InputObject { name } => name.to_owned(),
+        GraphQlTypeRef::NonNull(boxed) => format!("{}!", render_type_name(&boxed.of_type)),
+        GraphQlTypeRef::List(boxed) => format!("[{}]", render_type_name(&boxed.of_type)),
+    }
+}
+
+/// Renders the Rust type that `graphql-client` generates for the given GraphQL type.
+///
+/// Named types are rendered as-is, as the generated modules define aliases for the
+/// built-in and custom scalars.
+fn render_rust_type(ty: &GraphQlTypeRef) -> String {
+    render_rust_type_with(ty, &|name| name.to_owned())
+}
+
+fn render_non_null_rust_type(ty: &GraphQlTypeRef) -> String {
+    render_non_null_rust_type_with(ty, &|name| name.to_owned())
+}
+
+/// Renders the Rust type for the given GraphQL type, with its named type rendered by
+/// `render_named`.
+fn render_rust_type_with(ty: &GraphQlTypeRef, render_named: &dyn Fn(&str) -> String) -> String {
+    match ty {
+        GraphQlTypeRef::NonNull(boxed) => {
+            render_non_null_rust_type_with(&boxed.of_type, render_named)
+        }
+        ty => format!(
+            "Option<{}>",
+            render_non_null_rust_type_with(ty, render_named)
+        ),
+    }
+}
+
+fn render_non_null_rust_type_with(
+    ty: &GraphQlTypeRef,
+    render_named: &dyn Fn(&str) -> String,
+) -> String {
+    match ty {
+        GraphQlTypeRef::NonNull(boxed) => {
+            render_non_null_rust_type_with(&boxed.of_type, render_named)
+        }
+        GraphQlTypeRef::List(boxed) => {
+            format!(
+                "Vec<{}>",
+                render_rust_type_with(&boxed.of_type, render_named)
+            )
+        }
+        ty => render_named(resolve_type_name(ty)),
+    }
+}
+
+/// Renders the Rust type of a scalar outside of the generated modules, where the aliases
+/// they define for the scalars aren't in scope.
+fn render_qualified_scalar_type(name: &str) -> String {
+    match name {
+        "Boolean" => "bool".to_string(),
+        "Float" => "f64".to_string(),
+        "Int" => "i64".to_string(),
+        "ID" | "String" => "String".to_string(),
+        name => format!("crate::graphql::custom_scalars::{name}"),
+    }
+}
+
+/// The Rust keywords that `graphql-client` suffixes with an underscore when used as names.
+const RUST_KEYWORDS: &[&str] = &[
+    "abstract", "alignof", "as", "async", "await", "become", "box", "break", "const", "continue",
+    "crate", "do", "else", "enum", "extern", "false", "final", "fn", "for", "if", "impl", "in",
+    "let", "loop", "macro", "match", "mod", "move", "mut", "offsetof", "override", "priv", "proc",
+    "pub", "pure", "ref", "return", "self", "sizeof", "static", "struct", "super", "trait", "true",
+    "type", "typeof", "unsafe", "unsized", "use", "virtual", "where", "while", "yield",
+];
+
+/// Returns the name `graphql-client` uses for the Rust field of the given GraphQL name.
+fn rust_field_name(name: &str) -> String {
+    let name = name.to_snake_case();
+
+    if RUST_KEYWORDS.contains(&name.as_str()) {
+        format!("{}_", name)
+    } else {
+        name
+    }
+}
+
+fn sanitize_name(name: String) -> String {
+    name.replace("OAuth", "Oauth")
+}
+
+fn find_object_type<'a>(
+    schema: &'a IntrospectionSchema,
+    name: &str,
+) -> Option<&'a GraphQlObjectType> {
+    schema.types.iter().find_map(|ty| match ty {
+        GraphQlFullType::Object(object) if object.name == name => Some(object),
+        _ => None,
+    })
+}
+
+#[derive(Debug)]
+struct QueryType {
+    fields: Vec<Field>,
+}
+
+impl QueryType {
+    pub fn fields(&self) -> &[Field] {
+        &self.fields
+    }
+}
+
+impl TryFrom<&IntrospectionSchema> for QueryType {
+    type Error = &'static str;
+
+    fn try_from(schema: &IntrospectionSchema) -> Result<Self, Self::Error> {
+        let query_type = schema
+            .query_type
+            .as_ref()
+            .ok_or("Schema does not define a Query type")?;
+
+        let query_type = find_object_type(schema, &query_type.name).ok_or("No Query type found")?;
+
+        Ok(Self {
+            fields: query_type.fields.to_vec(),
+        })
+    }
+}
+
+#[derive(Debug)]
+struct MutationType {
+    fields: Vec<Field>,
+}
+
+impl MutationType {
+    pub fn from_schema(schema: &IntrospectionSchema) -> Result<Option<Self>, &'static str> {
+        let mutation_type = match &schema.mutation_type {
+            Some(mutation_type) => mutation_type,
+            None => return Ok(None),
+        };
+
+        let mutation_type =
+            find_object_type(schema, &mutation_type.name).ok_or("No Mutation type found")?;
+
+        Ok(Some(MutationType {
+            fields: mutation_type.fields.to_vec(),
+        }))
+    }
+
+    pub fn fields(&self) -> &[Field] {
+        &self.fields
+    }
+}
+
+#[derive(Debug)]
+struct SubscriptionType {
+    fields: Vec<Field>,
+}
+
+impl SubscriptionType {
+    pub fn from_schema(schema: &IntrospectionSchema) -> Result<Option<Self>, &'static str> {
+        let subscription_type = match &schema.subscription_type {
+            Some(subscription_type) => subscription_type,
+            None => return Ok(None),
+        };
+
+        let subscription_type = find_object_type(schema, &subscription_type.name)
+            .ok_or("No Subscription type found")?;
+
+        Ok(Some(SubscriptionType {
+            fields: subscription_type.fields.to_vec(),
+        }))
+    }
+
+    pub fn fields(&self) -> &[Field] {
+        &self.fields
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+enum GraphQlOperation {
+    Query,
+    Mutation,
+    Subscription,
+}
+
+/// Returns the name of the root type of the given operation, such as `Query`.
+fn root_type_name(schema: &IntrospectionSchema, operation: GraphQlOperation) -> Option<&str> {
+    match operation {
+        GraphQlOperation::Query => schema.query_type.as_ref().map(|ty| ty.name.as_str()),
+        GraphQlOperation::Mutation => schema.mutation_type.as_ref().map(|ty| ty.name.as_str()),
+        GraphQlOperation::Subscription => {
+            schema.subscription_type.as_ref().map(|ty| ty.name.as_str())
+        }
+    }
+}
+
+/// Returns the root fields of the schema, paired with the operation they belong to.
+///
+/// A schema without a Query type is invalid and results in an error. The Mutation
+/// and Subscription types are optional and only contribute fields when present.
+fn root_fields(
+    schema: &IntrospectionSchema,
+) -> Result<Vec<(GraphQlOperation, Field)>, &'static str> {
+    let query = QueryType::try_from(schema)?;
+    let mutation = MutationType::from_schema(schema)?;
+    let subscription = SubscriptionType::from_schema(schema)?;
+
+    let mut fields = Vec::new();
+    fields.extend(
+        query
+            .fields()
+            .iter()
+            .map(|field| (GraphQlOperation::Query, field.clone())),
+    );
+
+    if let Some(mutation) = &mutation {
+        fields.extend(
+            mutation
+                .fields()
+                .iter()
+                .map(|field| (GraphQlOperation::Mutation, field.clone())),
+        );
+    }
+
+    if let Some(subscription) = &subscription {
+        fields.extend(
+            subscription
+                .fields()
+                .iter()
+                .map(|field| (GraphQlOperation::Subscription, field.clone())),
+        );
+    }
+
+    Ok(fields)
+}
+
+/// Returns the name `graphql-client` gives the type of an operation's root field, or of
+/// its items when it returns a list, such as `UncompleteTaskUncompleteTask`.
+fn payload_type_name(operation_name: &str, field: &Field) -> String {
+    format!("{}{}", operation_name, field.name.to_pascal_case())
+}
+
+/// Renders a shorter alias for the type of an operation's root field, such as
+/// `UncompleteTaskPayload` for `UncompleteTaskUncompleteTask`.
+fn render_payload_alias(module_name: &str, operation_name: &str, field: &Field) -> String {
+    let list_type = match &field.ty {
+        GraphQlTypeRef::NonNull(boxed) => &boxed.of_type,
+        ty => ty,
+    };
+    let description = match list_type {
+        GraphQlTypeRef::List(_) => format!("The type of the items in the `{}` list", field.name),
+        _ => format!("The type of the `{}` field", field.name),
+    };
+
+    format!(
+        r#"
+/// {description} of the response.
+pub type {operation_name}Payload = {module_name}::{payload_type_name};
+        "#,
+        payload_type_name = payload_type_name(operation_name, field),
+    )
+}
+
+/// Renders the Rust type of the items of a field that returns a list, relative to the
+/// parent of the operation's module.
+fn render_list_item_type_name(
+    module_name: &str,
+    operation_name: &str,
+    field: &Field,
+) -> Option<String> {
+    let list_type = match &field.ty {
+        GraphQlTypeRef::NonNull(boxed) => &boxed.of_type,
+        ty => ty,
+    };
+
+    let item_type = match list_type {
+        GraphQlTypeRef::List(boxed) => &boxed.of_type,
+        _ => return None,
+    };
+
+    let item_type_name = format!(
+        "{}::{}",
+        module_name,
+        payload_type_name(operation_name, field)
+    );
+
+    Some(match item_type {
+        GraphQlTypeRef::NonNull(_) => item_type_name,
+        _ => format!("Option<{}>", item_type_name),
+    })
+}
+
+/// Renders the list helpers for an operation whose root field is a list.
+///
+/// Returns `None` when the root field is not a list.
+fn render_list_helpers(module_name: &str, operation_name: &str, field: &Field) -> Option<String> {
+    let item_type_name = render_list_item_type_name(module_name, operation_name, field)?;
+    let is_nullable = !matches!(field.ty, GraphQlTypeRef::NonNull(_));
+
+    let field_name = field.name.to_snake_case();
+    let (items, into_iter) = if is_nullable {
+        (
+            format!("self.{}.as_deref().unwrap_or_default()", field_name),
+            format!("self.{}.unwrap_or_default().into_iter()", field_name),
+        )
+    } else {
+        (
+            format!("&self.{}", field_name),
+            format!("self.{}.into_iter()", field_name),
+        )
+    };
+
+    Some(format!(
+        r#"
+impl {module_name}::ResponseData {{
+    /// Returns the items in the `{graphql_field_name}` list.
+    pub fn items(&self) -> &[{item_type_name}] {{
+        {items}
+    }}
+}}
+
+impl IntoIterator for {module_name}::ResponseData {{
+    type Item = {item_type_name};
+    type IntoIter = std::vec::IntoIter<{item_type_name}>;
+
+    fn into_iter(self) -> Self::IntoIter {{
+        {into_iter}
+    }}
+}}
+        "#,
+        graphql_field_name = field.name,
+    ))
+}
+
+/// Renders the conversion from the `data` of a raw response into an operation's
+/// `ResponseData`.
+fn render_try_from_value(module_name: &str) -> String {
+    format!(
+        r#"
+impl TryFrom<serde_json::Value> for {module_name}::ResponseData {{
+    type Error = crate::BlipsError;
+
+    fn try_from(value: serde_json::Value) -> Result<Self, Self::Error> {{
+        crate::data_from_value({module_name}::OPERATION_NAME, value)
+    }}
+}}
+        "#
+    )
+}
+
+/// The state enums in `blips::core`, by the type they describe, along with the fields
+/// passed to their `from_flags` constructor.
+const STATE_TYPES: &[(&str, &str, &[&str])] = &[
+    ("Task", "TaskState", &["completed", "isRecurring", "spring"]),
+    ("Project", "ProjectState", &["completed", "springEnabled"]),
+];
+
+/// Renders the `state` method for an operation's fragment type, when it has a state enum.
+fn render_state_helper(module_name: &str, object: &GraphQlObjectType) -> Option<String> {
+    let (_, state_type, fields) = STATE_TYPES
+        .iter()
+        .find(|(type_name, _, _)| *type_name == object.name)?;
+
+    let args = fields
+        .iter()
+        .map(|field| format!("self.{}", rust_field_name(field)))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    Some(format!(
+        r#"
+impl {module_name}::{type_name} {{
+    /// Returns the state of the {description}, derived from its flags.
+    pub fn state(&self) -> crate::{state_type} {{
+        crate::{state_type}::from_flags({args})
+    }}
+}}
+        "#,
+        type_name = object.name,
+        description = object.name.to_lowercase(),
+    ))
+}
+
+/// Renders the completion consistency helpers for an operation's fragment type, when it
+/// has both a non-null `completed` flag and a nullable `completedAt` time.
+fn render_completion_helpers(module_name: &str, object: &GraphQlObjectType) -> Option<String> {
+    let has_field = |name: &str, non_null: bool| {
+        object.fields.iter().any(|field| {
+            field.name == name && matches!(field.ty, GraphQlTypeRef::NonNull(_)) == non_null
+        })
+    };
+    if !has_field("completed", true) || !has_field("completedAt", false) {
+        return None;
+    }
+
+    Some(format!(
+        r#"
+impl {module_name}::{type_name} {{
+    /// Returns whether the {description} is completed.
+    ///
+    /// `completed` and `completedAt` can disagree due to races or server bugs. When they
+    /// do, `completed` is trusted and `completedAt` is ignored. Use
+    /// [`Self::validate_consistency`] to detect the disagreement.
+    pub fn is_effectively_completed(&self) -> bool {{
+        self.completed
+    }}
+
+    /// Returns warnings for the fields of the {description} that contradict each other.
+    pub fn validate_consistency(&self) -> Vec<crate::ConsistencyWarning> {{
+        crate::ConsistencyWarning::for_completion(self.completed, self.completed_at.is_some())
+            .into_iter()
+            .collect()
+    }}
+}}
+        "#,
+        type_name = object.name,
+        description = object.name.to_lowercase(),
+    ))
+}
+
+/// The types that get a `diff` method, for detecting what changed between two fetches.
+const DIFF_TYPES: &[&str] = &["Task", "Project"];
+
+/// Renders the `diff` method for an operation's fragment type, which compares the scalar
+/// fields the fragment selects.
+fn render_diff_helper(
+    module_name: &str,
+    object: &GraphQlObjectType,
+    field_names: &[String],
+) -> Option<String> {
+    if !DIFF_TYPES.contains(&object.name.as_str()) {
+        return None;
+    }
+
+    let comparisons = field_names
+        .iter()
+        .map(|field_name| {
+            format!(
+                r#"
+        if self.{rust_field_name} != other.{rust_field_name} {{
+            changes.push(crate::FieldChange::new(
+                {field_name:?},
+                &self.{rust_field_name},
+                &other.{rust_field_name},
+            ));
+        }}"#,
+                rust_field_name = rust_field_name(field_name),
+            )
+        })
+        .collect::<String>();
+
+    Some(format!(
+        r#"
+impl {module_name}::{type_name} {{
+    /// Returns the fields that differ between this {description} and `other`, in the order
+    /// they are selected.
+    pub fn diff(&self, other: &Self) -> Vec<crate::FieldChange> {{
+        let mut changes = Vec::new();
+        {comparisons}
+
+        changes
+    }}
+}}
+        "#,
+        type_name = object.name,
+        description = object.name.to_lowercase(),
+    ))
+}
+
+/// Returns the `totalCount` field of a connection type, which is an object with a scalar
+/// `totalCount` and a `nodes` list of objects.
+fn connection_total_count_field(object: &GraphQlObjectType) -> Option<&Field> {
+    let is_connection = object.fields.iter().any(|field| {
+        let list_type = match &field.ty {
+            GraphQlTypeRef::NonNull(boxed) => &boxed.of_type,
+            ty => ty,
+        };
+
+        field.name == "nodes" && matches!(list_type, GraphQlTypeRef::List(_))
+    });
+    if !is_connection {
+        return None;
+    }
+
+    object
+        .fields
+        .iter()
+        .find(|field| field.name == "totalCount" && resolve_type_name(&field.ty) == "Int")
+}
+
+/// Renders the selection of the scalar fields of a connection's nodes, when the given type
+/// is a connection.
+fn render_connection_nodes_selection(
+    schema: &IntrospectionSchema,
+    object: &GraphQlObjectType,
+) -> Option<String> {
+    connection_total_count_field(object)?;
+
+    let nodes = object.fields.iter().find(|field| field.name == "nodes")?;
+    let node_type = find_object_type(schema, resolve_type_name(&nodes.ty))?;
+    let node_fields = node_type
+        .fields
+        .iter()
+        .filter(|field| is_scalar_field(schema, field))
+        .map(|field| format!("\n        {}", field.name))
+        .collect::<String>();
+
+    Some(format!("nodes {{\n        __typename{node_fields}\n    }}"))
+}
+
+/// Returns whether the given field returns a scalar.
+fn is_scalar_field(schema: &IntrospectionSchema, field: &Field) -> bool {
+    let type_name = resolve_type_name(&field.ty);
+    schema
+        .types
+        .iter()
+        .any(|ty| matches!(ty, GraphQlFullType::Scalar(scalar) if &scalar.name == type_name))
+}
+
+/// Renders the selections of the object fields of a type, `depth` levels deep, indented
+/// by `indent` levels.
+///
+/// Each object selects its scalar fields, along with its own object fields while the
+/// depth allows. Fields with required arguments aren't selected, as there's no value to
+/// pass them, and neither are fields that return interfaces or unions. Fields annotated
+/// with `@skip` are left out, and object fields annotated with `@generate` are selected
+/// beyond the depth, with only their scalar fields.
+///
+/// When `value_types` is given, the other object fields beyond the depth are selected with
+/// only their scalar fields too, and the names of the types `graphql-client` generates for
+/// them are added to it, so that they can be replaced with `serde_json::Value`. The names
+/// are built from `type_name`, which is the name of the type generated for `object`.
+fn render_nested_selections(
+    schema: &IntrospectionSchema,
+    object: &GraphQlObjectType,
+    type_name: &str,
+    depth: u32,
+    indent: usize,
+    annotations: &Annotations,
+    mut value_types: Option<&mut Vec<String>>,
+) -> Vec<String> {
+    let field_indent = "    ".repeat(indent + 1);
+    let selects_values = value_types.is_some();
+    let is_generated = |field: &Field| {
+        annotations
+            .generated_fields(&object.name)
+            .any(|field_name| field_name == field.name)
+    };
+    object
+        .fields
+        .iter()
+        .filter(|field| !annotations.is_skipped(&object.name, &field.name))
+        .filter(|field| depth > 0 || selects_values || is_generated(field))
+        .filter(|field| {
+            !field
+                .args
+                .iter()
+                .any(|arg| matches!(arg.ty, GraphQlTypeRef::NonNull(_)))
+        })
+        .filter_map(|field| {
+            let nested_object = find_object_type(schema, resolve_type_name(&field.ty))?;
+            let nested_type_name = format!("{}{}", type_name, field.name.to_pascal_case());
+
+            let nested_selections = match depth {
+                0 => Vec::new(),
+                _ => render_nested_selections(
+                    schema,
+                    nested_object,
+                    &nested_type_name,
+                    depth - 1,
+                    indent + 1,
+                    annotations,
+                    value_types.as_deref_mut(),
+                ),
+            };
+            let selections = nested_object
+                .fields
+                .iter()
+                .filter(|nested_field| is_scalar_field(schema, nested_field))
+                .filter(|nested_field| {
+                    !annotations.is_skipped(&nested_object.name, &nested_field.name)
+                })
+                .map(|nested_field| nested_field.name.clone())
+                .chain(nested_selections)
+                .collect::<Vec<_>>();
+            if selections.is_empty() {
+                return None;
+            }
+
+            if depth == 0 && !is_generated(field) {
+                if let Some(value_types) = value_types.as_deref_mut() {
+                    value_types.push(nested_type_name);
+                }
+            }
+
+            Some(format!(
+                "{} {{\n{field_indent}{}\n{}}}",
+                field.name,
+                selections.join(&format!("\n{field_indent}")),
+                "    ".repeat(indent),
+            ))
+        })
+        .collect()
+}
+
+/// Renders the field enum, partial type, and `PartialQuery` impl of an operation, for
+/// selecting a subset of the scalar fields its fragment selects at runtime.
+fn render_partial_query(
+    operation_name: &str,
+    field: &Field,
+    object: &GraphQlObjectType,
+    field_names: &[String],
+    scalar_serde_with: &BTreeMap<String, String>,
+    validated_scalars: &BTreeMap<String, String>,
+    serde_aliases: bool,
+) -> Option<String> {
+    let fields = object
+        .fields
+        .iter()
+        .filter(|field| field_names.contains(&field.name))
+        .collect::<Vec<_>>();
+    if fields.is_empty() {
+        return None;
+    }
+
+    let field_enum = format!("{operation_name}Field");
+    let partial_type = format!("{operation_name}Partial");
+    let render_scalar_type = |name: &str| {
+        if validated_scalars.contains_key(name) {
+            format!("crate::graphql::validated_scalars::{name}")
+        } else {
+            render_qualified_scalar_type(name)
+        }
+    };
+
+    let variants = fields
+        .iter()
+        .map(|field| field.name.to_pascal_case())
+        .collect::<Vec<_>>();
+    let partial_fields = fields
+        .iter()
+        .map(|field| {
+            let rust_name = rust_field_name(&field.name);
+            let rename = if rust_name == field.name {
+                String::new()
+            } else {
+                format!("\n    {}", serde_rename(&field.name, serde_aliases))
+            };
+            let with = match scalar_serde_with.get(resolve_type_name(&field.ty)) {
+                Some(module) => format!("\n    #[serde(with = \"{module}::option\")]"),
+                None => String::new(),
+            };
+
+            format!(
+                "{rename}{with}\n    pub {rust_name}: Option<{}>,",
+                render_non_null_rust_type_with(&field.ty, &render_scalar_type)
+            )
+        })
+        .collect::<String>();
+    let partial_value_type = render_rust_type_with(&field.ty, &|name| {
+        if name == object.name {
+            partial_type.clone()
+        } else {
+            render_scalar_type(name)
+        }
+    });
+    let field_name_arms = fields
+        .iter()
+        .zip(&variants)
+        .map(|(field, variant)| {
+            format!(
+                "\n            {field_enum}::{variant} => \"{}\",",
+                field.name
+            )
+        })
+        .collect::<String>();
+
+    Some(format!(
+        r#"
+/// A field of the `{type_name}` returned by [`{operation_name}`], for selecting with
+/// [`crate::BlipsClient::execute_partial`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum {field_enum} {{
+    {variants}
+}}
+
+impl {field_enum} {{
+    /// Every field, for selecting the whole fragment.
+    pub const ALL: &'static [Self] = &[{all}];
+}}
+
+/// The `{type_name}` returned by [`{operation_name}`], with the fields selected by
+/// [`crate::BlipsClient::execute_partial`].
+///
+/// A field is `None` both when it wasn't selected and when the server returned `null` for it.
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+#[serde(default)]
+pub struct {partial_type} {{{partial_fields}
+}}
+
+impl crate::PartialQuery for {operation_name} {{
+    type Field = {field_enum};
+    type Partial = {partial_value_type};
+
+    const FRAGMENT_NAME: &'static str = "{fragment_name}";
+    const ROOT_FIELD: &'static str = "{root_field}";
+
+    fn field_name(field: Self::Field) -> &'static str {{
+        match field {{{field_name_arms}
+        }}
+    }}
+}}
+        "#,
+        type_name = object.name,
+        variants = variants.join(",\n    "),
+        all = variants
+            .iter()
+            .map(|variant| format!("Self::{variant}"))
+            .collect::<Vec<_>>()
+            .join(", "),
+        fragment_name = object.name.to_pascal_case(),
+        root_field = field.name,
+    ))
+}
+
+/// Renders an inline fragment for each possible type of an interface or union, selecting
+/// the scalar fields it has beyond the shared fields of the interface.
+///
+/// Possible types without fields of their own don't get an inline fragment. Fields that
+/// share a name but not a type across possible types can't be selected under the same
+/// name, so they're aliased with the name of their type, such as `diaryDate`.
+fn render_implementation_selections(
+    schema: &IntrospectionSchema,
+    shared_fields: &[Field],
+    possible_types: &[GraphQlTypeRef],
+) -> Vec<String> {
+    let implementations = possible_types
+        .iter()
+        .filter_map(|possible_type| find_object_type(schema, resolve_type_name(possible_type)))
+        .map(|implementation| {
+            let fields = implementation
+                .fields
+                .iter()
+                .filter(|field| is_scalar_field(schema, field))
+                .filter(|field| {
+                    !shared_fields
+                        .iter()
+                        .any(|shared_field| shared_field.name == field.name)
+                })
+                .collect::<Vec<_>>();
+
+            (implementation, fields)
+        })
+        .collect::<Vec<_>>();
+
+    let mut field_types: BTreeMap<&str, BTreeSet<String>> = BTreeMap::new();
+    for (_, fields) in &implementations {
+        for field in fields {
+            field_types
+                .entry(&field.name)
+                .or_default()
+                .insert(render_type_name(&field.ty));
+        }
+    }
+
+    implementations
+        .iter()
+        .filter(|(_, fields)| !fields.is_empty())
+        .map(|(implementation, fields)| {
+            let selections = fields
+                .iter()
+                .map(|field| {
+                    if field_types[field.name.as_str()].len() > 1 {
+                        format!(
+                            "{}{}: {}",
+                            implementation.name.to_lower_camel_case(),
+                            field.name.to_pascal_case(),
+                            field.name
+                        )
+                    } else {
+                        field.name.clone()
+                    }
+                })
+                .collect::<Vec<_>>();
+
+            format!(
+                "... on {} {{\n        {}\n    }}",
+                implementation.name,
+                selections.join("\n        ")
+            )
+        })
+        .collect()
+}
+
+/// Renders the `total_count` method for an operation's fragment type, when it's a
+/// connection.
+fn render_total_count_helper(module_name: &str, object: &GraphQlObjectType) -> Option<String> {
+    let total_count_field = connection_total_count_field(object)?;
+    let (return_type, doc) = match total_count_field.ty {
+        GraphQlTypeRef::NonNull(_) => ("i64", ""),
+        _ => ("Option<i64>", ", if the server provided it"),
+    };
+
+    Some(format!(
+        r#"
+impl {module_name}::{type_name} {{
+    /// Returns the total number of items in the connection{doc}, which may be more than
+    /// the items in this page.
+    pub fn total_count(&self) -> {return_type} {{
+        self.total_count
+    }}
+}}
+        "#,
+        type_name = object.name,
+    ))
+}
+
+/// Renders comparators for the ordering fields of an operation's fragment type.
+///
+/// An ordering field is an `Int` field named `order` or ending in `Order`. Values without
+/// an order sort after those with one, so the comparators can be passed straight to
+/// `sort_by`. Returns `None` when the type has no ordering fields.
+fn render_ordering_helpers(module_name: &str, object: &GraphQlObjectType) -> Option<String> {
+    let comparators = object
+        .fields
+        .iter()
+        .filter(|field| field.name == "order" || field.name.ends_with("Order"))
+        .filter(|field| resolve_type_name(&field.ty) == "Int")
+        .map(|field| {
+            let field_name = rust_field_name(&field.name);
+            let (compare, doc) = match &field.ty {
+                GraphQlTypeRef::NonNull(_) => (
+                    format!("a.{field_name}.cmp(&b.{field_name})"),
+                    String::new(),
+                ),
+                _ => (
+                    format!(
+                        r#"match (a.{field_name}, b.{field_name}) {{
+            (Some(a), Some(b)) => a.cmp(&b),
+            (Some(_), None) => std::cmp::Ordering::Less,
+            (None, Some(_)) => std::cmp::Ordering::Greater,
+            (None, None) => std::cmp::Ordering::Equal,
+        }}"#
+                    ),
+                    ", sorting values without one last".to_owned(),
+                ),
+            };
+
+            format!(
+                r#"
+    /// Compares two values by `{graphql_field_name}`{doc}.
+    pub fn by_{field_name}(a: &Self, b: &Self) -> std::cmp::Ordering {{
+        {compare}
+    }}"#,
+                graphql_field_name = field.name,
+            )
+        })
+        .collect::<Vec<_>>();
+
+    if comparators.is_empty() {
+        return None;
+    }
+
+    Some(format!(
+        r#"
+impl {module_name}::{type_name} {{{comparators}
+}}
+        "#,
+        type_name = object.name.to_pascal_case(),
+        comparators = comparators.join("\n"),
+    ))
+}
+
+/// Renders the `impl Variables` block for an operation with arguments.
+///
+/// Returns `None` when the operation has no arguments, as `graphql-client` generates a
+/// unit struct for its variables.
+fn render_variables_impl(field: &Field) -> Option<String> {
+    if field.args.is_empty() {
+        return None;
+    }
+
+    let params = field
+        .args
+        .iter()
+        .map(|arg| {
+            format!(
+                "{}: {}",
+                rust_field_name(&arg.name),
+                render_rust_type(&arg.ty)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+    let fields = field
+        .args
+        .iter()
+        .map(|arg| rust_field_name(&arg.name))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let setters = field
+        .args
+        .iter()
+        .map(|arg| {
+            let field_name = rust_field_name(&arg.name);
+            let (param_type, value, doc) = match &arg.ty {
+                // Non-null lists also accept a single value, which GraphQL coerces into a
+                // list with one element.
+                GraphQlTypeRef::NonNull(of_type) => match &of_type.of_type {
+                    GraphQlTypeRef::List(item_type) => (
+                        format!(
+                            "impl Into<crate::OneOrMany<{}>>",
+                            render_rust_type(&item_type.of_type)
+                        ),
+                        format!("{}.into().into_vec()", field_name),
+                        "\n    ///\n    /// A single value is sent as a list with one element.",
+                    ),
+                    _ => (render_rust_type(&arg.ty), field_name.clone(), ""),
+                },
+                // So do nullable lists, which are then set.
+                GraphQlTypeRef::List(item_type) => (
+                    format!(
+                        "impl Into<crate::OneOrMany<{}>>",
+                        render_rust_type(&item_type.of_type)
+                    ),
+                    format!("Some({}.into().into_vec())", field_name),
+                    "\n    ///\n    /// A single value is sent as a list with one element.",
+                ),
+                ty => (
+                    format!("impl Into<Option<{}>>", render_non_null_rust_type(ty)),
+                    format!("{}.into()", field_name),
+                    "",
+                ),
+            };
+
+            format!(
+                r#"
+    /// Returns the variables with `{arg_name}` set to the provided value.{doc}
+    pub fn with_{setter_name}(mut self, {field_name}: {param_type}) -> Self {{
+        self.{field_name} = {value};
+        self
+    }}"#,
+                arg_name = arg.name,
+                setter_name = arg.name.to_snake_case(),
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    Some(format!(
+        r#"impl Variables {{
+    /// Returns a new [`Variables`] from the arguments of `{field_name}`.
+    ///
+    /// The parameters are in the order the arguments are declared in the schema.
+    pub fn new({params}) -> Self {{
+        Self {{ {fields} }}
+    }}
+{setters}
+}}"#,
+        field_name = field.name
+    ))
+}
+
+/// Returns whether the mutation updates an entity with the arguments that are set, such as
+/// `updateTask`, which gets a changeset.
+fn is_update_mutation(field: &Field) -> bool {
+    field.name.starts_with("update")
+        && field
+            .args
+            .iter()
+            .any(|arg| !matches!(arg.ty, GraphQlTypeRef::NonNull(_)))
+}
+
+/// Renders the `Changeset` of an update mutation, along with its `ChangesetQuery` impl.
+///
+/// Unlike the `Variables`, the optional arguments of a changeset are left out of the
+/// request when they aren't set, so that they can be told apart from arguments that are
+/// explicitly set to `null`.
+fn render_changeset(
+    operation_name: &str,
+    field: &Field,
+    variable_naming: VariableNaming,
+) -> String {
+    let (required_args, optional_args): (Vec<_>, Vec<_>) = field
+        .args
+        .iter()
+        .partition(|arg| matches!(arg.ty, GraphQlTypeRef::NonNull(_)));
+
+    let fields = field
+        .args
+        .iter()
+        .map(|arg| {
+            let field_name = rust_field_name(&arg.name);
+            let variable_name = variable_naming.variable_name(&arg.name);
+            let rename = if variable_name == field_name {
+                String::new()
+            } else {
+                format!("\n    #[serde(rename = \"{variable_name}\")]")
+            };
+
+            match &arg.ty {
+                GraphQlTypeRef::NonNull(_) => format!(
+                    "{rename}\n    {field_name}: {},",
+                    render_rust_type(&arg.ty)
+                ),
+                ty => format!(
+                    "{rename}\n    #[serde(skip_serializing_if = \"Option::is_none\")]\n    {field_name}: Option<{}>,",
+                    render_rust_type(ty)
+                ),
+            }
+        })
+        .collect::<String>();
+    let params = required_args
+        .iter()
+        .map(|arg| {
+            format!(
+                "{}: {}",
+                rust_field_name(&arg.name),
+                render_rust_type(&arg.ty)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+    let initializers = field
+        .args
+        .iter()
+        .map(|arg| match &arg.ty {
+            GraphQlTypeRef::NonNull(_) => rust_field_name(&arg.name),
+            _ => format!("{}: None", rust_field_name(&arg.name)),
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+    let setters = optional_args
+        .iter()
+        .map(|arg| {
+            format!(
+                r#"
+    /// Returns the changeset with `{arg_name}` set to the provided value, which is sent
+    /// as `null` when it is `None`.
+    pub fn with_{setter_name}(mut self, {field_name}: impl Into<{ty}>) -> Self {{
+        self.{field_name} = Some({field_name}.into());
+        self
+    }}"#,
+                arg_name = arg.name,
+                setter_name = arg.name.to_snake_case(),
+                field_name = rust_field_name(&arg.name),
+                ty = render_rust_type(&arg.ty),
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    format!(
+        r#"
+
+/// The arguments of `{field_name}` to change, for executing with
+/// [`crate::BlipsClient::execute_changeset`].
+///
+/// Only the arguments that are set with the `with_*` setters are sent. The rest are left out
+/// of the request, so the server leaves the fields they update untouched.
+#[derive(Serialize, Clone)]
+pub struct Changeset {{{fields}
+}}
+
+impl Changeset {{
+    /// Returns a new [`Changeset`] with the required arguments of `{field_name}`, which
+    /// doesn't change anything else.
+    pub fn new({params}) -> Self {{
+        Self {{ {initializers} }}
+    }}
+{setters}
+}}
+
+impl crate::ChangesetQuery for super::{operation_name} {{
+    type Changeset = Changeset;
+
+    fn build_changeset_query(changeset: Changeset) -> crate::runtime::QueryBody<Changeset> {{
+        crate::runtime::QueryBody {{
+            variables: changeset,
+            query: QUERY,
+            operation_name: OPERATION_NAME,
+        }}
+    }}
+}}"#,
+        field_name = field.name,
+    )
+}
+
+/// Returns whether the `Variables` of the operation for the given field can derive
+/// `Default`.
+///
+/// Defaulting a required argument would produce invalid variables, so `Default` is only
+/// derived when every argument may be omitted.
+fn can_derive_default_for_variables(field: &Field) -> bool {
+    field
+        .args
+        .iter()
+        .all(|arg| !matches!(arg.ty, GraphQlTypeRef::NonNull(_)))
+}
+
+/// Adds `Default` to the derives of the `Variables` in a module generated by
+/// `graphql-client`.
+fn derive_default_for_variables(contents: &str) -> String {
+    let Some(struct_start) = contents.find("pub struct Variables") else {
+        return contents.to_string();
+    };
+
+    let derives_end = contents[..struct_start]
+        .rfind(")]")
+        .expect("Variables struct has no derives");
+
+    let mut contents = contents.to_string();
+    contents.insert_str(derives_end, ", Default");
+    contents
+}
+
+/// Renders a `@oneOf` input object as an enum with a variant per field.
+///
+/// Each variant serializes to an object containing only that field, so the type system
+/// enforces that exactly one field is set.
+fn render_one_of_input_object(derives: &str, input_object: &GraphQlInputObjectType) -> String {
+    let variants = input_object
+        .input_fields
+        .iter()
+        .map(|input_field| {
+            format!(
+                "#[serde(rename = \"{name}\")]\n{variant}({ty}),",
+                name = input_field.name,
+                variant = input_field.name.to_pascal_case(),
+                ty = render_non_null_rust_type(&input_field.ty)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    format!(
+        "{derives}\npub enum {name} {{\n{variants}\n}}",
+        name = input_object.name
+    )
+}
+
+/// Replaces the structs `graphql-client` generates for `@oneOf` input objects with enums.
+fn replace_one_of_input_objects(schema: &IntrospectionSchema, contents: &str) -> String {
+    let mut contents = contents.to_string();
+
+    for ty in &schema.types {
+        let input_object = match ty {
+            GraphQlFullType::InputObject(input_object) if input_object.is_one_of => input_object,
+            _ => continue,
+        };
+
+        let struct_start = match contents.find(&format!("pub struct {} {{", input_object.name)) {
+            Some(struct_start) => struct_start,
+            None => continue,
+        };
+
+        let derives_start = contents[..struct_start]
+            .rfind("#[derive(")
+            .expect("Input object struct has no derives");
+        let derives = contents[derives_start..struct_start].trim().to_string();
+
+        let struct_end = struct_start
+            + contents[struct_start..]
+                .find('}')
+                .expect("Input object struct is not closed")
+            + 1;
+
+        contents.replace_range(
+            derives_start..struct_end,
+            &render_one_of_input_object(&derives, input_object),
+        );
+    }
+
+    contents
+}
+
+/// Renames the variants of the enums in a module generated by `graphql-client` from the
+/// `SCREAMING_SNAKE_CASE` values in the schema to `PascalCase`.
+///
+/// `graphql-client` names the variants exactly like the values, and (de)serializes them
+/// with hand-written impls that match on the values as string literals, so only the
+/// variants are renamed and the values on the wire stay exactly as the schema declares
+/// them. Enums whose renamed variants would collide, including with the `Other` variant
+/// for unknown values, keep the names of the values.
+fn rename_enum_variants(schema: &IntrospectionSchema, contents: &str) -> String {
+    let mut contents = contents.to_string();
+
+    for ty in &schema.types {
+        let GraphQlFullType::Enum(r#enum) = ty else {
+            continue;
+        };
+
+        let enum_declaration = format!("pub enum {} {{", r#enum.name);
+        if !contents.contains(&enum_declaration) {
+            continue;
+        }
+
+        let renames = r#enum
+            .enum_values
+            .iter()
+            .map(|value| (value.name.as_str(), value.name.to_pascal_case()))
+            .collect::<BTreeMap<_, _>>();
+        let mut variants = renames.values().collect::<Vec<_>>();
+        variants.sort();
+        variants.dedup();
+        if variants.len() != renames.len() || renames.values().any(|variant| variant == "Other") {
+            continue;
+        }
+
+        let rename = |variant: &str| {
+            renames
+                .get(variant)
+                .cloned()
+                .unwrap_or_else(|| variant.to_string())
+        };
+
+        // The variants are declared one per line in the body of the enum.
+        let mut lines = Vec::new();
+        let mut in_enum = false;
+        for line in contents.lines() {
+            let trimmed = line.trim();
+            if trimmed == enum_declaration {
+                in_enum = true;
+            } else if in_enum && trimmed == "}" {
+                in_enum = false;
+            } else if let Some(variant) = trimmed.strip_suffix(',').filter(|_| in_enum) {
+                lines.push(line.replacen(variant, &rename(variant), 1));
+                continue;
+            }
+
+            lines.push(line.to_string());
+        }
+        let declared = lines.join("\n") + "\n";
+
+        // Everywhere else, the variants are referred to by their paths.
+        let path_prefix = format!("{}::", r#enum.name);
+        let mut output = String::with_capacity(declared.len());
+        let mut rest = declared.as_str();
+        while let Some(index) = rest.find(&path_prefix) {
+            let is_enum_path = !rest[..index].ends_with(|c: char| c.is_alphanumeric() || c == '_');
+            let (before, after) = rest.split_at(index + path_prefix.len());
+            output.push_str(before);
+
+            let variant_len = after
+                .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+                .unwrap_or(after.len());
+            let (variant, after) = after.split_at(variant_len);
+            if is_enum_path {
+                output.push_str(&rename(variant));
+            } else {
+                output.push_str(variant);
+            }
+            rest = after;
+        }
+        output.push_str(rest);
+
+        contents = output;
+    }
+
+    contents
+}
+
+/// The value that the generated `as_str` returns for values of an enum that the schema
+/// didn't declare. GraphQL reserves names that start with `__`, so it can't be mistaken
+/// for a declared value.
+const OTHER_ENUM_VALUE: &str = "__OTHER";
+
+/// Adds `as_str` and `other_value` methods to the enums in a module generated by
+/// `graphql-client`, along with a `From` impl for `&'static str`.
+///
+/// `as_str` returns the value of a variant as the schema declares it, without allocating,
+/// and [`OTHER_ENUM_VALUE`] for the `Other` variant, whose value `other_value` returns
+/// instead. The arms are copied from the `Serialize` impls `graphql-client` generates for
+/// enums, which already match each variant to its value as a string literal.
+fn add_enum_as_str(contents: &str) -> String {
+    let lines = contents.lines().collect::<Vec<_>>();
+    let mut output = Vec::with_capacity(lines.len());
+
+    let mut index = 0;
+    while index < lines.len() {
+        let line = lines[index];
+        output.push(line.to_string());
+        index += 1;
+
+        let Some(enum_name) = line
+            .trim()
+            .strip_prefix("impl ::serde::Serialize for ")
+            .and_then(|rest| rest.strip_suffix(" {"))
+        else {
+            continue;
+        };
+
+        let indent = &line[..line.len() - line.trim_start().len()];
+        let mut arms = Vec::new();
+        let mut other_variant = None;
+        while index < lines.len() {
+            let line = lines[index];
+            output.push(line.to_string());
+            index += 1;
+
+            if let Some((variant, _)) = line.trim().split_once("(ref s) => ") {
+                arms.push(format!("{variant}(_) => {OTHER_ENUM_VALUE:?},"));
+                other_variant = Some(variant.to_string());
+            } else if line.contains(" => ") {
+                arms.push(line.trim().to_string());
+            } else if line.strip_prefix(indent) == Some("}") {
+                break;
+            }
+        }
+
+        let (other_doc, other_value) = match other_variant {
+            Some(other_variant) => (
+                format!(
+                    r#"
+    ///
+    /// Values that the schema didn't declare when the SDK was generated are returned as
+    /// `{OTHER_ENUM_VALUE:?}`. Use [`{enum_name}::other_value`] to get them."#
+                ),
+                format!(
+                    r#"
+    /// Returns the value of a variant that the schema didn't declare when the SDK was
+    /// generated, or `None` for the declared variants.
+    pub fn other_value(&self) -> Option<&str> {{
+        match self {{
+            {other_variant}(value) => Some(value),
+            _ => None,
+        }}
+    }}
+"#
+                ),
+            ),
+            None => (String::new(), String::new()),
+        };
+
+        output.push(format!(
+            r#"impl {enum_name} {{
+    /// Returns the value as the schema declares it, such as for logging or metric labels,
+    /// without allocating.{other_doc}
+    pub fn as_str(&self) -> &'static str {{
+        match self {{
+            {arms}
+        }}
+    }}
+{other_value}}}
+impl From<{enum_name}> for &'static str {{
+    fn from(value: {enum_name}) -> Self {{
+        value.as_str()
+    }}
+}}"#,
+            arms = arms.join("\n            ")
+        ));
+    }
+
+    output.join("\n")
+}
+
+/// Replaces the per-field `rename` attributes on the input types in a module generated by
+/// `graphql-client` with a container-level `rename_all = "camelCase"`, where every field
+/// is renamed that way.
+///
+/// Each input type, including the input objects nested in other input objects, is
+/// collapsed on its own, so the fields keep serializing to the names in the schema.
+fn collapse_serde_renames(contents: &str) -> String {
+    let lines = contents.lines().collect::<Vec<_>>();
+    let mut output = Vec::with_capacity(lines.len());
+
+    let mut index = 0;
+    while index < lines.len() {
+        let line = lines[index];
+        let next_line = lines
+            .get(index + 1)
+            .map(|line| line.trim())
+            .unwrap_or_default();
+
+        let is_input_type =
+            line.trim().starts_with("#[derive(Serialize") && !line.contains("Deserialize");
+        if !is_input_type || !next_line.starts_with("pub struct ") || !next_line.ends_with('{') {
+            output.push(line.to_string());
+            index += 1;
+            continue;
+        }
+
+        let body_start = index + 2;
+        let body_end = body_start
+            + lines[body_start..]
+                .iter()
+                .position(|line| line.trim() == "}")
+                .expect("Input type struct is not closed");
+        let body = &lines[body_start..body_end];
+
+        output.push(line.to_string());
+        match collapse_struct_renames(body) {
+            Some(fields) => {
+                output.push(r#"#[serde(rename_all = "camelCase")]"#.to_string());
+                output.push(lines[index + 1].to_string());
+                output.extend(fields);
+            }
+            None => {
+                output.push(lines[index + 1].to_string());
+                output.extend(body.iter().map(|line| line.to_string()));
+            }
+        }
+
+        index = body_end;
+    }
+
+    output.join("\n") + "\n"
+}
+
+/// Returns the lines of the struct body without its `rename` attributes, if every field
+/// is renamed to the `camelCase` form of its name and at least one is renamed at all.
+fn collapse_struct_renames(body: &[&str]) -> Option<Vec<String>> {
+    let mut fields = Vec::with_capacity(body.len());
+    let mut has_renames = false;
+    let mut rename = None;
+
+    for line in body {
+        let trimmed = line.trim();
+
+        if let Some(name) = trimmed
+            .strip_prefix(r#"#[serde(rename = ""#)
+            .and_then(|name| name.strip_suffix(r#"")]"#))
+        {
+            rename = Some(name);
+            has_renames = true;
+            continue;
+        }
+
+        if let Some((field_name, _)) = trimmed
+            .strip_prefix("pub ")
+            .and_then(|field| field.split_once(": "))
+        {
+            let serialized_name = rename.take().unwrap_or(field_name);
+            if serialized_name != serde_camel_case(field_name) {
+                return None;
+            }
+        }
+
+        fields.push(line.to_string());
+    }
+
+    has_renames.then_some(fields)
+}
+
+/// Adds a `snake_case` alias to the renamed fields of the response types in a module
+/// generated by `graphql-client`, so that they accept either casing.
+fn add_serde_aliases(contents: &str) -> String {
+    let mut output = Vec::new();
+
+    let mut derives_deserialize = false;
+    let mut in_response_type = false;
+    for line in contents.lines() {
+        let trimmed = line.trim();
+
+        if trimmed.starts_with("#[derive(") {
+            derives_deserialize = trimmed.contains("Deserialize");
+        } else if trimmed.starts_with("pub struct ") || trimmed.starts_with("pub enum ") {
+            in_response_type =
+                derives_deserialize && trimmed.starts_with("pub struct ") && trimmed.ends_with('{');
+            derives_deserialize = false;
+        } else if trimmed == "}" {
+            in_response_type = false;
+        }
+
+        let name = trimmed
+            .strip_prefix(r#"#[serde(rename = ""#)
+            .and_then(|name| name.strip_suffix(r#"")]"#));
+        match name {
+            Some(name) if in_response_type => {
+                let indent = &line[..line.len() - trimmed.len()];
+                output.push(format!("{indent}{}", serde_rename(name, true)));
+            }
+            _ => output.push(line.to_owned()),
+        }
+    }
+
+    output.join("\n") + "\n"
+}
+
+/// Adds an `extras` field to the response types in a module generated by `graphql-client`,
+/// which captures the fields they don't declare with the given keys.
+///
+/// Types that already have a flattened field are left as they are, as that field would
+/// see the same fields as `extras`.
+fn add_extra_fields(contents: &str, keys: ExtraFieldKeys) -> String {
+    let lines = contents.lines().collect::<Vec<_>>();
+    let mut output = Vec::with_capacity(lines.len());
+
+    let mut derives_deserialize = false;
+    let mut index = 0;
+    while index < lines.len() {
+        let line = lines[index];
+        let trimmed = line.trim();
+        output.push(line.to_owned());
+        index += 1;
+
+        if trimmed.starts_with("#[derive(") {
+            derives_deserialize = trimmed.contains("Deserialize");
+            continue;
+        }
+        if !trimmed.starts_with("pub struct ") || !trimmed.ends_with('{') {
+            if !trimmed.starts_with("#[") {
+                derives_deserialize = false;
+            }
+            continue;
+        }
+        if !std::mem::take(&mut derives_deserialize) {
+            continue;
+        }
+
+        let indent = &line[..line.len() - trimmed.len()];
+        let closing = format!("{indent}}}");
+        let Some(length) = lines[index..].iter().position(|line| *line == closing) else {
+            continue;
+        };
+        let fields = &lines[index..index + length];
+        output.extend(fields.iter().map(|line| line.to_string()));
+        index += length;
+
+        if !fields.iter().any(|line| line.contains("#[serde(flatten")) {
+            output.push(format!(
+                r#"{indent}    #[serde(flatten, deserialize_with = "{}")]
+{indent}    #[cfg_attr(feature = "ts-rs", ts(skip))]
+{indent}    pub extras: crate::ExtraFields,"#,
+                keys.deserialize_with()
+            ));
+        }
+    }
+
+    output.join("\n") + "\n"
+}
+
+/// Returns the `#[serde(rename = "...")]` attribute of a field with the given GraphQL name,
+/// with an alias for its `snake_case` form if `serde_aliases` is set and it differs.
+fn serde_rename(name: &str, serde_aliases: bool) -> String {
+    let alias = name.to_snake_case();
+    if serde_aliases && alias != name {
+        format!("#[serde(rename = \"{name}\", alias = \"{alias}\")]")
+    } else {
+        format!("#[serde(rename = \"{name}\")]")
+    }
+}
+
+/// Replaces the given types in a module generated by `graphql-client` with
+/// `serde_json::Value`, for the fields selected beyond the maximum depth.
+///
+/// The types are removed, and the fields of those types get a TypeScript type of
+/// `unknown`, as `serde_json::Value` doesn't implement `ts_rs::TS`.
+fn replace_json_value_types(contents: &str, value_types: &[String]) -> String {
+    let mut output: Vec<String> = Vec::new();
+
+    let mut in_value_type = false;
+    for line in contents.lines() {
+        let trimmed = line.trim();
+
+        if in_value_type {
+            in_value_type = trimmed != "}";
+            continue;
+        }
+
+        let struct_name = trimmed
+            .strip_prefix("pub struct ")
+            .and_then(|name| name.strip_suffix(" {"));
+        if struct_name.is_some_and(|name| value_types.iter().any(|ty| ty == name)) {
+            while output
+                .last()
+                .is_some_and(|line| line.trim_start().starts_with("#["))
+            {
+                output.pop();
+            }
+            in_value_type = true;
+            continue;
+        }
+
+        let field = trimmed
+            .strip_prefix("pub ")
+            .and_then(|field| field.split_once(": "))
+            .and_then(|(name, ty)| Some((name, ty.strip_suffix(',')?)));
+        if let Some((name, ty)) = field {
+            let value_ty = value_types.iter().fold(ty.to_string(), |ty, value_type| {
+                replace_type_name(&ty, value_type, "serde_json::Value")
+            });
+            if value_ty != ty {
+                let indent = &line[..line.len() - trimmed.len()];
+                output.push(format!(
+                    r#"{indent}#[cfg_attr(feature = "ts-rs", ts(type = "{}"))]"#,
+                    json_value_ts_type(&value_ty)
+                ));
+                output.push(format!("{indent}pub {name}: {value_ty},"));
+                continue;
+            }
+        }
+
+        output.push(line.to_owned());
+    }
+
+    output.join("\n") + "\n"
+}
+
+/// Returns the TypeScript type of a Rust type made of `Option`, `Vec`, and
+/// `serde_json::Value`.
+fn json_value_ts_type(ty: &str) -> String {
+    if let Some(inner) = ty
+        .strip_prefix("Option<")
+        .and_then(|ty| ty.strip_suffix('>'))
+    {
+        format!("{} | null", json_value_ts_type(inner))
+    } else if let Some(inner) = ty.strip_prefix("Vec<").and_then(|ty| ty.strip_suffix('>')) {
+        format!("Array<{}>", json_value_ts_type(inner))
+    } else {
+        "unknown".to_owned()
+    }
+}
+
+/// Converts a `snake_case` field name to `camelCase` the way `serde(rename_all)` does.
+fn serde_camel_case(field_name: &str) -> String {
+    let mut output = String::with_capacity(field_name.len());
+    let mut capitalize = false;
+
+    for char in field_name.chars() {
+        if char == '_' {
+            capitalize = !output.is_empty();
+        } else if capitalize {
+            output.extend(char.to_uppercase());
+            capitalize = false;
+        } else {
+            output.push(char);
+        }
+    }
+
+    output
+}
+
+/// Documents the aliases of the custom scalars that have a `specifiedByURL` with the URL
+/// that describes their format.
+fn document_custom_scalars(schema: &IntrospectionSchema, contents: &str) -> String {
+    let mut contents = contents.to_owned();
+
+    for ty in &schema.types {
+        let GraphQlFullType::Scalar(scalar) = ty else {
+            continue;
+        };
+        let Some(specified_by_url) = &scalar.specified_by_url else {
+            continue;
+        };
+
+        let alias = format!(
+            "type {name} = crate::graphql::custom_scalars::{name};",
+            name = scalar.name
+        );
+        contents = contents.replace(
+            &alias,
+            &format!(
+                "/// The `{}` scalar, whose format is specified by <{}>.\n{}",
+                scalar.name, specified_by_url, alias
+            ),
+        );
+    }
+
+    contents
+}
+
+/// Adds `#[serde(with = "...")]` to the fields of the scalars that have a serde `with`
+/// module, in a module generated by `graphql-client`.
+///
+/// Nullable fields use the `option` module nested in the scalar's module. Lists of such
+/// scalars result in an error, as neither module can (de)serialize them.
+fn apply_scalar_serde_with(
+    scalar_serde_with: &BTreeMap<String, String>,
+    contents: &str,
+) -> Result<String, String> {
+    let mut output = Vec::new();
+
+    for line in contents.lines() {
+        let field_type = line
+            .trim_start()
+            .strip_prefix("pub ")
+            .and_then(|field| field.split_once(": "))
+            .and_then(|(_, field_type)| field_type.strip_suffix(','));
+
+        if let Some(field_type) = field_type {
+            for (scalar, module) in scalar_serde_with {
+                let attribute = if field_type == scalar {
+                    format!("#[serde(with = \"{module}\")]")
+                } else if field_type == format!("Option<{scalar}>") {
+                    format!("#[serde(default, with = \"{module}::option\")]")
+                } else if field_type
+                    .split(|c: char| !c.is_alphanumeric() && c != '_')
+                    .any(|name| name == scalar)
+                {
+                    return Err(format!(
+                        "The `{}` scalar has a serde `with` module, which can't be applied to a `{}` field",
+                        scalar, field_type
+                    ));
+                } else {
+                    continue;
+                };
+
+                let indent = &line[..line.len() - line.trim_start().len()];
+                output.push(format!("{indent}{attribute}"));
+            }
+        }
+
+        output.push(line.to_owned());
+    }
+
+    Ok(output.join("\n"))
+}
+
+/// Points the aliases of the validated scalars in a module generated by `graphql-client`
+/// at their newtypes in the `validated_scalars` module.
+fn use_validated_scalars(validated_scalars: &BTreeMap<String, String>, contents: &str) -> String {
+    let mut contents = contents.to_owned();
+
+    for scalar in validated_scalars.keys() {
+        contents = contents.replace(
+            &format!("type {scalar} = crate::graphql::custom_scalars::{scalar};"),
+            &format!("type {scalar} = crate::graphql::validated_scalars::{scalar};"),
+        );
+    }
+
+    contents
+}
+
+/// Renders the `validated_scalars` module, with a newtype for each validated scalar that
+/// checks its value with the scalar's validator when it's deserialized or constructed.
+fn render_validated_scalars(validated_scalars: &BTreeMap<String, String>) -> String {
+    if validated_scalars.is_empty() {
+        return String::new();
+    }
+
+    let newtypes = validated_scalars
+        .iter()
+        .map(|(scalar, validator)| {
+            let deserialize = format!("deserialize_{}", scalar.to_snake_case());
+
+            format!(
+                r#"
+    /// The `{scalar}` scalar, whose values are checked by [`{validator}`].
+    ///
+    /// A value that fails the check is a deserialization error, so malformed values from the
+    /// server are caught at the boundary instead of being passed along.
+    #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+    #[serde(transparent)]
+    pub struct {scalar}(
+        #[serde(deserialize_with = "{deserialize}")] crate::graphql::custom_scalars::{scalar},
+    );
+
+    impl {scalar} {{
+        /// Returns a new `{scalar}`, or the reason the value isn't valid.
+        pub fn new(value: crate::graphql::custom_scalars::{scalar}) -> Result<Self, String> {{
+            {validator}(&value).map_err(|err| err.to_string())?;
+            Ok(Self(value))
+        }}
+
+        /// Returns the validated value.
+        pub fn get(&self) -> &crate::graphql::custom_scalars::{scalar} {{
+            &self.0
+        }}
+
+        /// Returns the validated value, consuming the `{scalar}`.
+        pub fn into_inner(self) -> crate::graphql::custom_scalars::{scalar} {{
+            self.0
+        }}
+    }}
+
+    fn {deserialize}<'de, D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<crate::graphql::custom_scalars::{scalar}, D::Error> {{
+        let value = <crate::graphql::custom_scalars::{scalar} as serde::Deserialize>::deserialize(
+            deserializer,
+        )?;
+        {validator}(&value).map_err(|err| {{
+            serde::de::Error::custom(format_args!("invalid `{scalar}`: {{err}}"))
+        }})?;
+        Ok(value)
+    }}"#
+            )
+        })
+        .collect::<String>();
+
+    format!(
+        r#"
+
+/// The custom scalars that are validated when they're deserialized.
+pub mod validated_scalars {{{newtypes}
+}}
+"#
+    )
+}
+
+/// The Rust types used to generate the TypeScript types of scalars whose Rust
+/// representation doesn't map to the right TypeScript type on its own.
+///
+/// `Int` (an `i64`) and `DateTime` (a `u64`) would be mapped to a `bigint` by `ts-rs`,
+/// but they are plain numbers in JSON.
+const SCALAR_TS_TYPES: &[(&str, &str)] = &[("Int", "i32"), ("DateTime", "f64")];
+
+const TS_RS_DERIVE: &str = r#"#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]"#;
+
+/// Adds `ts_rs::TS` derives, behind the `ts-rs` feature, to the response types in a
+/// module generated by `graphql-client`.
+fn add_ts_rs_derives(validated_scalars: &BTreeMap<String, String>, contents: &str) -> String {
+    let lines = contents.lines().collect::<Vec<_>>();
+    let mut output = Vec::with_capacity(lines.len());
+
+    let mut in_response_type = false;
+    for (index, line) in lines.iter().enumerate() {
+        let trimmed = line.trim();
+        let next_line = lines
+            .get(index + 1)
+            .map(|line| line.trim())
+            .unwrap_or_default();
+
+        if trimmed.starts_with("#[derive(") && trimmed.contains("Deserialize") {
+            output.push(line.to_string());
+            output.push(TS_RS_DERIVE.to_string());
+            in_response_type = true;
+            continue;
+        }
+
+        // GraphQL enums have hand-written serde impls with an `Other` variant for
+        // unknown values, so they're represented as a string.
+        if trimmed == "#[derive(Debug)]" && next_line.starts_with("pub enum ") {
+            output.push(line.to_string());
+            output.push(
+                r#"#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS), ts(type = "string"))]"#
+                    .to_string(),
+            );
+            continue;
+        }
+
+        if in_response_type {
+            if trimmed == "}" {
+                in_response_type = false;
+            } else if trimmed == "#[serde(other)]" {
+                // The catch-all variant for unknown types has no TypeScript equivalent, and
+                // `ts-rs` can't parse its serde attribute.
+                output.push(r#"#[cfg_attr(feature = "ts-rs", ts(skip))]"#.to_string());
+            } else if let Some(ts_type) = field_ts_type_override(validated_scalars, trimmed) {
+                output.push(format!(
+                    r#"#[cfg_attr(feature = "ts-rs", ts(as = "{}"))]"#,
+                    ts_type
+                ));
+            }
+        }
+
+        output.push(line.to_string());
+    }
+
+    output.join("\n") + "\n"
+}
+
+/// Returns the type to use for generating the TypeScript type of the field on the given
+/// line, if its type contains a scalar that needs an override.
+///
+/// The newtypes of validated scalars don't derive `ts_rs::TS`, as `ts-rs` can't parse
+/// their serde attributes, so their fields use the type the scalar is aliased to.
+fn field_ts_type_override(
+    validated_scalars: &BTreeMap<String, String>,
+    line: &str,
+) -> Option<String> {
+    let (_, ty) = line.strip_prefix("pub ")?.split_once(": ")?;
+    let ty = ty.strip_suffix(',')?;
+
+    let overridden_ty = SCALAR_TS_TYPES
+        .iter()
+        .fold(ty.to_string(), |ty, (scalar, ts_type)| {
+            replace_type_name(&ty, scalar, ts_type)
+        });
+    let overridden_ty = validated_scalars.keys().fold(overridden_ty, |ty, scalar| {
+        replace_type_name(
+            &ty,
+            scalar,
+            &format!("crate::graphql::custom_scalars::{scalar}"),
+        )
+    });
+
+    (overridden_ty != ty).then_some(overridden_ty)
+}
+
+/// Replaces all occurrences of the type name `from` in the Rust type `ty` with `to`.
+fn replace_type_name(ty: &str, from: &str, to: &str) -> String {
+    let mut output = String::with_capacity(ty.len());
+    let mut name = String::new();
+
+    for c in ty.chars() {
+        if c.is_alphanumeric() || c == '_' || c == ':' {
+            name.push(c);
+            continue;
+        }
+
+        output.push_str(if name == from { to } else { &name });
+        output.push(c);
+        name.clear();
+    }
+    output.push_str(if name == from { to } else { &name });
+
+    output
+}
+
+/// Returns whether the field looks up many objects by their IDs, such as
+/// `tasks(ids: [ID!]!): [Task]!`.
+fn is_batch_lookup(field: &Field) -> bool {
+    let [arg] = field.args.as_slice() else {
+        return false;
+    };
+
+    let is_id_list = match &arg.ty {
+        GraphQlTypeRef::NonNull(list) => match &list.of_type {
+            GraphQlTypeRef::List(item) => matches!(
+                &item.of_type,
+                GraphQlTypeRef::NonNull(id) if matches!(&id.of_type, GraphQlTypeRef::Scalar { name } if name == "ID")
+            ),
+            _ => false,
+        },
+        _ => false,
+    };
+
+    is_id_list && render_list_item_type_name("", "", field).is_some()
+}
+
+/// Renders a client method that fetches many objects by their IDs in a single request,
+/// along with its blocking mirror.
+fn render_batch_lookup(
+    client: &str,
+    module_name: &str,
+    operation_name: &str,
+    field: &Field,
+) -> (String, String) {
+    let item_type_name = render_list_item_type_name(
+        &format!("crate::graphql::{}", module_name),
+        operation_name,
+        field,
+    )
+    .expect("batch lookups return a list");
+    let mut doc = format!(
+        "/// Fetches the `{}` with the given IDs in a single request.",
+        field.name
+    );
+    if item_type_name.starts_with("Option<") {
+        doc.push_str("\n    ///\n    /// IDs that don't match an object are returned as `None`.");
+    }
+
+    let fn_name = format!("get_{}", sanitize_name(field.name.clone()).to_snake_case());
+    let params = "ids: impl IntoIterator<Item = impl Into<String>>";
+    let return_type = format!("crate::Result<Vec<{}>>", item_type_name);
+
+    let method = format!(
+        r#"
+    {doc}
+    pub async fn {fn_name}(
+        &self,
+        {params},
+    ) -> {return_type} {{
+        let variables =
+            crate::graphql::{module_name}::Variables::new(ids.into_iter().map(Into::into).collect());
+
+        {client}
+            .execute::<crate::graphql::{operation_name}>(variables)
+            .await
+            .map(|data| data.into_iter().collect())
+    }}
+        "#,
+    )
+    .trim()
+    .to_string();
+
+    let blocking_method = render_blocking_method(&doc, &fn_name, params, &return_type, "ids");
+
+    (method, blocking_method)
+}
+
+/// Renders the blocking mirror of a generated client method, which runs the async method
+/// to completion on the runtime of the blocking client.
+fn render_blocking_method(
+    doc: &str,
+    fn_name: &str,
+    params: &str,
+    return_type: &str,
+    args: &str,
+) -> String {
+    format!(
+        r#"
+    {doc}
+    pub fn {fn_name}(
+        &self,
+        {params},
+    ) -> {return_type} {{
+        self.block_on(self.client().{fn_name}({args}))
+    }}
+        "#
+    )
+    .trim()
+    .to_string()
+}
+
+/// Renders the generated client type and its operation methods.
+///
+/// The blocking mirrors of the methods are only emitted for the primary client, as the
+/// blocking client wraps a `BlipsClient`.
+fn render_client(client_name: &str, client_impls: &[String], blocking_impls: &[String]) -> String {
+    let impls = client_impls.join("\n\n");
+
+    if client_name == PRIMARY_CLIENT_NAME {
+        let blocking_impls = blocking_impls.join("\n\n");
+
+        return format!(
+            r#"
+impl crate::{client_name} {{
+    {impls}
+}}
+
+#[cfg(feature = "blocking")]
+impl crate::blocking::{client_name} {{
+    {blocking_impls}
+}}
+            "#
+        );
+    }
+
+    format!(
+        r#"
+/// The {client_name} client.
+#[derive(Clone)]
+pub struct {client_name} {{
+    client: crate::{PRIMARY_CLIENT_NAME},
+}}
+
+impl {client_name} {{
+    /// Returns a new instance of the {client_name} client using the transport configuration
+    /// of the provided [`crate::{PRIMARY_CLIENT_NAME}`].
+    pub fn new(client: crate::{PRIMARY_CLIENT_NAME}) -> Self {{
+        Self {{ client }}
+    }}
+
+    {impls}
+}}
+        "#
+    )
+}
+
+/// Replaces the references to `graphql_client` in a module it generated with the SDK's
+/// runtime types, so that the generated code only depends on `graphql_client` when the SDK
+/// does.
+fn use_runtime_types(contents: &str) -> String {
+    contents
+        .replace("::graphql_client::", "crate::runtime::")
+        .replace("graphql_client::", "crate::runtime::")
+}
+
+/// Adds an `OPERATION_KIND` constant next to the `OPERATION_NAME` of the operation, so
+/// that code handling operations generically can tell queries from mutations.
+fn add_operation_kind(contents: &str, operation: GraphQlOperation) -> String {
+    let mut lines = Vec::new();
+    for line in contents.lines() {
+        lines.push(line.to_owned());
+
+        if line.trim_start().starts_with("pub const OPERATION_NAME:") {
+            let indent = &line[..line.len() - line.trim_start().len()];
+            lines.push(format!(
+                "{indent}pub const OPERATION_KIND: crate::OperationKind = crate::OperationKind::{:?};",
+                operation
+            ));
+        }
+    }
+
+    lines.join("\n") + "\n"
+}
+
+/// Renders the entry of an operation in the generated operation registry.
+fn render_operation_info(
+    module_name: &str,
+    field: &Field,
+    variable_naming: VariableNaming,
+) -> String {
+    let arguments = field
+        .args
+        .iter()
+        .map(|arg| {
+            format!(
+                r#"crate::ArgumentInfo {{
+            name: {name:?},
+            variable_name: {variable_name:?},
+            graphql_type: {graphql_type:?},
+        }}"#,
+                name = arg.name,
+                variable_name = variable_naming.variable_name(&arg.name),
+                graphql_type = render_type_name(&arg.ty),
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    format!(
+        r#"crate::OperationInfo {{
+        name: {module_name}::OPERATION_NAME,
+        kind: {module_name}::OPERATION_KIND,
+        arguments: &[{arguments}],
+        document: {module_name}::QUERY,
+    }}"#
+    )
+}
+
+/// Renders the enum of the generated operations, which can be parsed from their names.
+fn render_operation_enum(operation_names: &[String]) -> String {
+    let variants = operation_names
+        .iter()
+        .map(|operation_name| format!("    {},", operation_name))
+        .collect::<Vec<_>>()
+        .join("\n");
+    let from_str_arms = operation_names
+        .iter()
+        .map(|operation_name| {
+            format!(
+                "            {:?} => Ok(Self::{}),",
+                operation_name, operation_name
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    format!(
+        r#"
+/// A generated operation, for selecting one by name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Operation {{
+{variants}
+}}
+
+impl Operation {{
+    /// Returns the metadata of the operation.
+    pub fn info(self) -> &'static crate::OperationInfo {{
+        &OPERATIONS[self as usize]
+    }}
+
+    /// Returns the name of the operation.
+    pub fn name(self) -> &'static str {{
+        self.info().name
+    }}
+}}
+
+impl std::str::FromStr for Operation {{
+    type Err = crate::UnknownOperationError;
+
+    /// Parses an operation from its name, such as `Tasks`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {{
+        match s {{
+{from_str_arms}
+            _ => Err(crate::UnknownOperationError(s.to_owned())),
+        }}
+    }}
+}}
+        "#
+    )
+    .trim()
+    .to_string()
+}
+
+/// Renders the metadata of an argument or an input field for the schema reflection module.
+fn render_input_value_info(input_value: &InputValue) -> String {
+    format!(
+        "crate::InputValueInfo {{ name: {:?}, graphql_type: {:?}, default_value: {:?} }}",
+        input_value.name,
+        render_type_name(&input_value.ty),
+        input_value.default_value,
+    )
+}
+
+/// Renders the metadata of the fields of an object or an interface for the schema
+/// reflection module.
+fn render_field_infos(fields: &[Field]) -> String {
+    fields
+        .iter()
+        .map(|field| {
+            format!(
+                "crate::FieldInfo {{ name: {:?}, graphql_type: {:?}, arguments: &[{}], is_deprecated: {} }}",
+                field.name,
+                render_type_name(&field.ty),
+                field
+                    .args
+                    .iter()
+                    .map(render_input_value_info)
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                field.is_deprecated,
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Renders the schema reflection module, which describes every type in the schema except
+/// the introspection types.
+///
+/// The types are sorted by name, so they can be looked up with a binary search. The same
+/// goes for the map of types to the operations that return them.
+fn render_schema_reflection(
+    schema: &IntrospectionSchema,
+    type_operations: &BTreeMap<String, BTreeSet<String>>,
+) -> String {
+    let mut types = schema
+        .types
+        .iter()
+        .filter_map(|ty| {
+            let name = ty.name()?;
+            if name.starts_with("__") {
+                return None;
+            }
+
+            let (kind, fields, input_fields, enum_values, possible_types) = match ty {
+                GraphQlFullType::Scalar(_) => {
+                    ("Scalar", String::new(), String::new(), vec![], vec![])
+                }
+                GraphQlFullType::Object(object) => (
+                    "Object",
+                    render_field_infos(&object.fields),
+                    String::new(),
+                    vec![],
+                    vec![],
+                ),
+                GraphQlFullType::Interface(interface) => (
+                    "Interface",
+                    render_field_infos(&interface.fields),
+                    String::new(),
+                    vec![],
+                    interface
+                        .possible_types
+                        .iter()
+                        .map(resolve_type_name)
+                        .cloned()
+                        .collect(),
+                ),
+                GraphQlFullType::Union(union) => (
+                    "Union",
+                    String::new(),
+                    String::new(),
+                    vec![],
+                    union
+                        .possible_types
+                        .iter()
+                        .flatten()
+                        .map(resolve_type_name)
+                        .cloned()
+                        .collect(),
+                ),
+                GraphQlFullType::Enum(r#enum) => (
+                    "Enum",
+                    String::new(),
+                    String::new(),
+                    r#enum
+                        .enum_values
+                        .iter()
+                        .map(|value| value.name.clone())
+                        .collect(),
+                    vec![],
+                ),
+                GraphQlFullType::InputObject(input_object) => (
+                    "InputObject",
+                    String::new(),
+                    input_object
+                        .input_fields
+                        .iter()
+                        .map(render_input_value_info)
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                    vec![],
+                    vec![],
+                ),
+            };
+
+            Some((
+                name.clone(),
+                format!(
+                    r#"crate::TypeInfo {{
+        name: {name:?},
+        kind: crate::TypeKind::{kind},
+        fields: &[{fields}],
+        input_fields: &[{input_fields}],
+        enum_values: &{enum_values:?},
+        possible_types: &{possible_types:?},
+    }}"#
+                ),
+            ))
+        })
+        .collect::<Vec<_>>();
+    types.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+    let type_operations = type_operations
+        .iter()
+        .map(|(type_name, operation_names)| {
+            format!(
+                "({type_name:?}, &{:?})",
+                operation_names.iter().collect::<Vec<_>>()
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    format!(
+        r#"
+/// The types in the schema, sorted by name.
+pub static TYPES: &[crate::TypeInfo] = &[{types}];
+
+/// Returns the type with the given name, if the schema has one.
+pub fn find_type(name: &str) -> Option<&'static crate::TypeInfo> {{
+    TYPES
+        .binary_search_by(|ty| ty.name.cmp(name))
+        .ok()
+        .map(|index| &TYPES[index])
+}}
+
+/// The names of the generated operations that return each type, sorted by type name.
+///
+/// Operations that return an interface or a union are listed under its possible types too.
+pub static TYPE_OPERATIONS: &[(&str, &[&str])] = &[{type_operations}];
+
+/// Returns the names of the generated operations that return the type with the given name,
+/// such as every operation that returns a `Task`.
+///
+/// This is useful for invalidating cached queries after a mutation. Returns an empty slice
+/// when no operation returns the type.
+pub fn operations_returning(type_name: &str) -> &'static [&'static str] {{
+    TYPE_OPERATIONS
+        .binary_search_by(|(name, _)| name.cmp(&type_name))
+        .map_or(&[], |index| TYPE_OPERATIONS[index].1)
+}}
+        "#,
+        types = types
+            .into_iter()
+            .map(|(_, ty)| ty)
+            .collect::<Vec<_>>()
+            .join(", "),
+    )
+    .trim()
+    .to_owned()
+}
+
+/// Renders the map of operation names to persisted document IDs used by the client.
+fn render_persisted_document_ids(persisted_ids: &BTreeMap<String, String>) -> String {
+    let entries = persisted_ids
+        .iter()
+        .map(|(operation_name, id)| format!("    ({:?}, {:?}),", operation_name, id))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    format!(
+        r#"
+/// The persisted document IDs of the operations, keyed by operation name.
+pub(crate) const PERSISTED_DOCUMENT_IDS: &[(&str, &str)] = &[
+{entries}
+];
+        "#
+    )
+}
+
+/// How the generated code is laid out in the output directory.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum OutputLayout {
+    /// Writes `graphql.rs`, `client_generated.rs`, and a module per operation under
+    /// `graphql/generated`, mirroring the `src` directory of the `blips` crate.
+    #[default]
+    Modules,
+
+    /// Writes all of the generated code to a single `generated.rs`, for including it with
+    /// `include!`.
+    ///
+    /// The file holds the contents of the `graphql` module, with the generated client in a
+    /// nested `client_generated` module.
+    SingleFile,
+}
+
+/// Where the introspection schema is read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SchemaSource {
+    /// A file, which is `schema.json` by default.
+    Path(PathBuf),
+
+    /// The standard input, for schemas produced by another step of a pipeline.
+    Stdin,
+
+    /// A GraphQL endpoint, which is introspected with `graphql-client introspect-schema`.
+    Url(String),
+}
+
+impl Default for SchemaSource {
+    fn default() -> Self {
+        Self::Path(PathBuf::from("schema.json"))
+    }
+}
+
+impl std::str::FromStr for SchemaSource {
+    type Err = std::convert::Infallible;
+
+    /// Parses `-` as the standard input, and `http://` and `https://` URLs as endpoints.
+    /// Anything else is a path.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Ok(if value == "-" {
+            Self::Stdin
+        } else if value.starts_with("http://") || value.starts_with("https://") {
+            Self::Url(value.to_owned())
+        } else {
+            Self::Path(PathBuf::from(value))
+        })
+    }
+}
+
+/// The file of the introspection schema, which is removed once generating is done when it
+/// was written from the standard input or an endpoint.
+struct SchemaFile {
+    path: PathBuf,
+    is_temporary: bool,
+}
+
+impl SchemaFile {
+    /// Returns the file of the schema from the given source, writing the schema to a
+    /// temporary file when it doesn't come from one already.
+    ///
+    /// The file is needed even when the schema is read from elsewhere, as `graphql-client`
+    /// reads the schema from a path.
+    fn from_source(
+        source: &SchemaSource,
+        headers: &[String],
+    ) -> Result<Self, Box<dyn std::error::Error>> {
+        let temporary_path =
+            std::env::temp_dir().join(format!("blips-schema-{}.json", std::process::id()));
+
+        match source {
+            SchemaSource::Path(path) => Ok(Self {
+                path: path.clone(),
+                is_temporary: false,
+            }),
+            SchemaSource::Stdin => {
+                let schema: serde_json::Value = serde_json::from_reader(std::io::stdin().lock())
+                    .map_err(|err| format!("Failed to read the schema from stdin: {}", err))?;
+                fs::write(&temporary_path, serde_json::to_vec(&schema)?)?;
+
+                Ok(Self {
+                    path: temporary_path,
+                    is_temporary: true,
+                })
+            }
+            SchemaSource::Url(url) => {
+                let mut introspect_command = Command::new("graphql-client");
+                introspect_command.arg("introspect-schema");
+                for header in headers {
+                    introspect_command.arg("--header").arg(header);
+                }
+                introspect_command
+                    .arg(url)
+                    .arg("--output")
+                    .arg(&temporary_path);
+
+                // The file is removed when introspecting fails halfway through.
+                let schema_file = Self {
+                    path: temporary_path,
+                    is_temporary: true,
+                };
+                let status = introspect_command.status()?;
+                if !status.success() {
+                    return Err(format!(
+                        "graphql-client failed to introspect '{}': {}",
+                        url, status
+                    )
+                    .into());
+                }
+
+                Ok(schema_file)
+            }
+        }
+    }
+}
+
+impl Drop for SchemaFile {
+    fn drop(&mut self) {
+        if self.is_temporary {
+            let _ = fs::remove_file(&self.path);
+        }
+    }
+}
+
+/// The options for generating the GraphQL client.
+#[derive(Debug, Clone)]
+pub struct CodegenOptions {
+    output_dir: PathBuf,
+    schema_source: SchemaSource,
+    schema_headers: Vec<String>,
+    overrides_dir: Option<PathBuf>,
+    annotations_path: Option<PathBuf>,
+    client_name: String,
+    variable_naming: VariableNaming,
+    persisted_ids: BTreeMap<String, String>,
+    layout: OutputLayout,
+    format: bool,
+    operation_name_prefix: String,
+    operation_name_suffix: String,
+    max_depth: u32,
+    values_beyond_max_depth: bool,
+    crate_path: String,
+    scalar_serde_with: BTreeMap<String, String>,
+    validated_scalars: BTreeMap<String, String>,
+    serde_aliases: bool,
+    extra_fields: Option<ExtraFieldKeys>,
+}
+
+impl CodegenOptions {
+    /// Returns the default options for generating the client into the given directory.
+    pub fn new(output_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            output_dir: output_dir.into(),
+            schema_source: SchemaSource::default(),
+            schema_headers: Vec::new(),
+            overrides_dir: None,
+            annotations_path: None,
+            client_name: PRIMARY_CLIENT_NAME.to_owned(),
+            variable_naming: VariableNaming::SnakeCase,
+            persisted_ids: BTreeMap::new(),
+            layout: OutputLayout::default(),
+            format: true,
+            operation_name_prefix: String::new(),
+            operation_name_suffix: String::new(),
+            max_depth: 1,
+            values_beyond_max_depth: false,
+            crate_path: "crate".to_owned(),
+            scalar_serde_with: BTreeMap::new(),
+            validated_scalars: BTreeMap::new(),
+            serde_aliases: false,
+            extra_fields: None,
+        }
+    }
+
+    /// Sets the path to the introspection schema to generate the client from.
+    pub fn schema_path(mut self, schema_path: impl Into<PathBuf>) -> Self {
+        self.schema_source = SchemaSource::Path(schema_path.into());
+        self
+    }
+
+    /// Sets where to read the introspection schema from, which is `schema.json` by default.
+    ///
+    /// Reading the schema from the standard input or an endpoint saves writing it to a
+    /// file first, such as in a CI pipeline that produces it on the fly.
+    pub fn schema_source(mut self, schema_source: SchemaSource) -> Self {
+        self.schema_source = schema_source;
+        self
+    }
+
+    /// Adds a header to send when introspecting the schema from an endpoint, such as
+    /// `Cookie: ...`.
+    pub fn schema_header(mut self, header: impl Into<String>) -> Self {
+        self.schema_headers.push(header.into());
+        self
+    }
+
+    /// Sets the directory containing the fragments that override the generated ones.
+    ///
+    /// An override for an operation is named after its module, such as `tasks.graphql`,
+    /// and must define the fragment the operation spreads, such as `fragment Task on Task`.
+    pub fn overrides_dir(mut self, overrides_dir: impl Into<PathBuf>) -> Self {
+        self.overrides_dir = Some(overrides_dir.into());
+        self
+    }
+
+    /// Sets the path to an SDL file of annotations that choose which operations are
+    /// generated and which fields their fragments select.
+    ///
+    /// The file extends the types of the schema with `@generate` and `@skip` on their
+    /// fields. On a root type, such as `Query`, `@skip` leaves out the operation for the
+    /// field, and once any of its fields is annotated with `@generate`, only the annotated
+    /// fields get an operation. On other types, `@skip` leaves the field out of the
+    /// generated fragments, and `@generate` selects an object field (with its scalar
+    /// fields) that the depth wouldn't select. `@skip` takes precedence over `@generate`,
+    /// and operations with an override don't use the annotations of their fragment.
+    pub fn annotations_path(mut self, annotations_path: impl Into<PathBuf>) -> Self {
+        self.annotations_path = Some(annotations_path.into());
+        self
+    }
+
+    /// Sets the name of the client type to implement the generated operations on.
+    pub fn client_name(mut self, client_name: impl Into<String>) -> Self {
+        self.client_name = client_name.into();
+        self
+    }
+
+    /// Sets the naming convention for the variables declared in the generated documents.
+    pub fn variable_naming(mut self, variable_naming: VariableNaming) -> Self {
+        self.variable_naming = variable_naming;
+        self
+    }
+
+    /// Sets the persisted document IDs of the operations, keyed by operation name.
+    pub fn persisted_ids(mut self, persisted_ids: BTreeMap<String, String>) -> Self {
+        self.persisted_ids = persisted_ids;
+        self
+    }
+
+    /// Sets how the generated code is laid out in the output directory.
+    pub fn layout(mut self, layout: OutputLayout) -> Self {
+        self.layout = layout;
+        self
+    }
+
+    /// Sets whether the generated code is formatted with `rustfmt`.
+    pub fn format(mut self, format: bool) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Sets a prefix for the names of the generated operations, such as `Blips` for
+    /// `BlipsCreateBoard`.
+    ///
+    /// The prefix applies to the operation structs, their modules, and the types in them,
+    /// which keeps them from colliding with the types of other generated SDKs. It's also
+    /// part of the operation names sent to the server and used for persisted document
+    /// IDs. The client methods keep their names.
+    pub fn operation_name_prefix(mut self, operation_name_prefix: impl Into<String>) -> Self {
+        self.operation_name_prefix = operation_name_prefix.into();
+        self
+    }
+
+    /// Sets a suffix for the names of the generated operations, such as `Op` for
+    /// `CreateBoardOp`.
+    ///
+    /// See [`CodegenOptions::operation_name_prefix`] for what the suffix applies to.
+    pub fn operation_name_suffix(mut self, operation_name_suffix: impl Into<String>) -> Self {
+        self.operation_name_suffix = operation_name_suffix.into();
+        self
+    }
+
+    /// Sets how many levels of objects the generated fragments select, which is `1` by
+    /// default.
+    ///
+    /// At depth `1`, a fragment selects the scalar fields of the type the operation returns
+    /// (and the nodes of connections). Each level beyond that also selects the scalar fields
+    /// of the objects one level further down. Deeper fragments save requests for related
+    /// data, but over-fetch for callers that don't need it and grow quickly in size, as
+    /// every level selects every object field. A depth of `0` is treated as `1`.
+    pub fn max_depth(mut self, max_depth: u32) -> Self {
+        self.max_depth = max_depth.max(1);
+        self
+    }
+
+    /// Sets whether the object fields beyond the maximum depth are selected as a
+    /// `serde_json::Value`, rather than left out, which is off by default.
+    ///
+    /// GraphQL requires a selection set for object fields, so such a field selects the
+    /// scalar fields of its object, and the `Value` holds them as the server returned them.
+    /// This keeps deep data reachable without typing every level. Operations with an
+    /// override don't use it.
+    pub fn values_beyond_max_depth(mut self, values_beyond_max_depth: bool) -> Self {
+        self.values_beyond_max_depth = values_beyond_max_depth;
+        self
+    }
+
+    /// Sets the path the generated code refers to the SDK and the generated modules by,
+    /// which is `crate` by default.
+    ///
+    /// This is for vendoring the generated code into a crate other than `blips`, such as
+    /// at `crate::api`. That path must export the `blips` items the generated code uses
+    /// (such as with `pub use blips::*;`) alongside the generated `graphql` module and its
+    /// `custom_scalars` module. As methods can't be added to `BlipsClient` from another
+    /// crate, vendored code needs a dedicated client type (see
+    /// [`CodegenOptions::client_name`]).
+    pub fn crate_path(mut self, crate_path: impl Into<String>) -> Self {
+        self.crate_path = crate_path.into();
+        self
+    }
+
+    /// Sets the serde `with` module for the fields of a custom scalar, such as
+    /// `crate::graphql::custom_scalars::iso8601_duration` for a `Duration` scalar sent as
+    /// an ISO 8601 string.
+    ///
+    /// This is for scalars whose encoding can't be expressed by the type they're aliased
+    /// to. The module must have the `serialize` and `deserialize` functions of the
+    /// `#[serde(with = "...")]` contract for the scalar's type, and an `option` module
+    /// nested in it with the same functions for an `Option` of it, which is used for the
+    /// nullable fields. Lists of the scalar aren't supported, and update mutations that
+    /// take it don't get a changeset.
+    pub fn scalar_serde_with(
+        mut self,
+        scalar: impl Into<String>,
+        module: impl Into<String>,
+    ) -> Self {
+        self.scalar_serde_with.insert(scalar.into(), module.into());
+        self
+    }
+
+    /// Sets the validator for the values of a custom scalar, such as
+    /// `crate::graphql::custom_scalars::validate_url` for a `Url` scalar.
+    ///
+    /// The scalar gets a `#[serde(transparent)]` newtype around the type it's aliased to in
+    /// the generated `validated_scalars` module, which the generated code uses in place of
+    /// that type. The validator is a function that takes a reference to the aliased type
+    /// and returns a `Result<(), E>`, where `E` implements `Display`. It runs when the
+    /// newtype is deserialized, so a malformed value from the server is a deserialization
+    /// error with the path to it, and when one is constructed with `new`. A scalar can't
+    /// have both a validator and a serde `with` module.
+    pub fn validated_scalar(
+        mut self,
+        scalar: impl Into<String>,
+        validator: impl Into<String>,
+    ) -> Self {
+        self.validated_scalars
+            .insert(scalar.into(), validator.into());
+        self
+    }
+
+    /// Sets whether the renamed fields of the response types also accept the `snake_case`
+    /// form of their name, such as `completed_at` for `completedAt`, which is off by
+    /// default.
+    ///
+    /// This is for a server migration during which a field may come back in either
+    /// casing. Each renamed field gets a `#[serde(alias = "...")]` for its `snake_case`
+    /// form, so both keys deserialize into the same field.
+    pub fn serde_aliases(mut self, serde_aliases: bool) -> Self {
+        self.serde_aliases = serde_aliases;
+        self
+    }
+
+    /// Sets whether the response types capture the fields they don't declare, and how the
+    /// keys of those fields are stored, which is off by default.
+    ///
+    /// Each response type gets a `#[serde(flatten)]` field named `extras`, of type
+    /// `ExtraFields`, with the fields the server returned beyond the ones it declares, such
+    /// as a field added to the schema after the code was generated. With
+    /// [`ExtraFieldKeys::AsReceived`] the keys are stored as the server sent them, and with
+    /// [`ExtraFieldKeys::SnakeCase`] they are converted to `snake_case`. Types that already
+    /// have a flattened field, such as the types of interfaces, don't get `extras`.
+    pub fn extra_fields(mut self, extra_fields: Option<ExtraFieldKeys>) -> Self {
+        self.extra_fields = extra_fields;
+        self
+    }
+
+    /// Returns the name of the operation for the given root field, such as `CreateBoard`
+    /// for `createBoard`, with the configured prefix and suffix.
+    fn operation_name(&self, field: &Field) -> String {
+        format!(
+            "{}{}{}",
+            self.operation_name_prefix,
+            sanitize_name(field.name.clone()).to_pascal_case(),
+            self.operation_name_suffix
+        )
+    }
+}
+
+/// Generates the GraphQL client from the Blips introspection schema.
+///
+/// Requires the `graphql-client` CLI to be installed.
+pub fn generate(options: &CodegenOptions) -> Result<(), Box<dyn std::error::Error>> {
+    // This is kept until the end, as `graphql-client` reads the schema from its file.
+    let schema_source_file =
+        SchemaFile::from_source(&options.schema_source, &options.schema_headers)?;
+    let schema_path = &schema_source_file.path;
+
+    if let Some(scalar) = options
+        .validated_scalars
+        .keys()
+        .find(|scalar| options.scalar_serde_with.contains_key(*scalar))
+    {
+        return Err(format!(
+            "The `{}` scalar can't have both a validator and a serde `with` module",
+            scalar
+        )
+        .into());
+    }
+
+    let schema_file = File::open(schema_path)?;
+    let buf_reader = BufReader::new(schema_file);
+
+    let schema_query: IntrospectionResponse = serde_json::from_reader(buf_reader)?;
+
+    let schema = schema_query.data.schema;
+
+    let annotations = match &options.annotations_path {
+        Some(annotations_path) => {
+            let annotations = Annotations::parse(&fs::read_to_string(annotations_path)?)?;
+            annotations.validate(&schema)?;
+            annotations
+        }
+        None => Annotations::default(),
+    };
+
+    let fields = root_fields(&schema)?
+        .into_iter()
+        .filter(|(operation, field)| {
+            root_type_name(&schema, *operation)
+                .is_none_or(|root| annotations.includes_operation(root, &field.name))
+        })
+        .collect::<Vec<_>>();
+
+    let mut persisted_ids = options.persisted_ids.clone();
+
+    let generated_dir = options.output_dir.join("graphql/generated");
+    fs::create_dir_all(&generated_dir)?;
+
+    let mut emitted_graphql_modules: Vec<String> = Vec::new();
+    let mut generated_client_impls: Vec<String> = Vec::new();
+    let mut generated_blocking_impls: Vec<String> = Vec::new();
+    let mut module_extensions: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    let mut variables_impls: BTreeMap<String, String> = BTreeMap::new();
+    let mut default_variables_modules: Vec<String> = Vec::new();
+    let mut json_value_types: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    let mut operation_infos: BTreeMap<String, String> = BTreeMap::new();
+    let mut operation_kinds: BTreeMap<String, GraphQlOperation> = BTreeMap::new();
+    let mut operation_enum_variants: BTreeMap<String, String> = BTreeMap::new();
+    let mut documents: BTreeMap<String, String> = BTreeMap::new();
+    let mut type_operations: BTreeMap<String, BTreeSet<String>> = BTreeMap::new();
+
+    for (operation, field) in &fields {
+        let field_type_name = resolve_type_name(&field.ty);
+
+        let has_args = !field.args.is_empty();
+        let args_list = field
+            .args
+            .iter()
+            .map(|arg| {
+                format!(
+                    "${}: {}",
+                    options.variable_naming.variable_name(&arg.name),
+                    render_type_name(&arg.ty)
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        let applied_args_list = field
+            .args
+            .iter()
+            .map(|arg| {
+                format!(
+                    "{}: ${}",
+                    arg.name,
+                    options.variable_naming.variable_name(&arg.name)
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        let field_type = schema
+            .types
+            .iter()
+            .find(|ty| ty.name().as_ref() == Some(field_type_name))
+            .ok_or_else(|| format!("No type found for field '{}'", field_type_name))?;
+
+        // Interfaces select the fields they declare, which every implementation shares.
+        let fragment_type_fields = match field_type {
+            GraphQlFullType::Object(object) => object.fields.as_slice(),
+            GraphQlFullType::Interface(interface) => interface.fields.as_slice(),
+            _ => &[],
+        };
+
+        let mut fragment_field_names = Vec::new();
+        for sub_field in fragment_type_fields {
+            let sub_field_type_name = resolve_type_name(&sub_field.ty);
+
+            let sub_field_type = schema
+                .types
+                .iter()
+                .find(|ty| ty.name().as_ref() == Some(sub_field_type_name))
+                .ok_or_else(|| format!("No type found for sub field '{}'", sub_field_type_name))?;
+
+            if let GraphQlFullType::Scalar(_) = sub_field_type {
+                if !annotations.is_skipped(field_type_name, &sub_field.name) {
+                    fragment_field_names.push(sub_field.name.clone());
+                }
+            }
+        }
+
+        let fragment_name = field_type_name.to_pascal_case();
+
+        // Beyond the first level, the object fields are selected too. At the first level,
+        // connections are only useful with their items, so they also select the scalar
+        // fields of their nodes alongside their `totalCount`, unless the nodes are
+        // already selected by an annotation.
+        let mut value_types = Vec::new();
+        let nested_selections = match field_type {
+            GraphQlFullType::Object(object) if options.max_depth > 1 => render_nested_selections(
+                &schema,
+                object,
+                &fragment_name,
+                options.max_depth - 1,
+                1,
+                &annotations,
+                options.values_beyond_max_depth.then_some(&mut value_types),
+            ),
+            GraphQlFullType::Object(object) => {
+                let mut selections = render_nested_selections(
+                    &schema,
+                    object,
+                    &fragment_name,
+                    0,
+                    1,
+                    &annotations,
+                    options.values_beyond_max_depth.then_some(&mut value_types),
+                );
+                // The nodes of a connection are selected with their own type instead.
+                let nodes_type = format!("{fragment_name}Nodes");
+                if connection_total_count_field(object).is_some()
+                    && value_types.contains(&nodes_type)
+                {
+                    value_types.retain(|value_type| *value_type != nodes_type);
+                    selections.retain(|selection| !selection.starts_with("nodes "));
+                }
+                if !selections
+                    .iter()
+                    .any(|selection| selection.starts_with("nodes "))
+                {
+                    selections.extend(render_connection_nodes_selection(&schema, object));
+                }
+                selections
+            }
+            GraphQlFullType::Interface(interface) => render_implementation_selections(
+                &schema,
+                &interface.fields,
+                &interface.possible_types,
+            ),
+            GraphQlFullType::Union(union) => render_implementation_selections(
+                &schema,
+                &[],
+                union.possible_types.as_deref().unwrap_or_default(),
+            ),
+            _ => Vec::new(),
+        };
+
+        let operation_name = options.operation_name(field);
+        let rust_module_name = operation_name.to_snake_case();
+
+        // Overrides are named after the module without the prefix and suffix, so that they
+        // keep applying when those change.
+        let fragment_override = match &options.overrides_dir {
+            Some(overrides_dir) => read_fragment_override(
+                overrides_dir,
+                &sanitize_name(field.name.clone()).to_snake_case(),
+            )?,
+            None => None,
+        };
+
+        let fragment = match &fragment_override {
+            Some(fragment) => fragment.trim().to_string(),
+            None => format!(
+                r#"fragment {fragment_name} on {fragment_name} {{
+    __typename
+    {fragment_fields}
+}}"#,
+                fragment_fields = fragment_field_names
+                    .iter()
+                    .chain(&nested_selections)
+                    .cloned()
+                    .collect::<Vec<_>>()
+                    .join("\n    ")
+            ),
+        };
+        if fragment_override.is_none() && !value_types.is_empty() {
+            json_value_types.insert(rust_module_name.clone(), value_types);
+        }
+
+        let contents = format!(
+            r#"
+{operation} {query_name}{args_list} {{
+    {field_name}{applied_args_list} {{
+        ...{fragment_name}
+    }}
+}}
+
+{fragment}
+            "#,
+            operation = match operation {
+                GraphQlOperation::Query => "query",
+                GraphQlOperation::Mutation => "mutation",
+                GraphQlOperation::Subscription => "subscription",
+            },
+            query_name = operation_name,
+            args_list = if has_args {
+                format!("({})", args_list)
+            } else {
+                String::new()
+            },
+            applied_args_list = if has_args {
+                format!("({})", applied_args_list)
+            } else {
+                String::new()
+            },
+            field_name = field.name,
+        );
+
+        validate_document(&schema, &contents)
+            .map_err(|err| format!("Invalid document for '{}': {}", field.name, err))?;
+
+        documents.insert(rust_module_name.clone(), contents);
+
+        emitted_graphql_modules.push(rust_module_name.clone());
+
+        operation_infos.insert(
+            rust_module_name.clone(),
+            render_operation_info(&rust_module_name, field, options.variable_naming),
+        );
+        operation_kinds.insert(rust_module_name.clone(), *operation);
+        operation_enum_variants.insert(rust_module_name.clone(), operation_name.clone());
+
+        // Only entities are mapped to their operations, not the scalars and enums some
+        // mutations return.
+        let returned_types = match field_type {
+            GraphQlFullType::Object(_) => Some(&[][..]),
+            GraphQlFullType::Interface(interface) => Some(interface.possible_types.as_slice()),
+            GraphQlFullType::Union(union) => {
+                Some(union.possible_types.as_deref().unwrap_or_default())
+            }
+            _ => None,
+        };
+        if let Some(possible_types) = returned_types {
+            for type_name in
+                std::iter::once(field_type_name).chain(possible_types.iter().map(resolve_type_name))
+            {
+                type_operations
+                    .entry(type_name.clone())
+                    .or_default()
+                    .insert(operation_name.clone());
+            }
+        }
+
+        if let Some(mut variables_impl) = render_variables_impl(field) {
+            // The fields of a changeset are doubly optional, which a serde `with` module
+            // can't (de)serialize, so mutations that take such scalars don't get one.
+            let takes_serde_with_scalar = field.args.iter().any(|arg| {
+                options
+                    .scalar_serde_with
+                    .contains_key(resolve_type_name(&arg.ty))
+            });
+            if *operation == GraphQlOperation::Mutation
+                && is_update_mutation(field)
+                && !takes_serde_with_scalar
+            {
+                variables_impl.push_str(&render_changeset(
+                    &operation_name,
+                    field,
+                    options.variable_naming,
+                ));
+            }
+
+            variables_impls.insert(rust_module_name.clone(), variables_impl);
+        }
+
+        if can_derive_default_for_variables(field) {
+            default_variables_modules.push(rust_module_name.clone());
+        }
+
+        module_extensions
+            .entry(rust_module_name.clone())
+            .or_default()
+            .push(render_try_from_value(&rust_module_name));
+
+        module_extensions
+            .entry(rust_module_name.clone())
+            .or_default()
+            .push(render_payload_alias(
+                &rust_module_name,
+                &operation_name,
+                field,
+            ));
+
+        if let Some(list_helpers) = render_list_helpers(&rust_module_name, &operation_name, field) {
+            module_extensions
+                .entry(rust_module_name.clone())
+                .or_default()
+                .push(list_helpers);
+        }
+
+        // An overridden fragment, or one with skipped fields, may not select the fields the
+        // helpers use.
+        if let (GraphQlFullType::Object(object), None, false) = (
+            field_type,
+            &fragment_override,
+            annotations.skips_fields_of(field_type_name),
+        ) {
+            if let Some(ordering_helpers) = render_ordering_helpers(&rust_module_name, object) {
+                module_extensions
+                    .entry(rust_module_name.clone())
+                    .or_default()
+                    .push(ordering_helpers);
+            }
+
+            if let Some(total_count_helper) = render_total_count_helper(&rust_module_name, object) {
+                module_extensions
+                    .entry(rust_module_name.clone())
+                    .or_default()
+                    .push(total_count_helper);
+            }
+
+            if let Some(state_helper) = render_state_helper(&rust_module_name, object) {
+                module_extensions
+                    .entry(rust_module_name.clone())
+                    .or_default()
+                    .push(state_helper);
+            }
+
+            if let Some(completion_helpers) = render_completion_helpers(&rust_module_name, object) {
+                module_extensions
+                    .entry(rust_module_name.clone())
+                    .or_default()
+                    .push(completion_helpers);
+            }
+
+            if let Some(diff_helper) =
+                render_diff_helper(&rust_module_name, object, &fragment_field_names)
+            {
+                module_extensions
+                    .entry(rust_module_name.clone())
+                    .or_default()
+                    .push(diff_helper);
+            }
+
+            // Subscriptions can't be executed over HTTP, so they can't be executed partially.
+            if *operation != GraphQlOperation::Subscription {
+                if let Some(partial_query) = render_partial_query(
+                    &operation_name,
+                    field,
+                    object,
+                    &fragment_field_names,
+                    &options.scalar_serde_with,
+                    &options.validated_scalars,
+                    options.serde_aliases,
+                ) {
+                    module_extensions
+                        .entry(rust_module_name.clone())
+                        .or_default()
+                        .push(partial_query);
+                }
+            }
+        }
+
+        // Subscriptions can't be executed over HTTP, so we only emit their types.
+        if *operation == GraphQlOperation::Subscription {
+            continue;
+        }
+
+        let client = if options.client_name == PRIMARY_CLIENT_NAME {
+            "self"
+        } else {
+            "self.client"
+        };
+
+        let fn_name = sanitize_name(field.name.clone()).to_snake_case();
+        let params = format!("variables: crate::graphql::{}::Variables", rust_module_name);
+        let return_type = format!(
+            "crate::Result<crate::graphql::{}::ResponseData>",
+            rust_module_name
+        );
+
+        let generated_client_impl = format!(
+            r#"
+    pub async fn {fn_name}(
+        &self,
+        {params},
+    ) -> {return_type} {{
+        {client}
+            .execute::<crate::graphql::{operation_name}>(variables)
+            .await
+    }}
+            "#
+        )
+        .trim()
+        .to_string();
+
+        generated_client_impls.push(generated_client_impl);
+        generated_blocking_impls.push(render_blocking_method(
+            "",
+            &fn_name,
+            &params,
+            &return_type,
+            "variables",
+        ));
+
+        if *operation == GraphQlOperation::Query && is_batch_lookup(field) {
+            let (method, blocking_method) =
+                render_batch_lookup(client, &rust_module_name, &operation_name, field);
+            generated_client_impls.push(method);
+            generated_blocking_impls.push(blocking_method);
+        }
+    }
+
+    emitted_graphql_modules.sort_unstable();
+
+    // Only write the documents once all of them have been validated.
+    for (module_name, contents) in &documents {
+        let mut graphql_file =
+            File::create(generated_dir.join(format!("{}.graphql", module_name)))?;
+
+        graphql_file.write_all(contents.trim().as_bytes())?;
+    }
+
+    let operation_names = fields
+        .iter()
+        .filter(|(operation, _)| *operation != GraphQlOperation::Subscription)
+        .map(|(_, field)| options.operation_name(field))
+        .collect::<Vec<_>>();
+    persisted_ids.retain(|operation_name, _| {
+        let is_known = operation_names.contains(operation_name);
+        if !is_known {
+            eprintln!(
+                "Ignoring persisted document ID for unknown operation '{}'",
+                operation_name
+            );
+        }
+        is_known
+    });
+
+    for emitted_graphql_module in &emitted_graphql_modules {
+        let mut generate_command = Command::new("graphql-client");
+
+        generate_command
+            .arg("generate")
+            .arg(format!("--schema-path={}", schema_path.display()))
+            .arg("--custom-scalars-module=crate::graphql::custom_scalars")
+            .arg("--response-derives=Debug,Serialize")
+            .arg("--variables-derives=Clone")
+            .arg("--fragments-other-variant")
+            .arg(generated_dir.join(format!("{}.graphql", emitted_graphql_module)));
+
+        let status = generate_command.status()?;
+        if !status.success() {
+            return Err(format!(
+                "graphql-client failed to generate '{}': {}",
+                emitted_graphql_module, status
+            )
+            .into());
+        }
+    }
+
+    for emitted_graphql_module in &emitted_graphql_modules {
+        let generated_file_path = generated_dir.join(format!("{}.rs", emitted_graphql_module));
+
+        let contents = fs::read_to_string(&generated_file_path)?;
+        let replaced_contents = use_runtime_types(&contents);
+        let replaced_contents = match json_value_types.get(emitted_graphql_module) {
+            Some(value_types) => replace_json_value_types(&replaced_contents, value_types),
+            None => replaced_contents,
+        };
+        let replaced_contents = replace_one_of_input_objects(&schema, &replaced_contents);
+        let replaced_contents = collapse_serde_renames(&replaced_contents);
+        let replaced_contents = if options.serde_aliases {
+            add_serde_aliases(&replaced_contents)
+        } else {
+            replaced_contents
+        };
+        let replaced_contents = rename_enum_variants(&schema, &replaced_contents);
+        let replaced_contents = add_enum_as_str(&replaced_contents);
+        let replaced_contents = add_ts_rs_derives(&options.validated_scalars, &replaced_contents);
+        let replaced_contents = document_custom_scalars(&schema, &replaced_contents);
+        let replaced_contents =
+            use_validated_scalars(&options.validated_scalars, &replaced_contents);
+        let replaced_contents =
+            apply_scalar_serde_with(&options.scalar_serde_with, &replaced_contents)?;
+        let replaced_contents = match options.extra_fields {
+            Some(keys) => add_extra_fields(&replaced_contents, keys),
+            None => replaced_contents,
+        };
+        let replaced_contents = match operation_kinds.get(emitted_graphql_module) {
+            Some(operation) => add_operation_kind(&replaced_contents, *operation),
+            None => replaced_contents,
+        };
+        let replaced_contents = match variables_impls.get(emitted_graphql_module) {
+            Some(variables_impl) => {
+                replaced_contents.replacen("impl Variables {}", variables_impl, 1)
+            }
+            None => replaced_contents,
+        };
+        let replaced_contents = if default_variables_modules.contains(emitted_graphql_module) {
+            derive_default_for_variables(&replaced_contents)
+        } else {
+            replaced_contents
+        };
+        if replaced_contents != contents {
+            fs::write(&generated_file_path, replaced_contents)?;
+        }
+    }
+
+    // Append the code we generate ourselves to the modules generated by `graphql-client`.
+    for (module_name, extensions) in &module_extensions {
+        let mut generated_file = OpenOptions::new()
+            .append(true)
+            .open(generated_dir.join(format!("{}.rs", module_name)))?;
+
+        for extension in extensions {
+            generated_file.write_all(extension.as_bytes())?;
+        }
+    }
+
+    let reexports = emitted_graphql_modules
+        .iter()
+        .map(|module_name| format!("pub use generated::{}::*;", module_name))
+        .collect::<Vec<_>>()
+        .join("\n");
+    let operations = operation_infos.into_values().collect::<Vec<_>>().join(", ");
+    // The registry and the enum are both ordered by module name, so the discriminant of a
+    // variant is the index of its operation in the registry.
+    let operation_enum =
+        render_operation_enum(&operation_enum_variants.into_values().collect::<Vec<_>>());
+    let client = render_client(
+        &options.client_name,
+        &generated_client_impls,
+        &generated_blocking_impls,
+    )
+    .trim()
+    .to_owned()
+        + "\n"
+        + render_persisted_document_ids(&persisted_ids).trim_end();
+
+    let schema_reflection = render_schema_reflection(&schema, &type_operations);
+    let validated_scalars = render_validated_scalars(&options.validated_scalars);
+
+    let written_files = match options.layout {
+        OutputLayout::Modules => {
+            let generated_module_path = options.output_dir.join("graphql/generated.rs");
+            fs::write(
+                &generated_module_path,
+                emitted_graphql_modules
+                    .iter()
+                    .map(|module_name| format!("pub mod {};", module_name))
+                    .collect::<Vec<_>>()
+                    .join("\n")
+                    + "\n",
+            )?;
+
+            // Tests of the generated code are kept in a hand-written module, which is only
+            // declared when it exists, as it isn't generated.
+            let tests = if options.output_dir.join("graphql/tests.rs").exists() {
+                "\n#[cfg(test)]\nmod tests;"
+            } else {
+                ""
+            };
+            let graphql_module_path = options.output_dir.join("graphql.rs");
+            fs::write(
+                &graphql_module_path,
+                format!(
+                    r#"
+pub mod custom_scalars;
+mod generated;{tests}
+
+// Auto-generated:
+{reexports}
+
+/// The generated operations, for using them dynamically.
+pub static OPERATIONS: &[crate::OperationInfo] = &[{operations}];
+
+{operation_enum}{validated_scalars}
+            "#
+                )
+                .trim(),
+            )?;
+
+            let client_module_path = options.output_dir.join("client_generated.rs");
+            fs::write(&client_module_path, client)?;
+
+            let schema_module_path = options.output_dir.join("schema.rs");
+            fs::write(&schema_module_path, schema_reflection)?;
+
+            let mut written_files = vec![
+                generated_module_path,
+                graphql_module_path,
+                client_module_path,
+                schema_module_path,
+            ];
+            written_files.extend(
+                emitted_graphql_modules
+                    .iter()
+                    .map(|module_name| generated_dir.join(format!("{}.rs", module_name))),
+            );
+            written_files
+        }
+        OutputLayout::SingleFile => {
+            let mut modules = Vec::new();
+            for module_name in &emitted_graphql_modules {
+                let contents =
+                    fs::read_to_string(generated_dir.join(format!("{}.rs", module_name)))?;
+                modules.push(format!("pub mod {} {{\n{}\n}}", module_name, contents));
+            }
+
+            let single_file_path = options.output_dir.join("generated.rs");
+            fs::write(
+                &single_file_path,
+                format!(
+                    r#"
+mod generated {{
+{modules}
+}}
+
+{reexports}
+
+/// The generated operations, for using them dynamically.
+pub static OPERATIONS: &[crate::OperationInfo] = &[{operations}];
+
+{operation_enum}{validated_scalars}
+
+pub(crate) mod client_generated {{
+{client}
+}}
+
+#[cfg(feature = "schema-reflection")]
+pub mod schema {{
+{schema_reflection}
+}}
+            "#,
+                    modules = modules.join("\n\n"),
+                )
+                .trim_start(),
+            )?;
+
+            vec![single_file_path]
+        }
+    };
+
+    if options.crate_path != "crate" {
+        for written_file in &written_files {
+            let contents = fs::read_to_string(written_file)?;
+            fs::write(
+                written_file,
+                relocate_crate_paths(&contents, &options.crate_path),
+            )?;
+        }
+    }
+
+    if options.format {
+        Command::new("rustfmt")
+            .arg("--edition=2021")
+            .args(&written_files)
+            .status()?;
+    }
+
+    Ok(())
+}
+
+/// Replaces the `crate` at the start of the paths in the generated code with the given
+/// crate path.
+///
+/// Only the paths in the code are replaced, along with string literals that hold nothing
+/// but a path, such as the modules of `#[serde(with = "...")]`. Comments, including doc
+/// comments, and other string literals, such as the documents of the operations, are left
+/// as they are.
+fn relocate_crate_paths(contents: &str, crate_path: &str) -> String {
+    const CRATE_PATH: &str = "crate::";
+
+    let is_name_char = |c: char| c.is_alphanumeric() || c == '_';
+    let relocate_path = |path: &str| match path.strip_prefix(CRATE_PATH) {
+        Some(rest) if !rest.is_empty() && rest.chars().all(|c| is_name_char(c) || c == ':') => {
+            format!("{crate_path}::{rest}")
+        }
+        _ => path.to_owned(),
+    };
+
+    let mut output = String::with_capacity(contents.len());
+    let mut rest = contents;
+    while let Some(c) = rest.chars().next() {
+        let follows_name = output.ends_with(is_name_char);
+
+        let verbatim_len = if rest.starts_with("//") {
+            rest.find('\n').unwrap_or(rest.len())
+        } else if rest.starts_with("/*") {
+            rest.find("*/").map_or(rest.len(), |end| end + "*/".len())
+        } else if c == '\'' {
+            // A character literal, such as `'"'`, rather than a lifetime.
+            let literal_len = if rest[1..].starts_with('\\') { 4 } else { 3 };
+            match rest.get(literal_len - 1..literal_len) {
+                Some("'") => literal_len,
+                _ => 1,
+            }
+        } else {
+            0
+        };
+        if verbatim_len > 0 {
+            output.push_str(&rest[..verbatim_len]);
+            rest = &rest[verbatim_len..];
+            continue;
+        }
+
+        let raw_string_hashes = rest
+            .strip_prefix('r')
+            .filter(|_| !follows_name)
+            .map(|raw| raw.len() - raw.trim_start_matches('#').len())
+            .filter(|hashes| rest[1 + hashes..].starts_with('"'));
+        if let Some(hashes) = raw_string_hashes {
+            let open_len = 1 + hashes + 1;
+            let close = format!("\"{}", "#".repeat(hashes));
+            let content_len = rest[open_len..]
+                .find(&close)
+                .unwrap_or(rest.len() - open_len);
+            output.push_str(&rest[..open_len]);
+            output.push_str(&relocate_path(&rest[open_len..open_len + content_len]));
+            rest = &rest[open_len + content_len..];
+            let close_len = close.len().min(rest.len());
+            output.push_str(&rest[..close_len]);
+            rest = &rest[close_len..];
+            continue;
+        }
+
+        if c == '"' {
+            let mut content_len = rest.len() - 1;
+            let mut escaped = false;
+            for (index, c) in rest[1..].char_indices() {
+                match c {
+                    '"' if !escaped => {
+                        content_len = index;
+                        break;
+                    }
+                    '\\' => escaped = !escaped,
+                    _ => escaped = false,
+                }
+            }
+            output.push('"');
+            output.push_str(&relocate_path(&rest[1..1 + content_len]));
+            rest = &rest[1 + content_len..];
+            if let Some(after_quote) = rest.strip_prefix('"') {
+                output.push('"');
+                rest = after_quote;
+            }
+            continue;
+        }
+
+        if !follows_name {
+            if let Some(after_path) = rest.strip_prefix(CRATE_PATH) {
+                output.push_str(crate_path);
+                output.push_str("::");
+                rest = after_path;
+                continue;
+            }
+        }
+
+        output.push(c);
+        rest = &rest[c.len_utf8()..];
+    }
+
+    output
+}
+
+/// Reads the fragment that overrides the generated one for the given module, if there is
+/// one.
+fn read_fragment_override(
+    overrides_dir: &Path,
+    module_name: &str,
+) -> Result<Option<String>, Box<dyn std::error::Error>> {
+    let override_path = overrides_dir.join(format!("{}.graphql", module_name));
+    match fs::read_to_string(&override_path) {
+        Ok(fragment) => Ok(Some(fragment)),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(err) => Err(format!("Failed to read '{}': {}", override_path.display(), err).into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    /// Returns the introspection JSON of a field with the given name and type.
+    fn field_json(name: &str, ty: serde_json::Value) -> serde_json::Value {
+        json!({
+            "name": name,
+            "description": null,
+            "type": ty,
+            "args": [],
+            "isDeprecated": false,
+            "deprecationReason": null,
+        })
+    }
+
+    /// Returns the introspection JSON of an object type with a `String` field of each of
+    /// the given names.
+    fn object_type_json(name: &str, field_names: &[&str]) -> serde_json::Value {
+        json!({
+            "kind": "OBJECT",
+            "name": name,
+            "description": null,
+            "fields": field_names
+                .iter()
+                .map(|field_name| field_json(field_name, json!({ "kind": "SCALAR", "name": "String" })))
+                .collect::<Vec<_>>(),
+        })
+    }
+
+    fn parse_schema(schema: serde_json::Value) -> IntrospectionSchema {
+        serde_json::from_value(schema).unwrap()
+    }
+
+    fn root_field_names(schema: &IntrospectionSchema) -> Vec<(GraphQlOperation, String)> {
+        root_fields(schema)
+            .unwrap()
+            .into_iter()
+            .map(|(operation, field)| (operation, field.name))
+            .collect()
+    }
+
+    /// Returns the introspection JSON of a nullable named type of the given kind.
+    fn named_type_json(kind: &str, name: &str) -> serde_json::Value {
+        json!({ "kind": kind, "name": name })
+    }
+
+    /// Returns the introspection JSON of an input value with the given name and type.
+    fn input_value_json(name: &str, ty: serde_json::Value) -> serde_json::Value {
+        json!({
+            "name": name,
+            "description": null,
+            "type": ty,
+            "defaultValue": null,
+        })
+    }
+
+    #[test]
+    fn root_fields_of_a_query_only_schema() {
+        let schema = parse_schema(json!({
+            "queryType": { "name": "Query" },
+            "mutationType": null,
+            "subscriptionType": null,
+            "types": [object_type_json("Query", &["tasks"])],
+        }));
+
+        assert_eq!(
+            root_field_names(&schema),
+            vec![(GraphQlOperation::Query, "tasks".to_string())]
+        );
+    }
+
+    #[test]
+    fn root_fields_of_a_query_and_mutation_schema() {
+        let schema = parse_schema(json!({
+            "queryType": { "name": "Query" },
+            "mutationType": { "name": "Mutation" },
+            "subscriptionType": null,
+            "types": [
+                object_type_json("Query", &["tasks"]),
+                object_type_json("Mutation", &["createTask"]),
+            ],
+        }));
+
+        assert_eq!(
+            root_field_names(&schema),
+            vec![
+                (GraphQlOperation::Query, "tasks".to_string()),
+                (GraphQlOperation::Mutation, "createTask".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn root_fields_of_a_schema_with_every_root_type() {
+        let schema = parse_schema(json!({
+            "queryType": { "name": "QueryRoot" },
+            "mutationType": { "name": "MutationRoot" },
+            "subscriptionType": { "name": "SubscriptionRoot" },
+            "types": [
+                object_type_json("QueryRoot", &["tasks"]),
+                object_type_json("MutationRoot", &["createTask"]),
+                object_type_json("SubscriptionRoot", &["tasksUpdated"]),
+            ],
+        }));
+
+        assert_eq!(
+            root_field_names(&schema),
+            vec![
+                (GraphQlOperation::Query, "tasks".to_string()),
+                (GraphQlOperation::Mutation, "createTask".to_string()),
+                (GraphQlOperation::Subscription, "tasksUpdated".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn root_fields_require_a_query_type() {
+        let schema = parse_schema(json!({
+            "queryType": null,
+            "mutationType": { "name": "Mutation" },
+            "subscriptionType": null,
+            "types": [object_type_json("Mutation", &["createTask"])],
+        }));
+
+        assert_eq!(
+            root_fields(&schema).unwrap_err(),
+            "Schema does not define a Query type"
+        );
+    }
+
+    #[test]
+    fn one_of_input_objects_become_enums() {
+        let schema = parse_schema(json!({
+            "queryType": { "name": "Query" },
+            "types": [{
+                "kind": "INPUT_OBJECT",
+                "name": "TaskSelector",
+                "description": null,
+                "inputFields": [
+                    input_value_json("id", named_type_json("SCALAR", "ID")),
+                    input_value_json("projectOrder", named_type_json("SCALAR", "Int")),
+                ],
+                "isOneOf": true,
+            }],
+        }));
+        let contents = r#"    #[derive(Serialize, Clone)]
+    pub struct TaskSelector {
+        pub id: Option<ID>,
+        #[serde(rename = "projectOrder")]
+        pub project_order: Option<Int>,
+    }
+    #[derive(Serialize, Clone)]
+    pub struct Variables {
+        pub selector: TaskSelector,
+    }
+"#;
+
+        assert_eq!(
+            replace_one_of_input_objects(&schema, contents),
+            r#"    #[derive(Serialize, Clone)]
+pub enum TaskSelector {
+#[serde(rename = "id")]
+Id(ID),
+#[serde(rename = "projectOrder")]
+ProjectOrder(Int),
+}
+    #[derive(Serialize, Clone)]
+    pub struct Variables {
+        pub selector: TaskSelector,
+    }
+"#
+        );
+    }
+
+    #[test]
+    fn one_of_input_object_enums_serialize_to_a_single_field() {
+        // The enum rendered for `TaskSelector` above.
+        #[derive(serde::Serialize)]
+        enum TaskSelector {
+            #[serde(rename = "id")]
+            Id(String),
+            #[serde(rename = "projectOrder")]
+            ProjectOrder(i64),
+        }
+
+        assert_eq!(
+            serde_json::to_value(TaskSelector::Id("1".to_string())).unwrap(),
+            json!({ "id": "1" })
+        );
+        assert_eq!(
+            serde_json::to_value(TaskSelector::ProjectOrder(2)).unwrap(),
+            json!({ "projectOrder": 2 })
+        );
+    }
+
+    #[test]
+    fn serde_renames_of_nested_input_objects_are_collapsed() {
+        let contents = r#"    #[derive(Serialize, Clone)]
+    pub struct CreateTaskInput {
+        pub name: String,
+        pub schedule: Option<ScheduleInput>,
+    }
+    #[derive(Serialize, Clone)]
+    pub struct ScheduleInput {
+        #[serde(rename = "dueDate")]
+        pub due_date: Option<Date>,
+        pub repeat: Option<RepeatInput>,
+    }
+    #[derive(Serialize, Clone)]
+    pub struct RepeatInput {
+        #[serde(rename = "everyDays")]
+        pub every_days: Int,
+        #[serde(rename = "skipWeekends")]
+        pub skip_weekends: Boolean,
+    }
+    #[derive(Deserialize, Debug, Serialize)]
+    pub struct ResponseData {
+        #[serde(rename = "createTask")]
+        pub create_task: ID,
+    }
+"#;
+
+        assert_eq!(
+            collapse_serde_renames(contents),
+            r#"    #[derive(Serialize, Clone)]
+    pub struct CreateTaskInput {
+        pub name: String,
+        pub schedule: Option<ScheduleInput>,
+    }
+    #[derive(Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+    pub struct ScheduleInput {
+        pub due_date: Option<Date>,
+        pub repeat: Option<RepeatInput>,
+    }
+    #[derive(Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+    pub struct RepeatInput {
+        pub every_days: Int,
+        pub skip_weekends: Boolean,
+    }
+    #[derive(Deserialize, Debug, Serialize)]
+    pub struct ResponseData {
+        #[serde(rename = "createTask")]
+        pub create_task: ID,
+    }
+"#
+        );
+    }
+
+    #[test]
+    fn collapsed_nested_input_objects_serialize_to_the_schema_names() {
+        // The structs rendered for `ScheduleInput` and `RepeatInput` above.
+        #[derive(serde::Serialize)]
+        #[serde(rename_all = "camelCase")]
+        struct ScheduleInput {
+            due_date: Option<String>,
+            repeat: Option<RepeatInput>,
+        }
+        #[derive(serde::Serialize)]
+        #[serde(rename_all = "camelCase")]
+        struct RepeatInput {
+            every_days: i64,
+            skip_weekends: bool,
+        }
+
+        let input = ScheduleInput {
+            due_date: Some("2023-09-01".to_string()),
+            repeat: Some(RepeatInput {
+                every_days: 2,
+                skip_weekends: true,
+            }),
+        };
+
+        assert_eq!(
+            serde_json::to_value(input).unwrap(),
+            json!({
+                "dueDate": "2023-09-01",
+                "repeat": { "everyDays": 2, "skipWeekends": true },
+            })
+        );
+    }
+
+    fn validated_scalars() -> BTreeMap<String, String> {
+        BTreeMap::from([(
+            "Url".to_string(),
+            "crate::graphql::custom_scalars::validate_url".to_string(),
+        )])
+    }
+
+    #[test]
+    fn validated_scalar_aliases_use_the_newtypes() {
+        let contents = r#"    type Date = crate::graphql::custom_scalars::Date;
+    type Url = crate::graphql::custom_scalars::Url;
+"#;
+
+        assert_eq!(
+            use_validated_scalars(&validated_scalars(), contents),
+            r#"    type Date = crate::graphql::custom_scalars::Date;
+    type Url = crate::graphql::validated_scalars::Url;
+"#
+        );
+    }
+
+    #[test]
+    fn validated_scalars_module_is_only_rendered_with_validated_scalars() {
+        assert_eq!(render_validated_scalars(&BTreeMap::new()), "");
+
+        let module = render_validated_scalars(&validated_scalars());
+        assert!(module.contains("pub mod validated_scalars {"));
+        assert!(module.contains("#[serde(transparent)]\n    pub struct Url("));
+        assert!(module.contains(
+            r#"#[serde(deserialize_with = "deserialize_url")] crate::graphql::custom_scalars::Url,"#
+        ));
+        assert!(module.contains("crate::graphql::custom_scalars::validate_url(&value)"));
+    }
+
+    #[test]
+    fn validated_scalar_fields_use_the_aliased_type_in_typescript() {
+        assert_eq!(
+            field_ts_type_override(&validated_scalars(), "pub links: Vec<Option<Url>>,"),
+            Some("Vec<Option<crate::graphql::custom_scalars::Url>>".to_string())
+        );
+        assert_eq!(
+            field_ts_type_override(&validated_scalars(), "pub name: String,"),
+            None
+        );
+    }
+
+    #[test]
+    fn validated_scalar_newtypes_check_their_values_when_deserialized() {
+        // The newtype rendered for `Url`, aliased to a `String`, with a validator that
+        // requires an `https` URL.
+        fn validate_url(value: &str) -> Result<(), &'static str> {
+            if value.starts_with("https://") {
+                Ok(())
+            } else {
+                Err("expected an `https` URL")
+            }
+        }
+
+        #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+        #[serde(transparent)]
+        struct Url(#[serde(deserialize_with = "deserialize_url")] String);
+
+        fn deserialize_url<'de, D: serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<String, D::Error> {
+            let value = <String as serde::Deserialize>::deserialize(deserializer)?;
+            validate_url(&value)
+                .map_err(|err| serde::de::Error::custom(format_args!("invalid `Url`: {err}")))?;
+            Ok(value)
+        }
+
+        let url: Url = serde_json::from_value(json!("https://example.com")).unwrap();
+        assert_eq!(url, Url("https://example.com".to_string()));
+        assert_eq!(
+            serde_json::to_value(url).unwrap(),
+            json!("https://example.com")
+        );
+
+        let err = serde_json::from_value::<Url>(json!("ftp://example.com")).unwrap_err();
+        assert_eq!(err.to_string(), "invalid `Url`: expected an `https` URL");
+    }
+
+    /// Returns a `task` field with the given arguments.
+    fn task_field_with_args(args: Vec<serde_json::Value>) -> Field {
+        serde_json::from_value(json!({
+            "name": "task",
+            "description": null,
+            "type": named_type_json("OBJECT", "Task"),
+            "args": args,
+            "isDeprecated": false,
+            "deprecationReason": null,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn variables_of_operations_with_required_args_do_not_derive_default() {
+        let field = task_field_with_args(vec![
+            input_value_json(
+                "id",
+                json!({ "kind": "NON_NULL", "name": null, "ofType": named_type_json("SCALAR", "ID") }),
+            ),
+            input_value_json("includeArchived", named_type_json("SCALAR", "Boolean")),
+        ]);
+
+        assert!(!can_derive_default_for_variables(&field));
+    }
+
+    #[test]
+    fn variables_of_operations_with_optional_or_no_args_derive_default() {
+        let optional_args = task_field_with_args(vec![input_value_json(
+            "includeArchived",
+            named_type_json("SCALAR", "Boolean"),
+        )]);
+        let no_args = task_field_with_args(Vec::new());
+
+        assert!(can_derive_default_for_variables(&optional_args));
+        assert!(can_derive_default_for_variables(&no_args));
+    }
+
+    #[test]
+    fn union_members_select_their_scalar_fields() {
+        let scalar = |name: &str| json!({ "kind": "SCALAR", "name": name, "description": null });
+        let object = |name: &str, fields: Vec<serde_json::Value>| json!({ "kind": "OBJECT", "name": name, "description": null, "fields": fields });
+        let schema = parse_schema(json!({
+            "queryType": { "name": "Query" },
+            "mutationType": null,
+            "subscriptionType": null,
+            "types": [
+                scalar("String"),
+                scalar("Date"),
+                object("Task", vec![
+                    field_json("name", named_type_json("SCALAR", "String")),
+                    field_json("date", named_type_json("SCALAR", "Date")),
+                    field_json("project", named_type_json("OBJECT", "Project")),
+                ]),
+                object("Project", vec![
+                    field_json("name", named_type_json("SCALAR", "String")),
+                    field_json("date", named_type_json("SCALAR", "String")),
+                ]),
+                object("Board", vec![field_json("owner", named_type_json("OBJECT", "Project"))]),
+            ],
+        }));
+        let possible_types: Vec<GraphQlTypeRef> = serde_json::from_value(json!([
+            named_type_json("OBJECT", "Task"),
+            named_type_json("OBJECT", "Project"),
+            named_type_json("OBJECT", "Board"),
+        ]))
+        .unwrap();
+
+        let selections = render_implementation_selections(&schema, &[], &possible_types);
+
+        // `date` has a different type on each member, so it's aliased, and `Board` has no
+        // scalar fields, so it's left out.
+        assert_eq!(
+            selections,
+            vec![
+                "... on Task {\n        name\n        taskDate: date\n    }",
+                "... on Project {\n        name\n        projectDate: date\n    }",
+            ]
+        );
+    }
+
+    #[test]
+    fn interface_implementations_select_their_fields_beyond_the_shared_ones() {
+        let scalar = |name: &str| json!({ "kind": "SCALAR", "name": name, "description": null });
+        let id_field = || {
+            field_json(
+                "id",
+                json!({ "kind": "NON_NULL", "name": null, "ofType": named_type_json("SCALAR", "ID") }),
+            )
+        };
+        let schema = parse_schema(json!({
+            "queryType": { "name": "Query" },
+            "mutationType": null,
+            "subscriptionType": null,
+            "types": [
+                scalar("ID"),
+                scalar("String"),
+                scalar("Int"),
+                {
+                    "kind": "INTERFACE",
+                    "name": "Node",
+                    "description": null,
+                    "fields": [id_field()],
+                    "possibleTypes": [
+                        named_type_json("OBJECT", "Task"),
+                        named_type_json("OBJECT", "Project"),
+                    ],
+                },
+                {
+                    "kind": "OBJECT",
+                    "name": "Task",
+                    "description": null,
+                    "fields": [id_field(), field_json("name", named_type_json("SCALAR", "String"))],
+                },
+                {
+                    "kind": "OBJECT",
+                    "name": "Project",
+                    "description": null,
+                    "fields": [id_field(), field_json("order", named_type_json("SCALAR", "Int"))],
+                },
+            ],
+        }));
+        let Some(GraphQlFullType::Interface(interface)) = schema
+            .types
+            .iter()
+            .find(|ty| ty.name().as_deref() == Some("Node"))
+        else {
+            panic!("`Node` isn't an interface");
+        };
+
+        let selections =
+            render_implementation_selections(&schema, &interface.fields, &interface.possible_types);
+
+        // The shared `id` is selected on the interface itself, so only the fields specific
+        // to each implementation are in its inline fragment.
+        assert_eq!(
+            selections,
+            vec![
+                "... on Task {\n        name\n    }",
+                "... on Project {\n        order\n    }",
+            ]
+        );
+    }
+
+    #[test]
+    fn interface_selections_deserialize_into_shared_fields_and_a_tagged_enum() {
+        // The types `graphql-client` generates for the selections of `Node` above, with the
+        // shared `id` in the struct and the fields of each implementation in its variant.
+        #[derive(Debug, PartialEq, serde::Deserialize)]
+        struct Node {
+            id: String,
+            #[serde(flatten)]
+            on: NodeOn,
+        }
+        #[derive(Debug, PartialEq, serde::Deserialize)]
+        #[serde(tag = "__typename")]
+        enum NodeOn {
+            Project(NodeOnProject),
+            Task(NodeOnTask),
+            #[serde(other)]
+            Unknown,
+        }
+        #[derive(Debug, PartialEq, serde::Deserialize)]
+        struct NodeOnProject {
+            order: Option<i64>,
+        }
+        #[derive(Debug, PartialEq, serde::Deserialize)]
+        struct NodeOnTask {
+            name: Option<String>,
+        }
+
+        let task: Node = serde_json::from_value(
+            json!({ "__typename": "Task", "id": "task-1", "name": "Write tests" }),
+        )
+        .unwrap();
+        let project: Node = serde_json::from_value(
+            json!({ "__typename": "Project", "id": "project-1", "order": 2 }),
+        )
+        .unwrap();
+        let note: Node =
+            serde_json::from_value(json!({ "__typename": "Note", "id": "note-1" })).unwrap();
+
+        assert_eq!(
+            task,
+            Node {
+                id: "task-1".to_string(),
+                on: NodeOn::Task(NodeOnTask {
+                    name: Some("Write tests".to_string())
+                }),
+            }
+        );
+        assert_eq!(
+            project,
+            Node {
+                id: "project-1".to_string(),
+                on: NodeOn::Project(NodeOnProject { order: Some(2) }),
+            }
+        );
+        assert_eq!(
+            note,
+            Node {
+                id: "note-1".to_string(),
+                on: NodeOn::Unknown,
+            }
+        );
+    }
+
+    #[test]
+    fn nullability_of_custom_scalars_is_kept_through_wrapping() {
+        let date_time = named_type_json("SCALAR", "DateTime");
+        let non_null =
+            |ty: serde_json::Value| json!({ "kind": "NON_NULL", "name": null, "ofType": ty });
+        let list = |ty: serde_json::Value| json!({ "kind": "LIST", "name": null, "ofType": ty });
+        let fields = [
+            ("date", date_time.clone(), "DateTime", "Option<DateTime>"),
+            (
+                "dateReq",
+                non_null(date_time.clone()),
+                "DateTime!",
+                "DateTime",
+            ),
+            (
+                "dates",
+                non_null(list(non_null(date_time))),
+                "[DateTime!]!",
+                "Vec<DateTime>",
+            ),
+        ];
+
+        for (name, ty, type_name, rust_type) in fields {
+            let field: Field = serde_json::from_value(field_json(name, ty)).unwrap();
+
+            assert_eq!(render_type_name(&field.ty), type_name, "for `{name}`");
+            assert_eq!(render_rust_type(&field.ty), rust_type, "for `{name}`");
+        }
+    }
+
+    #[test]
+    fn list_setters_accept_a_single_value() {
+        let non_null_id =
+            json!({ "kind": "NON_NULL", "name": null, "ofType": named_type_json("SCALAR", "ID") });
+        let field = task_field_with_args(vec![
+            input_value_json(
+                "taskIds",
+                json!({
+                    "kind": "NON_NULL",
+                    "name": null,
+                    "ofType": { "kind": "LIST", "name": null, "ofType": non_null_id },
+                }),
+            ),
+            input_value_json(
+                "ids",
+                json!({ "kind": "LIST", "name": null, "ofType": non_null_id }),
+            ),
+        ]);
+
+        let rendered = render_variables_impl(&field).unwrap();
+
+        assert!(rendered.contains(
+            r#"    pub fn with_task_ids(mut self, task_ids: impl Into<crate::OneOrMany<ID>>) -> Self {
+        self.task_ids = task_ids.into().into_vec();"#
+        ));
+        assert!(rendered.contains(
+            r#"    pub fn with_ids(mut self, ids: impl Into<crate::OneOrMany<ID>>) -> Self {
+        self.ids = Some(ids.into().into_vec());"#
+        ));
+    }
+
+    #[test]
+    fn default_is_added_to_the_derives_of_variables() {
+        let contents = r#"    #[derive(Serialize, Clone)]
+    pub struct Variables {
+        pub include_archived: Option<Boolean>,
+    }
+"#;
+
+        assert_eq!(
+            derive_default_for_variables(contents),
+            r#"    #[derive(Serialize, Clone, Default)]
+    pub struct Variables {
+        pub include_archived: Option<Boolean>,
+    }
+"#
+        );
+    }
+
+    /// Returns a schema with a `TaskStatus` enum of the given values.
+    fn task_status_schema(values: &[&str]) -> IntrospectionSchema {
+        parse_schema(json!({
+            "queryType": { "name": "Query" },
+            "types": [{
+                "kind": "ENUM",
+                "name": "TaskStatus",
+                "description": null,
+                "enumValues": values
+                    .iter()
+                    .map(|value| json!({
+                        "name": value,
+                        "description": null,
+                        "isDeprecated": false,
+                        "deprecationReason": null,
+                    }))
+                    .collect::<Vec<_>>(),
+            }],
+        }))
+    }
+
+    #[test]
+    fn enum_variants_are_renamed_to_pascal_case() {
+        let schema = task_status_schema(&["IN_PROGRESS", "DONE"]);
+        let contents = r#"    pub enum TaskStatus {
+        DONE,
+        IN_PROGRESS,
+        Other(String),
+    }
+    impl ::serde::Serialize for TaskStatus {
+        fn serialize<S: serde::Serializer>(&self, ser: S) -> Result<S::Ok, S::Error> {
+            ser.serialize_str(match *self {
+                TaskStatus::DONE => "DONE",
+                TaskStatus::IN_PROGRESS => "IN_PROGRESS",
+                TaskStatus::Other(ref s) => &s,
+            })
+        }
+    }
+    impl<'de> ::serde::Deserialize<'de> for TaskStatus {
+        fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let s: String = ::serde::Deserialize::deserialize(deserializer)?;
+            match s.as_str() {
+                "DONE" => Ok(TaskStatus::DONE),
+                "IN_PROGRESS" => Ok(TaskStatus::IN_PROGRESS),
+                _ => Ok(TaskStatus::Other(s)),
+            }
+        }
+    }
+"#;
+
+        assert_eq!(
+            rename_enum_variants(&schema, contents),
+            r#"    pub enum TaskStatus {
+        Done,
+        InProgress,
+        Other(String),
+    }
+    impl ::serde::Serialize for TaskStatus {
+        fn serialize<S: serde::Serializer>(&self, ser: S) -> Result<S::Ok, S::Error> {
+            ser.serialize_str(match *self {
+                TaskStatus::Done => "DONE",
+                TaskStatus::InProgress => "IN_PROGRESS",
+                TaskStatus::Other(ref s) => &s,
+            })
+        }
+    }
+    impl<'de> ::serde::Deserialize<'de> for TaskStatus {
+        fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let s: String = ::serde::Deserialize::deserialize(deserializer)?;
+            match s.as_str() {
+                "DONE" => Ok(TaskStatus::Done),
+                "IN_PROGRESS" => Ok(TaskStatus::InProgress),
+                _ => Ok(TaskStatus::Other(s)),
+            }
+        }
+    }
+"#
+        );
+    }
+
+    #[test]
+    fn enum_variants_that_would_collide_are_not_renamed() {
+        let schema = task_status_schema(&["IN_PROGRESS", "InProgress"]);
+        let contents = r#"    pub enum TaskStatus {
+        IN_PROGRESS,
+        InProgress,
+        Other(String),
+    }
+"#;
+
+        assert_eq!(rename_enum_variants(&schema, contents), contents);
+    }
+
+    #[test]
+    fn renamed_enum_variants_round_trip_through_their_values() {
+        // The enum rendered for `TaskStatus` above.
+        #[derive(Debug, PartialEq)]
+        enum TaskStatus {
+            Done,
+            InProgress,
+            Other(String),
+        }
+        impl serde::Serialize for TaskStatus {
+            fn serialize<S: serde::Serializer>(&self, ser: S) -> Result<S::Ok, S::Error> {
+                ser.serialize_str(match *self {
+                    TaskStatus::Done => "DONE",
+                    TaskStatus::InProgress => "IN_PROGRESS",
+                    TaskStatus::Other(ref s) => s,
+                })
+            }
+        }
+        impl<'de> serde::Deserialize<'de> for TaskStatus {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let s: String = serde::Deserialize::deserialize(deserializer)?;
+                match s.as_str() {
+                    "DONE" => Ok(TaskStatus::Done),
+                    "IN_PROGRESS" => Ok(TaskStatus::InProgress),
+                    _ => Ok(TaskStatus::Other(s)),
+                }
+            }
+        }
+
+        let status: TaskStatus = serde_json::from_value(json!("IN_PROGRESS")).unwrap();
+        assert_eq!(status, TaskStatus::InProgress);
+        assert_eq!(serde_json::to_value(status).unwrap(), json!("IN_PROGRESS"));
+    }
+
+    #[test]
+    fn as_str_is_added_to_enums() {
+        let contents = r#"    impl ::serde::Serialize for TaskStatus {
+        fn serialize<S: serde::Serializer>(&self, ser: S) -> Result<S::Ok, S::Error> {
+            ser.serialize_str(match *self {
+                TaskStatus::Done => "DONE",
+                TaskStatus::InProgress => "IN_PROGRESS",
+                TaskStatus::Other(ref s) => &s,
+            })
+        }
+    }"#;
+
+        assert_eq!(
+            add_enum_as_str(contents),
+            r#"    impl ::serde::Serialize for TaskStatus {
+        fn serialize<S: serde::Serializer>(&self, ser: S) -> Result<S::Ok, S::Error> {
+            ser.serialize_str(match *self {
+                TaskStatus::Done => "DONE",
+                TaskStatus::InProgress => "IN_PROGRESS",
+                TaskStatus::Other(ref s) => &s,
+            })
+        }
+    }
+impl TaskStatus {
+    /// Returns the value as the schema declares it, such as for logging or metric labels,
+    /// without allocating.
+    ///
+    /// Values that the schema didn't declare when the SDK was generated are returned as
+    /// `"__OTHER"`. Use [`TaskStatus::other_value`] to get them.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TaskStatus::Done => "DONE",
+            TaskStatus::InProgress => "IN_PROGRESS",
+            TaskStatus::Other(_) => "__OTHER",
+        }
+    }
+
+    /// Returns the value of a variant that the schema didn't declare when the SDK was
+    /// generated, or `None` for the declared variants.
+    pub fn other_value(&self) -> Option<&str> {
+        match self {
+            TaskStatus::Other(value) => Some(value),
+            _ => None,
+        }
+    }
+}
+impl From<TaskStatus> for &'static str {
+    fn from(value: TaskStatus) -> Self {
+        value.as_str()
+    }
+}"#
+        );
+    }
+
+    #[test]
+    fn as_str_is_added_to_enums_without_an_other_variant() {
+        let contents = r#"    impl ::serde::Serialize for TaskStatus {
+        fn serialize<S: serde::Serializer>(&self, ser: S) -> Result<S::Ok, S::Error> {
+            ser.serialize_str(match *self {
+                TaskStatus::Done => "DONE",
+                TaskStatus::InProgress => "IN_PROGRESS",
+            })
+        }
+    }"#;
+
+        assert_eq!(
+            add_enum_as_str(contents),
+            r#"    impl ::serde::Serialize for TaskStatus {
+        fn serialize<S: serde::Serializer>(&self, ser: S) -> Result<S::Ok, S::Error> {
+            ser.serialize_str(match *self {
+                TaskStatus::Done => "DONE",
+                TaskStatus::InProgress => "IN_PROGRESS",
+            })
+        }
+    }
+impl TaskStatus {
+    /// Returns the value as the schema declares it, such as for logging or metric labels,
+    /// without allocating.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TaskStatus::Done => "DONE",
+            TaskStatus::InProgress => "IN_PROGRESS",
+        }
+    }
+}
+impl From<TaskStatus> for &'static str {
+    fn from(value: TaskStatus) -> Self {
+        value.as_str()
+    }
+}"#
+        );
+    }
+
+    #[test]
+    fn extra_fields_are_added_to_response_types() {
+        let contents = r#"    #[derive(Serialize, Clone)]
+    pub struct Variables {
+        pub task_id: ID,
+    }
+    #[derive(Deserialize, Debug, Serialize)]
+    #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+    pub struct Task {
+        pub id: ID,
+    }
+    #[derive(Deserialize, Debug, Serialize)]
+    pub struct Node {
+        pub id: ID,
+        #[serde(flatten)]
+        pub on: NodeOn,
+    }
+"#;
+
+        assert_eq!(
+            add_extra_fields(contents, ExtraFieldKeys::SnakeCase),
+            r#"    #[derive(Serialize, Clone)]
+    pub struct Variables {
+        pub task_id: ID,
+    }
+    #[derive(Deserialize, Debug, Serialize)]
+    #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+    pub struct Task {
+        pub id: ID,
+        #[serde(flatten, deserialize_with = "crate::deserialize_snake_case_extra_fields")]
+        #[cfg_attr(feature = "ts-rs", ts(skip))]
+        pub extras: crate::ExtraFields,
+    }
+    #[derive(Deserialize, Debug, Serialize)]
+    pub struct Node {
+        pub id: ID,
+        #[serde(flatten)]
+        pub on: NodeOn,
+    }
+"#
+        );
+    }
+
+    #[test]
+    fn serde_aliases_are_added_to_renamed_response_fields() {
+        let contents = r#"    #[derive(Serialize, Clone)]
+    pub struct TaskInput {
+        #[serde(rename = "dueDate")]
+        pub due_date: Option<Date>,
+    }
+    #[derive(Deserialize, Debug, Serialize)]
+    pub struct Task {
+        #[serde(rename = "completedAt")]
+        pub completed_at: Option<DateTime>,
+        pub name: String,
+    }
+"#;
+
+        assert_eq!(
+            add_serde_aliases(contents),
+            r#"    #[derive(Serialize, Clone)]
+    pub struct TaskInput {
+        #[serde(rename = "dueDate")]
+        pub due_date: Option<Date>,
+    }
+    #[derive(Deserialize, Debug, Serialize)]
+    pub struct Task {
+        #[serde(rename = "completedAt", alias = "completed_at")]
+        pub completed_at: Option<DateTime>,
+        pub name: String,
+    }
+"#
+        );
+    }
+
+    #[test]
+    fn aliased_fields_deserialize_from_either_casing() {
+        // The `Task` rendered above.
+        #[derive(Debug, serde::Deserialize)]
+        struct Task {
+            #[serde(rename = "completedAt", alias = "completed_at")]
+            completed_at: Option<i64>,
+            name: String,
+        }
+
+        for key in ["completedAt", "completed_at"] {
+            let task: Task =
+                serde_json::from_value(json!({ key: 1693526400000_i64, "name": "Write tests" }))
+                    .unwrap();
+
+            assert_eq!(task.completed_at, Some(1693526400000), "for `{key}`");
+            assert_eq!(task.name, "Write tests");
+        }
+    }
+
+    #[test]
+    fn crate_paths_in_code_are_relocated() {
+        let contents = r#"impl crate::runtime::GraphQLQuery for Tasks {
+    type Variables = tasks::Variables;
+    type ResponseData = crate::graphql::tasks::ResponseData;
+}
+type Date = crate::graphql::custom_scalars::Date;
+let completed: Option<my_crate::Date> = None;
+"#;
+
+        assert_eq!(
+            relocate_crate_paths(contents, "crate::api"),
+            r#"impl crate::api::runtime::GraphQLQuery for Tasks {
+    type Variables = tasks::Variables;
+    type ResponseData = crate::api::graphql::tasks::ResponseData;
+}
+type Date = crate::api::graphql::custom_scalars::Date;
+let completed: Option<my_crate::Date> = None;
+"#
+        );
+    }
+
+    #[test]
+    fn crate_paths_in_path_strings_are_relocated() {
+        let contents = r##"#[serde(with = "crate::graphql::custom_scalars::iso8601_duration")]
+#[serde(with = r#"crate::graphql::custom_scalars::iso8601_duration::option"#)]
+"##;
+
+        assert_eq!(
+            relocate_crate_paths(contents, "my_app::api"),
+            r##"#[serde(with = "my_app::api::graphql::custom_scalars::iso8601_duration")]
+#[serde(with = r#"my_app::api::graphql::custom_scalars::iso8601_duration::option"#)]
+"##
+        );
+    }
+
+    #[test]
+    fn crate_paths_in_comments_and_other_strings_are_kept() {
+        let contents = r#"/// Executes the operation with [`crate::BlipsClient::execute_partial`].
+// Refers to crate::graphql::OPERATIONS.
+/* crate::graphql */
+pub const QUERY: &str = "query Crate { crate::x }";
+const MESSAGE: &str = "see \"crate::graphql\" for crate::BlipsClient";
+const QUOTE: char = '"';
+fn lifetime<'de>(value: &'de str) -> crate::Result<()> {
+"#;
+
+        assert_eq!(
+            relocate_crate_paths(contents, "my_app::api"),
+            r#"/// Executes the operation with [`crate::BlipsClient::execute_partial`].
+// Refers to crate::graphql::OPERATIONS.
+/* crate::graphql */
+pub const QUERY: &str = "query Crate { crate::x }";
+const MESSAGE: &str = "see \"crate::graphql\" for crate::BlipsClient";
+const QUOTE: char = '"';
+fn lifetime<'de>(value: &'de str) -> my_app::api::Result<()> {
+"#
+        );
+    }
+
+    /// Returns the `tasks` field of a schema whose arguments are declared in `snake_case`.
+    fn snake_case_args_field() -> Field {
+        serde_json::from_value(json!({
+            "name": "tasks",
+            "description": null,
+            "type": named_type_json("OBJECT", "Task"),
+            "args": [
+                input_value_json("project_id", named_type_json("SCALAR", "ID")),
+                input_value_json("due_date", named_type_json("SCALAR", "Date")),
+            ],
+            "isDeprecated": false,
+            "deprecationReason": null,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn variable_names_of_snake_case_args() {
+        let field = snake_case_args_field();
+        let variable_names = |variable_naming: VariableNaming| {
+            field
+                .args
+                .iter()
+                .map(|arg| variable_naming.variable_name(&arg.name))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            variable_names(VariableNaming::SnakeCase),
+            ["project_id", "due_date"]
+        );
+        assert_eq!(
+            variable_names(VariableNaming::CamelCase),
+            ["projectId", "dueDate"]
+        );
+        assert_eq!(
+            variable_names(VariableNaming::None),
+            ["project_id", "due_date"]
+        );
+    }
+
+    #[test]
+    fn operation_info_of_snake_case_args() {
+        let operation_info =
+            render_operation_info("tasks", &snake_case_args_field(), VariableNaming::None);
+
+        assert!(operation_info.contains(
+            r#"name: "project_id",
+            variable_name: "project_id","#
+        ));
+        assert!(operation_info.contains(
+            r#"name: "due_date",
+            variable_name: "due_date","#
+        ));
+    }
+}
@@ -1,331 +1,189 @@
-mod introspection_schema;
-
+use std::collections::BTreeMap;
 use std::fs::File;
-use std::io::{BufReader, Write};
-use std::process::Command;
+use std::io::BufReader;
+use std::path::PathBuf;
 
-use heck::{ToPascalCase, ToSnakeCase};
+use clap::Parser;
 
-use introspection_schema::{
-    Field, GraphQlFullType, GraphQlTypeRef, IntrospectionResponse, IntrospectionSchema,
+use blips_codegen::{
+    CodegenOptions, ExtraFieldKeys, OutputLayout, SchemaSource, VariableNaming, PRIMARY_CLIENT_NAME,
 };
 
-fn resolve_type_name(ty: &GraphQlTypeRef) -> &String {
-    match ty {
-        GraphQlTypeRef::Scalar { name }
-        | GraphQlTypeRef::Object { name }
-        | GraphQlTypeRef::Interface { name }
-        | GraphQlTypeRef::Union { name }
-        | GraphQlTypeRef::Enum { name }
-        | GraphQlTypeRef::InputObject { name } => name,
-        GraphQlTypeRef::NonNull(boxed) | GraphQlTypeRef::List(boxed) => {
-            resolve_type_name(&boxed.of_type)
-        }
-    }
+/// Generates the GraphQL client from the Blips introspection schema.
+#[derive(Debug, Parser)]
+struct Args {
+    /// The name of the client type to implement the generated operations on.
+    ///
+    /// The primary schema uses `BlipsClient`. Any other name emits a dedicated
+    /// client type that wraps a `BlipsClient` for its transport configuration,
+    /// which keeps operations from different schemas from colliding.
+    #[arg(long, default_value = PRIMARY_CLIENT_NAME)]
+    client_name: String,
+
+    /// The naming convention for the variables declared in the generated documents.
+    ///
+    /// The generated `Variables` serialize using these names. Input object fields always
+    /// use the names declared in the schema.
+    #[arg(long, value_enum, default_value_t = VariableNaming::SnakeCase)]
+    variable_naming: VariableNaming,
+
+    /// A JSON file mapping operation names to persisted document IDs.
+    ///
+    /// The IDs are assigned by the build step that registers the documents with the
+    /// server. When the client has persisted documents enabled, it sends the ID instead of
+    /// the document for the operations in this map.
+    #[arg(long)]
+    persisted_ids: Option<PathBuf>,
+
+    /// The introspection schema: a path, `-` for the standard input, or the URL of a
+    /// GraphQL endpoint to introspect with `graphql-client introspect-schema`.
+    #[arg(long, alias = "schema", default_value = "schema.json")]
+    schema_path: SchemaSource,
+
+    /// A header to send when introspecting the schema from a URL, such as
+    /// `Cookie: <COOKIE_VALUE>`. Can be repeated.
+    #[arg(long)]
+    schema_header: Vec<String>,
+
+    /// The directory to write the generated code to.
+    #[arg(long, default_value = "crates/blips/src")]
+    output_dir: PathBuf,
+
+    /// The directory containing the fragments that override the generated ones.
+    ///
+    /// An override for an operation is named after its module, such as `tasks.graphql`,
+    /// and must define the fragment the operation spreads, such as `fragment Task on Task`.
+    #[arg(long, default_value = "crates/blips/src/graphql/overrides")]
+    overrides_dir: PathBuf,
+
+    /// An SDL file of `@generate` and `@skip` annotations that choose which operations
+    /// are generated and which fields their fragments select.
+    ///
+    /// See the README for the format of the annotations and their precedence.
+    #[arg(long)]
+    annotations: Option<PathBuf>,
+
+    /// How the generated code is laid out in the output directory.
+    #[arg(long, value_enum, default_value_t = OutputLayout::Modules)]
+    layout: OutputLayout,
+
+    /// A prefix for the names of the generated operations, such as `Blips` for
+    /// `BlipsCreateBoard`.
+    ///
+    /// This keeps the generated types from colliding with those of other generated SDKs.
+    /// The prefix is also part of the operation names sent to the server.
+    #[arg(long, default_value = "")]
+    operation_name_prefix: String,
+
+    /// A suffix for the names of the generated operations, such as `Op` for
+    /// `CreateBoardOp`.
+    #[arg(long, default_value = "")]
+    operation_name_suffix: String,
+
+    /// How many levels of objects the generated fragments select.
+    ///
+    /// At `1`, fragments select the scalar fields of the type each operation returns.
+    /// Each level beyond that also selects the objects one level further down, which saves
+    /// requests for related data at the cost of larger responses.
+    #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u32).range(1..))]
+    max_depth: u32,
+
+    /// Select the object fields beyond the maximum depth as a `serde_json::Value` with the
+    /// scalar fields of their object, rather than leaving them out.
+    #[arg(long)]
+    values_beyond_max_depth: bool,
+
+    /// The path the generated code refers to the SDK and the generated modules by.
+    ///
+    /// Set this when vendoring the generated code into another crate, such as to
+    /// `crate::api`, which must export the `blips` items alongside the `graphql` module.
+    #[arg(long, default_value = "crate")]
+    crate_path: String,
+
+    /// The serde `with` module for the fields of a custom scalar, as `SCALAR=MODULE`, such
+    /// as `Duration=crate::graphql::custom_scalars::iso8601_duration`. Can be repeated.
+    ///
+    /// The module must have `serialize` and `deserialize` functions for the scalar's type,
+    /// and an `option` module nested in it with the same functions for an `Option` of it.
+    #[arg(long, value_parser = parse_scalar_serde_with)]
+    scalar_serde_with: Vec<(String, String)>,
+
+    /// The validator for the values of a custom scalar, as `SCALAR=VALIDATOR`, such as
+    /// `Url=crate::graphql::custom_scalars::validate_url`. Can be repeated.
+    ///
+    /// The scalar gets a newtype that runs the validator when it's deserialized. The
+    /// validator takes a reference to the scalar's type and returns a `Result<(), E>`,
+    /// where `E` implements `Display`.
+    #[arg(long, value_parser = parse_validated_scalar)]
+    validated_scalar: Vec<(String, String)>,
+
+    /// Also accept the `snake_case` form of each renamed field when deserializing
+    /// responses, such as `completed_at` for `completedAt`.
+    ///
+    /// This is for a server migration that returns either casing for a while.
+    #[arg(long)]
+    serde_aliases: bool,
+
+    /// Capture the fields a response type doesn't declare in an `extras` field, with their
+    /// keys as received, which is the default, or converted to `snake_case`.
+    ///
+    /// This is for forwarding fields the server added after the code was generated.
+    #[arg(long, value_enum, num_args = 0..=1, default_missing_value = "as-received")]
+    extra_fields: Option<ExtraFieldKeys>,
 }
 
-fn render_type_name(ty: &GraphQlTypeRef) -> String {
-    match ty {
-        GraphQlTypeRef::Scalar { name }
-        | GraphQlTypeRef::Object { name }
-        | GraphQlTypeRef::Interface { name }
-        | GraphQlTypeRef::Union { name }
-        | GraphQlTypeRef::Enum { name }
-        | GraphQlTypeRef::InputObject { name } => name.to_owned(),
-        GraphQlTypeRef::NonNull(boxed) => format!("{}!", render_type_name(&boxed.of_type)),
-        GraphQlTypeRef::List(boxed) => format!("[{}]", render_type_name(&boxed.of_type)),
-    }
-}
+/// Parses a `SCALAR=MODULE` pair for `--scalar-serde-with`.
+fn parse_scalar_serde_with(value: &str) -> Result<(String, String), String> {
+    let (scalar, module) = value
+        .split_once('=')
+        .ok_or_else(|| format!("expected `SCALAR=MODULE`, found `{}`", value))?;
 
-fn sanitize_name(name: String) -> String {
-    name.replace("OAuth", "Oauth")
+    Ok((scalar.to_owned(), module.to_owned()))
 }
 
-#[derive(Debug)]
-struct QueryType {
-    fields: Vec<Field>,
-}
+/// Parses a `SCALAR=VALIDATOR` pair for `--validated-scalar`.
+fn parse_validated_scalar(value: &str) -> Result<(String, String), String> {
+    let (scalar, validator) = value
+        .split_once('=')
+        .ok_or_else(|| format!("expected `SCALAR=VALIDATOR`, found `{}`", value))?;
 
-impl QueryType {
-    pub fn fields(&self) -> &[Field] {
-        &self.fields
-    }
-}
-
-impl TryFrom<&IntrospectionSchema> for QueryType {
-    type Error = &'static str;
-
-    fn try_from(schema: &IntrospectionSchema) -> Result<Self, Self::Error> {
-        let query_name = &schema.query_type.name;
-
-        let query_type = schema
-            .types
-            .iter()
-            .find_map(|ty| match ty {
-                GraphQlFullType::Object(object) if &object.name == query_name => Some(object),
-                _ => None,
-            })
-            .ok_or("No Query type found")?;
-
-        Ok(Self {
-            fields: query_type.fields.to_vec(),
-        })
-    }
-}
-
-#[derive(Debug)]
-struct MutationType {
-    fields: Vec<Field>,
-}
-
-impl MutationType {
-    pub fn from_schema(schema: &IntrospectionSchema) -> Result<Option<Self>, &'static str> {
-        let mutation_type = match &schema.mutation_type {
-            Some(mutation_type) => mutation_type,
-            None => return Ok(None),
-        };
-
-        let mutation_name = &mutation_type.name;
-
-        let mutation_type = schema
-            .types
-            .iter()
-            .find_map(|ty| match ty {
-                GraphQlFullType::Object(object) if &object.name == mutation_name => Some(object),
-                _ => None,
-            })
-            .ok_or("No Mutation type found")?;
-
-        Ok(Some(MutationType {
-            fields: mutation_type.fields.to_vec(),
-        }))
-    }
-
-    pub fn fields(&self) -> &[Field] {
-        &self.fields
-    }
-}
-
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
-enum GraphQlOperation {
-    Query,
-    Mutation,
+    Ok((scalar.to_owned(), validator.to_owned()))
 }
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let schema_file = File::open("schema.json")?;
-    let buf_reader = BufReader::new(schema_file);
-
-    let schema_query: IntrospectionResponse = serde_json::from_reader(buf_reader)?;
-
-    let schema = schema_query.data.schema;
-
-    let query = QueryType::try_from(&schema)?;
-    let mutation = MutationType::from_schema(&schema)?;
-
-    let mut emitted_graphql_modules: Vec<String> = Vec::new();
-    let mut generated_client_impls: Vec<String> = Vec::new();
-
-    let mut fields = Vec::new();
-    fields.extend(
-        query
-            .fields()
-            .iter()
-            .map(|field| (GraphQlOperation::Query, field)),
-    );
-
-    if let Some(mutation) = &mutation {
-        fields.extend(
-            mutation
-                .fields()
-                .iter()
-                .map(|field| (GraphQlOperation::Mutation, field)),
-        );
+    let args = Args::parse();
+
+    let persisted_ids: BTreeMap<String, String> = match &args.persisted_ids {
+        Some(path) => serde_json::from_reader(BufReader::new(File::open(path)?))?,
+        None => BTreeMap::new(),
+    };
+
+    let mut options = CodegenOptions::new(args.output_dir)
+        .schema_source(args.schema_path)
+        .overrides_dir(args.overrides_dir)
+        .client_name(args.client_name)
+        .variable_naming(args.variable_naming)
+        .persisted_ids(persisted_ids)
+        .layout(args.layout)
+        .operation_name_prefix(args.operation_name_prefix)
+        .operation_name_suffix(args.operation_name_suffix)
+        .max_depth(args.max_depth)
+        .values_beyond_max_depth(args.values_beyond_max_depth)
+        .crate_path(args.crate_path)
+        .serde_aliases(args.serde_aliases)
+        .extra_fields(args.extra_fields);
+    if let Some(annotations) = args.annotations {
+        options = options.annotations_path(annotations);
     }
-
-    for (operation, field) in fields {
-        let field_type_name = resolve_type_name(&field.ty);
-
-        let has_args = !field.args.is_empty();
-        let args_list = field
-            .args
-            .iter()
-            .map(|arg| {
-                format!(
-                    "${}: {}",
-                    arg.name.to_snake_case(),
-                    render_type_name(&arg.ty)
-                )
-            })
-            .collect::<Vec<_>>()
-            .join(", ");
-        let applied_args_list = field
-            .args
-            .iter()
-            .map(|arg| format!("{}: ${}", arg.name, arg.name.to_snake_case()))
-            .collect::<Vec<_>>()
-            .join(", ");
-
-        let field_type = schema
-            .types
-            .iter()
-            .find(|ty| ty.name().as_ref() == Some(&field_type_name))
-            .expect(&format!("No type found for field '{}'", field_type_name));
-
-        let mut fragment_field_names = Vec::new();
-        if let GraphQlFullType::Object(object) = &field_type {
-            for sub_field in &object.fields {
-                let sub_field_type_name = resolve_type_name(&sub_field.ty);
-
-                let sub_field_type = schema
-                    .types
-                    .iter()
-                    .find(|ty| ty.name().as_ref() == Some(&sub_field_type_name))
-                    .expect(&format!(
-                        "No type found for sub field '{}'",
-                        sub_field_type_name
-                    ));
-
-                if let GraphQlFullType::Scalar(_) = sub_field_type {
-                    fragment_field_names.push(sub_field.name.clone());
-                }
-            }
-        }
-
-        let contents = format!(
-            r#"
-{operation} {query_name}{args_list} {{
-    {field_name}{applied_args_list} {{
-        ...{fragment_name}
-    }}
-}}
-
-fragment {fragment_name} on {fragment_name} {{
-    __typename
-    {fragment_fields}
-}}
-            "#,
-            operation = match operation {
-                GraphQlOperation::Query => "query",
-                GraphQlOperation::Mutation => "mutation",
-            },
-            query_name = sanitize_name(field.name.clone()).to_pascal_case(),
-            args_list = if has_args {
-                format!("({})", args_list)
-            } else {
-                String::new()
-            },
-            applied_args_list = if has_args {
-                format!("({})", applied_args_list)
-            } else {
-                String::new()
-            },
-            field_name = field.name,
-            fragment_name = field_type_name.to_pascal_case(),
-            fragment_fields = fragment_field_names.join("\n    ")
-        );
-
-        let rust_module_name = sanitize_name(field.name.clone()).to_snake_case();
-
-        let mut graphql_file = File::create(format!(
-            "crates/blips/src/graphql/generated/{}.graphql",
-            rust_module_name
-        ))?;
-
-        graphql_file.write_all(contents.trim().as_bytes())?;
-
-        emitted_graphql_modules.push(rust_module_name.clone());
-
-        let generated_client_impl = format!(
-            r#"
-    pub async fn {fn_name}(
-        &self,
-        variables: crate::graphql::{module_name}::Variables,
-    ) -> Result<crate::graphql::{module_name}::ResponseData, reqwest::Error> {{
-        let response_body = self
-            .post_graphql::<crate::graphql::{operation_name}>(variables)
-            .await?;
-
-        Ok(response_body.data.expect("No data"))
-    }}
-            "#,
-            fn_name = sanitize_name(field.name.clone()).to_snake_case(),
-            module_name = rust_module_name,
-            operation_name = sanitize_name(field.name.clone()).to_pascal_case()
-        )
-        .trim()
-        .to_string();
-
-        generated_client_impls.push(generated_client_impl);
+    for header in args.schema_header {
+        options = options.schema_header(header);
     }
-
-    emitted_graphql_modules.sort_unstable();
-
-    for emitted_graphql_module in &emitted_graphql_modules {
-        let mut generate_command = Command::new("graphql-client");
-
-        generate_command
-            .arg("generate")
-            .arg("--schema-path=schema.json")
-            .arg("--custom-scalars-module=crate::graphql::custom_scalars")
-            .arg("--response-derives=Debug")
-            .arg(format!(
-                "crates/blips/src/graphql/generated/{}.graphql",
-                emitted_graphql_module
-            ));
-
-        generate_command.status()?;
+    for (scalar, module) in args.scalar_serde_with {
+        options = options.scalar_serde_with(scalar, module);
+    }
+    for (scalar, validator) in args.validated_scalar {
+        options = options.validated_scalar(scalar, validator);
     }
 
-    let mut generated_module_file = File::create("crates/blips/src/graphql/generated.rs")?;
-
-    generated_module_file.write_all(
-        (emitted_graphql_modules
-            .iter()
-            .map(|module_name| format!("pub mod {};", module_name))
-            .collect::<Vec<_>>()
-            .join("\n")
-            + "\n")
-            .as_bytes(),
-    )?;
-
-    let mut generated_graphql_module_file = File::create("crates/blips/src/graphql.rs")?;
-
-    generated_graphql_module_file.write_all(
-        format!(
-            r#"
-mod custom_scalars;
-mod generated;
-
-// Auto-generated:
-{}
-            "#,
-            emitted_graphql_modules
-                .iter()
-                .map(|module_name| format!("pub use generated::{}::*;", module_name))
-                .collect::<Vec<_>>()
-                .join("\n")
-        )
-        .trim()
-        .as_bytes(),
-    )?;
-
-    let mut generated_client_file = File::create("crates/blips/src/client_generated.rs")?;
-
-    generated_client_file.write_all(
-        format!(
-            r#"
-impl crate::BlipsClient {{
-    {impls}
-}}
-            "#,
-            impls = generated_client_impls.join("\n\n")
-        )
-        .trim()
-        .as_bytes(),
-    )?;
-
-    Command::new("cargo").arg("fmt").status()?;
-
-    Ok(())
+    blips_codegen::generate(&options)
 }
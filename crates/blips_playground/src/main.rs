@@ -1,17 +1,12 @@
-use std::env;
-
 use anyhow::{anyhow, Result};
-use blips::{BlipsClient, CsrfToken, SessionCookie};
+use blips::BlipsClient;
 use dotenv::dotenv;
 
 #[tokio::main]
 async fn main() -> Result<()> {
     dotenv().ok();
 
-    let session_cookie = SessionCookie::from(env::var("BLIPS_SESSION_COOKIE")?);
-    let csrf_token = CsrfToken::from(env::var("BLIPS_CSRF_TOKEN")?);
-
-    let client = BlipsClient::new(&session_cookie, &csrf_token);
+    let client = BlipsClient::from_env()?;
 
     let me = client
         .me(blips::graphql::me::Variables {})